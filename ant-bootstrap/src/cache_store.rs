@@ -61,7 +61,8 @@ impl CacheData {
     /// - Removes all the expired addrs for a peer
     /// - Removes all peers with empty addrs set
     /// - Maintains `max_addr` per peer by removing the addr with the lowest success rate
-    /// - Maintains `max_peers` in the list by removing the peer with the oldest last_seen
+    /// - Maintains `max_peers` in the list by removing the peers we never connected to first, then
+    ///   the peer with the oldest last_seen
     pub fn perform_cleanup(&mut self, cfg: &BootstrapCacheConfig) {
         self.peers.values_mut().for_each(|bootstrap_addresses| {
             bootstrap_addresses.0.retain(|bootstrap_addr| {
@@ -92,11 +93,15 @@ impl CacheData {
         self.try_remove_oldest_peers(cfg);
     }
 
-    /// Remove the oldest peers until we're under the max_peers limit
+    /// Remove the oldest peers until we're under the max_peers limit.
+    ///
+    /// The peers we never connected to, e.g. shared by other peers, are removed first, so that
+    /// they cannot push out the ones we have verified ourselves.
     pub fn try_remove_oldest_peers(&mut self, cfg: &BootstrapCacheConfig) {
         if self.peers.len() > cfg.max_peers {
             let mut peer_last_seen_map = HashMap::new();
             for (peer, addrs) in self.peers.iter() {
+                let is_unverified = addrs.0.iter().all(|addr| addr.success_count == 0);
                 let mut latest_seen = Duration::from_secs(u64::MAX);
                 for addr in addrs.0.iter() {
                     if let Ok(elapsed) = addr.last_seen.elapsed() {
@@ -108,16 +113,16 @@ impl CacheData {
                     }
                 }
                 trace!("Last seen for {peer:?} is {latest_seen:?}");
-                peer_last_seen_map.insert(*peer, latest_seen);
+                peer_last_seen_map.insert(*peer, (is_unverified, latest_seen));
             }
 
             while self.peers.len() > cfg.max_peers {
-                // find the peer with the largest last_seen
-                if let Some((&oldest_peer, last_seen)) = peer_last_seen_map
+                // find the peer with the largest last_seen, among the unverified ones if any
+                if let Some((&oldest_peer, (is_unverified, last_seen))) = peer_last_seen_map
                     .iter()
-                    .max_by_key(|(_, last_seen)| **last_seen)
+                    .max_by_key(|(_, unverified_and_last_seen)| **unverified_and_last_seen)
                 {
-                    debug!("Found the oldest peer to remove: {oldest_peer:?} with last_seen of {last_seen:?}");
+                    debug!("Found the oldest peer to remove: {oldest_peer:?} with last_seen of {last_seen:?}, unverified: {is_unverified}");
                     self.peers.remove(&oldest_peer);
                     peer_last_seen_map.remove(&oldest_peer);
                 }
@@ -136,6 +141,11 @@ impl Default for CacheData {
     }
 }
 
+/// Max number of addrs that are shared with, or accepted from, another peer.
+pub const MAX_SHARED_ADDRS: usize = 50;
+/// Max number of new addrs that a single peer can add to the cache when sharing its own.
+const MAX_NEW_SHARED_ADDRS: usize = 10;
+
 #[derive(Clone, Debug)]
 pub struct BootstrapCacheStore {
     pub(crate) cache_path: PathBuf,
@@ -253,6 +263,63 @@ impl BootstrapCacheStore {
         addrs.into_iter().map(|addr| &addr.addr)
     }
 
+    /// Get a sample of the well-scored addrs to be shared with other peers, the most reliable first.
+    /// Only a single addr per peer is shared, and only if we have successfully connected to it.
+    pub fn get_shareable_addrs(&self, max_addrs: usize) -> Vec<BootstrapAddr> {
+        let mut addrs = self
+            .data
+            .peers
+            .values()
+            .flat_map(|bootstrap_addresses| bootstrap_addresses.get_least_faulty())
+            .filter(|addr| addr.success_count > 0 && addr.is_reliable())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        addrs.sort_by(|a, b| {
+            a.failure_rate()
+                .total_cmp(&b.failure_rate())
+                .then(b.success_count.cmp(&a.success_count))
+        });
+        addrs.truncate(max_addrs.min(MAX_SHARED_ADDRS));
+        addrs
+    }
+
+    /// Add the addrs shared by another peer to the cache.
+    ///
+    /// The scores of the other peer are not trusted: the new addrs are added as unverified, and
+    /// the addrs we already know keep our own scores. A single peer can add at most
+    /// `MAX_NEW_SHARED_ADDRS` addrs.
+    pub fn sync_shared_addrs(&mut self, addrs: Vec<BootstrapAddr>) {
+        let mut added = 0;
+        for bootstrap_addr in addrs.into_iter().take(MAX_SHARED_ADDRS) {
+            if added >= MAX_NEW_SHARED_ADDRS {
+                debug!("Reached the max number of new addrs from a single peer");
+                break;
+            }
+            let Some(addr) = craft_valid_multiaddr(&bootstrap_addr.addr, false) else {
+                debug!("Ignoring invalid shared addr: {}", bootstrap_addr.addr);
+                continue;
+            };
+            let Some(peer_id) = multiaddr_get_peer_id(&addr) else {
+                continue;
+            };
+            let is_known = self
+                .data
+                .peers
+                .get(&peer_id)
+                .is_some_and(|bootstrap_addresses| bootstrap_addresses.get_addr(&addr).is_some());
+            if is_known {
+                continue;
+            }
+
+            self.data.insert(peer_id, BootstrapAddr::new(addr));
+            added += 1;
+        }
+
+        debug!("Added {added} shared addrs to the cache");
+        self.perform_cleanup();
+    }
+
     /// Update the status of an addr in the cache. The peer must be added to the cache first.
     pub fn update_addr_status(&mut self, addr: &Multiaddr, success: bool) {
         if let Some(peer_id) = multiaddr_get_peer_id(addr) {
@@ -450,4 +517,75 @@ mod tests {
         assert_eq!(peers.len(), 1);
         assert_eq!(peers[0].addr, addr);
     }
+
+    #[tokio::test]
+    async fn test_shared_addrs_are_added_unverified() {
+        let (mut store, _) = create_test_store().await;
+        let known: Multiaddr =
+            "/ip4/127.0.0.1/tcp/8080/p2p/12D3KooWRBhwfeP2Y4TCx1SM6s9rUoHhR5STiGwxBhgFRcw3UERE"
+                .parse()
+                .unwrap();
+        let shared: Multiaddr =
+            "/ip4/127.0.0.1/tcp/8081/p2p/12D3KooWD2aV1f3qkhggzEFaJ24CEFYkSdZF5RKoMLpU6CwExYV5"
+                .parse()
+                .unwrap();
+        store.add_addr(known.clone());
+
+        let mut other = BootstrapCacheStore::new(store.config.clone()).unwrap();
+        other.add_addr(known.clone());
+        other.add_addr(shared.clone());
+        for _ in 0..99 {
+            other.update_addr_status(&shared, true);
+        }
+        // Unreliable addrs are not shared.
+        other.update_addr_status(&known, false);
+        other.update_addr_status(&known, false);
+
+        let shareable = other.get_shareable_addrs(MAX_SHARED_ADDRS);
+        assert_eq!(shareable.len(), 1);
+        assert_eq!(shareable[0].addr, shared);
+        assert_eq!(shareable[0].success_count, 100);
+
+        store.sync_shared_addrs(shareable);
+        let addrs = store.get_all_addrs().collect::<Vec<_>>();
+        assert_eq!(addrs.len(), 2);
+        let shared = addrs.iter().find(|addr| addr.addr == shared).unwrap();
+        assert_eq!((shared.success_count, shared.failure_count), (0, 0));
+        let known = addrs.iter().find(|addr| addr.addr == known).unwrap();
+        assert_eq!((known.success_count, known.failure_count), (1, 0));
+    }
+
+    #[tokio::test]
+    async fn test_shared_addrs_are_capped_and_evicted_first() {
+        let (mut store, _) = create_test_store().await;
+        store.config.max_peers = MAX_NEW_SHARED_ADDRS;
+
+        let verified: Multiaddr =
+            "/ip4/127.0.0.1/tcp/8080/p2p/12D3KooWRBhwfeP2Y4TCx1SM6s9rUoHhR5STiGwxBhgFRcw3UERE"
+                .parse()
+                .unwrap();
+        store.add_addr(verified.clone());
+        store.update_addr_status(&verified, true);
+
+        let shared = (0..MAX_NEW_SHARED_ADDRS * 2)
+            .map(|port| {
+                let addr: Multiaddr = format!("/ip4/127.0.0.1/tcp/{}", 9000 + port)
+                    .parse()
+                    .unwrap();
+                let mut bootstrap_addr =
+                    BootstrapAddr::new(addr.with(Protocol::P2p(PeerId::random())));
+                bootstrap_addr.success_count = 100;
+                bootstrap_addr
+            })
+            .collect::<Vec<_>>();
+        store.sync_shared_addrs(shared);
+
+        let addrs = store.get_all_addrs().collect::<Vec<_>>();
+        assert_eq!(addrs.len(), MAX_NEW_SHARED_ADDRS);
+        assert!(addrs.iter().any(|addr| addr.addr == verified));
+        assert!(addrs
+            .iter()
+            .filter(|addr| addr.addr != verified)
+            .all(|addr| addr.success_count == 0));
+    }
 }
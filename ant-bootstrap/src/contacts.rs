@@ -10,7 +10,8 @@ use crate::{cache_store::CacheData, craft_valid_multiaddr_from_str, BootstrapAdd
 use futures::stream::{self, StreamExt};
 use libp2p::Multiaddr;
 use reqwest::Client;
-use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use url::Url;
 
 const MAINNET_CONTACTS: &[&str] = &[
@@ -22,6 +23,12 @@ const MAINNET_CONTACTS: &[&str] = &[
     "http://139.59.200.27/bootstrap_cache.json",
 ];
 
+/// The name of the environment variable that can be used to provide the hex-encoded BLS public
+/// keys, separated by commas, that contacts lists are allowed to be signed with.
+pub const ANT_CONTACTS_PUBLIC_KEYS_ENV: &str = "ANT_CONTACTS_PUBLIC_KEYS";
+/// The public keys trusted to sign contacts lists, set when building the release binaries.
+const CONTACTS_PUBLIC_KEYS_BUILD_TIME_VAL: Option<&str> = option_env!("ANT_CONTACTS_PUBLIC_KEYS");
+
 /// The client fetch timeout
 const FETCH_TIMEOUT_SECS: u64 = 30;
 /// Maximum number of endpoints to fetch at a time
@@ -39,6 +46,10 @@ pub struct ContactsFetcher {
     request_client: Client,
     /// Ignore PeerId in the multiaddr if not present. This is only useful for fetching nat detection contacts
     ignore_peer_id: bool,
+    /// The keys that a signed contacts list has to be signed with.
+    trusted_keys: Vec<bls::PublicKey>,
+    /// Accept lists that are not signed. This should only be used for local and test networks.
+    allow_unsigned: bool,
 }
 
 impl ContactsFetcher {
@@ -48,6 +59,9 @@ impl ContactsFetcher {
     }
 
     /// Create a new struct with the provided endpoints
+    ///
+    /// Signed lists are verified against the keys from `ANT_CONTACTS_PUBLIC_KEYS`. Unsigned lists
+    /// are rejected.
     pub fn with_endpoints(endpoints: Vec<Url>) -> Result<Self> {
         let request_client = Client::builder()
             .timeout(Duration::from_secs(FETCH_TIMEOUT_SECS))
@@ -58,6 +72,8 @@ impl ContactsFetcher {
             endpoints,
             request_client,
             ignore_peer_id: false,
+            trusted_keys: default_trusted_keys()?,
+            allow_unsigned: false,
         })
     }
 
@@ -85,6 +101,19 @@ impl ContactsFetcher {
         self.ignore_peer_id = ignore_peer_id;
     }
 
+    /// Trust an additional key to sign contacts lists.
+    pub fn insert_trusted_key(&mut self, key: bls::PublicKey) {
+        self.trusted_keys.push(key);
+    }
+
+    /// Accept lists that are not signed, i.e. plain text lists and bootstrap cache files.
+    ///
+    /// This must only be used for local and test networks, as anyone able to tamper with an
+    /// endpoint could then point every new peer at nodes of their choosing.
+    pub fn allow_unsigned_contacts(&mut self, allow_unsigned: bool) {
+        self.allow_unsigned = allow_unsigned;
+    }
+
     /// Fetch the list of bootstrap addresses from all configured endpoints
     pub async fn fetch_bootstrap_addresses(&self) -> Result<Vec<BootstrapAddr>> {
         Ok(self
@@ -110,15 +139,7 @@ impl ContactsFetcher {
                     "Attempting to fetch bootstrap addresses from endpoint: {}",
                     endpoint
                 );
-                (
-                    Self::fetch_from_endpoint(
-                        self.request_client.clone(),
-                        &endpoint,
-                        self.ignore_peer_id,
-                    )
-                    .await,
-                    endpoint,
-                )
+                (self.fetch_from_endpoint(&endpoint).await, endpoint)
             })
             .buffer_unordered(MAX_CONCURRENT_FETCHES);
 
@@ -159,24 +180,29 @@ impl ContactsFetcher {
     }
 
     /// Fetch the list of multiaddrs from a single endpoint
-    async fn fetch_from_endpoint(
-        request_client: Client,
-        endpoint: &Url,
-        ignore_peer_id: bool,
-    ) -> Result<Vec<Multiaddr>> {
+    async fn fetch_from_endpoint(&self, endpoint: &Url) -> Result<Vec<Multiaddr>> {
         info!("Fetching peers from endpoint: {endpoint}");
         let mut retries = 0;
 
         let bootstrap_addresses = loop {
-            let response = request_client.get(endpoint.clone()).send().await;
+            let response = self.request_client.get(endpoint.clone()).send().await;
 
             match response {
                 Ok(response) => {
                     if response.status().is_success() {
                         let text = response.text().await?;
 
-                        match Self::try_parse_response(&text, ignore_peer_id) {
+                        match self.try_parse_response(&text) {
                             Ok(addrs) => break addrs,
+                            // Fetching the same list again would not change the outcome.
+                            Err(
+                                err @ (Error::UnsignedContacts
+                                | Error::InvalidContactsSignature
+                                | Error::ContactsExpired(_)),
+                            ) => {
+                                error!("Rejected the contacts list from {endpoint}: {err}");
+                                return Err(err);
+                            }
                             Err(err) => {
                                 warn!("Failed to parse response with err: {err:?}");
                                 retries += 1;
@@ -220,7 +246,26 @@ impl ContactsFetcher {
     }
 
     /// Try to parse a response from an endpoint
-    fn try_parse_response(response: &str, ignore_peer_id: bool) -> Result<Vec<Multiaddr>> {
+    fn try_parse_response(&self, response: &str) -> Result<Vec<Multiaddr>> {
+        if let Ok(signed_contacts) = serde_json::from_str::<SignedContacts>(response) {
+            signed_contacts.verify(&self.trusted_keys, SystemTime::now())?;
+            let bootstrap_addresses = signed_contacts
+                .contacts
+                .iter()
+                .filter_map(|str| craft_valid_multiaddr_from_str(str, self.ignore_peer_id))
+                .collect::<Vec<_>>();
+            info!(
+                "Successfully parsed {} valid bootstrap addrs from a signed list",
+                bootstrap_addresses.len()
+            );
+            return Ok(bootstrap_addresses);
+        }
+
+        if !self.allow_unsigned {
+            return Err(Error::UnsignedContacts);
+        }
+
+        let ignore_peer_id = self.ignore_peer_id;
         match serde_json::from_str::<CacheData>(response) {
             Ok(json_endpoints) => {
                 info!(
@@ -268,6 +313,110 @@ impl ContactsFetcher {
     }
 }
 
+/// A list of contacts signed by its publisher, along with the time after which it must no longer
+/// be used.
+///
+/// It is published as JSON, e.g.
+/// `{"contacts":["/ip4/1.2.3.4/udp/1200/quic-v1/p2p/12D3K..."],"expiry":1735689600,"signature":"a1b2..."}`
+/// where `expiry` is in seconds since the Unix epoch and `signature` is the hex-encoded BLS
+/// signature over [`SignedContacts::bytes_for_signature`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedContacts {
+    /// The multiaddrs of the contacts.
+    pub contacts: Vec<String>,
+    /// Seconds since the Unix epoch after which the list is no longer valid.
+    pub expiry: u64,
+    /// Hex-encoded signature over the contacts and the expiry.
+    pub signature: String,
+}
+
+impl SignedContacts {
+    /// Sign a list of contacts, valid until `expiry`.
+    pub fn sign(contacts: Vec<String>, expiry: SystemTime, secret_key: &bls::SecretKey) -> Self {
+        let expiry = expiry
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let signature = secret_key.sign(Self::bytes_for_signature(&contacts, expiry));
+        Self {
+            contacts,
+            expiry,
+            signature: hex::encode(signature.to_bytes()),
+        }
+    }
+
+    /// The bytes that are signed: each contact followed by a newline, then the expiry as
+    /// big-endian bytes.
+    pub fn bytes_for_signature(contacts: &[String], expiry: u64) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for contact in contacts {
+            bytes.extend_from_slice(contact.as_bytes());
+            bytes.push(b'\n');
+        }
+        bytes.extend_from_slice(&expiry.to_be_bytes());
+        bytes
+    }
+
+    /// Check that the list has not expired and has been signed by one of the trusted keys.
+    pub fn verify(&self, trusted_keys: &[bls::PublicKey], now: SystemTime) -> Result<()> {
+        let now = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        if now >= self.expiry {
+            return Err(Error::ContactsExpired(self.expiry));
+        }
+
+        let bytes = Self::bytes_for_signature(&self.contacts, self.expiry);
+        if is_signed_by_trusted_key(&self.signature, &bytes, trusted_keys) {
+            Ok(())
+        } else {
+            Err(Error::InvalidContactsSignature)
+        }
+    }
+}
+
+/// Check that the hex-encoded signature over `bytes` is from one of the trusted keys.
+pub fn is_signed_by_trusted_key(
+    signature: &str,
+    bytes: &[u8],
+    trusted_keys: &[bls::PublicKey],
+) -> bool {
+    let Some(signature) = hex::decode(signature)
+        .ok()
+        .and_then(|bytes| <[u8; bls::SIG_SIZE]>::try_from(bytes).ok())
+        .and_then(|bytes| bls::Signature::from_bytes(bytes).ok())
+    else {
+        return false;
+    };
+    trusted_keys.iter().any(|key| key.verify(&signature, bytes))
+}
+
+/// Parse a comma-separated list of hex-encoded public keys.
+///
+/// Fails if any of the keys cannot be parsed, rather than silently trusting fewer keys.
+pub fn parse_trusted_keys(keys: &str) -> Result<Vec<bls::PublicKey>> {
+    keys.split(',')
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .map(|key| {
+            bls::PublicKey::from_hex(key).map_err(|err| {
+                error!("Failed to parse the trusted public key {key}: {err:?}");
+                Error::InvalidTrustedKey(key.to_string())
+            })
+        })
+        .collect()
+}
+
+/// The keys trusted to sign contacts lists, read from `ANT_CONTACTS_PUBLIC_KEYS` at runtime, or
+/// else at build time.
+fn default_trusted_keys() -> Result<Vec<bls::PublicKey>> {
+    let keys = match std::env::var(ANT_CONTACTS_PUBLIC_KEYS_ENV) {
+        Ok(keys) => keys,
+        Err(_) => CONTACTS_PUBLIC_KEYS_BUILD_TIME_VAL
+            .unwrap_or_default()
+            .to_string(),
+    };
+    parse_trusted_keys(&keys)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -291,6 +440,7 @@ mod tests {
             .await;
 
         let mut fetcher = ContactsFetcher::new().unwrap();
+        fetcher.allow_unsigned_contacts(true);
         fetcher.endpoints = vec![mock_server.uri().parse().unwrap()];
 
         let addrs = fetcher.fetch_bootstrap_addresses().await.unwrap();
@@ -330,6 +480,7 @@ mod tests {
             .await;
 
         let mut fetcher = ContactsFetcher::new().unwrap();
+        fetcher.allow_unsigned_contacts(true);
         fetcher.endpoints = vec![
             mock_server1.uri().parse().unwrap(),
             mock_server2.uri().parse().unwrap(),
@@ -360,6 +511,7 @@ mod tests {
             .await;
 
         let mut fetcher = ContactsFetcher::new().unwrap();
+        fetcher.allow_unsigned_contacts(true);
         fetcher.endpoints = vec![mock_server.uri().parse().unwrap()];
 
         let addrs = fetcher.fetch_bootstrap_addresses().await.unwrap();
@@ -383,6 +535,7 @@ mod tests {
             .await;
 
         let mut fetcher = ContactsFetcher::new().unwrap();
+        fetcher.allow_unsigned_contacts(true);
         fetcher.endpoints = vec![mock_server.uri().parse().unwrap()];
 
         let addrs = fetcher.fetch_bootstrap_addresses().await.unwrap();
@@ -395,6 +548,119 @@ mod tests {
         assert_eq!(addrs[0].addr, addr);
     }
 
+    #[tokio::test]
+    async fn test_unsigned_contacts_rejected() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "/ip4/127.0.0.1/tcp/8080/p2p/12D3KooWD2aV1f3qkhggzEFaJ24CEFYkSdZF5RKoMLpU6CwExYV5",
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let mut fetcher = ContactsFetcher::new().unwrap();
+        fetcher.endpoints = vec![mock_server.uri().parse().unwrap()];
+        fetcher.trusted_keys = vec![];
+        let addrs = fetcher.fetch_bootstrap_addresses().await.unwrap();
+        assert!(addrs.is_empty());
+
+        fetcher.insert_trusted_key(bls::SecretKey::random().public_key());
+        let addrs = fetcher.fetch_bootstrap_addresses().await.unwrap();
+        assert!(addrs.is_empty());
+
+        fetcher.allow_unsigned_contacts(true);
+        let addrs = fetcher.fetch_bootstrap_addresses().await.unwrap();
+        assert_eq!(addrs.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_trusted_keys() {
+        let first = bls::SecretKey::random().public_key();
+        let second = bls::SecretKey::random().public_key();
+
+        let keys =
+            parse_trusted_keys(&format!(" {}, {},", first.to_hex(), second.to_hex())).unwrap();
+        assert_eq!(keys, vec![first, second]);
+        assert!(parse_trusted_keys("").unwrap().is_empty());
+
+        assert!(matches!(
+            parse_trusted_keys(&format!("{},not-a-key", first.to_hex())),
+            Err(Error::InvalidTrustedKey(key)) if key == "not-a-key"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_signed_contacts() {
+        let secret_key = bls::SecretKey::random();
+        let contact =
+            "/ip4/127.0.0.1/tcp/8080/p2p/12D3KooWD2aV1f3qkhggzEFaJ24CEFYkSdZF5RKoMLpU6CwExYV5";
+        let signed = SignedContacts::sign(
+            vec![contact.to_string()],
+            SystemTime::now() + Duration::from_secs(3600),
+            &secret_key,
+        );
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(serde_json::to_string(&signed).unwrap()),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let mut fetcher = ContactsFetcher::new().unwrap();
+        fetcher.endpoints = vec![mock_server.uri().parse().unwrap()];
+        fetcher.insert_trusted_key(secret_key.public_key());
+
+        let addrs = fetcher.fetch_bootstrap_addresses().await.unwrap();
+        assert_eq!(addrs.len(), 1);
+        assert_eq!(addrs[0].addr, contact.parse::<Multiaddr>().unwrap());
+    }
+
+    #[test]
+    fn test_signed_contacts_verification() {
+        let secret_key = bls::SecretKey::random();
+        let trusted_keys = [secret_key.public_key()];
+        let now = SystemTime::now();
+        let signed = SignedContacts::sign(
+            vec!["/ip4/127.0.0.1/udp/8080/quic-v1".to_string()],
+            now + Duration::from_secs(3600),
+            &secret_key,
+        );
+        assert!(signed.verify(&trusted_keys, now).is_ok());
+
+        // Untrusted key
+        assert!(matches!(
+            signed.verify(&[bls::SecretKey::random().public_key()], now),
+            Err(Error::InvalidContactsSignature)
+        ));
+
+        // Expired
+        assert!(matches!(
+            signed.verify(&trusted_keys, now + Duration::from_secs(3601)),
+            Err(Error::ContactsExpired(_))
+        ));
+
+        // Tampered contacts or expiry
+        let mut tampered = signed.clone();
+        tampered
+            .contacts
+            .push("/ip4/6.6.6.6/udp/8080/quic-v1".to_string());
+        assert!(matches!(
+            tampered.verify(&trusted_keys, now),
+            Err(Error::InvalidContactsSignature)
+        ));
+        let mut tampered = signed;
+        tampered.expiry += 1;
+        assert!(matches!(
+            tampered.verify(&trusted_keys, now),
+            Err(Error::InvalidContactsSignature)
+        ));
+    }
+
     #[tokio::test]
     async fn test_custom_endpoints() {
         let endpoints = vec!["http://example.com".parse().unwrap()];
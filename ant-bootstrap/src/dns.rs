@@ -0,0 +1,313 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::{craft_valid_multiaddr, Error, Result};
+use async_trait::async_trait;
+use hickory_resolver::{
+    config::{NameServerConfigGroup, ResolverConfig, ResolverOpts},
+    TokioResolver,
+};
+use libp2p::{multiaddr::Protocol, Multiaddr};
+use std::net::SocketAddr;
+
+/// The name of the environment variable that can be used to resolve `/dnsaddr/` addresses with
+/// a specific name server, e.g. `127.0.0.1:5353`, instead of the one configured by the system.
+pub const ANT_DNS_RESOLVER_ENV: &str = "ANT_DNS_RESOLVER";
+
+/// The prefix of the TXT records that hold the addresses of a `/dnsaddr/` domain.
+const DNSADDR_TXT_PREFIX: &str = "dnsaddr=";
+/// Max number of nested `/dnsaddr/` lookups, which protects against loops between records.
+const MAX_DNSADDR_DEPTH: usize = 4;
+/// Max number of addresses resolved from a single `/dnsaddr/` address.
+const MAX_DNSADDR_ADDRS: usize = 64;
+
+/// Looks up the TXT records of a domain.
+#[async_trait]
+pub trait TxtResolver: Send + Sync {
+    /// Returns the TXT records of the domain, each record being the concatenation of its
+    /// character strings.
+    async fn txt_records(&self, domain: &str) -> Result<Vec<String>>;
+}
+
+/// A [`TxtResolver`] using the system DNS configuration, or the name server from
+/// `ANT_DNS_RESOLVER` if set.
+pub struct DnsTxtResolver {
+    resolver: TokioResolver,
+}
+
+impl DnsTxtResolver {
+    pub fn new() -> Result<Self> {
+        let resolver = match std::env::var(ANT_DNS_RESOLVER_ENV) {
+            Ok(name_server) => {
+                let name_server: SocketAddr = name_server.parse().map_err(|_| {
+                    Error::DnsLookup(format!("Invalid {ANT_DNS_RESOLVER_ENV}: {name_server}"))
+                })?;
+                info!("Resolving dnsaddr addresses with {name_server}");
+                Self::with_name_server(name_server).resolver
+            }
+            Err(_) => TokioResolver::tokio_from_system_conf()
+                .map_err(|err| Error::DnsLookup(err.to_string()))?,
+        };
+        Ok(Self { resolver })
+    }
+
+    /// Create a resolver that only queries the provided name server.
+    pub fn with_name_server(name_server: SocketAddr) -> Self {
+        let config = ResolverConfig::from_parts(
+            None,
+            vec![],
+            NameServerConfigGroup::from_ips_clear(&[name_server.ip()], name_server.port(), true),
+        );
+        Self {
+            resolver: TokioResolver::tokio(config, ResolverOpts::default()),
+        }
+    }
+}
+
+#[async_trait]
+impl TxtResolver for DnsTxtResolver {
+    async fn txt_records(&self, domain: &str) -> Result<Vec<String>> {
+        let lookup = self
+            .resolver
+            .txt_lookup(domain)
+            .await
+            .map_err(|err| Error::DnsLookup(format!("{domain}: {err}")))?;
+        Ok(lookup
+            .iter()
+            .map(|txt| {
+                txt.txt_data()
+                    .iter()
+                    .map(|data| String::from_utf8_lossy(data))
+                    .collect::<String>()
+            })
+            .collect())
+    }
+}
+
+/// Returns true if the address has to be resolved through DNS before it can be dialed.
+pub fn is_dnsaddr(addr: &Multiaddr) -> bool {
+    matches!(addr.iter().next(), Some(Protocol::Dnsaddr(_)))
+}
+
+/// Resolve a `/dnsaddr/<domain>` address into the addresses listed in the
+/// `_dnsaddr.<domain>` TXT records, following nested `/dnsaddr/` records.
+///
+/// If the address ends with a peer ID, only the addresses of that peer are kept, including the
+/// ones listed by nested records. The resolved addresses are crafted the same way as the ones
+/// provided on the command line.
+pub async fn resolve_dnsaddr(resolver: &dyn TxtResolver, addr: &Multiaddr) -> Vec<Multiaddr> {
+    let mut resolved = vec![];
+    let mut to_resolve = vec![(addr.clone(), 0, crate::multiaddr_get_peer_id(addr))];
+
+    while let Some((addr, depth, peer_id)) = to_resolve.pop() {
+        let Some(Protocol::Dnsaddr(domain)) = addr.iter().next() else {
+            if peer_id.is_some() && crate::multiaddr_get_peer_id(&addr) != peer_id {
+                continue;
+            }
+            if let Some(addr) = craft_valid_multiaddr(&addr, false) {
+                if !resolved.contains(&addr) {
+                    resolved.push(addr);
+                }
+            } else {
+                warn!("Invalid multiaddress format from dnsaddr record: {addr}");
+            }
+            continue;
+        };
+        if depth >= MAX_DNSADDR_DEPTH {
+            warn!("Max dnsaddr depth reached while resolving {addr}, skipping");
+            continue;
+        }
+
+        let records = match resolver.txt_records(&format!("_dnsaddr.{domain}")).await {
+            Ok(records) => records,
+            Err(err) => {
+                warn!("Failed to resolve {addr}: {err}");
+                continue;
+            }
+        };
+
+        for record in records {
+            let Some(record) = record.strip_prefix(DNSADDR_TXT_PREFIX) else {
+                continue;
+            };
+            let Ok(record_addr) = record.parse::<Multiaddr>() else {
+                warn!("Invalid multiaddress in the dnsaddr record of {domain}: {record}");
+                continue;
+            };
+            // The peer requested by the first address applies to every resolved address, while
+            // a nested record can only narrow the search down to a peer of its own.
+            let record_peer_id = peer_id.or_else(|| crate::multiaddr_get_peer_id(&record_addr));
+            to_resolve.push((record_addr, depth + 1, record_peer_id));
+        }
+
+        if resolved.len() + to_resolve.len() > MAX_DNSADDR_ADDRS {
+            warn!("Too many addresses resolved from {addr}, truncating");
+            to_resolve.truncate(MAX_DNSADDR_ADDRS.saturating_sub(resolved.len()));
+        }
+    }
+
+    info!("Resolved {} addrs from {addr}", resolved.len());
+    resolved
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Serves TXT records from memory.
+    #[derive(Default)]
+    pub(crate) struct StubTxtResolver {
+        pub(crate) records: HashMap<String, Vec<String>>,
+    }
+
+    impl StubTxtResolver {
+        pub(crate) fn insert(&mut self, domain: &str, records: &[&str]) {
+            let _ = self.records.insert(
+                domain.to_string(),
+                records.iter().map(|record| record.to_string()).collect(),
+            );
+        }
+    }
+
+    #[async_trait]
+    impl TxtResolver for StubTxtResolver {
+        async fn txt_records(&self, domain: &str) -> Result<Vec<String>> {
+            self.records
+                .get(domain)
+                .cloned()
+                .ok_or_else(|| Error::DnsLookup(format!("{domain} not found")))
+        }
+    }
+
+    const PEER_1: &str = "12D3KooWRBhwfeP2Y4TCx1SM6s9rUoHhR5STiGwxBhgFRcw3UERE";
+    const PEER_2: &str = "12D3KooWD2aV1f3qkhggzEFaJ24CEFYkSdZF5RKoMLpU6CwExYV5";
+
+    #[tokio::test]
+    async fn dnsaddr_should_resolve_nested_records() {
+        let mut resolver = StubTxtResolver::default();
+        resolver.insert(
+            "_dnsaddr.bootstrap.example.com",
+            &[
+                &format!("dnsaddr=/ip4/10.0.0.1/udp/1200/quic-v1/p2p/{PEER_1}"),
+                "dnsaddr=/dnsaddr/eu.bootstrap.example.com",
+                "v=spf1 -all",
+            ],
+        );
+        resolver.insert(
+            "_dnsaddr.eu.bootstrap.example.com",
+            &[&format!(
+                "dnsaddr=/ip4/10.0.0.2/udp/1200/quic-v1/p2p/{PEER_2}"
+            )],
+        );
+
+        let addr: Multiaddr = "/dnsaddr/bootstrap.example.com".parse().unwrap();
+        assert!(is_dnsaddr(&addr));
+        let mut addrs = resolve_dnsaddr(&resolver, &addr).await;
+        addrs.sort();
+
+        let mut expected: Vec<Multiaddr> = vec![
+            format!("/ip4/10.0.0.1/udp/1200/quic-v1/p2p/{PEER_1}")
+                .parse()
+                .unwrap(),
+            format!("/ip4/10.0.0.2/udp/1200/quic-v1/p2p/{PEER_2}")
+                .parse()
+                .unwrap(),
+        ];
+        expected.sort();
+        assert_eq!(addrs, expected);
+
+        // Only the addresses of the requested peer are kept.
+        let addr: Multiaddr = format!("/dnsaddr/bootstrap.example.com/p2p/{PEER_2}")
+            .parse()
+            .unwrap();
+        let addrs = resolve_dnsaddr(&resolver, &addr).await;
+        let expected: Multiaddr = format!("/ip4/10.0.0.2/udp/1200/quic-v1/p2p/{PEER_2}")
+            .parse()
+            .unwrap();
+        assert_eq!(addrs, vec![expected]);
+    }
+
+    #[tokio::test]
+    async fn dnsaddr_should_filter_nested_records_by_peer() {
+        let mut resolver = StubTxtResolver::default();
+        resolver.insert(
+            "_dnsaddr.bootstrap.example.com",
+            &["dnsaddr=/dnsaddr/eu.bootstrap.example.com"],
+        );
+        resolver.insert(
+            "_dnsaddr.eu.bootstrap.example.com",
+            &[
+                &format!("dnsaddr=/ip4/10.0.0.1/udp/1200/quic-v1/p2p/{PEER_1}"),
+                &format!("dnsaddr=/ip4/10.0.0.2/udp/1200/quic-v1/p2p/{PEER_2}"),
+            ],
+        );
+
+        let addr: Multiaddr = format!("/dnsaddr/bootstrap.example.com/p2p/{PEER_1}")
+            .parse()
+            .unwrap();
+        let addrs = resolve_dnsaddr(&resolver, &addr).await;
+        let expected: Multiaddr = format!("/ip4/10.0.0.1/udp/1200/quic-v1/p2p/{PEER_1}")
+            .parse()
+            .unwrap();
+        assert_eq!(addrs, vec![expected]);
+    }
+
+    #[tokio::test]
+    async fn dnsaddr_loops_should_terminate() {
+        let mut resolver = StubTxtResolver::default();
+        resolver.insert(
+            "_dnsaddr.a.example.com",
+            &["dnsaddr=/dnsaddr/b.example.com"],
+        );
+        resolver.insert(
+            "_dnsaddr.b.example.com",
+            &["dnsaddr=/dnsaddr/a.example.com"],
+        );
+
+        let addr: Multiaddr = "/dnsaddr/a.example.com".parse().unwrap();
+        assert!(resolve_dnsaddr(&resolver, &addr).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn resolved_addrs_should_keep_their_cached_scores() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config = crate::BootstrapCacheConfig::empty()
+            .with_cache_path(temp_dir.path().join("cache.json"));
+
+        let known: Multiaddr = format!("/ip4/10.0.0.1/udp/1200/quic-v1/p2p/{PEER_1}")
+            .parse()
+            .unwrap();
+        let unknown: Multiaddr = format!("/ip4/10.0.0.2/udp/1200/quic-v1/p2p/{PEER_2}")
+            .parse()
+            .unwrap();
+        let mut store = crate::BootstrapCacheStore::new(config.clone()).unwrap();
+        store.add_addr(known.clone());
+        store.update_addr_status(&known, true);
+        store.update_addr_status(&known, false);
+        store.write().unwrap();
+
+        let mut resolver = StubTxtResolver::default();
+        resolver.insert(
+            "_dnsaddr.bootstrap.example.com",
+            &[&format!("dnsaddr={known}"), &format!("dnsaddr={unknown}")],
+        );
+
+        let peers_args = crate::PeersArgs::default();
+        let dnsaddr: Multiaddr = "/dnsaddr/bootstrap.example.com".parse().unwrap();
+        let addrs = peers_args
+            .resolve_dnsaddrs_with(&resolver, &[dnsaddr], Some(config))
+            .await;
+        assert_eq!(addrs.len(), 2);
+
+        let known = addrs.iter().find(|addr| addr.addr == known).unwrap();
+        assert_eq!((known.success_count, known.failure_count), (2, 1));
+        let unknown = addrs.iter().find(|addr| addr.addr == unknown).unwrap();
+        assert_eq!((unknown.success_count, unknown.failure_count), (0, 0));
+    }
+}
@@ -22,6 +22,16 @@ pub enum Error {
     FailedToObtainAddrsFromUrl(String, usize),
     #[error("Failed to parse Url")]
     FailedToParseUrl,
+    #[error("The contacts list is not signed, and unsigned lists are not allowed")]
+    UnsignedContacts,
+    #[error("The signature of the contacts list does not match any trusted key")]
+    InvalidContactsSignature,
+    #[error("The contacts list expired at {0}")]
+    ContactsExpired(u64),
+    #[error("Invalid trusted public key: {0}")]
+    InvalidTrustedKey(String),
+    #[error("DNS lookup failed: {0}")]
+    DnsLookup(String),
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
     #[error("JSON error: {0}")]
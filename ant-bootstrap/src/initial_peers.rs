@@ -7,8 +7,10 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::{
+    cache_store::CacheData,
     config::cache_file_name,
     craft_valid_multiaddr, craft_valid_multiaddr_from_str,
+    dns::{is_dnsaddr, resolve_dnsaddr, DnsTxtResolver, TxtResolver},
     error::{Error, Result},
     BootstrapAddr, BootstrapCacheConfig, BootstrapCacheStore, ContactsFetcher,
 };
@@ -38,6 +40,9 @@ pub struct PeersArgs {
     ///
     /// This argument can be provided multiple times to connect to multiple peers.
     ///
+    /// A '/dnsaddr/<domain>' multiaddr is resolved into the peers listed in the TXT records of
+    /// '_dnsaddr.<domain>', which allows the bootstrap peers to be rotated through DNS.
+    ///
     /// Alternatively, the `ANT_PEERS` environment variable can provide a comma-separated peer
     /// list.
     #[clap(
@@ -49,8 +54,10 @@ pub struct PeersArgs {
     pub addrs: Vec<Multiaddr>,
     /// Specify the URL to fetch the network contacts from.
     ///
-    /// The URL can point to a text file containing Multiaddresses separated by newline character, or
-    /// a bootstrap cache JSON file.
+    /// The URL can point to a signed contacts list, verified against the keys provided through
+    /// the `ANT_CONTACTS_PUBLIC_KEYS` environment variable. When `--testnet` is used, it can also
+    /// point to a text file containing Multiaddresses separated by newline character, or a
+    /// bootstrap cache JSON file.
     #[clap(long, conflicts_with = "first", value_delimiter = ',')]
     pub network_contacts_url: Vec<String>,
     /// Set to indicate this is a local network.
@@ -58,7 +65,8 @@ pub struct PeersArgs {
     pub local: bool,
     /// Set to indicate this is a testnet.
     ///
-    /// This disables fetching peers from the mainnet network contacts.
+    /// This disables fetching peers from the mainnet network contacts, and allows unsigned lists
+    /// to be fetched from the network contacts URLs.
     #[clap(name = "testnet", long)]
     pub disable_mainnet_contacts: bool,
     /// Set to not load the bootstrap addresses from the local cache.
@@ -109,13 +117,26 @@ impl PeersArgs {
 
         // Read from ANT_PEERS environment variable if present
         bootstrap_addresses.extend(Self::read_bootstrap_addr_from_env());
+        let dnsaddrs = Self::read_dnsaddrs_from_env();
+        if !dnsaddrs.is_empty() {
+            bootstrap_addresses.extend(self.resolve_dnsaddrs(&dnsaddrs, config.clone()).await);
+        }
 
         if !bootstrap_addresses.is_empty() {
             return Ok(bootstrap_addresses);
         }
 
         // Add addrs from arguments if present
-        for addr in &self.addrs {
+        let dnsaddrs = self
+            .addrs
+            .iter()
+            .filter(|addr| is_dnsaddr(addr))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !dnsaddrs.is_empty() {
+            bootstrap_addresses.extend(self.resolve_dnsaddrs(&dnsaddrs, config.clone()).await);
+        }
+        for addr in self.addrs.iter().filter(|addr| !is_dnsaddr(addr)) {
             if let Some(addr) = craft_valid_multiaddr(addr, false) {
                 info!("Adding addr from arguments: {addr}");
                 bootstrap_addresses.push(BootstrapAddr::new(addr));
@@ -135,15 +156,7 @@ impl PeersArgs {
 
         // load from cache if present
         if !self.ignore_cache {
-            let cfg = if let Some(config) = config {
-                Some(config)
-            } else {
-                BootstrapCacheConfig::default_config(self.local).ok()
-            };
-            if let Some(mut cfg) = cfg {
-                if let Some(file_path) = self.get_bootstrap_cache_path()? {
-                    cfg.cache_file_path = file_path;
-                }
+            if let Some(cfg) = self.cache_config(config)? {
                 info!("Loading bootstrap addresses from cache");
                 if let Ok(data) = BootstrapCacheStore::load_cache_data(&cfg) {
                     let from_cache = data.peers.into_iter().filter_map(|(_, addrs)| {
@@ -180,6 +193,8 @@ impl PeersArgs {
                 .map(|url| url.parse::<Url>().map_err(|_| Error::FailedToParseUrl))
                 .collect::<Result<Vec<Url>>>()?;
             let mut contacts_fetcher = ContactsFetcher::with_endpoints(addrs)?;
+            // Local and test networks are not expected to sign their contacts lists.
+            contacts_fetcher.allow_unsigned_contacts(self.local || self.disable_mainnet_contacts);
             if let Some(count) = count {
                 contacts_fetcher.set_max_addrs(count);
             }
@@ -217,10 +232,99 @@ impl PeersArgs {
         }
     }
 
+    /// The config of the cache to load the bootstrap addresses from.
+    fn cache_config(
+        &self,
+        config: Option<BootstrapCacheConfig>,
+    ) -> Result<Option<BootstrapCacheConfig>> {
+        let cfg = if let Some(config) = config {
+            Some(config)
+        } else {
+            BootstrapCacheConfig::default_config(self.local).ok()
+        };
+        let Some(mut cfg) = cfg else {
+            return Ok(None);
+        };
+        if let Some(file_path) = self.get_bootstrap_cache_path()? {
+            cfg.cache_file_path = file_path;
+        }
+        Ok(Some(cfg))
+    }
+
+    /// Resolve the `/dnsaddr/` addresses with the system resolver.
+    async fn resolve_dnsaddrs(
+        &self,
+        dnsaddrs: &[Multiaddr],
+        config: Option<BootstrapCacheConfig>,
+    ) -> Vec<BootstrapAddr> {
+        match DnsTxtResolver::new() {
+            Ok(resolver) => {
+                self.resolve_dnsaddrs_with(&resolver, dnsaddrs, config)
+                    .await
+            }
+            Err(err) => {
+                warn!("Failed to create a DNS resolver, skipping {dnsaddrs:?}: {err}");
+                vec![]
+            }
+        }
+    }
+
+    /// Resolve the `/dnsaddr/` addresses, keeping the scores that the cache holds for the
+    /// resolved addresses, so that they are ranked like any other cached address.
+    pub(crate) async fn resolve_dnsaddrs_with(
+        &self,
+        resolver: &dyn TxtResolver,
+        dnsaddrs: &[Multiaddr],
+        config: Option<BootstrapCacheConfig>,
+    ) -> Vec<BootstrapAddr> {
+        let cache_data = if self.ignore_cache {
+            None
+        } else {
+            self.cache_config(config)
+                .ok()
+                .flatten()
+                .and_then(|cfg| BootstrapCacheStore::load_cache_data(&cfg).ok())
+        };
+
+        let mut bootstrap_addresses = vec![];
+        for dnsaddr in dnsaddrs {
+            for addr in resolve_dnsaddr(resolver, dnsaddr).await {
+                info!("Adding addr resolved from {dnsaddr}: {addr}");
+                bootstrap_addresses.push(Self::cached_or_new(cache_data.as_ref(), addr));
+            }
+        }
+        bootstrap_addresses
+    }
+
+    fn cached_or_new(cache_data: Option<&CacheData>, addr: Multiaddr) -> BootstrapAddr {
+        cache_data
+            .zip(crate::multiaddr_get_peer_id(&addr))
+            .and_then(|(data, peer_id)| data.peers.get(&peer_id))
+            .and_then(|addrs| addrs.get_addr(&addr))
+            .cloned()
+            .unwrap_or_else(|| BootstrapAddr::new(addr))
+    }
+
+    /// Read the `/dnsaddr/` addresses from the `ANT_PEERS` environment variable.
+    fn read_dnsaddrs_from_env() -> Vec<Multiaddr> {
+        let Ok(addrs) = std::env::var(ANT_PEERS_ENV) else {
+            return vec![];
+        };
+        addrs
+            .split(',')
+            .filter_map(|addr_str| addr_str.trim().parse::<Multiaddr>().ok())
+            .filter(is_dnsaddr)
+            .collect()
+    }
+
+    /// Read the addresses from the `ANT_PEERS` environment variable, to be passed on to a node.
+    ///
+    /// The `/dnsaddr/` addresses are kept as they are, to be resolved by the node.
     pub fn read_addr_from_env() -> Vec<Multiaddr> {
         Self::read_bootstrap_addr_from_env()
             .into_iter()
             .map(|addr| addr.addr)
+            .chain(Self::read_dnsaddrs_from_env())
             .collect()
     }
 
@@ -229,6 +333,10 @@ impl PeersArgs {
         // Read from ANT_PEERS environment variable if present
         if let Ok(addrs) = std::env::var(ANT_PEERS_ENV) {
             for addr_str in addrs.split(',') {
+                if addr_str.trim().starts_with("/dnsaddr/") {
+                    // Resolved separately, as it requires a DNS lookup.
+                    continue;
+                }
                 if let Some(addr) = craft_valid_multiaddr_from_str(addr_str, false) {
                     info!("Adding addr from environment variable: {addr}");
                     bootstrap_addresses.push(BootstrapAddr::new(addr));
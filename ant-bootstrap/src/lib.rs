@@ -24,18 +24,22 @@ extern crate tracing;
 mod cache_store;
 pub mod config;
 pub mod contacts;
+pub mod dns;
 pub mod error;
 mod initial_peers;
 
-use ant_protocol::version::{get_network_id, get_truncate_version_str};
+use ant_protocol::version::{get_network_id, get_pnet_fingerprint, get_truncate_version_str};
 use libp2p::{multiaddr::Protocol, Multiaddr, PeerId};
 use serde::{Deserialize, Serialize};
 use std::time::SystemTime;
 use thiserror::Error;
 
-pub use cache_store::BootstrapCacheStore;
+pub use cache_store::{BootstrapCacheStore, MAX_SHARED_ADDRS};
 pub use config::BootstrapCacheConfig;
-pub use contacts::ContactsFetcher;
+pub use contacts::{
+    is_signed_by_trusted_key, parse_trusted_keys, ContactsFetcher, SignedContacts,
+    ANT_CONTACTS_PUBLIC_KEYS_ENV,
+};
 pub use error::{Error, Result};
 pub use initial_peers::{PeersArgs, ANT_PEERS_ENV};
 
@@ -254,6 +258,15 @@ pub fn multiaddr_get_peer_id(addr: &Multiaddr) -> Option<PeerId> {
     }
 }
 
+/// Returns the version of the network, which is used to keep the bootstrap cache and contacts
+/// of different networks apart. Private networks are told apart by the fingerprint of their key.
 pub fn get_network_version() -> String {
-    format!("{}_{}", get_network_id(), get_truncate_version_str())
+    match get_pnet_fingerprint() {
+        Some(fingerprint) => format!(
+            "{}_{}_{fingerprint}",
+            get_network_id(),
+            get_truncate_version_str()
+        ),
+        None => format!("{}_{}", get_network_id(), get_truncate_version_str()),
+    }
 }
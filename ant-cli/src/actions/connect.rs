@@ -45,6 +45,7 @@ pub async fn connect_to_network_with_config(
         peers: peers_opt,
         evm_network,
         strategy: operation_config,
+        transports: peers.transports(),
     };
 
     let res = Client::init_with_config(config).await;
@@ -7,6 +7,7 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 mod file;
+mod map;
 mod register;
 mod vault;
 mod wallet;
@@ -24,6 +25,12 @@ pub enum SubCmd {
         command: FileCmd,
     },
 
+    /// Operations related to private key-value maps.
+    Map {
+        #[command(subcommand)]
+        command: MapCmd,
+    },
+
     /// Operations related to register management.
     Register {
         #[command(subcommand)]
@@ -82,6 +89,48 @@ pub enum FileCmd {
     List,
 }
 
+#[derive(Subcommand, Debug)]
+pub enum MapCmd {
+    /// Create a new, empty map with the given name.
+    /// Maps are private, their entries can only be read with your `SECRET_KEY`.
+    Create {
+        /// The name of the map.
+        name: String,
+    },
+
+    /// Set the value of a key in a map.
+    Insert {
+        /// The name of the map.
+        name: String,
+        /// The key to set.
+        key: String,
+        /// The value to store for the key.
+        value: String,
+    },
+
+    /// Remove a key from a map.
+    Remove {
+        /// The name of the map.
+        name: String,
+        /// The key to remove.
+        key: String,
+    },
+
+    /// Get the value of a key in a map.
+    Get {
+        /// The name of the map.
+        name: String,
+        /// The key to get.
+        key: String,
+    },
+
+    /// List the entries of a map.
+    List {
+        /// The name of the map.
+        name: String,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 pub enum RegisterCmd {
     /// Generate a new register key.
@@ -132,6 +181,24 @@ pub enum RegisterCmd {
         address: String,
     },
 
+    /// Resolve a fork of a register, caused by concurrent edits.
+    /// Without the pick or value options, the competing values are listed.
+    Resolve {
+        /// Use the name of the register instead of the address
+        /// Note that only the owner of the register can use this shorthand as the address can be generated from the name and register key.
+        #[arg(short, long)]
+        name: bool,
+        /// The address of the register
+        /// With the name option on the address will be used as a name
+        address: String,
+        /// Resolve the fork by picking the competing value with this number, as listed.
+        #[arg(short, long, conflicts_with = "value")]
+        pick: Option<usize>,
+        /// Resolve the fork by writing this value, e.g. a merge of the competing values.
+        #[arg(short, long)]
+        value: Option<String>,
+    },
+
     /// List previous registers
     List,
 }
@@ -197,7 +264,8 @@ pub enum WalletCmd {
 }
 
 pub async fn handle_subcommand(opt: Opt) -> Result<()> {
-    let peers = crate::access::network::get_peers(opt.peers);
+    let transports = opt.transports();
+    let peers = crate::access::network::get_peers(opt.peers, transports);
     let cmd = opt.command;
 
     match cmd {
@@ -215,6 +283,15 @@ pub async fn handle_subcommand(opt: Opt) -> Result<()> {
             } => file::download(&addr, &dest_file, peers.await?, quorum).await,
             FileCmd::List => file::list(),
         },
+        Some(SubCmd::Map { command }) => match command {
+            MapCmd::Create { name } => map::create(&name, peers.await?).await,
+            MapCmd::Insert { name, key, value } => {
+                map::insert(&name, &key, &value, peers.await?).await
+            }
+            MapCmd::Remove { name, key } => map::remove(&name, &key, peers.await?).await,
+            MapCmd::Get { name, key } => map::get(&name, &key, peers.await?).await,
+            MapCmd::List { name } => map::list(&name, peers.await?).await,
+        },
         Some(SubCmd::Register { command }) => match command {
             RegisterCmd::GenerateKey { overwrite } => register::generate_key(overwrite),
             RegisterCmd::Cost { name } => register::cost(&name, peers.await?).await,
@@ -227,6 +304,12 @@ pub async fn handle_subcommand(opt: Opt) -> Result<()> {
                 value,
             } => register::edit(address, name, &value, peers.await?).await,
             RegisterCmd::Get { address, name } => register::get(address, name, peers.await?).await,
+            RegisterCmd::Resolve {
+                address,
+                name,
+                pick,
+                value,
+            } => register::resolve(address, name, pick, value, peers.await?).await,
             RegisterCmd::List => register::list(),
         },
         Some(SubCmd::Vault { command }) => match command {
@@ -0,0 +1,112 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::network::NetworkPeers;
+use crate::wallet::load_wallet;
+use autonomi::client::map::SecretKey as MapSecretKey;
+use autonomi::{Bytes, Client};
+use color_eyre::eyre::Context;
+use color_eyre::eyre::Result;
+use color_eyre::Section;
+
+/// Maps are private, so their keys are derived from the same secret key as the vault.
+fn map_key(name: &str) -> Result<MapSecretKey> {
+    let vault_sk = crate::keys::get_vault_secret_key()
+        .wrap_err("The secret key is required to perform this action")?;
+    Ok(Client::map_key_from_name(&vault_sk, name))
+}
+
+pub async fn create(name: &str, peers: NetworkPeers) -> Result<()> {
+    let map_key = map_key(name)?;
+    let client = crate::actions::connect_to_network(peers).await?;
+    let wallet = load_wallet(client.evm_network())?;
+
+    println!("Creating map with name: {name}");
+    info!("Creating map with name: {name}");
+    let (cost, address) = client
+        .map_create(&map_key, wallet.into())
+        .await
+        .wrap_err("Failed to create map")?;
+
+    println!("✅ Map created at address: {}", address.to_hex());
+    println!("With name: {name}");
+    info!("Map created at address: {address:?} with name: {name}");
+    println!("Total cost: {cost} AttoTokens");
+    Ok(())
+}
+
+pub async fn insert(name: &str, key: &str, value: &str, peers: NetworkPeers) -> Result<()> {
+    let map_key = map_key(name)?;
+    let client = crate::actions::connect_to_network(peers).await?;
+    let wallet = load_wallet(client.evm_network())?;
+
+    println!("Setting [{key}] in map {name}");
+    info!("Setting [{key}] in map {name}");
+    let cost = client
+        .map_insert(&map_key, key, Bytes::from(value.to_string()), wallet.into())
+        .await
+        .wrap_err(format!("Failed to set [{key}] in map {name}"))
+        .with_suggestion(|| "Make sure you have already created the map with `map create`")?;
+
+    println!("✅ Successfully set [{key}] to [{value}]");
+    println!("Total cost: {cost} AttoTokens");
+    Ok(())
+}
+
+pub async fn remove(name: &str, key: &str, peers: NetworkPeers) -> Result<()> {
+    let map_key = map_key(name)?;
+    let client = crate::actions::connect_to_network(peers).await?;
+
+    println!("Removing [{key}] from map {name}");
+    info!("Removing [{key}] from map {name}");
+    client
+        .map_remove(&map_key, key)
+        .await
+        .wrap_err(format!("Failed to remove [{key}] from map {name}"))?;
+
+    println!("✅ Successfully removed [{key}]");
+    Ok(())
+}
+
+pub async fn get(name: &str, key: &str, peers: NetworkPeers) -> Result<()> {
+    let map_key = map_key(name)?;
+    let client = crate::actions::connect_to_network(peers).await?;
+
+    info!("Getting [{key}] from map {name}");
+    let value = client
+        .map_get(&map_key, key)
+        .await
+        .wrap_err(format!("Error getting [{key}] from map {name}"))?;
+
+    match value {
+        Some(value) => {
+            let value = String::from_utf8_lossy(&value);
+            println!("✅ [{key}]: [{value}]");
+        }
+        None => println!("[{key}] is not set in map {name}"),
+    }
+    Ok(())
+}
+
+pub async fn list(name: &str, peers: NetworkPeers) -> Result<()> {
+    let map_key = map_key(name)?;
+    let client = crate::actions::connect_to_network(peers).await?;
+
+    info!("Listing the entries of map {name}");
+    let entries: Vec<_> = client
+        .map_iter(&map_key)
+        .await
+        .wrap_err(format!("Error listing the entries of map {name}"))?
+        .collect();
+
+    println!("✅ Map {name} has {} entries:", entries.len());
+    for (key, value) in entries {
+        println!("{key}: {}", String::from_utf8_lossy(&value));
+    }
+    Ok(())
+}
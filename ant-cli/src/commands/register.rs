@@ -11,8 +11,9 @@
 use crate::network::NetworkPeers;
 use crate::wallet::load_wallet;
 use autonomi::client::register::RegisterAddress;
+use autonomi::client::register::RegisterError;
 use autonomi::client::register::SecretKey as RegisterSecretKey;
-use autonomi::Client;
+use autonomi::{AttoTokens, Bytes, Client};
 use color_eyre::eyre::eyre;
 use color_eyre::eyre::Context;
 use color_eyre::eyre::Result;
@@ -61,9 +62,8 @@ pub async fn create(name: &str, value: &str, peers: NetworkPeers) -> Result<()>
 
     println!("Creating register with name: {name}");
     info!("Creating register with name: {name}");
-    let content = Client::register_value_from_bytes(value.as_bytes())?;
     let (cost, address) = client
-        .register_create(&register_key, content, wallet.into())
+        .register_create_bytes(&register_key, Bytes::from(value.to_string()), wallet.into())
         .await
         .wrap_err("Failed to create register")?;
 
@@ -86,29 +86,14 @@ pub async fn edit(address: String, name: bool, value: &str, peers: NetworkPeers)
         .wrap_err("The register key is required to perform this action")?;
     let client = crate::actions::connect_to_network(peers).await?;
     let wallet = load_wallet(client.evm_network())?;
-    let value_bytes = Client::register_value_from_bytes(value.as_bytes())?;
 
-    let register_key = if name {
-        let name_str = address.clone();
-        Client::register_key_from_name(&main_registers_key, &name_str)
-    } else {
-        let addr = RegisterAddress::from_hex(&address)
-            .wrap_err(format!("Failed to parse register address: {address}"))
-            .with_suggestion(|| {
-                "if you want to use the name as the address, run the command with the --name flag"
-            })?;
-        let name_str = crate::user_data::get_name_of_local_register_with_address(&addr)
-            .wrap_err(format!("Could not find a register with address in local user data: {address}"))
-            .with_suggestion(|| "This register is not known to this client, try to create it first.")
-            .with_suggestion(|| "If you indeed have created this register before, retry using its name by using the --name flag")?;
-        Client::register_key_from_name(&main_registers_key, &name_str)
-    };
+    let register_key = register_key(&main_registers_key, &address, name)?;
 
     println!("Attempting to update register at {address} with new value: {value}");
     info!("Attempting to update register at {address} with new value: {value}");
 
     let cost = client
-        .register_update(&register_key, value_bytes, wallet.into())
+        .register_update_bytes(&register_key, Bytes::from(value.to_string()), wallet.into())
         .await
         .wrap_err(format!("Failed to update register at address: {address}"))?;
 
@@ -120,6 +105,30 @@ pub async fn edit(address: String, name: bool, value: &str, peers: NetworkPeers)
     Ok(())
 }
 
+/// Get the key of a register owned by this client, from its name or its address.
+fn register_key(
+    main_registers_key: &RegisterSecretKey,
+    address: &str,
+    name: bool,
+) -> Result<RegisterSecretKey> {
+    if name {
+        return Ok(Client::register_key_from_name(main_registers_key, address));
+    }
+    let addr = RegisterAddress::from_hex(address)
+        .wrap_err(format!("Failed to parse register address: {address}"))
+        .with_suggestion(|| {
+            "if you want to use the name as the address, run the command with the --name flag"
+        })?;
+    let name_str = crate::user_data::get_name_of_local_register_with_address(&addr)
+        .wrap_err(format!("Could not find a register with address in local user data: {address}"))
+        .with_suggestion(|| "This register is not known to this client, try to create it first.")
+        .with_suggestion(|| "If you indeed have created this register before, retry using its name by using the --name flag")?;
+    Ok(Client::register_key_from_name(
+        main_registers_key,
+        &name_str,
+    ))
+}
+
 pub async fn get(address: String, name: bool, peers: NetworkPeers) -> Result<()> {
     let client = crate::actions::connect_to_network(peers).await?;
 
@@ -144,10 +153,19 @@ pub async fn get(address: String, name: bool, peers: NetworkPeers) -> Result<()>
         println!("Getting register at address: {address}");
         info!("Getting register at address: {address}");
     }
-    let value_bytes = client
+    let content = client
         .register_get(&addr)
         .await
         .wrap_err(format!("Error getting register at: {address}"))?;
+    let value_bytes = match client.register_value_to_data(&content).await {
+        Ok(value) => value,
+        // Registers written before values of any size were supported hold their value as is
+        Err(RegisterError::RawValue) => {
+            info!("Register at {address} does not hold a sized value, showing it as is");
+            Bytes::copy_from_slice(&content)
+        }
+        Err(err) => return Err(err).wrap_err(format!("Error getting register at: {address}")),
+    };
 
     println!("✅ Register found at: {address}");
     info!("Register found at: {address}");
@@ -158,6 +176,82 @@ pub async fn get(address: String, name: bool, peers: NetworkPeers) -> Result<()>
     Ok(())
 }
 
+pub async fn resolve(
+    address: String,
+    name: bool,
+    pick: Option<usize>,
+    value: Option<String>,
+    peers: NetworkPeers,
+) -> Result<()> {
+    let main_registers_key = crate::keys::get_register_signing_key()
+        .wrap_err("The register key is required to perform this action")?;
+    let register_key = register_key(&main_registers_key, &address, name)?;
+    let addr = RegisterAddress::new(register_key.public_key());
+    let client = crate::actions::connect_to_network(peers).await?;
+
+    let heads = client
+        .register_fork_heads(&addr)
+        .await
+        .wrap_err(format!("Error getting register at: {address}"))?;
+    if heads.len() < 2 {
+        println!("✅ Register at {address} is not forked, there is nothing to resolve");
+        return Ok(());
+    }
+
+    println!(
+        "Register at {address} is forked, with {} competing values:",
+        heads.len()
+    );
+    for (i, head) in heads.iter().enumerate() {
+        let value = match client.register_value_to_data(head).await {
+            Ok(value) => value,
+            // Registers written before values of any size were supported hold their value as is
+            Err(RegisterError::ValueGet(_)) | Err(RegisterError::Corrupt(_)) => {
+                Bytes::copy_from_slice(head)
+            }
+            Err(err) => return Err(err).wrap_err("Error getting the competing values"),
+        };
+        println!("{i}: [{}]", String::from_utf8_lossy(&value));
+    }
+
+    let wallet = load_wallet(client.evm_network())?;
+    let (resolved, value_cost) = match (pick, value) {
+        (Some(pick), _) => {
+            let head = heads
+                .get(pick)
+                .ok_or_else(|| eyre!("There is no competing value with number {pick}"))?;
+            (*head, AttoTokens::zero())
+        }
+        (None, Some(value)) => client
+            .register_value_from_data(Bytes::from(value), wallet.clone().into())
+            .await
+            .wrap_err("Failed to store the resolved value")?,
+        (None, None) => {
+            println!(
+                "Use --pick <number> to pick one of them, or --value <value> to write a new value"
+            );
+            return Ok(());
+        }
+    };
+
+    println!("Resolving the fork of register at {address}...");
+    info!("Resolving the fork of register at {address}");
+    let cost = client
+        .register_resolve_fork(&register_key, |_| resolved, wallet.into())
+        .await
+        .wrap_err(format!(
+            "Failed to resolve the fork of register at: {address}"
+        ))?;
+
+    println!("✅ Successfully resolved the fork of register at {address}");
+    println!(
+        "Total cost: {} AttoTokens",
+        value_cost.checked_add(cost).unwrap_or(cost)
+    );
+    info!("Successfully resolved the fork of register at {address}");
+    Ok(())
+}
+
 pub fn list() -> Result<()> {
     println!("Retrieving local user data...");
     let registers = crate::user_data::get_local_registers()?;
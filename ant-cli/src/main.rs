@@ -37,6 +37,10 @@ async fn main() -> Result<()> {
     if let Some(network_id) = opt.network_id {
         ant_protocol::version::set_network_id(network_id);
     }
+    #[cfg(feature = "pnet")]
+    if let Some(key) = &opt.pnet_key_file {
+        ant_protocol::version::set_pnet_fingerprint(key.fingerprint().to_string());
+    }
 
     // The clone is necessary to resolve a clippy warning related to a mutex.
     let identify_protocol_str = version::IDENTIFY_PROTOCOL_STR
@@ -9,6 +9,9 @@
 use crate::commands::SubCmd;
 use ant_bootstrap::PeersArgs;
 use ant_logging::{LogFormat, LogOutputDest};
+use autonomi::client::config::TransportConfig;
+#[cfg(feature = "pnet")]
+use autonomi::client::config::{read_pre_shared_key, PreSharedKey};
 use clap::Parser;
 use color_eyre::Result;
 use std::time::Duration;
@@ -68,6 +71,28 @@ pub(crate) struct Opt {
     #[command(flatten)]
     pub(crate) peers: PeersArgs,
 
+    /// Also dial peers over TCP, for networks where QUIC (UDP) is blocked.
+    ///
+    /// QUIC is still preferred whenever a peer can be reached with it.
+    #[cfg(feature = "tcp")]
+    #[clap(global = true, long)]
+    pub tcp: bool,
+
+    /// Also dial peers over WebSocket, for networks that only allow HTTP(S)-like traffic.
+    ///
+    /// QUIC is still preferred whenever a peer can be reached with it.
+    #[cfg(feature = "websocket")]
+    #[clap(global = true, long)]
+    pub websocket: bool,
+
+    /// Connect to a private network, using the pre-shared key read from the given file.
+    ///
+    /// Only TCP is used to reach the peers of a private network, so they have to be provided
+    /// with TCP addresses.
+    #[cfg(feature = "pnet")]
+    #[clap(global = true, long, value_name = "PATH", value_parser = |path: &str| -> Result<PreSharedKey> { Ok(read_pre_shared_key(std::path::Path::new(path))?) })]
+    pub pnet_key_file: Option<PreSharedKey>,
+
     /// Print the package version.
     #[cfg(not(feature = "nightly"))]
     #[clap(long)]
@@ -81,3 +106,17 @@ pub(crate) struct Opt {
     #[clap(long)]
     pub version: bool,
 }
+
+impl Opt {
+    /// The transports to enable alongside QUIC. The client only dials, so no ports are required.
+    pub fn transports(&self) -> TransportConfig {
+        TransportConfig {
+            #[cfg(feature = "tcp")]
+            tcp: self.tcp.then_some(0),
+            #[cfg(feature = "websocket")]
+            websocket: self.websocket.then_some(0),
+            #[cfg(feature = "pnet")]
+            pre_shared_key: self.pnet_key_file,
+        }
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use ant_protocol::messages::{QueryResponse, Response};
+#[cfg(feature = "open-metrics")]
+use prometheus_client::{
+    encoding::{EncodeLabelSet, EncodeLabelValue},
+    metrics::{counter::Counter, family::Family},
+};
+use std::{sync::Mutex, time::Instant};
+
+/// The kind of traffic that a bandwidth budget applies to.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "open-metrics", derive(EncodeLabelValue))]
+pub(crate) enum TrafficClass {
+    /// Records fetched from, or served to, other nodes during replication.
+    Replication,
+    /// Records sent to clients fetching them directly, i.e. `GetReplicatedRecord` queries from
+    /// peers outside of the routing table.
+    ///
+    /// Records put by clients, or served through Kademlia lookups, are not charged.
+    Client,
+}
+
+#[cfg(feature = "open-metrics")]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct TrafficClassLabels {
+    class: TrafficClass,
+}
+
+/// A token bucket that refills at `rate` bytes per second, holding at most a second worth of
+/// bytes.
+///
+/// As the size of a record is only known once it has been transferred, the balance is allowed
+/// to go negative. New traffic is then held back until the debt has been paid back.
+#[derive(Debug)]
+struct TokenBucket {
+    rate: u64,
+    balance: i64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: u64, now: Instant) -> Self {
+        Self {
+            rate,
+            balance: rate as i64,
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        let refill = (elapsed.as_secs_f64() * self.rate as f64) as i64;
+        if refill > 0 {
+            self.balance = self.balance.saturating_add(refill).min(self.rate as i64);
+            self.last_refill = now;
+        }
+    }
+
+    fn is_exhausted(&mut self, now: Instant) -> bool {
+        self.refill(now);
+        self.balance <= 0
+    }
+
+    fn consume(&mut self, bytes: usize, now: Instant) {
+        self.refill(now);
+        self.balance = self.balance.saturating_sub(bytes as i64);
+    }
+}
+
+/// Enforces the bandwidth budgets of the node, one per [`TrafficClass`].
+///
+/// A class without a budget is never throttled.
+#[derive(Debug)]
+pub(crate) struct BandwidthLimiter {
+    replication: Option<Mutex<TokenBucket>>,
+    client: Option<Mutex<TokenBucket>>,
+    #[cfg(feature = "open-metrics")]
+    throttled_bytes: Option<Family<TrafficClassLabels, Counter>>,
+}
+
+impl BandwidthLimiter {
+    /// Create a limiter with the provided budgets, in bytes per second.
+    pub(crate) fn new(replication: Option<u64>, client: Option<u64>) -> Self {
+        let now = Instant::now();
+        Self {
+            replication: replication.map(|rate| Mutex::new(TokenBucket::new(rate, now))),
+            client: client.map(|rate| Mutex::new(TokenBucket::new(rate, now))),
+            #[cfg(feature = "open-metrics")]
+            throttled_bytes: None,
+        }
+    }
+
+    #[cfg(feature = "open-metrics")]
+    pub(crate) fn set_throttled_bytes_metrics(
+        &mut self,
+        family: Family<TrafficClassLabels, Counter>,
+    ) {
+        self.throttled_bytes = Some(family);
+    }
+
+    fn bucket(&self, class: TrafficClass) -> Option<&Mutex<TokenBucket>> {
+        match class {
+            TrafficClass::Replication => self.replication.as_ref(),
+            TrafficClass::Client => self.client.as_ref(),
+        }
+    }
+
+    /// Returns true if the budget of the class has been used up, and new traffic has to be held
+    /// back.
+    pub(crate) fn is_throttled(&self, class: TrafficClass) -> bool {
+        let Some(bucket) = self.bucket(class) else {
+            return false;
+        };
+        match bucket.lock() {
+            Ok(mut bucket) => bucket.is_exhausted(Instant::now()),
+            Err(_) => {
+                error!("Bandwidth bucket lock for {class:?} is poisoned");
+                false
+            }
+        }
+    }
+
+    /// Record bytes that have been transferred for the class.
+    pub(crate) fn record(&self, class: TrafficClass, bytes: usize) {
+        let Some(bucket) = self.bucket(class) else {
+            return;
+        };
+        match bucket.lock() {
+            Ok(mut bucket) => bucket.consume(bytes, Instant::now()),
+            Err(_) => error!("Bandwidth bucket lock for {class:?} is poisoned"),
+        }
+    }
+
+    /// Record bytes that have not been transferred as the class was throttled.
+    pub(crate) fn record_throttled(&self, class: TrafficClass, bytes: usize) {
+        debug!("Throttled {bytes} bytes of {class:?} traffic");
+        #[cfg(feature = "open-metrics")]
+        if let Some(throttled_bytes) = &self.throttled_bytes {
+            let _ = throttled_bytes
+                .get_or_create(&TrafficClassLabels { class })
+                .inc_by(bytes as u64);
+        }
+    }
+}
+
+/// Returns the size of the record carried by the response, if it is a replicated record.
+pub(crate) fn replicated_record_len(response: &Response) -> Option<usize> {
+    match response {
+        Response::Query(QueryResponse::GetReplicatedRecord(Ok((_holder, bytes)))) => {
+            Some(bytes.len())
+        }
+        _ => None,
+    }
+}
+
+impl Default for BandwidthLimiter {
+    fn default() -> Self {
+        Self::new(None, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn bucket_should_throttle_until_debt_is_paid_back() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(1000, start);
+        assert!(!bucket.is_exhausted(start));
+
+        // Going 1000 bytes into debt takes a second to be paid back.
+        bucket.consume(2000, start);
+        assert!(bucket.is_exhausted(start));
+        assert!(bucket.is_exhausted(start + Duration::from_millis(900)));
+        assert!(!bucket.is_exhausted(start + Duration::from_millis(1100)));
+
+        // The balance never exceeds a second worth of bytes.
+        let later = start + Duration::from_secs(60);
+        bucket.consume(1000, later);
+        assert!(bucket.is_exhausted(later));
+    }
+
+    #[test]
+    fn classes_without_budget_should_never_be_throttled() {
+        let limiter = BandwidthLimiter::new(Some(10), None);
+
+        limiter.record(TrafficClass::Client, 1_000_000);
+        assert!(!limiter.is_throttled(TrafficClass::Client));
+
+        limiter.record(TrafficClass::Replication, 1_000_000);
+        assert!(limiter.is_throttled(TrafficClass::Replication));
+    }
+}
@@ -109,6 +109,13 @@ pub enum LocalSwarmCmd {
     GetAllLocalRecordAddresses {
         sender: oneshot::Sender<Result<HashMap<NetworkAddress, ValidationType>>>,
     },
+    /// Get a sample of the well-scored addrs from the bootstrap cache, to be shared with the
+    /// requester.
+    GetShareableBootstrapAddrs {
+        requester: NetworkAddress,
+        max_addrs: usize,
+        sender: oneshot::Sender<Vec<(Multiaddr, u32, u32)>>,
+    },
     /// Get data from the local RecordStore
     GetLocalRecord {
         key: RecordKey,
@@ -284,6 +291,12 @@ impl Debug for LocalSwarmCmd {
             LocalSwarmCmd::PaymentReceived => {
                 write!(f, "LocalSwarmCmd::PaymentReceived")
             }
+            LocalSwarmCmd::GetShareableBootstrapAddrs { max_addrs, .. } => {
+                write!(
+                    f,
+                    "LocalSwarmCmd::GetShareableBootstrapAddrs {{ max_addrs: {max_addrs} }}"
+                )
+            }
             LocalSwarmCmd::GetLocalRecord { key, .. } => {
                 write!(
                     f,
@@ -700,6 +713,14 @@ impl SwarmDriver {
                     .store_mut()
                     .payment_received();
             }
+            LocalSwarmCmd::GetShareableBootstrapAddrs {
+                requester,
+                max_addrs,
+                sender,
+            } => {
+                cmd_string = "GetShareableBootstrapAddrs";
+                let _ = sender.send(self.shareable_bootstrap_addrs(&requester, max_addrs));
+            }
             LocalSwarmCmd::GetLocalRecord { key, sender } => {
                 cmd_string = "GetLocalRecord";
                 let record = self
@@ -7,6 +7,7 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::{
+    bandwidth::BandwidthLimiter,
     bootstrap::{ContinuousNetworkDiscover, NETWORK_DISCOVER_INTERVAL},
     circular_vec::CircularVec,
     cmd::{LocalSwarmCmd, NetworkSwarmCmd},
@@ -24,7 +25,9 @@ use crate::{
     relay_manager::RelayManager,
     replication_fetcher::ReplicationFetcher,
     time::{interval, spawn, Instant, Interval},
-    transport, GetRecordError, Network, NodeIssue, CLOSE_GROUP_SIZE,
+    transport::{self, TransportConfig},
+    tuning::NetworkTuning,
+    GetRecordError, Network, NodeIssue, CLOSE_GROUP_SIZE,
 };
 #[cfg(feature = "open-metrics")]
 use crate::{
@@ -65,8 +68,9 @@ use std::{
     fs,
     io::{Read, Write},
     net::{IpAddr, SocketAddr},
-    num::NonZeroUsize,
+    num::{NonZeroU8, NonZeroUsize},
     path::PathBuf,
+    sync::Arc,
 };
 use tokio::sync::{mpsc, oneshot, watch};
 use tokio::time::Duration;
@@ -113,7 +117,7 @@ pub(crate) type BadNodes = BTreeMap<PeerId, (Vec<(NodeIssue, Instant)>, bool)>;
 pub const MAX_PACKET_SIZE: usize = 1024 * 1024 * 5; // the chunk size is 1mb, so should be higher than that to prevent failures
 
 // Timeout for requests sent/received through the request_response behaviour.
-const REQUEST_TIMEOUT_DEFAULT_S: Duration = Duration::from_secs(30);
+pub(crate) const REQUEST_TIMEOUT_DEFAULT_S: Duration = Duration::from_secs(30);
 // Sets the keep-alive timeout of idle connections.
 const CONNECTION_KEEP_ALIVE_TIMEOUT: Duration = Duration::from_secs(10);
 
@@ -171,6 +175,8 @@ pub struct NetworkBuilder {
     #[cfg(feature = "open-metrics")]
     metrics_server_port: Option<u16>,
     request_timeout: Option<Duration>,
+    transport_config: TransportConfig,
+    tuning: NetworkTuning,
     upnp: bool,
 }
 
@@ -188,6 +194,8 @@ impl NetworkBuilder {
             #[cfg(feature = "open-metrics")]
             metrics_server_port: None,
             request_timeout: None,
+            transport_config: TransportConfig::default(),
+            tuning: NetworkTuning::default(),
             upnp: false,
         }
     }
@@ -204,6 +212,7 @@ impl NetworkBuilder {
         self.listen_addr = Some(listen_addr);
     }
 
+    /// Set the request timeout, taking precedence over the one of the tuning parameters.
     pub fn request_timeout(&mut self, request_timeout: Duration) {
         self.request_timeout = Some(request_timeout);
     }
@@ -229,6 +238,16 @@ impl NetworkBuilder {
         self.upnp = upnp;
     }
 
+    /// Set the transports to enable alongside QUIC.
+    pub fn transport_config(&mut self, transport_config: TransportConfig) {
+        self.transport_config = transport_config;
+    }
+
+    /// Override the default tuning parameters. The caller is expected to have validated them.
+    pub fn tuning(&mut self, tuning: NetworkTuning) {
+        self.tuning = tuning;
+    }
+
     /// Creates a new `SwarmDriver` instance, along with a `Network` handle
     /// for sending commands and an `mpsc::Receiver<NetworkEvent>` for receiving
     /// network events. It initializes the swarm, sets up the transport, and
@@ -309,12 +328,15 @@ impl NetworkBuilder {
                 storage_dir: storage_dir_path,
                 historic_quote_dir: root_dir.clone(),
                 encryption_seed,
+                max_records: self.tuning.max_records(),
                 ..Default::default()
             }
         };
 
         let listen_addr = self.listen_addr;
         let upnp = self.upnp;
+        #[allow(unused_variables)]
+        let transport_config = self.transport_config;
 
         let (network, events_receiver, mut swarm_driver) =
             self.build(kad_cfg, Some(store_cfg), false, ProtocolSupport::Full, upnp);
@@ -323,12 +345,34 @@ impl NetworkBuilder {
         let listen_socket_addr = listen_addr.ok_or(NetworkError::ListenAddressNotProvided)?;
 
         // Listen on QUIC
-        let addr_quic = Multiaddr::from(listen_socket_addr.ip())
-            .with(Protocol::Udp(listen_socket_addr.port()))
-            .with(Protocol::QuicV1);
-        swarm_driver
-            .listen_on(addr_quic)
-            .expect("Multiaddr should be supported by our configured transports");
+        if transport_config.is_quic_enabled() {
+            let addr_quic = Multiaddr::from(listen_socket_addr.ip())
+                .with(Protocol::Udp(listen_socket_addr.port()))
+                .with(Protocol::QuicV1);
+            swarm_driver
+                .listen_on(addr_quic)
+                .expect("Multiaddr should be supported by our configured transports");
+        }
+
+        #[cfg(feature = "tcp")]
+        if transport_config.is_tcp_enabled() {
+            // TCP replaces QUIC on a private network, hence listens on its port by default.
+            let port = transport_config.tcp.unwrap_or(listen_socket_addr.port());
+            let addr_tcp = Multiaddr::from(listen_socket_addr.ip()).with(Protocol::Tcp(port));
+            swarm_driver
+                .listen_on(addr_tcp)
+                .expect("Multiaddr should be supported by our configured transports");
+        }
+
+        #[cfg(feature = "websocket")]
+        if let Some(port) = transport_config.websocket {
+            let addr_ws = Multiaddr::from(listen_socket_addr.ip())
+                .with(Protocol::Tcp(port))
+                .with(Protocol::Ws("/".into()));
+            swarm_driver
+                .listen_on(addr_ws)
+                .expect("Multiaddr should be supported by our configured transports");
+        }
 
         Ok((network, events_receiver, swarm_driver))
     }
@@ -385,9 +429,13 @@ impl NetworkBuilder {
 
         // ==== Transport ====
         #[cfg(feature = "open-metrics")]
-        let main_transport = transport::build_transport(&self.keypair, &mut metrics_registries);
+        let main_transport = transport::build_transport(
+            &self.keypair,
+            &self.transport_config,
+            &mut metrics_registries,
+        );
         #[cfg(not(feature = "open-metrics"))]
-        let main_transport = transport::build_transport(&self.keypair);
+        let main_transport = transport::build_transport(&self.keypair, &self.transport_config);
         let transport = if !self.local {
             debug!("Preventing non-global dials");
             // Wrap upper in a transport that prevents dialing local addresses.
@@ -443,8 +491,10 @@ impl NetworkBuilder {
 
         // RequestResponse Behaviour
         let request_response = {
-            let cfg = RequestResponseConfig::default()
-                .with_request_timeout(self.request_timeout.unwrap_or(REQUEST_TIMEOUT_DEFAULT_S));
+            let cfg = RequestResponseConfig::default().with_request_timeout(
+                self.request_timeout
+                    .unwrap_or_else(|| self.tuning.request_timeout()),
+            );
             let req_res_version_str = REQ_RESPONSE_VERSION_STR
                 .read()
                 .expect("Failed to obtain read lock for REQ_RESPONSE_VERSION_STR")
@@ -461,6 +511,19 @@ impl NetworkBuilder {
             )
         };
 
+        let bandwidth_limiter = BandwidthLimiter::new(
+            self.tuning.replication_bandwidth_limit,
+            self.tuning.client_bandwidth_limit,
+        );
+        #[cfg(feature = "open-metrics")]
+        let mut bandwidth_limiter = bandwidth_limiter;
+        #[cfg(feature = "open-metrics")]
+        if let Some(metrics_recorder) = &metrics_recorder {
+            bandwidth_limiter
+                .set_throttled_bytes_metrics(metrics_recorder.bandwidth_throttled_bytes.clone());
+        }
+        let bandwidth_limiter = Arc::new(bandwidth_limiter);
+
         let (network_event_sender, network_event_receiver) = mpsc::channel(NETWORKING_CHANNEL_SIZE);
         let (network_swarm_cmd_sender, network_swarm_cmd_receiver) =
             mpsc::channel(NETWORKING_CHANNEL_SIZE);
@@ -549,17 +612,30 @@ impl NetworkBuilder {
             identify,
         };
 
-        let swarm_config = libp2p::swarm::Config::with_tokio_executor()
+        let mut swarm_config = libp2p::swarm::Config::with_tokio_executor()
             .with_idle_connection_timeout(CONNECTION_KEEP_ALIVE_TIMEOUT);
+        if self.transport_config.has_fallback_transports() {
+            // Dial the addresses of a peer one at a time, in order of transport preference,
+            // so that QUIC is attempted first and the other transports are only used as fallback.
+            swarm_config = swarm_config
+                .with_dial_concurrency_factor(NonZeroU8::new(1).expect("1 is not zero"));
+        }
 
         let swarm = Swarm::new(transport, behaviour, peer_id, swarm_config);
 
         let bootstrap = ContinuousNetworkDiscover::new();
-        let replication_fetcher = ReplicationFetcher::new(peer_id, network_event_sender.clone());
+        let replication_fetcher = ReplicationFetcher::new(
+            peer_id,
+            self.tuning.replication_max_parallel_fetch,
+            self.tuning.replication_fetch_timeout(),
+            Arc::clone(&bandwidth_limiter),
+            network_event_sender.clone(),
+        );
 
         // Enable relay manager for nodes behind home network
         let relay_manager = if !is_client && self.is_behind_home_network {
-            let relay_manager = RelayManager::new(peer_id);
+            let relay_manager =
+                RelayManager::new(peer_id, self.tuning.max_concurrent_relay_connections);
             #[cfg(feature = "open-metrics")]
             let mut relay_manager = relay_manager;
             #[cfg(feature = "open-metrics")]
@@ -593,10 +669,12 @@ impl NetworkBuilder {
             peers_in_rt: 0,
             bootstrap,
             bootstrap_cache: self.bootstrap_cache,
+            bootstrap_cache_exchanges: Default::default(),
             relay_manager,
             connected_relay_clients: Default::default(),
             external_address_manager,
             replication_fetcher,
+            bandwidth_limiter,
             #[cfg(feature = "open-metrics")]
             metrics_recorder,
             // kept here to ensure we can push messages to the channel
@@ -689,12 +767,16 @@ pub struct SwarmDriver {
     pub(crate) peers_in_rt: usize,
     pub(crate) bootstrap: ContinuousNetworkDiscover,
     pub(crate) bootstrap_cache: Option<BootstrapCacheStore>,
+    /// The peers that we have asked to share their bootstrap cache.
+    pub(crate) bootstrap_cache_exchanges: HashSet<PeerId>,
     pub(crate) external_address_manager: Option<ExternalAddressManager>,
     pub(crate) relay_manager: Option<RelayManager>,
     /// The peers that are using our relay service.
     pub(crate) connected_relay_clients: HashSet<PeerId>,
     /// The peers that are closer to our PeerId. Includes self.
     pub(crate) replication_fetcher: ReplicationFetcher,
+    /// Enforces the bandwidth budgets of the node.
+    pub(crate) bandwidth_limiter: Arc<BandwidthLimiter>,
     #[cfg(feature = "open-metrics")]
     pub(crate) metrics_recorder: Option<NetworkMetricsRecorder>,
 
@@ -7,6 +7,7 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::relay_manager::is_a_relayed_peer;
+use crate::transport::sort_addrs_by_transport_preference;
 use crate::{multiaddr_is_global, multiaddr_strip_p2p, NetworkEvent, SwarmDriver};
 use ant_protocol::version::{IDENTIFY_NODE_VERSION_STR, IDENTIFY_PROTOCOL_STR};
 use libp2p::identify::Info;
@@ -139,10 +140,12 @@ impl SwarmDriver {
             }
 
             info!(%peer_id, ?addrs, "received identify info from undialed peer for not full kbucket {ilog2:?}, dial back to confirm external accessible");
+            let mut dial_addrs: Vec<Multiaddr> = addrs.iter().cloned().collect();
+            sort_addrs_by_transport_preference(&mut dial_addrs);
             if let Err(err) = self.swarm.dial(
                 DialOpts::peer_id(peer_id)
                     .condition(PeerCondition::NotDialing)
-                    .addresses(addrs.iter().cloned().collect())
+                    .addresses(dial_addrs)
                     .build(),
             ) {
                 warn!(%peer_id, ?addrs, "dialing error: {err:?}");
@@ -166,7 +169,10 @@ impl SwarmDriver {
 
             debug!(%peer_id, ?addrs, "identify: attempting to add addresses to routing table");
 
-            // Attempt to add the addresses to the routing table.
+            // Attempt to add the addresses to the routing table, in order of transport preference
+            // as that is the order they get dialled in.
+            let mut addrs: Vec<Multiaddr> = addrs.into_iter().collect();
+            sort_addrs_by_transport_preference(&mut addrs);
             for multiaddr in addrs {
                 let _routing_update = self
                     .swarm
@@ -211,6 +217,7 @@ impl SwarmDriver {
             }
 
             if !addresses_to_add.is_empty() {
+                sort_addrs_by_transport_preference(&mut addresses_to_add);
                 debug!("Adding addresses to RT for {peer_id:?} as the new identify contains them: {addresses_to_add:?}");
                 for multiaddr in addresses_to_add {
                     let _routing_update = self
@@ -251,6 +251,7 @@ impl SwarmDriver {
                 bootstrap_cache.add_addr(addr.clone());
             }
         }
+        self.request_bootstrap_cache_exchange(added_peer);
 
         self.log_kbuckets(&added_peer);
         self.send_event(NetworkEvent::PeerAdded(added_peer, self.peers_in_rt));
@@ -7,15 +7,27 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::{
-    cmd::NetworkSwarmCmd, log_markers::Marker, MsgResponder, NetworkError, NetworkEvent,
-    SwarmDriver,
+    bandwidth::{replicated_record_len, TrafficClass},
+    cmd::NetworkSwarmCmd,
+    log_markers::Marker,
+    MsgResponder, NetworkError, NetworkEvent, SwarmDriver,
 };
+use ant_bootstrap::{BootstrapAddr, MAX_SHARED_ADDRS};
 use ant_protocol::{
-    messages::{CmdResponse, Request, Response},
+    error::Error as ProtocolError,
+    messages::{CmdResponse, Query, QueryResponse, Request, Response},
     storage::ValidationType,
     NetworkAddress,
 };
-use libp2p::request_response::{self, Message};
+use bytes::Bytes;
+use libp2p::{
+    kad::store::RecordStore,
+    request_response::{self, Message},
+    Multiaddr, PeerId,
+};
+
+/// Max number of peers that we ask to share their bootstrap cache, per run.
+const MAX_BOOTSTRAP_CACHE_EXCHANGES: usize = 3;
 
 impl SwarmDriver {
     /// Forwards `Request` to the upper layers using `Sender<NetworkEvent>`. Sends `Response` to the peers
@@ -93,6 +105,38 @@ impl SwarmDriver {
                                 error!("Received a bad_peer notification from {detected_by:?}, targeting {bad_peer:?}, which is not us.");
                             }
                         }
+                        Request::Query(Query::GetReplicatedRecord { key, .. }) => {
+                            // Answered here, so the record can be charged against the budget of
+                            // the requester: nodes fetch records to replicate them, while any
+                            // other peer is a client.
+                            let class = if self.is_peer_in_rt(&peer) {
+                                TrafficClass::Replication
+                            } else {
+                                TrafficClass::Client
+                            };
+                            let response = Response::Query(QueryResponse::GetReplicatedRecord(
+                                self.get_replicated_record(key, class),
+                            ));
+                            self.queue_network_swarm_cmd(NetworkSwarmCmd::SendResponse {
+                                resp: response,
+                                channel: MsgResponder::FromPeer(channel),
+                            });
+                        }
+                        Request::Query(Query::GetBootstrapPeers {
+                            requester,
+                            max_addrs,
+                            ..
+                        }) => {
+                            // Answered from the bootstrap cache held by the driver.
+                            let response = Response::Query(QueryResponse::GetBootstrapPeers {
+                                holder: NetworkAddress::from_peer(self.self_peer_id),
+                                addrs: self.shareable_bootstrap_addrs(&requester, max_addrs),
+                            });
+                            self.queue_network_swarm_cmd(NetworkSwarmCmd::SendResponse {
+                                resp: response,
+                                channel: MsgResponder::FromPeer(channel),
+                            });
+                        }
                         Request::Query(query) => {
                             self.send_event(NetworkEvent::QueryRequestReceived {
                                 query,
@@ -106,6 +150,10 @@ impl SwarmDriver {
                     response,
                 } => {
                     debug!("Got response {request_id:?} from peer {peer:?}, res: {response}.");
+                    if let Some(len) = replicated_record_len(&response) {
+                        self.bandwidth_limiter
+                            .record(TrafficClass::Replication, len);
+                    }
                     if let Some(sender) = self.pending_requests.remove(&request_id) {
                         // The sender will be provided if the caller (Requester) is awaiting for a response
                         // at the call site.
@@ -120,6 +168,12 @@ impl SwarmDriver {
                                     // Nothing to do, response was fine
                                     // This only exists to ensure we dont drop the handle and
                                     // exit early, potentially logging false connection woes
+                                } else if let Response::Query(QueryResponse::GetBootstrapPeers {
+                                    addrs,
+                                    ..
+                                }) = response
+                                {
+                                    self.sync_shared_bootstrap_addrs(peer, addrs);
                                 } else {
                                     // responses that are not awaited at the call site must be handled
                                     // separately
@@ -175,6 +229,126 @@ impl SwarmDriver {
         Ok(())
     }
 
+    /// Returns true if the peer is part of our routing table.
+    fn is_peer_in_rt(&mut self, peer: &PeerId) -> bool {
+        self.swarm
+            .behaviour_mut()
+            .kademlia
+            .kbucket(*peer)
+            .is_some_and(|kbucket| {
+                kbucket
+                    .iter()
+                    .any(|entry| entry.node.key.preimage() == peer)
+            })
+    }
+
+    /// Returns the locally held record for a `GetReplicatedRecord` query, charging it against
+    /// the bandwidth budget of the class.
+    ///
+    /// Once the budget is used up, the record is refused with a
+    /// [`ProtocolError::RecordThrottled`], which the requester can retry later.
+    fn get_replicated_record(
+        &mut self,
+        key: NetworkAddress,
+        class: TrafficClass,
+    ) -> Result<(NetworkAddress, Bytes), ProtocolError> {
+        let holder = Box::new(NetworkAddress::from_peer(self.self_peer_id));
+        let record = key.as_record_key().and_then(|record_key| {
+            self.swarm
+                .behaviour_mut()
+                .kademlia
+                .store_mut()
+                .get(&record_key)
+                .map(|record| record.into_owned())
+        });
+        let Some(record) = record else {
+            return Err(ProtocolError::ReplicatedRecordNotFound {
+                holder,
+                key: Box::new(key),
+            });
+        };
+
+        if self.bandwidth_limiter.is_throttled(class) {
+            self.bandwidth_limiter
+                .record_throttled(class, record.value.len());
+            return Err(ProtocolError::RecordThrottled {
+                holder,
+                key: Box::new(key),
+            });
+        }
+        self.bandwidth_limiter.record(class, record.value.len());
+
+        Ok((*holder, Bytes::from(record.value)))
+    }
+
+    /// Ask a newly added peer to share a sample of its bootstrap cache, until we have asked
+    /// `MAX_BOOTSTRAP_CACHE_EXCHANGES` peers.
+    pub(crate) fn request_bootstrap_cache_exchange(&mut self, peer: PeerId) {
+        if self.bootstrap_cache.is_none()
+            || self.bootstrap_cache_exchanges.len() >= MAX_BOOTSTRAP_CACHE_EXCHANGES
+            || !self.bootstrap_cache_exchanges.insert(peer)
+        {
+            return;
+        }
+
+        debug!("Requesting a sample of the bootstrap cache of {peer:?}");
+        self.queue_network_swarm_cmd(NetworkSwarmCmd::SendRequest {
+            req: Request::Query(Query::GetBootstrapPeers {
+                requester: NetworkAddress::from_peer(self.self_peer_id),
+                holder: NetworkAddress::from_peer(peer),
+                max_addrs: MAX_SHARED_ADDRS,
+            }),
+            peer,
+            sender: None,
+        });
+    }
+
+    /// The well-scored addrs of our bootstrap cache, without the ones of the requester.
+    pub(crate) fn shareable_bootstrap_addrs(
+        &self,
+        requester: &NetworkAddress,
+        max_addrs: usize,
+    ) -> Vec<(Multiaddr, u32, u32)> {
+        let Some(bootstrap_cache) = self.bootstrap_cache.as_ref() else {
+            return vec![];
+        };
+        let requester = requester.as_peer_id();
+
+        bootstrap_cache
+            .get_shareable_addrs(max_addrs)
+            .into_iter()
+            .filter(|bootstrap_addr| bootstrap_addr.peer_id() != requester)
+            .map(|bootstrap_addr| {
+                (
+                    bootstrap_addr.addr,
+                    bootstrap_addr.success_count,
+                    bootstrap_addr.failure_count,
+                )
+            })
+            .collect()
+    }
+
+    /// Merge the addrs shared by a peer into our bootstrap cache.
+    fn sync_shared_bootstrap_addrs(&mut self, holder: PeerId, addrs: Vec<(Multiaddr, u32, u32)>) {
+        let Some(bootstrap_cache) = self.bootstrap_cache.as_mut() else {
+            return;
+        };
+        debug!("Received {} bootstrap addrs from {holder:?}", addrs.len());
+
+        let self_peer_id = Some(self.self_peer_id);
+        let addrs = addrs
+            .into_iter()
+            .map(|(addr, success_count, failure_count)| {
+                let mut bootstrap_addr = BootstrapAddr::new(addr);
+                bootstrap_addr.success_count = success_count;
+                bootstrap_addr.failure_count = failure_count;
+                bootstrap_addr
+            })
+            .filter(|bootstrap_addr| bootstrap_addr.peer_id() != self_peer_id)
+            .collect();
+        bootstrap_cache.sync_shared_addrs(addrs);
+    }
+
     pub(crate) fn add_keys_to_replication_fetcher(
         &mut self,
         sender: NetworkAddress,
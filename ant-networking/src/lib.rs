@@ -9,6 +9,7 @@
 #[macro_use]
 extern crate tracing;
 
+mod bandwidth;
 mod bootstrap;
 mod circular_vec;
 mod cmd;
@@ -29,6 +30,7 @@ mod relay_manager;
 mod replication_fetcher;
 pub mod time;
 mod transport;
+mod tuning;
 
 use cmd::LocalSwarmCmd;
 use xor_name::XorName;
@@ -42,10 +44,16 @@ pub use self::{
     event::{MsgResponder, NetworkEvent},
     graph::get_graph_entry_from_record,
     record_store::NodeRecordStore,
+    transport::TransportConfig,
+    tuning::NetworkTuning,
 };
+#[cfg(feature = "pnet")]
+pub use libp2p::pnet::PreSharedKey;
 #[cfg(feature = "open-metrics")]
 pub use metrics::service::MetricsRegistries;
 pub use time::{interval, sleep, spawn, Instant, Interval};
+#[cfg(feature = "pnet")]
+pub use transport::read_pre_shared_key;
 
 use self::{cmd::NetworkSwarmCmd, error::Result};
 use ant_evm::{PaymentQuote, QuotingMetrics};
@@ -745,6 +753,25 @@ impl Network {
         self.send_local_swarm_cmd(LocalSwarmCmd::PaymentReceived);
     }
 
+    /// Get a sample of the well-scored addrs from our bootstrap cache, to be shared with the
+    /// requester.
+    pub async fn get_shareable_bootstrap_addrs(
+        &self,
+        requester: NetworkAddress,
+        max_addrs: usize,
+    ) -> Result<Vec<(Multiaddr, u32, u32)>> {
+        let (sender, receiver) = oneshot::channel();
+        self.send_local_swarm_cmd(LocalSwarmCmd::GetShareableBootstrapAddrs {
+            requester,
+            max_addrs,
+            sender,
+        });
+
+        receiver
+            .await
+            .map_err(|_e| NetworkError::InternalMsgChannelDropped)
+    }
+
     /// Get `Record` from the local RecordStore
     pub async fn get_local_record(&self, key: &RecordKey) -> Result<Option<Record>> {
         let (sender, receiver) = oneshot::channel();
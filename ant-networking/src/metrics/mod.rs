@@ -14,7 +14,7 @@ mod upnp;
 
 use std::sync::atomic::AtomicU64;
 
-use crate::MetricsRegistries;
+use crate::{bandwidth::TrafficClassLabels, MetricsRegistries};
 use crate::{log_markers::Marker, time::sleep};
 use bad_node::{BadNodeMetrics, BadNodeMetricsMsg, TimeFrame};
 use libp2p::{
@@ -47,6 +47,7 @@ pub(crate) struct NetworkMetricsRecorder {
     pub(crate) peers_in_routing_table: Gauge,
     pub(crate) records_stored: Gauge,
     pub(crate) relay_reservation_health: Gauge<f64, AtomicU64>,
+    pub(crate) bandwidth_throttled_bytes: Family<TrafficClassLabels, Counter>,
 
     // quoting metrics
     relevant_records: Gauge,
@@ -148,6 +149,13 @@ impl NetworkMetricsRecorder {
             relay_client_events.clone(),
         );
 
+        let bandwidth_throttled_bytes = Family::default();
+        sub_registry.register(
+            "bandwidth_throttled_bytes",
+            "The number of bytes that were not transferred as the bandwidth limit of their traffic class was reached",
+            bandwidth_throttled_bytes.clone(),
+        );
+
         let process_memory_used_mb = Gauge::<f64, AtomicU64>::default();
         sub_registry.register(
             "process_memory_used_mb",
@@ -229,6 +237,7 @@ impl NetworkMetricsRecorder {
             connected_peers,
             open_connections,
             relay_reservation_health,
+            bandwidth_throttled_bytes,
             peers_in_routing_table,
             relevant_records,
             max_records,
@@ -22,7 +22,7 @@ use std::time::Instant;
 #[cfg(feature = "open-metrics")]
 use std::{collections::btree_map::Entry, time::SystemTime};
 
-const MAX_CONCURRENT_RELAY_CONNECTIONS: usize = 4;
+pub(crate) const MAX_CONCURRENT_RELAY_CONNECTIONS: usize = 4;
 const MAX_POTENTIAL_CANDIDATES: usize = 1000;
 
 /// We could get multiple incoming connections from the same peer through multiple relay servers, and only one of them
@@ -52,6 +52,8 @@ pub(crate) fn is_a_relayed_peer(addrs: &HashSet<Multiaddr>) -> bool {
 #[derive(Debug)]
 pub(crate) struct RelayManager {
     self_peer_id: PeerId,
+    /// The max number of relay servers to keep reservations with.
+    max_concurrent_relay_connections: usize,
     /// The potential relay servers that we can connect to.
     relay_server_candidates: VecDeque<(PeerId, Multiaddr)>,
     /// The relay servers that we are waiting for a reservation from.
@@ -106,9 +108,10 @@ impl ReservationStat {
 }
 
 impl RelayManager {
-    pub(crate) fn new(self_peer_id: PeerId) -> Self {
+    pub(crate) fn new(self_peer_id: PeerId, max_concurrent_relay_connections: usize) -> Self {
         Self {
             self_peer_id,
+            max_concurrent_relay_connections,
             connected_relay_servers: Default::default(),
             waiting_for_reservation: Default::default(),
             relay_server_candidates: Default::default(),
@@ -169,14 +172,14 @@ impl RelayManager {
         swarm: &mut Swarm<NodeBehaviour>,
         bad_nodes: &BadNodes,
     ) {
-        if self.connected_relay_servers.len() >= MAX_CONCURRENT_RELAY_CONNECTIONS
+        if self.connected_relay_servers.len() >= self.max_concurrent_relay_connections
             || self.relay_server_candidates.is_empty()
         {
             return;
         }
 
         let reservations_to_make =
-            MAX_CONCURRENT_RELAY_CONNECTIONS - self.connected_relay_servers.len();
+            self.max_concurrent_relay_connections - self.connected_relay_servers.len();
         let mut n_reservations = 0;
 
         while n_reservations < reservations_to_make {
@@ -243,7 +246,7 @@ impl RelayManager {
             }
         }
 
-        if self.connected_relay_servers.len() == MAX_CONCURRENT_RELAY_CONNECTIONS {
+        if self.connected_relay_servers.len() == self.max_concurrent_relay_connections {
             debug!("We have reached the maximum number of relay connections. Push new identify info to all connected peers");
             // send identify to all connected peers.
 
@@ -8,7 +8,12 @@
 #![allow(clippy::mutable_key_type)]
 
 use crate::time::spawn;
-use crate::{event::NetworkEvent, time::Instant, CLOSE_GROUP_SIZE};
+use crate::{
+    bandwidth::{BandwidthLimiter, TrafficClass},
+    event::NetworkEvent,
+    time::Instant,
+    CLOSE_GROUP_SIZE,
+};
 use ant_protocol::{
     storage::{DataTypes, ValidationType},
     NetworkAddress, PrettyPrintRecordKey,
@@ -17,17 +22,20 @@ use libp2p::{
     kad::{KBucketDistance as Distance, RecordKey, K_VALUE},
     PeerId,
 };
-use std::collections::{hash_map::Entry, BTreeMap, HashMap, HashSet, VecDeque};
+use std::{
+    collections::{hash_map::Entry, BTreeMap, HashMap, HashSet, VecDeque},
+    sync::Arc,
+};
 use tokio::{sync::mpsc, time::Duration};
 
 // Max parallel fetches that can be undertaken at the same time.
-const MAX_PARALLEL_FETCH: usize = K_VALUE.get();
+pub(crate) const MAX_PARALLEL_FETCH: usize = K_VALUE.get();
 
 // The duration after which a peer will be considered failed to fetch data from,
 // if no response got from that peer.
 // Note this will also cover the period that node self write the fetched copy to disk.
 // Hence shall give a longer time as allowance.
-const FETCH_TIMEOUT: Duration = Duration::from_secs(20);
+pub(crate) const FETCH_TIMEOUT: Duration = Duration::from_secs(20);
 
 // The duration after which a pending entry shall be cleared from the `to_be_fetch` list.
 // This is to avoid holding too many outdated entries when the fetching speed is slow.
@@ -39,6 +47,12 @@ type ReplicationTimeout = Instant;
 #[derive(Debug)]
 pub(crate) struct ReplicationFetcher {
     self_peer_id: PeerId,
+    /// Max number of fetches that can be undertaken at the same time.
+    max_parallel_fetch: usize,
+    /// Duration after which a fetch from a peer is considered as failed.
+    fetch_timeout: Duration,
+    /// New fetches are held back while the replication bandwidth budget is used up.
+    bandwidth_limiter: Arc<BandwidthLimiter>,
     // Pending entries that to be fetched from the target peer.
     to_be_fetched: HashMap<(RecordKey, ValidationType, PeerId), ReplicationTimeout>,
     // Avoid fetching same chunk from different nodes AND carry out too many parallel tasks.
@@ -60,10 +74,19 @@ pub(crate) struct ReplicationFetcher {
 }
 
 impl ReplicationFetcher {
-    /// Instantiate a new replication fetcher with passed PeerId.
-    pub(crate) fn new(self_peer_id: PeerId, event_sender: mpsc::Sender<NetworkEvent>) -> Self {
+    /// Instantiate a new replication fetcher with passed PeerId and fetch limits.
+    pub(crate) fn new(
+        self_peer_id: PeerId,
+        max_parallel_fetch: usize,
+        fetch_timeout: Duration,
+        bandwidth_limiter: Arc<BandwidthLimiter>,
+        event_sender: mpsc::Sender<NetworkEvent>,
+    ) -> Self {
         Self {
             self_peer_id,
+            max_parallel_fetch,
+            fetch_timeout,
+            bandwidth_limiter,
             to_be_fetched: HashMap::new(),
             on_going_fetches: HashMap::new(),
             event_sender,
@@ -116,7 +139,7 @@ impl ReplicationFetcher {
                     let new_data_key = (addr.to_record_key(), record_type);
                     if let Entry::Vacant(entry) = self.on_going_fetches.entry(new_data_key) {
                         keys_to_fetch.push((holder, addr.to_record_key()));
-                        let _ = entry.insert((holder, Instant::now() + FETCH_TIMEOUT));
+                        let _ = entry.insert((holder, Instant::now() + self.fetch_timeout));
                     }
                 } else {
                     let _ = self
@@ -204,13 +227,21 @@ impl ReplicationFetcher {
 
     // Returns the set of keys that has to be fetched from the peer/network.
     // Target must not be under-fetching
-    // and no more than MAX_PARALLEL_FETCH fetches to be undertaken at the same time.
+    // and no more than `max_parallel_fetch` fetches to be undertaken at the same time.
     pub(crate) fn next_keys_to_fetch(&mut self) -> Vec<(PeerId, RecordKey)> {
         self.prune_expired_keys_and_slow_nodes();
 
         debug!("Next to fetch....");
 
-        if self.on_going_fetches.len() >= MAX_PARALLEL_FETCH {
+        if self
+            .bandwidth_limiter
+            .is_throttled(TrafficClass::Replication)
+        {
+            debug!("Replication bandwidth budget is used up, holding back new fetches.");
+            return vec![];
+        }
+
+        if self.on_going_fetches.len() >= self.max_parallel_fetch {
             warn!("Replication Fetcher doesn't have free fetch capacity. Currently has {} entries in queue.",
                 self.to_be_fetched.len());
             return vec![];
@@ -227,7 +258,7 @@ impl ReplicationFetcher {
         );
 
         // Pre-allocate vectors with known capacity
-        let remaining_capacity = MAX_PARALLEL_FETCH - self.on_going_fetches.len();
+        let remaining_capacity = self.max_parallel_fetch - self.on_going_fetches.len();
         let mut data_to_fetch = Vec::with_capacity(remaining_capacity);
 
         // Sort to_be_fetched by key closeness to our PeerId
@@ -245,7 +276,7 @@ impl ReplicationFetcher {
             // Already carried out expiration pruning above.
             // Hence here only need to check whether is ongoing fetching.
             // Also avoid fetching same record from different nodes.
-            if self.on_going_fetches.len() < MAX_PARALLEL_FETCH
+            if self.on_going_fetches.len() < self.max_parallel_fetch
                 && !self
                     .on_going_fetches
                     .contains_key(&(key.clone(), t.clone()))
@@ -253,12 +284,12 @@ impl ReplicationFetcher {
                 data_to_fetch.push((*holder, key.clone(), t.clone()));
                 let _ = self.on_going_fetches.insert(
                     (key.clone(), t.clone()),
-                    (*holder, Instant::now() + FETCH_TIMEOUT),
+                    (*holder, Instant::now() + self.fetch_timeout),
                 );
             }
 
             // break out the loop early if we can do no more now
-            if self.on_going_fetches.len() >= MAX_PARALLEL_FETCH {
+            if self.on_going_fetches.len() >= self.max_parallel_fetch {
                 break;
             }
         }
@@ -611,7 +642,13 @@ mod tests {
         //random peer_id
         let peer_id = PeerId::random();
         let (event_sender, _event_receiver) = mpsc::channel(4);
-        let mut replication_fetcher = ReplicationFetcher::new(peer_id, event_sender);
+        let mut replication_fetcher = ReplicationFetcher::new(
+            peer_id,
+            MAX_PARALLEL_FETCH,
+            FETCH_TIMEOUT,
+            Default::default(),
+            event_sender,
+        );
         let locally_stored_keys = HashMap::new();
 
         let mut incoming_keys = Vec::new();
@@ -685,7 +722,13 @@ mod tests {
         let peer_id = PeerId::random();
         let self_address = NetworkAddress::from_peer(peer_id);
         let (event_sender, _event_receiver) = mpsc::channel(4);
-        let mut replication_fetcher = ReplicationFetcher::new(peer_id, event_sender);
+        let mut replication_fetcher = ReplicationFetcher::new(
+            peer_id,
+            MAX_PARALLEL_FETCH,
+            FETCH_TIMEOUT,
+            Default::default(),
+            event_sender,
+        );
 
         // Set distance range
         let distance_target = NetworkAddress::from_peer(PeerId::random());
@@ -8,27 +8,159 @@
 
 #[cfg(feature = "open-metrics")]
 use crate::MetricsRegistries;
+#[cfg(feature = "pnet")]
+use libp2p::pnet::{PnetConfig, PreSharedKey};
 use libp2p::{
     core::{muxing::StreamMuxerBox, transport},
     identity::Keypair,
-    PeerId, Transport as _,
+    multiaddr::Protocol,
+    Multiaddr, PeerId, Transport as _,
 };
+use std::borrow::Borrow;
+#[cfg(feature = "pnet")]
+use std::path::Path;
 
 const MAX_STREAM_DATA_ENV_STR: &str = "ANT_MAX_STREAM_DATA";
 
+/// The transports to enable alongside QUIC, which is always enabled unless a private network
+/// key is set.
+///
+/// The TCP and WebSocket transports are secured with Noise and multiplexed with Yamux. Setting
+/// the port of a transport enables it. Nodes listen on that port, whereas clients only dial out
+/// and therefore ignore the port.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransportConfig {
+    /// Enable the TCP transport.
+    #[cfg(feature = "tcp")]
+    pub tcp: Option<u16>,
+    /// Enable the WebSocket transport.
+    #[cfg(feature = "websocket")]
+    pub websocket: Option<u16>,
+    /// Only connect to peers holding the same pre-shared key, forming a private network.
+    ///
+    /// Every connection is encrypted with the key before any other protocol runs on it, so peers
+    /// without the key cannot even complete a handshake. As QUIC cannot be wrapped this way, it
+    /// is disabled and TCP is always enabled. Nodes without a TCP port then listen for TCP on
+    /// their QUIC port.
+    #[cfg(feature = "pnet")]
+    pub pre_shared_key: Option<PreSharedKey>,
+}
+
+impl std::fmt::Debug for TransportConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("TransportConfig");
+        #[cfg(feature = "tcp")]
+        let _ = debug.field("tcp", &self.tcp);
+        #[cfg(feature = "websocket")]
+        let _ = debug.field("websocket", &self.websocket);
+        // Only show the fingerprint, so the key does not end up in the logs.
+        #[cfg(feature = "pnet")]
+        let _ = debug.field(
+            "pre_shared_key_fingerprint",
+            &self.pre_shared_key.map(|key| key.fingerprint().to_string()),
+        );
+        debug.finish()
+    }
+}
+
+impl TransportConfig {
+    /// Returns true if any transport other than QUIC is enabled.
+    pub fn has_fallback_transports(&self) -> bool {
+        // Every transport is disabled by default.
+        *self != Self::default()
+    }
+
+    /// Returns true if the QUIC transport is enabled.
+    pub fn is_quic_enabled(&self) -> bool {
+        #[cfg(feature = "pnet")]
+        if self.pre_shared_key.is_some() {
+            return false;
+        }
+        true
+    }
+
+    /// Returns true if the TCP transport is enabled.
+    #[cfg(feature = "tcp")]
+    pub fn is_tcp_enabled(&self) -> bool {
+        !self.is_quic_enabled() || self.tcp.is_some()
+    }
+}
+
+/// Read a pre-shared key from a file, in the `/key/swarm/psk/1.0.0/` format shared with the
+/// other libp2p implementations.
+#[cfg(feature = "pnet")]
+pub fn read_pre_shared_key(path: &Path) -> std::io::Result<PreSharedKey> {
+    let content = std::fs::read_to_string(path)?;
+    content.parse().map_err(|err| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("Invalid pre-shared key in {path:?}: {err}"),
+        )
+    })
+}
+
 pub(crate) fn build_transport(
     keypair: &Keypair,
+    #[allow(unused_variables)] config: &TransportConfig,
     #[cfg(feature = "open-metrics")] registries: &mut MetricsRegistries,
 ) -> transport::Boxed<(PeerId, StreamMuxerBox)> {
-    let trans = generate_quic_transport(keypair);
-    #[cfg(feature = "open-metrics")]
-    let trans = libp2p::metrics::BandwidthTransport::new(trans, &mut registries.standard_metrics);
+    let trans = if config.is_quic_enabled() {
+        generate_quic_transport(keypair)
+            .map(|(peer_id, muxer), _| (peer_id, StreamMuxerBox::new(muxer)))
+            .boxed()
+    } else {
+        tracing::info!("Private network key set, disabling the QUIC transport");
+        transport::dummy::DummyTransport::new().boxed()
+    };
 
-    let trans = trans.map(|(peer_id, muxer), _| (peer_id, StreamMuxerBox::new(muxer)));
+    // WebSocket has to come before TCP, so that `/tcp/../ws` addresses are not taken by TCP.
+    #[cfg(feature = "websocket")]
+    let trans = if config.websocket.is_some() {
+        tracing::info!("Enabling the WebSocket transport");
+        generate_websocket_transport(keypair, config)
+            .or_transport(trans)
+            .map(|either_output, _| either_output.into_inner())
+            .boxed()
+    } else {
+        trans
+    };
+
+    #[cfg(feature = "tcp")]
+    let trans = if config.is_tcp_enabled() {
+        tracing::info!("Enabling the TCP transport");
+        trans
+            .or_transport(generate_tcp_transport(keypair, config))
+            .map(|either_output, _| either_output.into_inner())
+            .boxed()
+    } else {
+        trans
+    };
+
+    #[cfg(feature = "open-metrics")]
+    let trans = libp2p::metrics::BandwidthTransport::new(trans, &mut registries.standard_metrics)
+        .map(|(peer_id, muxer), _| (peer_id, StreamMuxerBox::new(muxer)));
 
     trans.boxed()
 }
 
+/// Order the addresses by the preferred transport to dial them with: QUIC first, then TCP and
+/// lastly WebSocket. The order is otherwise preserved.
+pub(crate) fn sort_addrs_by_transport_preference<A: Borrow<Multiaddr>>(addrs: &mut [A]) {
+    addrs.sort_by_key(|addr| transport_preference(addr.borrow()));
+}
+
+fn transport_preference(addr: &Multiaddr) -> u8 {
+    if addr.iter().any(|p| matches!(p, Protocol::QuicV1)) {
+        0
+    } else if addr.iter().any(|p| matches!(p, Protocol::Ws(_))) {
+        2
+    } else if addr.iter().any(|p| matches!(p, Protocol::Tcp(_))) {
+        1
+    } else {
+        3
+    }
+}
+
 fn generate_quic_transport(
     keypair: &Keypair,
 ) -> libp2p::quic::GenTransport<libp2p::quic::tokio::Provider> {
@@ -47,3 +179,109 @@ fn generate_quic_transport(
 
     libp2p::quic::tokio::Transport::new(quic_config)
 }
+
+#[cfg(feature = "tcp")]
+fn generate_tcp_transport(
+    keypair: &Keypair,
+    config: &TransportConfig,
+) -> transport::Boxed<(PeerId, StreamMuxerBox)> {
+    let tcp = libp2p::tcp::tokio::Transport::new(libp2p::tcp::Config::default().nodelay(true));
+    secure_and_multiplex(tcp, keypair, config)
+}
+
+#[cfg(feature = "websocket")]
+fn generate_websocket_transport(
+    keypair: &Keypair,
+    config: &TransportConfig,
+) -> transport::Boxed<(PeerId, StreamMuxerBox)> {
+    let ws = libp2p::websocket::WsConfig::new(libp2p::tcp::tokio::Transport::new(
+        libp2p::tcp::Config::default().nodelay(true),
+    ));
+    secure_and_multiplex(ws, keypair, config)
+}
+
+/// Upgrade the raw streams of a transport with Noise and Yamux, after the private network
+/// handshake if a pre-shared key is set.
+#[cfg(feature = "tcp")]
+fn secure_and_multiplex<T>(
+    trans: T,
+    keypair: &Keypair,
+    #[allow(unused_variables)] config: &TransportConfig,
+) -> transport::Boxed<(PeerId, StreamMuxerBox)>
+where
+    T: transport::Transport + Send + Unpin + 'static,
+    T::Output: futures::AsyncRead + futures::AsyncWrite + Send + Unpin + 'static,
+    T::Error: Send + Sync + 'static,
+    T::Dial: Send + 'static,
+    T::ListenerUpgrade: Send + 'static,
+{
+    #[cfg(feature = "pnet")]
+    let trans = {
+        let pre_shared_key = config.pre_shared_key;
+        trans.and_then(move |socket, _| async move {
+            match pre_shared_key {
+                Some(key) => PnetConfig::new(key)
+                    .handshake(socket)
+                    .await
+                    .map(futures::future::Either::Left),
+                None => Ok(futures::future::Either::Right(socket)),
+            }
+        })
+    };
+
+    trans
+        .upgrade(libp2p::core::upgrade::Version::V1Lazy)
+        .authenticate(
+            libp2p::noise::Config::new(keypair)
+                .expect("Signing libp2p-noise static DH keypair failed."),
+        )
+        .multiplex(libp2p::yamux::Config::default())
+        .map(|(peer_id, muxer), _| (peer_id, StreamMuxerBox::new(muxer)))
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn addrs_should_be_sorted_quic_first() {
+        let ws: Multiaddr = "/ip4/1.2.3.4/tcp/1202/ws".parse().expect("valid multiaddr");
+        let tcp: Multiaddr = "/ip4/1.2.3.4/tcp/1201".parse().expect("valid multiaddr");
+        let quic: Multiaddr = "/ip4/1.2.3.4/udp/1200/quic-v1"
+            .parse()
+            .expect("valid multiaddr");
+
+        let mut addrs = vec![ws.clone(), tcp.clone(), quic.clone()];
+        sort_addrs_by_transport_preference(&mut addrs);
+
+        assert_eq!(addrs, vec![quic, tcp, ws]);
+    }
+
+    #[cfg(feature = "pnet")]
+    #[test]
+    fn pre_shared_key_should_replace_quic_with_tcp() -> eyre::Result<()> {
+        let key = PreSharedKey::new([7; 32]);
+        let dir = assert_fs::TempDir::new()?;
+        let path = dir.path().join("swarm.key");
+        std::fs::write(&path, key.to_string())?;
+        assert_eq!(read_pre_shared_key(&path)?, key);
+
+        let config = TransportConfig {
+            pre_shared_key: Some(key),
+            ..Default::default()
+        };
+        assert!(!config.is_quic_enabled());
+        assert!(config.is_tcp_enabled());
+        assert!(TransportConfig::default().is_quic_enabled());
+
+        // The key itself must never be printed.
+        let hex_key = "07".repeat(32);
+        assert!(!format!("{config:?}").contains(&hex_key));
+
+        std::fs::write(&path, "not a key")?;
+        assert!(read_pre_shared_key(&path).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::{
+    driver::{MAX_PACKET_SIZE, REQUEST_TIMEOUT_DEFAULT_S},
+    record_store::MAX_RECORDS_COUNT,
+    relay_manager::MAX_CONCURRENT_RELAY_CONNECTIONS,
+    replication_fetcher::{FETCH_TIMEOUT, MAX_PARALLEL_FETCH},
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Tunable parameters of the networking layer.
+///
+/// The defaults are the values used by the public network. Changing them is only intended for
+/// private testnets or constrained deployments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NetworkTuning {
+    /// Timeout, in seconds, for requests sent or received through the request/response protocol.
+    pub request_timeout_s: u64,
+    /// Max number of records that can be fetched in parallel during replication.
+    pub replication_max_parallel_fetch: usize,
+    /// Time, in seconds, after which a replication fetch from a peer is considered as failed.
+    pub replication_fetch_timeout_s: u64,
+    /// Max number of relay servers a node behind a home network keeps reservations with.
+    pub max_concurrent_relay_connections: usize,
+    /// Max bytes per second of replication traffic, sent and received combined.
+    /// Unlimited if not set.
+    pub replication_bandwidth_limit: Option<u64>,
+    /// Max bytes per second of records sent to clients fetching them directly from the node.
+    /// Unlimited if not set.
+    pub client_bandwidth_limit: Option<u64>,
+    /// Max bytes of records the node stores. The record store holds fewer records than the
+    /// default max if each one could be as large as the max packet size and still fit.
+    /// Uses the default max number of records if not set.
+    pub max_storage_bytes: Option<u64>,
+}
+
+impl Default for NetworkTuning {
+    fn default() -> Self {
+        Self {
+            request_timeout_s: REQUEST_TIMEOUT_DEFAULT_S.as_secs(),
+            replication_max_parallel_fetch: MAX_PARALLEL_FETCH,
+            replication_fetch_timeout_s: FETCH_TIMEOUT.as_secs(),
+            max_concurrent_relay_connections: MAX_CONCURRENT_RELAY_CONNECTIONS,
+            replication_bandwidth_limit: None,
+            client_bandwidth_limit: None,
+            max_storage_bytes: None,
+        }
+    }
+}
+
+impl NetworkTuning {
+    /// Check that the values can be used, returning a description of the first invalid one.
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        if self.request_timeout_s == 0 {
+            return Err("request_timeout_s must be greater than 0".to_string());
+        }
+        if self.replication_max_parallel_fetch == 0 {
+            return Err("replication_max_parallel_fetch must be greater than 0".to_string());
+        }
+        if self.replication_fetch_timeout_s == 0 {
+            return Err("replication_fetch_timeout_s must be greater than 0".to_string());
+        }
+        if self.max_concurrent_relay_connections == 0 {
+            return Err("max_concurrent_relay_connections must be greater than 0".to_string());
+        }
+        if self.replication_bandwidth_limit == Some(0) {
+            return Err("replication_bandwidth_limit must be greater than 0".to_string());
+        }
+        if self.client_bandwidth_limit == Some(0) {
+            return Err("client_bandwidth_limit must be greater than 0".to_string());
+        }
+        if let Some(bytes) = self.max_storage_bytes {
+            if bytes < MAX_PACKET_SIZE as u64 {
+                return Err(format!(
+                    "max_storage_bytes must be at least the max record size of {MAX_PACKET_SIZE} bytes"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The max number of records the record store can hold.
+    pub(crate) fn max_records(&self) -> usize {
+        match self.max_storage_bytes {
+            Some(bytes) => {
+                let records = bytes / MAX_PACKET_SIZE as u64;
+                usize::try_from(records)
+                    .unwrap_or(MAX_RECORDS_COUNT)
+                    .min(MAX_RECORDS_COUNT)
+            }
+            None => MAX_RECORDS_COUNT,
+        }
+    }
+
+    pub(crate) fn request_timeout(&self) -> Duration {
+        Duration::from_secs(self.request_timeout_s)
+    }
+
+    pub(crate) fn replication_fetch_timeout(&self) -> Duration {
+        Duration::from_secs(self.replication_fetch_timeout_s)
+    }
+}
@@ -9,7 +9,7 @@
 use ant_bootstrap::PeersArgs;
 use ant_evm::{EvmNetwork, RewardsAddress};
 use ant_logging::LogFormat;
-use ant_service_management::node::push_arguments_from_peers_args;
+use ant_service_management::{node::push_arguments_from_peers_args, resources::ResourceLimits};
 use color_eyre::{eyre::eyre, Result};
 use service_manager::{ServiceInstallCtx, ServiceLabel};
 use std::{
@@ -86,6 +86,7 @@ pub struct InstallNodeServiceCtxBuilder {
     pub node_ip: Option<Ipv4Addr>,
     pub node_port: Option<u16>,
     pub peers_args: PeersArgs,
+    pub resource_limits: ResourceLimits,
     pub rewards_address: RewardsAddress,
     pub rpc_socket_addr: SocketAddr,
     pub service_user: Option<String>,
@@ -139,6 +140,26 @@ impl InstallNodeServiceCtxBuilder {
             args.push(OsString::from("--max-log-files"));
             args.push(OsString::from(log_files.to_string()));
         }
+        if let Some(bandwidth) = self.resource_limits.max_replication_bandwidth {
+            args.push(OsString::from("--max-replication-bandwidth"));
+            args.push(OsString::from(bandwidth.to_string()));
+        }
+        if let Some(bandwidth) = self.resource_limits.max_client_bandwidth {
+            args.push(OsString::from("--max-client-bandwidth"));
+            args.push(OsString::from(bandwidth.to_string()));
+        }
+        if let Some(storage_quota) = self.resource_limits.storage_quota {
+            args.push(OsString::from("--max-storage"));
+            args.push(OsString::from(storage_quota.to_string()));
+        }
+        if let Some(max_memory) = self.resource_limits.max_memory {
+            args.push(OsString::from("--max-memory"));
+            args.push(OsString::from(max_memory.to_string()));
+        }
+        if let Some(max_open_files) = self.resource_limits.max_open_files {
+            args.push(OsString::from("--max-open-files"));
+            args.push(OsString::from(max_open_files.to_string()));
+        }
 
         args.push(OsString::from("--rewards-address"));
         args.push(OsString::from(self.rewards_address.to_string()));
@@ -189,6 +210,7 @@ pub struct AddNodeServiceOptions {
     pub node_ip: Option<Ipv4Addr>,
     pub node_port: Option<PortRange>,
     pub peers_args: PeersArgs,
+    pub resource_limits: ResourceLimits,
     pub rewards_address: RewardsAddress,
     pub rpc_address: Option<Ipv4Addr>,
     pub rpc_port: Option<PortRange>,
@@ -326,6 +348,7 @@ mod tests {
                 .unwrap(),
             rpc_socket_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8080),
             service_user: None,
+            resource_limits: ResourceLimits::default(),
             upnp: false,
         }
     }
@@ -362,6 +385,7 @@ mod tests {
             rpc_socket_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8080),
             antnode_path: PathBuf::from("/bin/antnode"),
             service_user: None,
+            resource_limits: ResourceLimits::default(),
             upnp: false,
         }
     }
@@ -398,6 +422,7 @@ mod tests {
             rpc_socket_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8080),
             antnode_path: PathBuf::from("/bin/antnode"),
             service_user: None,
+            resource_limits: ResourceLimits::default(),
             upnp: false,
         }
     }
@@ -481,6 +506,14 @@ mod tests {
         builder.node_ip = Some(Ipv4Addr::new(192, 168, 1, 1));
         builder.node_port = Some(12345);
         builder.metrics_port = Some(9090);
+        builder.resource_limits = ResourceLimits {
+            max_client_bandwidth: Some(1_000_000),
+            max_replication_bandwidth: Some(2_000_000),
+            storage_quota: Some(10_000_000_000),
+            max_memory: Some(4_000_000_000),
+            max_open_files: Some(4096),
+            ..Default::default()
+        };
         builder.peers_args.addrs = vec![
             "/ip4/127.0.0.1/tcp/8080".parse().unwrap(),
             "/ip4/192.168.1.1/tcp/8081".parse().unwrap(),
@@ -525,6 +558,16 @@ mod tests {
             "10",
             "--max-log-files",
             "10",
+            "--max-replication-bandwidth",
+            "2000000",
+            "--max-client-bandwidth",
+            "1000000",
+            "--max-storage",
+            "10000000000",
+            "--max-memory",
+            "4000000000",
+            "--max-open-files",
+            "4096",
             "--rewards-address",
             "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             "evm-custom",
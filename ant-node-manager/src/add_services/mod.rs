@@ -208,11 +208,25 @@ pub async fn add_node(
             rpc_socket_addr,
             antnode_path: service_antnode_path.clone(),
             service_user: options.user.clone(),
+            resource_limits: options.resource_limits.clone(),
             upnp: options.upnp,
         }
         .build()?;
 
-        match service_control.install(install_ctx, options.user_mode) {
+        let install_result = service_control
+            .install(install_ctx, options.user_mode)
+            .and_then(|()| {
+                if options.resource_limits.has_process_limits() {
+                    service_control.set_resource_limits(
+                        &service_name,
+                        &options.resource_limits,
+                        options.user_mode,
+                    )
+                } else {
+                    Ok(())
+                }
+            });
+        match install_result {
             Ok(()) => {
                 info!("Successfully added service {service_name}");
                 added_service_data.push((
@@ -240,6 +254,7 @@ pub async fn add_node(
                     node_ip: options.node_ip,
                     node_port,
                     number: node_number,
+                    resource_limits: options.resource_limits.clone(),
                     rewards_address: options.rewards_address,
                     reward_balance: None,
                     rpc_socket_addr,
@@ -248,6 +263,7 @@ pub async fn add_node(
                     pid: None,
                     service_name,
                     status: ServiceStatus::Added,
+                    supervisor_backend: crate::get_supervisor_backend(),
                     upnp: options.upnp,
                     user: options.user.clone(),
                     user_mode: options.user_mode,
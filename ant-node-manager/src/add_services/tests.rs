@@ -14,12 +14,14 @@ use crate::{
             AddNodeServiceOptions, InstallNodeServiceCtxBuilder, PortRange,
         },
     },
-    VerbosityLevel,
+    SupervisorBackend, VerbosityLevel,
 };
 use ant_bootstrap::PeersArgs;
 use ant_evm::{AttoTokens, CustomNetwork, EvmNetwork, RewardsAddress};
 use ant_service_management::{auditor::AuditorServiceData, control::ServiceControl};
-use ant_service_management::{error::Result as ServiceControlResult, NatDetectionStatus};
+use ant_service_management::{
+    error::Result as ServiceControlResult, resources::ResourceLimits, NatDetectionStatus,
+};
 use ant_service_management::{
     DaemonServiceData, FaucetServiceData, NodeRegistry, NodeServiceData, ServiceStatus,
 };
@@ -60,6 +62,7 @@ mock! {
         fn get_available_port(&self) -> ServiceControlResult<u16>;
         fn install(&self, install_ctx: ServiceInstallCtx, user_mode: bool) -> ServiceControlResult<()>;
         fn get_process_pid(&self, bin_path: &Path) -> ServiceControlResult<u32>;
+        fn set_resource_limits(&self, service_name: &str, limits: &ResourceLimits, user_mode: bool) -> ServiceControlResult<()>;
         fn start(&self, service_name: &str, user_mode: bool) -> ServiceControlResult<()>;
         fn stop(&self, service_name: &str, user_mode: bool) -> ServiceControlResult<()>;
         fn uninstall(&self, service_name: &str, user_mode: bool) -> ServiceControlResult<()>;
@@ -150,6 +153,7 @@ async fn add_genesis_node_should_use_latest_version_and_add_one_service() -> Res
             .join("antnode1")
             .join(ANTNODE_FILE_NAME),
         service_user: Some(get_username()),
+        resource_limits: ResourceLimits::default(),
         upnp: false,
     }
     .build()?;
@@ -196,6 +200,7 @@ async fn add_genesis_node_should_use_latest_version_and_add_one_service() -> Res
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -301,12 +306,14 @@ async fn add_genesis_node_should_return_an_error_if_there_is_already_a_genesis_n
             peer_id: None,
             peers_args: peers_args.clone(),
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
             reward_balance: Some(AttoTokens::zero()),
             rpc_socket_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8081),
             status: ServiceStatus::Added,
+            supervisor_backend: SupervisorBackend::Os,
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             upnp: false,
@@ -364,6 +371,7 @@ async fn add_genesis_node_should_return_an_error_if_there_is_already_a_genesis_n
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -453,6 +461,7 @@ async fn add_genesis_node_should_return_an_error_if_count_is_greater_than_1() ->
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -537,6 +546,7 @@ async fn add_node_should_use_latest_version_and_add_three_services() -> Result<(
             .join(ANTNODE_FILE_NAME),
         rewards_address: RewardsAddress::from_str("0x03B770D9cD32077cC0bF330c13C114a87643B124")?,
         service_user: Some(get_username()),
+        resource_limits: ResourceLimits::default(),
         upnp: false,
     }
     .build()?;
@@ -585,6 +595,7 @@ async fn add_node_should_use_latest_version_and_add_three_services() -> Result<(
             .join("antnode2")
             .join(ANTNODE_FILE_NAME),
         service_user: Some(get_username()),
+        resource_limits: ResourceLimits::default(),
         upnp: false,
     }
     .build()?;
@@ -633,6 +644,7 @@ async fn add_node_should_use_latest_version_and_add_three_services() -> Result<(
             .join("antnode3")
             .join(ANTNODE_FILE_NAME),
         service_user: Some(get_username()),
+        resource_limits: ResourceLimits::default(),
         upnp: false,
     }
     .build()?;
@@ -680,6 +692,7 @@ async fn add_node_should_use_latest_version_and_add_three_services() -> Result<(
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -814,6 +827,7 @@ async fn add_node_should_update_the_environment_variables_inside_node_registry()
             .join("antnode1")
             .join(ANTNODE_FILE_NAME),
         service_user: Some(get_username()),
+        resource_limits: ResourceLimits::default(),
         upnp: false,
     }
     .build()?;
@@ -860,6 +874,7 @@ async fn add_node_should_update_the_environment_variables_inside_node_registry()
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -938,6 +953,7 @@ async fn add_new_node_should_add_another_service() -> Result<()> {
             peer_id: None,
             peers_args: PeersArgs::default(),
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -946,6 +962,7 @@ async fn add_new_node_should_add_another_service() -> Result<()> {
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Added,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -999,6 +1016,7 @@ async fn add_new_node_should_add_another_service() -> Result<()> {
             .join("antnode2")
             .join(ANTNODE_FILE_NAME),
         service_user: Some(get_username()),
+        resource_limits: ResourceLimits::default(),
         upnp: false,
     }
     .build()?;
@@ -1046,6 +1064,7 @@ async fn add_new_node_should_add_another_service() -> Result<()> {
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1209,6 +1228,7 @@ async fn add_node_should_create_service_file_with_first_arg() -> Result<()> {
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1365,6 +1385,7 @@ async fn add_node_should_create_service_file_with_peers_args() -> Result<()> {
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1516,6 +1537,7 @@ async fn add_node_should_create_service_file_with_local_arg() -> Result<()> {
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1671,6 +1693,7 @@ async fn add_node_should_create_service_file_with_network_contacts_url_arg() ->
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1825,6 +1848,7 @@ async fn add_node_should_create_service_file_with_testnet_arg() -> Result<()> {
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1976,6 +2000,7 @@ async fn add_node_should_create_service_file_with_ignore_cache_arg() -> Result<(
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -2128,6 +2153,7 @@ async fn add_node_should_create_service_file_with_custom_bootstrap_cache_path()
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -2273,6 +2299,7 @@ async fn add_node_should_create_service_file_with_network_id() -> Result<()> {
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -2416,6 +2443,7 @@ async fn add_node_should_use_custom_ip() -> Result<()> {
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -2501,6 +2529,7 @@ async fn add_node_should_use_custom_ports_for_one_service() -> Result<()> {
             .join("antnode1")
             .join(ANTNODE_FILE_NAME),
         service_user: Some(get_username()),
+        resource_limits: ResourceLimits::default(),
         upnp: false,
     }
     .build()?;
@@ -2548,6 +2577,7 @@ async fn add_node_should_use_custom_ports_for_one_service() -> Result<()> {
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -2568,6 +2598,136 @@ async fn add_node_should_use_custom_ports_for_one_service() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn add_node_should_apply_resource_limits() -> Result<()> {
+    let tmp_data_dir = assert_fs::TempDir::new()?;
+    let node_reg_path = tmp_data_dir.child("node_reg.json");
+
+    let mut mock_service_control = MockServiceControl::new();
+
+    let mut node_registry = NodeRegistry {
+        auditor: None,
+        faucet: None,
+        save_path: node_reg_path.to_path_buf(),
+        nat_status: None,
+        nodes: vec![],
+        environment_variables: None,
+        daemon: None,
+    };
+    let latest_version = "0.96.4";
+    let temp_dir = assert_fs::TempDir::new()?;
+    let node_data_dir = temp_dir.child("data");
+    node_data_dir.create_dir_all()?;
+    let node_logs_dir = temp_dir.child("logs");
+    node_logs_dir.create_dir_all()?;
+    let antnode_download_path = temp_dir.child(ANTNODE_FILE_NAME);
+    antnode_download_path.write_binary(b"fake antnode bin")?;
+
+    let resource_limits = ResourceLimits {
+        cpu_quota: Some(50),
+        max_memory: Some(2 * 1024 * 1024 * 1024),
+        max_open_files: Some(4096),
+        storage_quota: Some(50 * 1024 * 1024 * 1024),
+        ..Default::default()
+    };
+
+    let mut seq = Sequence::new();
+
+    mock_service_control
+        .expect_get_available_port()
+        .times(1)
+        .returning(|| Ok(12001))
+        .in_sequence(&mut seq);
+    let install_ctx = InstallNodeServiceCtxBuilder {
+        autostart: false,
+        data_dir_path: node_data_dir.to_path_buf().join("antnode1"),
+        env_variables: None,
+        evm_network: EvmNetwork::ArbitrumOne,
+        home_network: false,
+        log_dir_path: node_logs_dir.to_path_buf().join("antnode1"),
+        log_format: None,
+        max_archived_log_files: None,
+        max_log_files: None,
+        metrics_port: None,
+        network_id: None,
+        name: "antnode1".to_string(),
+        node_ip: None,
+        node_port: None,
+        peers_args: PeersArgs::default(),
+        rewards_address: RewardsAddress::from_str("0x03B770D9cD32077cC0bF330c13C114a87643B124")?,
+        rpc_socket_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 12001),
+        antnode_path: node_data_dir
+            .to_path_buf()
+            .join("antnode1")
+            .join(ANTNODE_FILE_NAME),
+        service_user: Some(get_username()),
+        resource_limits: resource_limits.clone(),
+        upnp: false,
+    }
+    .build()?;
+    assert!(install_ctx
+        .args
+        .windows(2)
+        .any(|pair| pair[0] == "--max-storage" && pair[1] == "53687091200"));
+
+    mock_service_control
+        .expect_install()
+        .times(1)
+        .with(eq(install_ctx), eq(false))
+        .returning(|_, _| Ok(()))
+        .in_sequence(&mut seq);
+    mock_service_control
+        .expect_set_resource_limits()
+        .times(1)
+        .with(eq("antnode1"), eq(resource_limits.clone()), eq(false))
+        .returning(|_, _, _| Ok(()))
+        .in_sequence(&mut seq);
+
+    add_node(
+        AddNodeServiceOptions {
+            auto_restart: false,
+            auto_set_nat_flags: false,
+            count: None,
+            delete_antnode_src: true,
+            enable_metrics_server: false,
+            env_variables: None,
+            evm_network: EvmNetwork::ArbitrumOne,
+            home_network: false,
+            log_format: None,
+            max_archived_log_files: None,
+            max_log_files: None,
+            metrics_port: None,
+            network_id: None,
+            node_ip: None,
+            node_port: None,
+            peers_args: PeersArgs::default(),
+            resource_limits: resource_limits.clone(),
+            rewards_address: RewardsAddress::from_str(
+                "0x03B770D9cD32077cC0bF330c13C114a87643B124",
+            )?,
+            rpc_address: None,
+            rpc_port: None,
+            antnode_dir_path: temp_dir.to_path_buf(),
+            antnode_src_path: antnode_download_path.to_path_buf(),
+            service_data_dir_path: node_data_dir.to_path_buf(),
+            service_log_dir_path: node_logs_dir.to_path_buf(),
+            upnp: false,
+            user: Some(get_username()),
+            user_mode: false,
+            version: latest_version.to_string(),
+        },
+        &mut node_registry,
+        &mock_service_control,
+        VerbosityLevel::Normal,
+    )
+    .await?;
+
+    assert_eq!(node_registry.nodes.len(), 1);
+    assert_eq!(node_registry.nodes[0].resource_limits, resource_limits);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn add_node_should_use_a_custom_port_range() -> Result<()> {
     let tmp_data_dir = assert_fs::TempDir::new()?;
@@ -2805,6 +2965,7 @@ async fn add_node_should_use_a_custom_port_range() -> Result<()> {
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -2863,6 +3024,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_port_is_used() -> R
             peer_id: None,
             peers_args: PeersArgs::default(),
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -2871,6 +3033,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_port_is_used() -> R
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Added,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -2924,6 +3087,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_port_is_used() -> R
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -2980,6 +3144,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_port_in_range_is_us
             number: 1,
             peer_id: None,
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -2988,6 +3153,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_port_in_range_is_us
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Added,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -3041,6 +3207,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_port_in_range_is_us
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -3119,6 +3286,7 @@ async fn add_node_should_return_an_error_if_port_and_node_count_do_not_match() -
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -3202,6 +3370,7 @@ async fn add_node_should_return_an_error_if_multiple_services_are_specified_with
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -3347,6 +3516,7 @@ async fn add_node_should_set_random_ports_if_enable_metrics_server_is_true() ->
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -3484,6 +3654,7 @@ async fn add_node_should_set_max_archived_log_files() -> Result<()> {
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -3622,6 +3793,7 @@ async fn add_node_should_set_max_log_files() -> Result<()> {
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -3874,6 +4046,7 @@ async fn add_node_should_use_a_custom_port_range_for_metrics_server() -> Result<
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -3929,6 +4102,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_metrics_port_is_use
             peer_id: None,
             peers_args: PeersArgs::default(),
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -3937,6 +4111,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_metrics_port_is_use
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Added,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -3990,6 +4165,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_metrics_port_is_use
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -4047,6 +4223,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_metrics_port_in_ran
             peer_id: None,
             peers_args: PeersArgs::default(),
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -4055,6 +4232,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_metrics_port_in_ran
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Added,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -4108,6 +4286,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_metrics_port_in_ran
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -4343,6 +4522,7 @@ async fn add_node_should_use_a_custom_port_range_for_the_rpc_server() -> Result<
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -4409,6 +4589,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_rpc_port_is_used()
             peer_id: None,
             peers_args: PeersArgs::default(),
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -4417,6 +4598,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_rpc_port_is_used()
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Added,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -4470,6 +4652,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_rpc_port_is_used()
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -4527,6 +4710,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_rpc_port_in_range_i
             peer_id: None,
             peers_args: PeersArgs::default(),
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -4535,6 +4719,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_rpc_port_in_range_i
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Added,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -4588,6 +4773,7 @@ async fn add_node_should_return_an_error_if_duplicate_custom_rpc_port_in_range_i
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -4671,6 +4857,7 @@ async fn add_node_should_disable_upnp_and_home_network_if_nat_status_is_public()
             .join("antnode1")
             .join(ANTNODE_FILE_NAME),
         service_user: Some(get_username()),
+        resource_limits: ResourceLimits::default(),
         upnp: false,
     }
     .build()?;
@@ -4717,6 +4904,7 @@ async fn add_node_should_disable_upnp_and_home_network_if_nat_status_is_public()
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -4797,6 +4985,7 @@ async fn add_node_should_enable_upnp_if_nat_status_is_upnp() -> Result<()> {
             .join("antnode1")
             .join(ANTNODE_FILE_NAME),
         service_user: Some(get_username()),
+        resource_limits: ResourceLimits::default(),
         upnp: true,
     }
     .build()?;
@@ -4843,6 +5032,7 @@ async fn add_node_should_enable_upnp_if_nat_status_is_upnp() -> Result<()> {
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -4923,6 +5113,7 @@ async fn add_node_should_enable_home_network_if_nat_status_is_private() -> Resul
             .join("antnode1")
             .join(ANTNODE_FILE_NAME),
         service_user: Some(get_username()),
+        resource_limits: ResourceLimits::default(),
         upnp: false,
     }
     .build()?;
@@ -4969,6 +5160,7 @@ async fn add_node_should_enable_home_network_if_nat_status_is_private() -> Resul
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -5055,6 +5247,7 @@ async fn add_node_should_return_an_error_if_nat_status_is_none_but_auto_set_nat_
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -5664,6 +5857,7 @@ async fn add_node_should_not_delete_the_source_binary_if_path_arg_is_used() -> R
             .join("antnode1")
             .join(ANTNODE_FILE_NAME),
         service_user: Some(get_username()),
+        resource_limits: ResourceLimits::default(),
         upnp: false,
     }
     .build()?;
@@ -5711,6 +5905,7 @@ async fn add_node_should_not_delete_the_source_binary_if_path_arg_is_used() -> R
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -5792,6 +5987,7 @@ async fn add_node_should_apply_the_home_network_flag_if_it_is_used() -> Result<(
             .join("antnode1")
             .join(ANTNODE_FILE_NAME),
         service_user: Some(get_username()),
+        resource_limits: ResourceLimits::default(),
         upnp: false,
     }
     .build()?;
@@ -5839,6 +6035,7 @@ async fn add_node_should_apply_the_home_network_flag_if_it_is_used() -> Result<(
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -5920,6 +6117,7 @@ async fn add_node_should_add_the_node_in_user_mode() -> Result<()> {
             .join("antnode1")
             .join(ANTNODE_FILE_NAME),
         service_user: Some(get_username()),
+        resource_limits: ResourceLimits::default(),
         upnp: false,
     }
     .build()?;
@@ -5967,6 +6165,7 @@ async fn add_node_should_add_the_node_in_user_mode() -> Result<()> {
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -6045,6 +6244,7 @@ async fn add_node_should_add_the_node_with_upnp_enabled() -> Result<()> {
             .join("antnode1")
             .join(ANTNODE_FILE_NAME),
         service_user: Some(get_username()),
+        resource_limits: ResourceLimits::default(),
         upnp: true,
     }
     .build()?;
@@ -6092,6 +6292,7 @@ async fn add_node_should_add_the_node_with_upnp_enabled() -> Result<()> {
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -6226,6 +6427,7 @@ async fn add_node_should_auto_restart() -> Result<()> {
                     "0x8464135c8F25Da09e49BC8782676a84730C318bC",
                 )?,
             }),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
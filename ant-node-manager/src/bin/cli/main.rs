@@ -15,12 +15,17 @@ use ant_logging::{LogBuilder, LogFormat};
 use ant_node_manager::{
     add_services::config::PortRange,
     cmd::{self},
-    VerbosityLevel, DEFAULT_NODE_STARTUP_CONNECTION_TIMEOUT_S,
+    rolling_upgrade::RollingUpgradeOptions,
+    SupervisorBackend, VerbosityLevel, DEFAULT_NODE_STARTUP_CONNECTION_TIMEOUT_S,
+};
+use ant_service_management::{
+    resources::{parse_byte_size, ResourceLimits},
+    supervisor::RestartBackoff,
 };
 use clap::{Parser, Subcommand};
 use color_eyre::{eyre::eyre, Result};
 use libp2p::Multiaddr;
-use std::{net::Ipv4Addr, path::PathBuf};
+use std::{net::Ipv4Addr, path::PathBuf, time::Duration};
 use tracing::Level;
 
 const DEFAULT_NODE_COUNT: u16 = 25;
@@ -45,6 +50,25 @@ pub(crate) struct Cmd {
     #[clap(long)]
     pub package_version: bool,
 
+    /// The mechanism used to run and supervise new node services.
+    ///
+    /// 'os' uses the OS service manager: systemd, launchd, OpenRC or WinSW. 'builtin' uses a
+    /// supervisor process run by antctl itself, for environments such as containers that don't
+    /// have a service manager.
+    ///
+    /// The backend is recorded for each service, and existing services are always managed by the
+    /// backend they were added with. If not set, the backend of the existing services is used, or
+    /// else 'os'.
+    #[clap(long, global = true, env = "ANTCTL_SUPERVISOR")]
+    supervisor: Option<SupervisorBackend>,
+
+    /// Skip verifying downloaded releases against the signed release manifests.
+    ///
+    /// Releases are otherwise verified, and cannot be downloaded if no key is trusted to sign the
+    /// manifests. Custom binaries, provided with a path or a URL, are never verified.
+    #[clap(long, global = true, env = "ANTCTL_SKIP_RELEASE_VERIFICATION")]
+    skip_release_verification: bool,
+
     /// Output trace-level logging to stderr.
     #[clap(long, conflicts_with = "debug")]
     trace: bool,
@@ -57,6 +81,7 @@ pub(crate) struct Cmd {
     version: bool,
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand, Debug)]
 pub enum SubCmd {
     /// Add one or more antnode services.
@@ -159,6 +184,42 @@ pub enum SubCmd {
         /// After reaching this limit, the older archived files are deleted.
         #[clap(long, verbatim_doc_comment)]
         max_archived_log_files: Option<usize>,
+        /// Limit the bandwidth used to send records to clients fetching them directly, in bytes
+        /// per second.
+        ///
+        /// If the argument is not used, client traffic is not limited.
+        #[clap(long, verbatim_doc_comment)]
+        max_client_bandwidth: Option<u64>,
+        /// Limit the bandwidth used by replication between nodes, in bytes per second.
+        ///
+        /// If the argument is not used, replication traffic is not limited.
+        #[clap(long, verbatim_doc_comment)]
+        max_replication_bandwidth: Option<u64>,
+        /// Limit the CPU time each node can use, as a percentage of a single core.
+        ///
+        /// For example, 50 allows half a core and 200 allows two full cores.
+        ///
+        /// This is only enforced for services managed by systemd.
+        #[clap(long, value_parser = clap::value_parser!(u32).range(1..), verbatim_doc_comment)]
+        max_cpu: Option<u32>,
+        /// Limit the memory each node can use.
+        ///
+        /// The value is in bytes, or can use a K, M, G or T suffix, e.g., 2G.
+        ///
+        /// The node applies this to itself as an rlimit on Unix platforms, and systemd enforces it too.
+        #[clap(long, value_parser = parse_byte_size, verbatim_doc_comment)]
+        max_memory: Option<u64>,
+        /// Limit the number of files each node can have open.
+        ///
+        /// The node applies this to itself as an rlimit on Unix platforms, and systemd enforces it too.
+        #[clap(long, value_parser = clap::value_parser!(u64).range(1..), verbatim_doc_comment)]
+        max_open_files: Option<u64>,
+        /// Limit the space each node can use to store records.
+        ///
+        /// The value is in bytes, or can use a K, M, G or T suffix, e.g., 50G. The node stores fewer
+        /// records so they always fit within the limit.
+        #[clap(long, value_parser = parse_byte_size, verbatim_doc_comment)]
+        max_storage: Option<u64>,
         /// Specify a port for the open metrics server.
         ///
         /// If you're passing the compiled antnode via --node-path, make sure to enable the open-metrics feature
@@ -268,6 +329,8 @@ pub enum SubCmd {
     Local(LocalSubCmd),
     #[clap(subcommand)]
     NatDetection(NatDetectionSubCmd),
+    #[clap(subcommand)]
+    Registry(RegistrySubCmd),
     /// Remove antnode service(s).
     ///
     /// If no peer ID(s) or service name(s) are supplied, all services will be removed.
@@ -331,7 +394,7 @@ pub enum SubCmd {
         /// 'connection-timeout' argument.
         ///
         /// Units are milliseconds.
-        #[clap(long, conflicts_with = "connection-timeout")]
+        #[clap(long, conflicts_with = "connection_timeout")]
         interval: Option<u64>,
         /// The peer ID of the service to start.
         ///
@@ -368,7 +431,7 @@ pub enum SubCmd {
         /// An interval applied between stopping each service.
         ///
         /// Units are milliseconds.
-        #[clap(long, conflicts_with = "connection-timeout")]
+        #[clap(long)]
         interval: Option<u64>,
         /// The peer ID of the service to stop.
         ///
@@ -381,6 +444,27 @@ pub enum SubCmd {
         #[clap(long, conflicts_with = "peer_id")]
         service_name: Vec<String>,
     },
+    /// Run the built-in supervisor in the foreground.
+    ///
+    /// The supervisor restarts node services managed by the built-in backend when they exit,
+    /// waiting an exponentially increasing delay between attempts. The output of each service is
+    /// captured to log files in the supervisor's state directory.
+    ///
+    /// The start and upgrade commands launch a supervisor in the background when the built-in
+    /// backend is used, but it can also be run directly, e.g., as the entrypoint of a container.
+    #[clap(name = "supervise")]
+    Supervise {
+        /// The delay before the first attempt to restart a service that has exited.
+        ///
+        /// The delay doubles for each consecutive failure. Units are seconds.
+        #[clap(long, default_value_t = 2)]
+        initial_restart_delay: u64,
+        /// The maximum delay between attempts to restart a service.
+        ///
+        /// Units are seconds.
+        #[clap(long, default_value_t = 300)]
+        max_restart_delay: u64,
+    },
     /// Upgrade antnode services.
     ///
     /// By default, each node service is started after the previous node has successfully connected to the network or
@@ -423,14 +507,21 @@ pub enum SubCmd {
         /// Required if we want to downgrade, or for testing purposes.
         #[clap(long)]
         force: bool,
+        /// The max time in seconds for an upgraded node to recover during a rolling upgrade.
+        #[clap(long, default_value_t = 300, requires = "rolling")]
+        health_check_timeout: u64,
         /// An interval applied between upgrading each service.
         ///
         /// Use connection-timeout to scale the interval automatically. This argument is mutually exclusive with the
         /// 'connection-timeout' argument.
         ///
         /// Units are milliseconds.
-        #[clap(long, conflicts_with = "connection-timeout")]
+        #[clap(long, conflicts_with = "connection_timeout")]
         interval: Option<u64>,
+        /// Set this flag to only abort a rolling upgrade when a node fails its health check,
+        /// leaving the node on the new version rather than rolling it back.
+        #[clap(long, requires = "rolling")]
+        no_rollback: bool,
         /// Provide a path for the antnode binary to be used by the service.
         ///
         /// Useful for upgrading the service using a custom built binary.
@@ -439,6 +530,17 @@ pub enum SubCmd {
         /// The peer ID of the service to upgrade
         #[clap(long)]
         peer_id: Vec<String>,
+        /// The share of its connected peers and records, between 0 and 1, that an upgraded node
+        /// must recover during a rolling upgrade.
+        #[clap(long, default_value_t = 0.5, requires = "rolling", value_parser = parse_recovery_ratio)]
+        recovery_ratio: f64,
+        /// Set this flag to upgrade the nodes one at a time, waiting for each upgraded node to
+        /// connect to the network and recover its peers and records before moving on.
+        ///
+        /// A node that fails its health check is rolled back to its previous binary, and the
+        /// remaining nodes are not upgraded.
+        #[clap(long, conflicts_with_all = ["do_not_start", "interval"])]
+        rolling: bool,
         /// The name of the service to upgrade
         #[clap(long, conflicts_with = "peer_id")]
         service_name: Vec<String>,
@@ -660,6 +762,77 @@ pub enum NatDetectionSubCmd {
     },
 }
 
+/// Back up and restore the node registry.
+#[derive(Subcommand, Debug)]
+pub enum RegistrySubCmd {
+    /// Export the node services to a bundle file.
+    ///
+    /// The bundle can be imported on another machine, or on the same machine after a reinstall,
+    /// to define the same node services again.
+    #[clap(name = "export")]
+    Export {
+        /// Include the secret key of each node, so the nodes keep their peer IDs when imported.
+        ///
+        /// Anyone with the bundle can then impersonate the nodes, so keep it somewhere safe.
+        #[clap(long)]
+        include_keys: bool,
+        /// The path of the bundle file to write.
+        #[clap(long, short)]
+        output: PathBuf,
+    },
+    /// Import the node services from a bundle file.
+    ///
+    /// The services are added after any existing services, with new data and log directories.
+    /// The import fails without making changes if any of the ports used by the bundled services
+    /// are already in use by existing services.
+    ///
+    /// The services are defined but not started.
+    ///
+    /// On Windows, this command must run as the administrative user. On Linux/macOS, run using
+    /// sudo if you want system-wide services; otherwise, do not run the command elevated.
+    #[clap(name = "import")]
+    Import {
+        /// The path of the bundle file.
+        bundle: PathBuf,
+        /// Provide the path for the data directory for the imported nodes.
+        ///
+        /// This path is a prefix. Each imported node will have its own directory underneath it.
+        ///
+        /// If not provided, the same default location as the add command is used.
+        #[clap(long)]
+        data_dir_path: Option<PathBuf>,
+        /// Provide the path for the log directory for the imported nodes.
+        ///
+        /// This path is a prefix. Each imported node will have its own directory underneath it.
+        ///
+        /// If not provided, the same default location as the add command is used.
+        #[clap(long)]
+        log_dir_path: Option<PathBuf>,
+        /// Provide an antnode binary using a path.
+        ///
+        /// The path must refer to an existing binary. If not provided, the latest version, or the
+        /// version specified with --version, will be downloaded.
+        #[clap(long, conflicts_with_all = ["url", "version"])]
+        path: Option<PathBuf>,
+        /// Provide an antnode binary using a URL.
+        ///
+        /// The binary must be inside a zip or gzipped tar archive.
+        #[clap(long, conflicts_with = "version")]
+        url: Option<String>,
+        /// Specify a user for the imported services.
+        ///
+        /// If not provided, the 'ant' user will be used. This argument has no effect for
+        /// user-mode services.
+        #[clap(long)]
+        user: Option<String>,
+        /// Specify the version of antnode to use.
+        ///
+        /// The version number should be in the form X.Y.Z, with no 'v' prefix.
+        #[clap(long)]
+        version: Option<String>,
+    },
+}
+
 /// Manage local networks.
 #[derive(Subcommand, Debug)]
 pub enum LocalSubCmd {
@@ -919,6 +1092,14 @@ async fn main() -> Result<()> {
         None
     };
 
+    ant_node_manager::set_supervisor_backend(
+        args.supervisor
+            .or_else(ant_node_manager::get_registered_supervisor_backend)
+            .unwrap_or_default(),
+    );
+    ant_node_manager::release_verification::set_skip_release_verification(
+        args.skip_release_verification,
+    );
     configure_winsw(verbosity).await?;
 
     tracing::info!("Executing cmd: {:?}", args.cmd);
@@ -936,7 +1117,13 @@ async fn main() -> Result<()> {
             log_dir_path,
             log_format,
             max_archived_log_files,
+            max_client_bandwidth,
+            max_cpu,
             max_log_files,
+            max_memory,
+            max_open_files,
+            max_replication_bandwidth,
+            max_storage,
             metrics_port,
             network_id,
             node_ip,
@@ -969,6 +1156,14 @@ async fn main() -> Result<()> {
                 node_ip,
                 node_port,
                 peers,
+                ResourceLimits {
+                    cpu_quota: max_cpu,
+                    max_memory,
+                    max_open_files,
+                    storage_quota: max_storage,
+                    max_client_bandwidth,
+                    max_replication_bandwidth,
+                },
                 rewards_address,
                 rpc_address,
                 rpc_port,
@@ -1133,6 +1328,31 @@ async fn main() -> Result<()> {
             cmd::nat_detection::run_nat_detection(servers, true, path, url, version, verbosity)
                 .await
         }
+        Some(SubCmd::Registry(RegistrySubCmd::Export {
+            include_keys,
+            output,
+        })) => cmd::registry::export(output, include_keys, verbosity).await,
+        Some(SubCmd::Registry(RegistrySubCmd::Import {
+            bundle,
+            data_dir_path,
+            log_dir_path,
+            path,
+            url,
+            user,
+            version,
+        })) => {
+            cmd::registry::import(
+                bundle,
+                data_dir_path,
+                log_dir_path,
+                path,
+                url,
+                user,
+                version,
+                verbosity,
+            )
+            .await
+        }
         Some(SubCmd::Remove {
             keep_directories,
             peer_id: peer_ids,
@@ -1164,18 +1384,36 @@ async fn main() -> Result<()> {
             peer_id: peer_ids,
             service_name: service_names,
         }) => cmd::node::stop(interval, peer_ids, service_names, verbosity).await,
+        Some(SubCmd::Supervise {
+            initial_restart_delay,
+            max_restart_delay,
+        }) => cmd::supervisor::run(RestartBackoff {
+            initial_delay: Duration::from_secs(initial_restart_delay),
+            max_delay: Duration::from_secs(max_restart_delay),
+            ..Default::default()
+        }),
         Some(SubCmd::Upgrade {
             connection_timeout,
             do_not_start,
             force,
+            health_check_timeout,
             interval,
+            no_rollback,
             path,
             peer_id: peer_ids,
+            recovery_ratio,
+            rolling,
             service_name: service_names,
             env_variables: provided_env_variable,
             url,
             version,
         }) => {
+            let rolling = rolling.then(|| RollingUpgradeOptions {
+                health_check_timeout: Duration::from_secs(health_check_timeout),
+                recovery_ratio,
+                rollback: !no_rollback,
+                ..Default::default()
+            });
             cmd::node::upgrade(
                 connection_timeout,
                 do_not_start,
@@ -1184,6 +1422,7 @@ async fn main() -> Result<()> {
                 interval,
                 peer_ids,
                 provided_env_variable,
+                rolling,
                 service_names,
                 url,
                 version,
@@ -1222,6 +1461,14 @@ fn parse_environment_variables(env_var: &str) -> Result<(String, String)> {
     Ok((parts[0].to_string(), parts[1].to_string()))
 }
 
+fn parse_recovery_ratio(ratio: &str) -> Result<f64> {
+    let ratio: f64 = ratio.parse()?;
+    if !(0.0..=1.0).contains(&ratio) {
+        return Err(eyre!("The recovery ratio must be between 0 and 1"));
+    }
+    Ok(ratio)
+}
+
 #[cfg(windows)]
 async fn configure_winsw(verbosity: VerbosityLevel) -> Result<()> {
     use ant_node_manager::config::get_node_manager_path;
@@ -1252,3 +1499,15 @@ async fn configure_winsw(verbosity: VerbosityLevel) -> Result<()> {
 async fn configure_winsw(_verbosity: VerbosityLevel) -> Result<()> {
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn cli_arguments_should_be_consistent() {
+        // Catches references to arguments that don't exist, e.g. in `conflicts_with`.
+        Cmd::command().debug_assert();
+    }
+}
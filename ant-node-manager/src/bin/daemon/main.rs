@@ -10,20 +10,43 @@
 extern crate tracing;
 
 use ant_logging::LogBuilder;
-use ant_node_manager::{config::get_node_registry_path, rpc, DAEMON_DEFAULT_PORT};
+use ant_node_manager::{
+    config::{get_node_registry_path, get_supervisor_state_dir},
+    rpc::{self, ProgressSender},
+    rpc_auth::{
+        server_tls_config, AuthTokenValidator, AUTH_TOKEN_ENV_VAR, TLS_CERT_ENV_VAR,
+        TLS_KEY_ENV_VAR,
+    },
+    SupervisorBackend, DAEMON_DEFAULT_PORT,
+};
 use ant_service_management::{
     antctl_proto::{
         ant_ctl_server::{AntCtl, AntCtlServer},
         get_status_response::Node,
-        GetStatusRequest, GetStatusResponse, NodeServiceRestartRequest, NodeServiceRestartResponse,
+        AddNodesRequest, AddNodesResponse, GetStatusRequest, GetStatusResponse,
+        MaintainRunningNodesRequest, NodeOperationProgress, NodeServiceRestartRequest,
+        NodeServiceRestartResponse, RemoveNodesRequest, ResetRequest, StartNodesRequest,
+        StopNodesRequest, UpgradeNodesRequest,
     },
+    supervisor::{is_supervisor_running, RestartBackoff, Supervisor},
     NodeRegistry,
 };
 use clap::Parser;
 use color_eyre::eyre::{eyre, Result};
 use libp2p_identity::PeerId;
-use std::net::{IpAddr, Ipv4Addr, SocketAddr};
-use tonic::{transport::Server, Code, Request, Response, Status};
+use std::{
+    future::Future,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::sync::{mpsc, Mutex};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{
+    transport::{Server, ServerTlsConfig},
+    Code, Request, Response, Status,
+};
 use tracing::Level;
 
 #[derive(Parser, Debug)]
@@ -34,6 +57,15 @@ struct Args {
     /// If not set, the daemon listens locally for commands.
     #[clap(long, default_value_t = Ipv4Addr::new(127, 0, 0, 1))]
     address: Ipv4Addr,
+    /// A token that clients must provide to use the RPC interface.
+    ///
+    /// Clients send it as a bearer token in the 'authorization' metadata. If it is not set, any
+    /// client that can reach the daemon is able to manage the nodes.
+    ///
+    /// Without TLS the token would be sent in plaintext, so it can only be used on a loopback
+    /// address unless the TLS certificate and key are also provided.
+    #[clap(long, env = AUTH_TOKEN_ENV_VAR, hide_env_values = true)]
+    auth_token: Option<String>,
     /// Print the crate version.
     #[clap(long)]
     pub crate_version: bool,
@@ -44,12 +76,32 @@ struct Args {
     /// Specify a port for the daemon to listen for RPCs. It defaults to 12500 if not set.
     #[clap(long, default_value_t = DAEMON_DEFAULT_PORT)]
     port: u16,
+    /// The mechanism used to run and supervise new node services: 'os' or 'builtin'.
+    ///
+    /// Existing services are always managed by the backend they were added with. If not set, the
+    /// backend of the existing services is used, or else 'os'.
+    #[clap(long, env = "ANTCTL_SUPERVISOR")]
+    supervisor: Option<SupervisorBackend>,
+    /// The path of a PEM encoded certificate used to serve the RPC interface over TLS.
+    #[clap(long, env = TLS_CERT_ENV_VAR, requires = "tls_key")]
+    tls_cert: Option<PathBuf>,
+    /// The path of the PEM encoded private key for the TLS certificate.
+    #[clap(long, env = TLS_KEY_ENV_VAR, requires = "tls_cert")]
+    tls_key: Option<PathBuf>,
     /// Print version information.
     #[clap(long)]
     version: bool,
 }
 
-struct AntCtlDaemon {}
+const PROGRESS_CHANNEL_SIZE: usize = 64;
+
+type ProgressStream = ReceiverStream<Result<NodeOperationProgress, Status>>;
+
+#[derive(Default)]
+struct AntCtlDaemon {
+    /// Only one operation is allowed to modify the node registry at a time.
+    registry_lock: Arc<Mutex<()>>,
+}
 
 // Implementing RPC interface for service defined in .proto
 #[tonic::async_trait]
@@ -60,6 +112,7 @@ impl AntCtl for AntCtlDaemon {
     ) -> Result<Response<NodeServiceRestartResponse>, Status> {
         println!("RPC request received {:?}", request.get_ref());
         info!("RPC request received {:?}", request.get_ref());
+        let _guard = self.registry_lock.lock().await;
         let node_registry = Self::load_node_registry().map_err(|err| {
             Status::new(
                 Code::Internal,
@@ -107,9 +160,118 @@ impl AntCtl for AntCtlDaemon {
         info!("Node status retrieved, nod len: {:?}", nodes_info.len());
         Ok(Response::new(GetStatusResponse { nodes: nodes_info }))
     }
+
+    async fn add_nodes(
+        &self,
+        request: Request<AddNodesRequest>,
+    ) -> Result<Response<AddNodesResponse>, Status> {
+        info!("RPC request received {:?}", request.get_ref());
+        let _guard = self.registry_lock.lock().await;
+        let request = request.into_inner();
+        let service_names = run_node_manager_op(|| rpc::add_nodes(request))
+            .await
+            .map_err(|err| Status::new(Code::Internal, format!("Failed to add nodes: {err}")))?;
+        info!("Added node services: {service_names:?}");
+        Ok(Response::new(AddNodesResponse { service_names }))
+    }
+
+    type StartNodesStream = ProgressStream;
+
+    async fn start_nodes(
+        &self,
+        request: Request<StartNodesRequest>,
+    ) -> Result<Response<Self::StartNodesStream>, Status> {
+        info!("RPC request received {:?}", request.get_ref());
+        let request = request.into_inner();
+        Ok(self
+            .stream_progress(|progress| async move { rpc::start_nodes(request, &progress).await }))
+    }
+
+    type StopNodesStream = ProgressStream;
+
+    async fn stop_nodes(
+        &self,
+        request: Request<StopNodesRequest>,
+    ) -> Result<Response<Self::StopNodesStream>, Status> {
+        info!("RPC request received {:?}", request.get_ref());
+        let request = request.into_inner();
+        Ok(self
+            .stream_progress(|progress| async move { rpc::stop_nodes(request, &progress).await }))
+    }
+
+    type RemoveNodesStream = ProgressStream;
+
+    async fn remove_nodes(
+        &self,
+        request: Request<RemoveNodesRequest>,
+    ) -> Result<Response<Self::RemoveNodesStream>, Status> {
+        info!("RPC request received {:?}", request.get_ref());
+        let request = request.into_inner();
+        Ok(self
+            .stream_progress(|progress| async move { rpc::remove_nodes(request, &progress).await }))
+    }
+
+    type UpgradeNodesStream = ProgressStream;
+
+    async fn upgrade_nodes(
+        &self,
+        request: Request<UpgradeNodesRequest>,
+    ) -> Result<Response<Self::UpgradeNodesStream>, Status> {
+        info!("RPC request received {:?}", request.get_ref());
+        let request = request.into_inner();
+        Ok(self.stream_progress(
+            |progress| async move { rpc::upgrade_nodes(request, &progress).await },
+        ))
+    }
+
+    type ResetStream = ProgressStream;
+
+    async fn reset(
+        &self,
+        request: Request<ResetRequest>,
+    ) -> Result<Response<Self::ResetStream>, Status> {
+        info!("RPC request received {:?}", request.get_ref());
+        Ok(self.stream_progress(|progress| async move { rpc::reset(&progress).await }))
+    }
+
+    type MaintainRunningNodesStream = ProgressStream;
+
+    async fn maintain_running_nodes(
+        &self,
+        request: Request<MaintainRunningNodesRequest>,
+    ) -> Result<Response<Self::MaintainRunningNodesStream>, Status> {
+        info!("RPC request received {:?}", request.get_ref());
+        let request = request.into_inner();
+        Ok(self.stream_progress(|progress| async move {
+            rpc::maintain_running_nodes(request, &progress).await
+        }))
+    }
 }
 
 impl AntCtlDaemon {
+    /// Run a long operation in the background, streaming its progress back to the client.
+    ///
+    /// If the operation fails as a whole, the error is sent as the final item of the stream.
+    fn stream_progress<F, Fut>(&self, operation: F) -> Response<ProgressStream>
+    where
+        F: FnOnce(ProgressSender) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<()>>,
+    {
+        let (sender, receiver) = mpsc::channel(PROGRESS_CHANNEL_SIZE);
+        let registry_lock = Arc::clone(&self.registry_lock);
+        let _handle = tokio::spawn(async move {
+            let _guard = registry_lock.lock().await;
+            let operation_sender = sender.clone();
+            if let Err(err) = run_node_manager_op(move || operation(operation_sender)).await {
+                error!("RPC operation failed: {err:?}");
+                let _ = sender
+                    .send(Err(Status::new(Code::Internal, err.to_string())))
+                    .await;
+            }
+        });
+        Response::new(ReceiverStream::new(receiver))
+    }
+
     fn load_node_registry() -> Result<NodeRegistry> {
         let node_registry_path = get_node_registry_path()
             .map_err(|err| eyre!("Could not obtain node registry path: {err:?}"))?;
@@ -164,22 +326,106 @@ async fn main() -> Result<()> {
     }
 
     let _log_handles = get_log_builder()?.initialize()?;
-    println!("Starting antctld");
-    let service = AntCtlDaemon {};
+    let supervisor = args
+        .supervisor
+        .or_else(ant_node_manager::get_registered_supervisor_backend)
+        .unwrap_or_default();
+    ant_node_manager::set_supervisor_backend(supervisor);
+    if supervisor == SupervisorBackend::Builtin {
+        // Run the supervisor inside the daemon, so there's a single long running process that
+        // owns the node processes.
+        let state_dir = get_supervisor_state_dir()?;
+        if is_supervisor_running(&state_dir) {
+            println!("Using the supervisor already running for {state_dir:?}");
+        } else {
+            let _ = std::thread::spawn(move || {
+                let mut supervisor = Supervisor::new(state_dir, RestartBackoff::default());
+                if let Err(err) = supervisor.run(Duration::from_secs(1)) {
+                    error!("The built-in supervisor failed: {err:?}");
+                }
+            });
+        }
+    }
+    let tls = match (&args.tls_cert, &args.tls_key) {
+        (Some(cert_path), Some(key_path)) => Some(server_tls_config(cert_path, key_path)?),
+        _ => None,
+    };
+    let auth = AuthTokenValidator::new(args.auth_token);
+    if auth.is_enabled() && tls.is_none() && !args.address.is_loopback() {
+        error!(
+            "Refusing to send the auth token in plaintext on {}",
+            args.address
+        );
+        return Err(eyre!(
+            "An auth token can only be used on {} with TLS, otherwise it is sent in plaintext. \
+            Provide a certificate with --tls-cert and --tls-key, or listen on a loopback address.",
+            args.address
+        ));
+    }
+    if !auth.is_enabled() && !args.address.is_loopback() {
+        warn!(
+            "antctld is listening on {} without an auth token",
+            args.address
+        );
+        println!(
+            "WARNING: antctld is listening on {} without an auth token. Anyone who can reach \
+            the port can manage the nodes. Set {AUTH_TOKEN_ENV_VAR} to require a token.",
+            args.address
+        );
+    }
 
-    if let Err(err) = Server::builder()
-        .add_service(AntCtlServer::new(service))
-        .serve(SocketAddr::new(IpAddr::V4(args.address), args.port))
-        .await
+    println!("Starting antctld");
+    if let Err(err) = serve(
+        SocketAddr::new(IpAddr::V4(args.address), args.port),
+        auth,
+        tls,
+    )
+    .await
     {
         error!("Antctl Daemon failed to start: {err:?}");
         println!("Antctl Daemon failed to start: {err:?}");
-        return Err(err.into());
+        return Err(err);
     }
 
     Ok(())
 }
 
+/// Serve the RPC interface, over TLS if it is configured.
+async fn serve(
+    addr: SocketAddr,
+    auth: AuthTokenValidator,
+    tls: Option<ServerTlsConfig>,
+) -> Result<()> {
+    let mut server = Server::builder();
+    if let Some(tls) = tls {
+        server = server.tls_config(tls)?;
+    }
+    server
+        .add_service(AntCtlServer::with_interceptor(
+            AntCtlDaemon::default(),
+            auth,
+        ))
+        .serve(addr)
+        .await?;
+    Ok(())
+}
+
+/// Run an operation that uses the node manager commands.
+///
+/// The futures for those commands are not `Send`, so they can't be spawned as tasks. Instead they
+/// are driven to completion on a blocking thread.
+async fn run_node_manager_op<T, F, Fut>(operation: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Fut + Send + 'static,
+    Fut: Future<Output = Result<T>>,
+{
+    let handle = tokio::runtime::Handle::current();
+    tokio::task::spawn_blocking(move || handle.block_on(operation()))
+        .await
+        .map_err(|err| eyre!("The operation could not be completed: {err}"))?
+}
+
 fn get_log_builder() -> Result<LogBuilder> {
     let logging_targets = vec![
         ("ant_node_manager".to_string(), Level::TRACE),
@@ -200,3 +446,152 @@ fn get_log_builder() -> Result<LogBuilder> {
     log_builder.output_dest(ant_logging::LogOutputDest::Path(output_dest));
     Ok(log_builder)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ant_node_manager::{rpc_auth::client_tls_config, rpc_client};
+    use ant_service_management::antctl_proto::NodeSelection;
+    use assert_fs::{prelude::*, TempDir};
+
+    fn invalid_selection() -> Option<NodeSelection> {
+        Some(NodeSelection {
+            peer_ids: vec![vec![0xff, 0x00, 0x01]],
+            service_names: vec![],
+        })
+    }
+
+    #[tokio::test]
+    async fn stream_progress_should_send_the_progress_then_the_failure() {
+        let daemon = AntCtlDaemon::default();
+        let response = daemon.stream_progress(|progress| async move {
+            let _ = progress
+                .send(Ok(NodeOperationProgress {
+                    service_name: "antnode1".to_string(),
+                    ..Default::default()
+                }))
+                .await;
+            Err(eyre!("The operation failed for: antnode1"))
+        });
+
+        let mut receiver = response.into_inner().into_inner();
+        let update = receiver.recv().await.unwrap().unwrap();
+        assert_eq!(update.service_name, "antnode1");
+        let status = receiver.recv().await.unwrap().unwrap_err();
+        assert_eq!(status.code(), Code::Internal);
+        assert_eq!(status.message(), "The operation failed for: antnode1");
+        assert!(receiver.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn lifecycle_rpcs_should_stream_the_failure_to_the_client() {
+        let daemon = AntCtlDaemon::default();
+
+        let mut stream = daemon
+            .start_nodes(Request::new(StartNodesRequest {
+                selection: invalid_selection(),
+                ..Default::default()
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .into_inner();
+        assert_eq!(
+            stream.recv().await.unwrap().unwrap_err().code(),
+            Code::Internal
+        );
+
+        let mut stream = daemon
+            .stop_nodes(Request::new(StopNodesRequest {
+                selection: invalid_selection(),
+                ..Default::default()
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .into_inner();
+        assert_eq!(
+            stream.recv().await.unwrap().unwrap_err().code(),
+            Code::Internal
+        );
+
+        let mut stream = daemon
+            .remove_nodes(Request::new(RemoveNodesRequest {
+                selection: invalid_selection(),
+                ..Default::default()
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .into_inner();
+        assert_eq!(
+            stream.recv().await.unwrap().unwrap_err().code(),
+            Code::Internal
+        );
+
+        let mut stream = daemon
+            .upgrade_nodes(Request::new(UpgradeNodesRequest {
+                selection: invalid_selection(),
+                ..Default::default()
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .into_inner();
+        assert_eq!(
+            stream.recv().await.unwrap().unwrap_err().code(),
+            Code::Internal
+        );
+
+        let status = daemon
+            .add_nodes(Request::new(AddNodesRequest {
+                count: 1,
+                options: None,
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::Internal);
+    }
+
+    #[tokio::test]
+    async fn daemon_should_require_the_token_over_tls() -> Result<()> {
+        let tmp_data_dir = TempDir::new()?;
+        let cert = rcgen::generate_simple_self_signed(vec!["localhost".to_string()])?;
+        let cert_file = tmp_data_dir.child("cert.pem");
+        cert_file.write_str(&cert.serialize_pem()?)?;
+        let key_file = tmp_data_dir.child("key.pem");
+        key_file.write_str(&cert.serialize_private_key_pem())?;
+
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+        let server_tls = server_tls_config(cert_file.path(), key_file.path())?;
+        let _server = tokio::spawn(serve(
+            addr,
+            AuthTokenValidator::new(Some("secret".to_string())),
+            Some(server_tls),
+        ));
+
+        let client_tls = client_tls_config(cert_file.path(), "localhost")?;
+        let mut client = rpc_client::connect(addr, None, Some(client_tls.clone())).await?;
+        let status = client
+            .start_nodes(StartNodesRequest {
+                selection: invalid_selection(),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::Unauthenticated);
+
+        let mut client =
+            rpc_client::connect(addr, Some("secret".to_string()), Some(client_tls)).await?;
+        let mut stream = client
+            .start_nodes(StartNodesRequest {
+                selection: invalid_selection(),
+                ..Default::default()
+            })
+            .await?
+            .into_inner();
+        let status = stream.message().await.unwrap_err();
+        assert_eq!(status.code(), Code::Internal);
+        Ok(())
+    }
+}
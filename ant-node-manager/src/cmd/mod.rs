@@ -12,6 +12,8 @@ pub mod faucet;
 pub mod local;
 pub mod nat_detection;
 pub mod node;
+pub mod registry;
+pub mod supervisor;
 
 use crate::{
     helpers::{download_and_extract_release, get_bin_version},
@@ -124,6 +126,13 @@ pub fn print_upgrade_summary(upgrade_summary: Vec<(String, UpgradeResult)>) {
                     service_name
                 );
             }
+            UpgradeResult::RolledBack(previous_version, target_version, reason) => {
+                println!(
+                    "{} {} failed its health check after the upgrade to {target_version} and was rolled back to {previous_version}: {reason}",
+                    "✕".red(),
+                    service_name
+                );
+            }
             UpgradeResult::Error(msg) => {
                 println!("{} {} was not upgraded: {}", "✕".red(), service_name, msg);
             }
@@ -37,6 +37,8 @@ pub async fn run_nat_detection(
         None => {
             let mut contacts_fetcher = ContactsFetcher::new()?;
             contacts_fetcher.ignore_peer_id(true);
+            // The servers are only used to detect the NAT status, not to join the network.
+            contacts_fetcher.allow_unsigned_contacts(true);
             contacts_fetcher.insert_endpoint(NAT_DETECTION_SERVERS_LIST_URL.parse()?);
 
             let servers = contacts_fetcher.fetch_addrs().await?;
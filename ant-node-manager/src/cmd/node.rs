@@ -15,17 +15,19 @@ use crate::{
         config::{AddNodeServiceOptions, PortRange},
     },
     config::{self, is_running_as_root},
+    get_service_control, get_service_control_for, get_supervisor_backend,
     helpers::{download_and_extract_release, get_bin_version},
-    print_banner, refresh_node_registry, status_report, ServiceManager, VerbosityLevel,
+    print_banner, refresh_node_registry,
+    rolling_upgrade::{ensure_node_recovered, PreUpgradeState, RollingUpgradeOptions},
+    status_report, ServiceManager, VerbosityLevel,
 };
 use ant_bootstrap::PeersArgs;
 use ant_evm::{EvmNetwork, RewardsAddress};
 use ant_logging::LogFormat;
 use ant_releases::{AntReleaseRepoActions, ReleaseType};
 use ant_service_management::{
-    control::{ServiceControl, ServiceController},
-    rpc::RpcClient,
-    NodeRegistry, NodeService, ServiceStateActions, ServiceStatus, UpgradeOptions, UpgradeResult,
+    resources::ResourceLimits, rpc::RpcClient, NodeRegistry, NodeService, ServiceStateActions,
+    ServiceStatus, UpgradeOptions, UpgradeResult,
 };
 use color_eyre::{eyre::eyre, Help, Result};
 use colored::Colorize;
@@ -53,6 +55,7 @@ pub async fn add(
     node_ip: Option<Ipv4Addr>,
     node_port: Option<PortRange>,
     mut peers_args: PeersArgs,
+    resource_limits: ResourceLimits,
     rewards_address: RewardsAddress,
     rpc_address: Option<Ipv4Addr>,
     rpc_port: Option<PortRange>,
@@ -70,7 +73,7 @@ pub async fn add(
         println!("{} service(s) to be added", count.unwrap_or(1));
     }
 
-    let service_manager = ServiceController {};
+    let service_manager = get_service_control()?;
     let service_user = if user_mode {
         None
     } else {
@@ -90,6 +93,19 @@ pub async fn add(
     };
 
     let mut node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
+    let supervisor_backend = get_supervisor_backend();
+    if let Some(node) = node_registry.nodes.iter().find(|node| {
+        node.status != ServiceStatus::Removed && node.supervisor_backend != supervisor_backend
+    }) {
+        error!(
+            "Cannot add services with the {supervisor_backend} supervisor backend, {} uses the {} backend",
+            node.service_name, node.supervisor_backend
+        );
+        return Err(eyre!(
+            "The existing services use the {} supervisor backend, so new services can't use the {supervisor_backend} backend",
+            node.supervisor_backend
+        ));
+    }
     let release_repo = <dyn AntReleaseRepoActions>::default_config();
 
     let (antnode_src_path, version) = if let Some(path) = src_path.clone() {
@@ -129,6 +145,7 @@ pub async fn add(
         node_ip,
         node_port,
         peers_args,
+        resource_limits,
         rewards_address,
         rpc_address,
         rpc_port,
@@ -143,7 +160,7 @@ pub async fn add(
     };
     info!("Adding node service(s)");
     let added_services_names =
-        add_node(options, &mut node_registry, &service_manager, verbosity).await?;
+        add_node(options, &mut node_registry, &*service_manager, verbosity).await?;
 
     node_registry.save()?;
     debug!("Node registry saved");
@@ -163,7 +180,7 @@ pub async fn balance(
     let mut node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
     refresh_node_registry(
         &mut node_registry,
-        &ServiceController {},
+        &*get_service_control()?,
         verbosity != VerbosityLevel::Minimal,
         false,
         false,
@@ -203,7 +220,7 @@ pub async fn remove(
     let mut node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
     refresh_node_registry(
         &mut node_registry,
-        &ServiceController {},
+        &*get_service_control()?,
         verbosity != VerbosityLevel::Minimal,
         false,
         false,
@@ -225,8 +242,8 @@ pub async fn remove(
         let node = &mut node_registry.nodes[index];
         let rpc_client = RpcClient::from_socket_addr(node.rpc_socket_addr);
         let service = NodeService::new(node, Box::new(rpc_client));
-        let mut service_manager =
-            ServiceManager::new(service, Box::new(ServiceController {}), verbosity);
+        let service_control = get_service_control_for(service.service_data.supervisor_backend)?;
+        let mut service_manager = ServiceManager::new(service, service_control, verbosity);
         match service_manager.remove(keep_directories).await {
             Ok(()) => {
                 debug!("Removed service {}", node.service_name);
@@ -286,11 +303,12 @@ pub async fn start(
         print_banner("Start Antnode Services");
     }
     info!("Starting antnode services for: {peer_ids:?}, {service_names:?}");
+    super::supervisor::ensure_running(verbosity)?;
 
     let mut node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
     refresh_node_registry(
         &mut node_registry,
-        &ServiceController {},
+        &*get_service_control()?,
         verbosity != VerbosityLevel::Minimal,
         false,
         false,
@@ -321,8 +339,8 @@ pub async fn start(
             service
         };
 
-        let mut service_manager =
-            ServiceManager::new(service, Box::new(ServiceController {}), verbosity);
+        let service_control = get_service_control_for(service.service_data.supervisor_backend)?;
+        let mut service_manager = ServiceManager::new(service, service_control, verbosity);
         if service_manager.service.status() != ServiceStatus::Running {
             // It would be possible here to check if the service *is* running and then just
             // continue without applying the delay. The reason for not doing so is because when
@@ -360,7 +378,7 @@ pub async fn status(details: bool, fail: bool, json: bool) -> Result<()> {
         }
         status_report(
             &mut node_registry,
-            &ServiceController {},
+            &*get_service_control()?,
             details,
             json,
             fail,
@@ -386,7 +404,7 @@ pub async fn stop(
     let mut node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
     refresh_node_registry(
         &mut node_registry,
-        &ServiceController {},
+        &*get_service_control()?,
         verbosity != VerbosityLevel::Minimal,
         false,
         false,
@@ -408,8 +426,8 @@ pub async fn stop(
         let node = &mut node_registry.nodes[index];
         let rpc_client = RpcClient::from_socket_addr(node.rpc_socket_addr);
         let service = NodeService::new(node, Box::new(rpc_client));
-        let mut service_manager =
-            ServiceManager::new(service, Box::new(ServiceController {}), verbosity);
+        let service_control = get_service_control_for(service.service_data.supervisor_backend)?;
+        let mut service_manager = ServiceManager::new(service, service_control, verbosity);
 
         if service_manager.service.status() == ServiceStatus::Running {
             if let Some(interval) = interval {
@@ -440,6 +458,7 @@ pub async fn upgrade(
     fixed_interval: Option<u64>,
     peer_ids: Vec<String>,
     provided_env_variables: Option<Vec<(String, String)>>,
+    rolling: Option<RollingUpgradeOptions>,
     service_names: Vec<String>,
     url: Option<String>,
     version: Option<String>,
@@ -456,6 +475,7 @@ pub async fn upgrade(
     info!(
         "Upgrading antnode services with use_force={use_force} for: {peer_ids:?}, {service_names:?}"
     );
+    super::supervisor::ensure_running(verbosity)?;
 
     let (upgrade_bin_path, target_version) = download_and_get_upgrade_bin_path(
         custom_bin_path.clone(),
@@ -469,7 +489,7 @@ pub async fn upgrade(
     let mut node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
     refresh_node_registry(
         &mut node_registry,
-        &ServiceController {},
+        &*get_service_control()?,
         verbosity != VerbosityLevel::Minimal,
         false,
         false,
@@ -502,7 +522,7 @@ pub async fn upgrade(
     trace!("service_indices len: {}", service_indices.len());
     let mut upgrade_summary = Vec::new();
 
-    for &index in &service_indices {
+    for (i, &index) in service_indices.iter().enumerate() {
         let node = &mut node_registry.nodes[index];
         let env_variables = if provided_env_variables.is_some() {
             &provided_env_variables
@@ -520,6 +540,11 @@ pub async fn upgrade(
         let service_name = node.service_name.clone();
 
         let rpc_client = RpcClient::from_socket_addr(node.rpc_socket_addr);
+        let pre_upgrade_state = if rolling.is_some() {
+            Some(PreUpgradeState::capture(node, &rpc_client).await?)
+        } else {
+            None
+        };
         let service = NodeService::new(node, Box::new(rpc_client));
         // set dynamic startup delay if fixed_interval is not set
         let service = if fixed_interval.is_none() {
@@ -528,12 +553,40 @@ pub async fn upgrade(
             service
         };
 
-        let mut service_manager =
-            ServiceManager::new(service, Box::new(ServiceController {}), verbosity);
+        let service_control = get_service_control_for(service.service_data.supervisor_backend)?;
+        let mut service_manager = ServiceManager::new(service, service_control, verbosity);
 
-        match service_manager.upgrade(options).await {
+        match service_manager.upgrade(options.clone()).await {
             Ok(upgrade_result) => {
                 info!("Service: {service_name} has been upgraded, result: {upgrade_result:?}",);
+                if let (Some(rolling), Some(pre_upgrade_state)) = (&rolling, &pre_upgrade_state) {
+                    let upgrade_result = ensure_node_recovered(
+                        &mut service_manager,
+                        upgrade_result,
+                        &options,
+                        pre_upgrade_state,
+                        rolling,
+                    )
+                    .await;
+                    pre_upgrade_state.remove_backup();
+                    let abort = !matches!(
+                        upgrade_result,
+                        UpgradeResult::NotRequired
+                            | UpgradeResult::Upgraded(..)
+                            | UpgradeResult::Forced(..)
+                    );
+                    upgrade_summary.push((service_name.clone(), upgrade_result));
+                    node_registry.save()?;
+                    if abort {
+                        let remaining = service_indices.len() - i - 1;
+                        error!("Aborting the rolling upgrade after {service_name} failed its health check, {remaining} services were not upgraded");
+                        if verbosity != VerbosityLevel::Minimal {
+                            println!("{} {service_name} failed its health check, aborting the upgrade of the remaining {remaining} services", "✕".red());
+                        }
+                        break;
+                    }
+                    continue;
+                }
                 if upgrade_result != UpgradeResult::NotRequired {
                     // It doesn't seem useful to apply the interval if there was no upgrade
                     // required for the previous service.
@@ -555,6 +608,11 @@ pub async fn upgrade(
                     UpgradeResult::Error(format!("Error: {err}")),
                 ));
                 node_registry.save()?;
+                if let Some(pre_upgrade_state) = &pre_upgrade_state {
+                    pre_upgrade_state.remove_backup();
+                    error!("Aborting the rolling upgrade after {service_name} failed to upgrade");
+                    break;
+                }
             }
         }
     }
@@ -566,6 +624,7 @@ pub async fn upgrade(
     if upgrade_summary.iter().any(|(_, r)| {
         matches!(r, UpgradeResult::Error(_))
             || matches!(r, UpgradeResult::UpgradedButNotStarted(_, _, _))
+            || matches!(r, UpgradeResult::RolledBack(_, _, _))
     }) {
         return Err(eyre!("There was a problem upgrading one or more nodes").suggestion(
             "For any services that were upgraded but did not start, you can attempt to start them \
@@ -597,6 +656,7 @@ pub async fn maintain_n_running_nodes(
     node_ip: Option<Ipv4Addr>,
     node_port: Option<PortRange>,
     peers_args: PeersArgs,
+    resource_limits: ResourceLimits,
     rewards_address: RewardsAddress,
     rpc_address: Option<Ipv4Addr>,
     rpc_port: Option<PortRange>,
@@ -701,6 +761,7 @@ pub async fn maintain_n_running_nodes(
                         node_ip,
                         Some(PortRange::Single(port)),
                         peers_args.clone(),
+                        resource_limits.clone(),
                         rewards_address,
                         rpc_address,
                         rpc_port.clone(),
@@ -764,7 +825,7 @@ pub async fn maintain_n_running_nodes(
     Ok(())
 }
 
-fn get_services_for_ops(
+pub(crate) fn get_services_for_ops(
     node_registry: &NodeRegistry,
     peer_ids: Vec<String>,
     service_names: Vec<String>,
@@ -0,0 +1,136 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::{
+    config::{self, is_running_as_root},
+    get_service_control,
+    helpers::{download_and_extract_release, get_bin_version},
+    print_banner,
+    registry_backup::{check_bundle_ports, import_bundle, ImportOptions, RegistryBundle},
+    VerbosityLevel,
+};
+use ant_releases::{AntReleaseRepoActions, ReleaseType};
+use ant_service_management::NodeRegistry;
+use color_eyre::Result;
+use colored::Colorize;
+use std::path::PathBuf;
+
+pub async fn export(output: PathBuf, include_keys: bool, verbosity: VerbosityLevel) -> Result<()> {
+    if verbosity != VerbosityLevel::Minimal {
+        print_banner("Export Node Registry");
+    }
+    let node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
+    let bundle = RegistryBundle::from_registry(&node_registry, include_keys)?;
+    bundle.save(&output)?;
+    info!(
+        "Exported {} node(s) and {} key(s) to {output:?}",
+        bundle.nodes.len(),
+        bundle.node_keys.len()
+    );
+
+    if verbosity != VerbosityLevel::Minimal {
+        println!(
+            "{} Exported {} node(s) to {}",
+            "✓".green(),
+            bundle.nodes.len(),
+            output.to_string_lossy()
+        );
+        if !bundle.node_keys.is_empty() {
+            println!(
+                "The bundle contains the secret keys for {} node(s). Keep it somewhere safe.",
+                bundle.node_keys.len()
+            );
+        }
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn import(
+    bundle_path: PathBuf,
+    data_dir_path: Option<PathBuf>,
+    log_dir_path: Option<PathBuf>,
+    src_path: Option<PathBuf>,
+    url: Option<String>,
+    user: Option<String>,
+    version: Option<String>,
+    verbosity: VerbosityLevel,
+) -> Result<()> {
+    if verbosity != VerbosityLevel::Minimal {
+        print_banner("Import Node Registry");
+    }
+    let bundle = RegistryBundle::load(&bundle_path)?;
+    let mut node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
+    // Fail before downloading anything if the services can't be imported.
+    check_bundle_ports(&bundle, &node_registry)?;
+
+    let user_mode = !is_running_as_root();
+    let service_control = get_service_control()?;
+    let service_user = if user_mode {
+        None
+    } else {
+        let service_user = user.unwrap_or_else(|| "ant".to_string());
+        service_control.create_service_user(&service_user)?;
+        Some(service_user)
+    };
+
+    let service_data_dir_path =
+        config::get_service_data_dir_path(data_dir_path, service_user.clone())?;
+    let service_log_dir_path =
+        config::get_service_log_dir_path(ReleaseType::AntNode, log_dir_path, service_user.clone())?;
+    let bootstrap_cache_dir = if let Some(user) = &service_user {
+        Some(config::get_bootstrap_cache_owner_path(user)?)
+    } else {
+        None
+    };
+
+    let (antnode_src_path, version) = if let Some(path) = src_path {
+        let version = get_bin_version(&path)?;
+        (path, version)
+    } else {
+        let release_repo = <dyn AntReleaseRepoActions>::default_config();
+        download_and_extract_release(
+            ReleaseType::AntNode,
+            url,
+            version,
+            &*release_repo,
+            verbosity,
+            None,
+        )
+        .await?
+    };
+
+    let options = ImportOptions {
+        antnode_src_path,
+        bootstrap_cache_dir,
+        service_data_dir_path,
+        service_log_dir_path,
+        user: service_user,
+        user_mode,
+        version,
+    };
+    let result = import_bundle(
+        &bundle,
+        &options,
+        &mut node_registry,
+        &*service_control,
+        verbosity,
+    );
+    // Save the services that were installed, even if a later one failed.
+    node_registry.save()?;
+    let imported = result?;
+
+    if verbosity != VerbosityLevel::Minimal {
+        println!(
+            "{} Imported {} node(s). Use the 'start' command to start them.",
+            "✓".green(),
+            imported.len()
+        );
+    }
+    Ok(())
+}
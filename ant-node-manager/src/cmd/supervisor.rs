@@ -0,0 +1,80 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::{config, get_supervisor_backend, SupervisorBackend, VerbosityLevel};
+use ant_service_management::supervisor::{
+    is_supervisor_running, reap_in_background, RestartBackoff, Supervisor,
+};
+use color_eyre::{eyre::eyre, Result};
+use std::{
+    fs::OpenOptions,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+const SUPERVISOR_LOG_FILE_NAME: &str = "supervisor.log";
+const SUPERVISOR_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Run the built-in supervisor in the foreground until the process is terminated.
+pub fn run(backoff: RestartBackoff) -> Result<()> {
+    let state_dir = config::get_supervisor_state_dir()?;
+    if is_supervisor_running(&state_dir) {
+        return Err(eyre!(
+            "A supervisor is already running for {}",
+            state_dir.to_string_lossy()
+        ));
+    }
+    let mut supervisor = Supervisor::new(state_dir, backoff);
+    supervisor.run(SUPERVISOR_POLL_INTERVAL)?;
+    Ok(())
+}
+
+/// Launch a background supervisor if the built-in backend is selected and there isn't one
+/// running already.
+///
+/// Services started by the built-in backend are not restarted when they exit unless a supervisor
+/// is running. The background supervisor is the current executable run with the `supervise`
+/// command.
+pub fn ensure_running(verbosity: VerbosityLevel) -> Result<()> {
+    if get_supervisor_backend() != SupervisorBackend::Builtin {
+        return Ok(());
+    }
+    let state_dir = config::get_supervisor_state_dir()?;
+    if is_supervisor_running(&state_dir) {
+        debug!("The built-in supervisor is already running");
+        return Ok(());
+    }
+
+    let log_path = state_dir.join(SUPERVISOR_LOG_FILE_NAME);
+    let log_file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&log_path)?;
+    let mut command = Command::new(std::env::current_exe()?);
+    let _ = command
+        .args(["--supervisor", "builtin", "supervise"])
+        .stdin(Stdio::null())
+        .stdout(log_file.try_clone()?)
+        .stderr(log_file);
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        let _ = command.process_group(0);
+    }
+    let child = command.spawn()?;
+    let pid = child.id();
+    reap_in_background(child);
+    info!("Launched the built-in supervisor with PID {pid}");
+    if verbosity != VerbosityLevel::Minimal {
+        println!(
+            "Launched the built-in supervisor with PID {pid} (log: {})",
+            log_path.to_string_lossy()
+        );
+    }
+    Ok(())
+}
@@ -272,6 +272,16 @@ pub fn is_running_as_root() -> bool {
     std::fs::read_dir("C:\\Windows\\System32\\config").is_ok()
 }
 
+/// Get the directory where the built-in supervisor keeps its service definitions, PID files and
+/// captured logs.
+pub fn get_supervisor_state_dir() -> Result<PathBuf> {
+    let path = get_node_manager_path()?.join("supervisor");
+    if !path.exists() {
+        std::fs::create_dir_all(&path)?;
+    }
+    Ok(path)
+}
+
 pub fn get_user_antnode_data_dir() -> Result<PathBuf> {
     Ok(dirs_next::data_dir()
         .ok_or_else(|| {
@@ -16,10 +16,28 @@ pub enum Error {
     Json(#[from] serde_json::Error),
     #[error(transparent)]
     Io(#[from] std::io::Error),
+    #[error("The release manifest signature is not valid, or not from a trusted key")]
+    InvalidReleaseManifestSignature,
+    #[error("The node did not recover after the upgrade: {0}")]
+    NodeHealthCheckFailed(String),
     #[error("The PID of the process was not found after starting it.")]
     PidNotFoundAfterStarting,
     #[error("The PID of the process was not set.")]
     PidNotSet,
+    #[error("The digest of {archive} does not match the release manifest. Expected: {expected}, got: {actual}")]
+    ReleaseDigestMismatch {
+        archive: std::path::PathBuf,
+        expected: String,
+        actual: String,
+    },
+    #[error("The release manifest for {0} does not match the requested release")]
+    ReleaseManifestMismatch(String),
+    #[error("Could not retrieve the release manifest from {0}")]
+    ReleaseManifestNotFound(String),
+    #[error("The release manifest has no digest for the {0} platform")]
+    ReleasePlatformNotInManifest(String),
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
     #[error(transparent)]
     SemverError(#[from] semver::Error),
     #[error("The service(s) is already running: {0:?}")]
@@ -28,6 +46,8 @@ pub enum Error {
     ServiceNotRunning(Vec<String>),
     #[error(transparent)]
     ServiceManagementError(#[from] ant_service_management::Error),
+    #[error(transparent)]
+    BootstrapError(#[from] ant_bootstrap::Error),
     #[error("The service status is not as expected. Expected: {expected:?}")]
     ServiceStatusMismatch {
         expected: ant_service_management::ServiceStatus,
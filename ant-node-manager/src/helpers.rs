@@ -21,7 +21,13 @@ use std::{
     sync::Arc,
 };
 
-use crate::{add_services::config::PortRange, config, VerbosityLevel};
+use crate::{
+    add_services::config::PortRange,
+    config,
+    error::Error,
+    release_verification::{skip_release_verification, ReleaseVerifier},
+    VerbosityLevel,
+};
 
 const MAX_DOWNLOAD_RETRIES: u8 = 3;
 
@@ -116,6 +122,10 @@ pub async fn configure_winsw(_dest_path: &Path, _verbosity: VerbosityLevel) -> R
 /// If the URL is supplied, that will be downloaded and extracted, and the binary inside the
 /// archive will be used; if the version is supplied, a specific version will be downloaded and
 /// used; otherwise the latest version will be downloaded and used.
+///
+/// Releases downloaded by version are verified against the signed release manifest before being
+/// extracted. If no key is trusted to sign the manifests, this fails, unless verification has
+/// explicitly been skipped. Archives supplied by URL are custom builds and are not verified.
 pub async fn download_and_extract_release(
     release_type: ReleaseType,
     url: Option<String>,
@@ -159,6 +169,32 @@ pub async fn download_and_extract_release(
     };
     debug!("Download directory: {download_dir_path:?}");
 
+    let verifier = if let Some(url) = &url {
+        warn!("The archive from {url} is not an official release and will not be verified");
+        if verbosity != VerbosityLevel::Minimal {
+            println!("The archive from {url} is not an official release and will not be verified");
+        }
+        None
+    } else if skip_release_verification() {
+        warn!("Release verification is disabled, the {release_type} release will not be verified");
+        if verbosity != VerbosityLevel::Minimal {
+            println!(
+                "Release verification is disabled, the {release_type} release will not be verified"
+            );
+        }
+        None
+    } else {
+        let verifier = ReleaseVerifier::new()?;
+        if !verifier.has_trusted_keys() {
+            error!("No keys are trusted to sign release manifests, the {release_type} release cannot be verified");
+            bail!(
+                "No keys are trusted to sign release manifests, the {release_type} release cannot be verified. \
+                Use --skip-release-verification to install it without verification."
+            );
+        }
+        Some(verifier)
+    };
+
     let mut download_attempts = 1;
     let binary_download_path = loop {
         if download_attempts > MAX_DOWNLOAD_RETRIES {
@@ -218,17 +254,35 @@ pub async fn download_and_extract_release(
                 &ArchiveType::TarGz
             );
             let archive_path = download_dir_path.join(&archive_name);
+            let platform = get_running_platform()?;
             if archive_path.exists() {
-                // try extracting it, else download it.
-                match release_repo.extract_release_archive(&archive_path, &download_dir_path) {
-                    Ok(binary_download_path) => {
+                // try verifying and extracting it, else download it.
+                let verified = match &verifier {
+                    Some(verifier) => verifier
+                        .verify_archive(&release_type, &version, &platform, &archive_path)
+                        .await
+                        .inspect_err(|err| {
+                            warn!("Cached {release_type} version {version} failed verification: {err}")
+                        })
+                        .is_ok(),
+                    None => true,
+                };
+                let extracted = if verified {
+                    release_repo
+                        .extract_release_archive(&archive_path, &download_dir_path)
+                        .ok()
+                } else {
+                    None
+                };
+                match extracted {
+                    Some(binary_download_path) => {
                         info!("Using cached {release_type} version {version}...");
                         if verbosity != VerbosityLevel::Minimal {
                             println!("Using cached {release_type} version {version}...");
                         }
                         break binary_download_path;
                     }
-                    Err(_) => {
+                    None => {
                         info!("Cached {release_type} version {version} is corrupted. Downloading again...");
                         if verbosity != VerbosityLevel::Minimal {
                             println!("Cached {release_type} version {version} is corrupted. Downloading again...");
@@ -244,7 +298,7 @@ pub async fn download_and_extract_release(
                 .download_release_from_s3(
                     &release_type,
                     &version,
-                    &get_running_platform()?,
+                    &platform,
                     &ArchiveType::TarGz,
                     &download_dir_path,
                     &callback,
@@ -252,6 +306,34 @@ pub async fn download_and_extract_release(
                 .await
             {
                 Ok(archive_path) => {
+                    if let Some(verifier) = &verifier {
+                        match verifier
+                            .verify_archive(&release_type, &version, &platform, &archive_path)
+                            .await
+                        {
+                            Ok(()) => {}
+                            Err(err @ Error::ReleaseDigestMismatch { .. }) => {
+                                // The download could have been corrupted, so try again.
+                                error!("Verification of the downloaded release failed. Trying again {download_attempts}/{MAX_DOWNLOAD_RETRIES}: {err}");
+                                if verbosity != VerbosityLevel::Minimal {
+                                    println!("Verification of the downloaded release failed. Trying again {download_attempts}/{MAX_DOWNLOAD_RETRIES}: {err}");
+                                }
+                                std::fs::remove_file(&archive_path)?;
+                                download_attempts += 1;
+                                if let Some(pb) = &pb {
+                                    pb.finish_and_clear();
+                                }
+                                continue;
+                            }
+                            Err(err) => {
+                                error!("Could not verify the downloaded release: {err}");
+                                std::fs::remove_file(&archive_path)?;
+                                bail!(
+                                    "Could not verify the downloaded {release_type} release: {err}"
+                                );
+                            }
+                        }
+                    }
                     let binary_download_path =
                         release_repo.extract_release_archive(&archive_path, &download_dir_path)?;
                     break binary_download_path;
@@ -15,9 +15,15 @@ pub mod config;
 pub mod error;
 pub mod helpers;
 pub mod local;
+pub mod registry_backup;
+pub mod release_verification;
+pub mod rolling_upgrade;
 pub mod rpc;
+pub mod rpc_auth;
 pub mod rpc_client;
 
+pub use ant_service_management::supervisor::SupervisorBackend;
+
 pub const DEFAULT_NODE_STARTUP_CONNECTION_TIMEOUT_S: u64 = 300;
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -38,15 +44,71 @@ impl From<u8> for VerbosityLevel {
     }
 }
 
+static SUPERVISOR_BACKEND: OnceLock<SupervisorBackend> = OnceLock::new();
+
+/// Select the supervisor backend for the lifetime of the process.
+///
+/// This should be called once, before any commands run. Subsequent calls have no effect.
+pub fn set_supervisor_backend(backend: SupervisorBackend) {
+    if SUPERVISOR_BACKEND.set(backend).is_err() {
+        warn!("The supervisor backend has already been set");
+    }
+}
+
+pub fn get_supervisor_backend() -> SupervisorBackend {
+    SUPERVISOR_BACKEND.get().copied().unwrap_or_default()
+}
+
+/// Get the supervisor backend the node services in the registry were added with, if there are
+/// any.
+///
+/// This is used when no backend is selected explicitly, so commands operate on the existing
+/// services with the backend that manages them.
+pub fn get_registered_supervisor_backend() -> Option<SupervisorBackend> {
+    let node_registry = config::get_node_registry_path()
+        .and_then(|path| Ok(NodeRegistry::load(&path)?))
+        .inspect_err(|err| warn!("Could not load the node registry: {err:?}"))
+        .ok()?;
+    node_registry
+        .nodes
+        .iter()
+        .find(|node| node.status != ServiceStatus::Removed)
+        .map(|node| node.supervisor_backend)
+}
+
+/// Get the `ServiceControl` implementation for the selected supervisor backend.
+///
+/// Use this for new services. Existing node services should be operated with
+/// [`get_service_control_for`] and the backend they were added with.
+pub fn get_service_control() -> color_eyre::Result<Box<dyn ServiceControl + Send>> {
+    get_service_control_for(get_supervisor_backend())
+}
+
+/// Get the `ServiceControl` implementation for a supervisor backend.
+pub fn get_service_control_for(
+    backend: SupervisorBackend,
+) -> color_eyre::Result<Box<dyn ServiceControl + Send>> {
+    match backend {
+        SupervisorBackend::Os => Ok(Box::new(ServiceController {})),
+        SupervisorBackend::Builtin => Ok(Box::new(BuiltinServiceController::new(
+            config::get_supervisor_state_dir()?,
+        ))),
+    }
+}
+
 use crate::error::{Error, Result};
 use ant_service_management::rpc::RpcActions;
 use ant_service_management::{
-    control::ServiceControl, error::Error as ServiceError, rpc::RpcClient, NodeRegistry,
-    NodeService, NodeServiceData, ServiceStateActions, ServiceStatus, UpgradeOptions,
+    control::{ServiceControl, ServiceController},
+    error::Error as ServiceError,
+    rpc::RpcClient,
+    supervisor::BuiltinServiceController,
+    NodeRegistry, NodeService, NodeServiceData, ServiceStateActions, ServiceStatus, UpgradeOptions,
     UpgradeResult,
 };
 use colored::Colorize;
 use semver::Version;
+use std::sync::OnceLock;
 use tracing::debug;
 
 pub const DAEMON_DEFAULT_PORT: u16 = 12500;
@@ -337,6 +399,15 @@ impl<T: ServiceStateActions + Send> ServiceManager<T> {
                 .build_upgrade_install_context(options.clone())?,
             self.service.is_user_mode(),
         )?;
+        // The limits are part of the service definition, which has just been replaced.
+        let resource_limits = self.service.resource_limits();
+        if resource_limits.has_process_limits() {
+            self.service_control.set_resource_limits(
+                &self.service.name(),
+                &resource_limits,
+                self.service.is_user_mode(),
+            )?;
+        }
 
         if options.start_service {
             match self.start().await {
@@ -423,6 +494,7 @@ pub async fn status_report(
                     .map_or("-".to_string(), |b| b.to_string())
             );
             println!("Rewards address: {}", node.rewards_address);
+            println!("Resource limits: {}", node.resource_limits);
             println!();
         }
 
@@ -655,7 +727,8 @@ mod tests {
     use ant_service_management::{
         error::{Error as ServiceControlError, Result as ServiceControlResult},
         node::{NodeService, NodeServiceData},
-        rpc::{NetworkInfo, NodeInfo, RecordAddress, RpcActions},
+        resources::ResourceLimits,
+        rpc::{NetworkInfo, NodeInfo, RecordAddress, RewardTotal, RpcActions},
         UpgradeOptions, UpgradeResult,
     };
     use assert_fs::prelude::*;
@@ -681,6 +754,7 @@ mod tests {
             async fn node_info(&self) -> ServiceControlResult<NodeInfo>;
             async fn network_info(&self) -> ServiceControlResult<NetworkInfo>;
             async fn record_addresses(&self) -> ServiceControlResult<Vec<RecordAddress>>;
+            async fn rewards_ledger(&self, windows: Vec<std::time::Duration>) -> ServiceControlResult<Vec<RewardTotal>>;
             async fn node_restart(&self, delay_millis: u64, retain_peer_id: bool) -> ServiceControlResult<()>;
             async fn node_stop(&self, delay_millis: u64) -> ServiceControlResult<()>;
             async fn node_update(&self, delay_millis: u64) -> ServiceControlResult<()>;
@@ -696,6 +770,7 @@ mod tests {
             fn get_available_port(&self) -> ServiceControlResult<u16>;
             fn install(&self, install_ctx: ServiceInstallCtx, user_mode: bool) -> ServiceControlResult<()>;
             fn get_process_pid(&self, bin_path: &Path) -> ServiceControlResult<u32>;
+            fn set_resource_limits(&self, service_name: &str, limits: &ResourceLimits, user_mode: bool) -> ServiceControlResult<()>;
             fn start(&self, service_name: &str, user_mode: bool) -> ServiceControlResult<()>;
             fn stop(&self, service_name: &str, user_mode: bool) -> ServiceControlResult<()>;
             fn uninstall(&self, service_name: &str, user_mode: bool) -> ServiceControlResult<()>;
@@ -774,6 +849,7 @@ mod tests {
             peer_id: None,
             peers_args: PeersArgs::default(),
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -782,6 +858,7 @@ mod tests {
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Added,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -889,6 +966,7 @@ mod tests {
             )?),
             peers_args: PeersArgs::default(),
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -897,6 +975,7 @@ mod tests {
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Stopped,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -967,6 +1046,7 @@ mod tests {
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -975,6 +1055,7 @@ mod tests {
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -1085,6 +1166,7 @@ mod tests {
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1093,6 +1175,7 @@ mod tests {
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -1174,6 +1257,7 @@ mod tests {
             peer_id: None,
             peers_args: PeersArgs::default(),
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1182,6 +1266,7 @@ mod tests {
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Added,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -1275,6 +1360,7 @@ mod tests {
             peer_id: None,
             peers_args: PeersArgs::default(),
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1283,6 +1369,7 @@ mod tests {
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Added,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: true,
@@ -1375,6 +1462,7 @@ mod tests {
             peer_id: None,
             peers_args: PeersArgs::default(),
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1383,6 +1471,7 @@ mod tests {
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Added,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -1447,6 +1536,7 @@ mod tests {
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1455,6 +1545,7 @@ mod tests {
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -1507,6 +1598,7 @@ mod tests {
             peer_id: None,
             peers_args: PeersArgs::default(),
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1515,6 +1607,7 @@ mod tests {
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Added,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -1569,6 +1662,7 @@ mod tests {
             )?),
             peers_args: PeersArgs::default(),
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1577,6 +1671,7 @@ mod tests {
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Stopped,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -1630,6 +1725,7 @@ mod tests {
             peer_id: None,
             peers_args: PeersArgs::default(),
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1638,6 +1734,7 @@ mod tests {
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Removed,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -1706,6 +1803,7 @@ mod tests {
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1714,6 +1812,7 @@ mod tests {
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: None,
             user_mode: true,
@@ -1845,6 +1944,7 @@ mod tests {
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1853,6 +1953,7 @@ mod tests {
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -1945,6 +2046,7 @@ mod tests {
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -1953,6 +2055,7 @@ mod tests {
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -2090,6 +2193,7 @@ mod tests {
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -2098,6 +2202,7 @@ mod tests {
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -2247,6 +2352,7 @@ mod tests {
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -2255,6 +2361,7 @@ mod tests {
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -2399,6 +2506,7 @@ mod tests {
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -2407,6 +2515,7 @@ mod tests {
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -2552,6 +2661,7 @@ mod tests {
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -2560,6 +2670,7 @@ mod tests {
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: None,
             user_mode: true,
@@ -2738,6 +2849,7 @@ mod tests {
                 bootstrap_cache_dir: None,
             },
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -2746,6 +2858,7 @@ mod tests {
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -2912,6 +3025,7 @@ network_id: None,
         bootstrap_cache_dir: None,
     },
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -2920,6 +3034,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -3078,6 +3193,7 @@ network_id: None,
             )?),
             peers_args: Default::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -3086,6 +3202,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -3246,6 +3363,7 @@ network_id: None,
                 bootstrap_cache_dir: None,
             },
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -3254,6 +3372,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -3418,6 +3537,7 @@ network_id: None,
                 bootstrap_cache_dir: None,
             },
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -3426,6 +3546,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -3594,6 +3715,7 @@ network_id: None,
                 bootstrap_cache_dir: None,
             },
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -3602,6 +3724,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -3768,6 +3891,7 @@ network_id: None,
                 bootstrap_cache_dir: None,
             },
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -3776,6 +3900,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -3939,6 +4064,7 @@ network_id: None,
                 )),
             },
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -3947,6 +4073,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -4108,6 +4235,7 @@ network_id: None,
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -4116,6 +4244,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: true,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -4269,6 +4398,7 @@ network_id: None,
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -4277,6 +4407,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -4433,6 +4564,7 @@ network_id: None,
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -4441,6 +4573,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -4594,6 +4727,7 @@ network_id: None,
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -4602,6 +4736,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -4758,6 +4893,7 @@ network_id: None,
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -4766,6 +4902,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -4923,11 +5060,13 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
             version: current_version.to_string(),
             evm_network: EvmNetwork::ArbitrumOne,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -5087,11 +5226,13 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
             version: current_version.to_string(),
             evm_network: EvmNetwork::ArbitrumOne,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -5244,6 +5385,7 @@ network_id: None,
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -5252,6 +5394,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -5408,6 +5551,7 @@ network_id: None,
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -5416,6 +5560,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -5570,6 +5715,7 @@ network_id: None,
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -5578,6 +5724,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -5743,6 +5890,7 @@ network_id: None,
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -5752,6 +5900,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -5917,6 +6066,7 @@ network_id: None,
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -5926,6 +6076,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -6081,6 +6232,7 @@ network_id: None,
             )?),
             peers_args: PeersArgs::default(),
             pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -6089,6 +6241,7 @@ network_id: None,
             antnode_path: current_node_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: true,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -6163,6 +6316,7 @@ network_id: None,
             peers_args: PeersArgs::default(),
             peer_id: None,
             pid: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -6170,6 +6324,7 @@ network_id: None,
             rpc_socket_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8081),
             antnode_path: antnode_bin.to_path_buf(),
             status: ServiceStatus::Stopped,
+            supervisor_backend: SupervisorBackend::Os,
             service_name: "antnode1".to_string(),
             version: "0.98.1".to_string(),
             upnp: false,
@@ -6233,6 +6388,7 @@ network_id: None,
             peer_id: Some(PeerId::from_str(
                 "12D3KooWS2tpXGGTmg2AHFiDh57yPQnat49YHnyqoggzXZWpqkCR",
             )?),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -6241,6 +6397,7 @@ network_id: None,
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -6316,6 +6473,7 @@ network_id: None,
             peer_id: Some(PeerId::from_str(
                 "12D3KooWS2tpXGGTmg2AHFiDh57yPQnat49YHnyqoggzXZWpqkCR",
             )?),
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -6324,6 +6482,7 @@ network_id: None,
             antnode_path: PathBuf::from("/var/antctl/services/antnode1/antnode"),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -6392,6 +6551,7 @@ network_id: None,
             pid: None,
             peers_args: PeersArgs::default(),
             peer_id: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -6400,6 +6560,7 @@ network_id: None,
             antnode_path: antnode_bin.to_path_buf(),
             service_name: "antnode1".to_string(),
             status: ServiceStatus::Stopped,
+            supervisor_backend: SupervisorBackend::Os,
             upnp: false,
             user: Some("ant".to_string()),
             user_mode: false,
@@ -6468,6 +6629,7 @@ network_id: None,
             pid: None,
             peers_args: PeersArgs::default(),
             peer_id: None,
+            resource_limits: ResourceLimits::default(),
             rewards_address: RewardsAddress::from_str(
                 "0x03B770D9cD32077cC0bF330c13C114a87643B124",
             )?,
@@ -6475,6 +6637,7 @@ network_id: None,
             rpc_socket_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8081),
             antnode_path: antnode_bin.to_path_buf(),
             status: ServiceStatus::Stopped,
+            supervisor_backend: SupervisorBackend::Os,
             service_name: "antnode1".to_string(),
             upnp: false,
             user: None,
@@ -16,7 +16,9 @@ use ant_evm::{EvmNetwork, RewardsAddress};
 use ant_logging::LogFormat;
 use ant_service_management::{
     control::ServiceControl,
+    resources::ResourceLimits,
     rpc::{RpcActions, RpcClient},
+    supervisor::SupervisorBackend,
     NodeRegistry, NodeServiceData, ServiceStatus,
 };
 use color_eyre::eyre::OptionExt;
@@ -435,10 +437,12 @@ pub async fn run_node(
             bootstrap_cache_dir: None,
         },
         pid: Some(node_info.pid),
+        resource_limits: ResourceLimits::default(),
         rewards_address: run_options.rewards_address,
         reward_balance: None,
         rpc_socket_addr: run_options.rpc_socket_addr,
         status: ServiceStatus::Running,
+        supervisor_backend: SupervisorBackend::Os,
         service_name: format!("antnode-local{}", run_options.number),
         upnp: false,
         user: None,
@@ -503,7 +507,7 @@ mod tests {
     use ant_evm::utils::dummy_address;
     use ant_service_management::{
         error::Result as RpcResult,
-        rpc::{NetworkInfo, NodeInfo, RecordAddress, RpcActions},
+        rpc::{NetworkInfo, NodeInfo, RecordAddress, RewardTotal, RpcActions},
     };
     use async_trait::async_trait;
     use libp2p_identity::PeerId;
@@ -518,6 +522,7 @@ mod tests {
             async fn node_info(&self) -> RpcResult<NodeInfo>;
             async fn network_info(&self) -> RpcResult<NetworkInfo>;
             async fn record_addresses(&self) -> RpcResult<Vec<RecordAddress>>;
+            async fn rewards_ledger(&self, windows: Vec<std::time::Duration>) -> RpcResult<Vec<RewardTotal>>;
             async fn node_restart(&self, delay_millis: u64, retain_peer_id: bool) -> RpcResult<()>;
             async fn node_stop(&self, delay_millis: u64) -> RpcResult<()>;
             async fn node_update(&self, delay_millis: u64) -> RpcResult<()>;
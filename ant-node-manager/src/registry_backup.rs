@@ -0,0 +1,664 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Export the node registry to a bundle that can be used to re-create the services elsewhere.
+//!
+//! The bundle holds the definition of each node service. Optionally it also holds each node's
+//! secret key, so the nodes keep their peer IDs when they are re-installed. Importing a bundle
+//! installs the services under new data and log directories on the target machine, after first
+//! checking their ports don't clash with any services already defined there.
+
+use crate::{
+    add_services::config::{InstallNodeServiceCtxBuilder, PortRange},
+    config::{create_owned_dir, get_user_antnode_data_dir},
+    helpers::check_port_availability,
+    VerbosityLevel,
+};
+use ant_service_management::{
+    control::ServiceControl, NatDetectionStatus, NodeRegistry, NodeServiceData, ServiceStatus,
+};
+use color_eyre::{eyre::eyre, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+pub const REGISTRY_BUNDLE_FORMAT_VERSION: u32 = 1;
+const SECRET_KEY_FILE_NAME: &str = "secret-key";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RegistryBundle {
+    pub format_version: u32,
+    pub environment_variables: Option<Vec<(String, String)>>,
+    pub nat_status: Option<NatDetectionStatus>,
+    pub nodes: Vec<NodeServiceData>,
+    /// Hex encoded secret keys, indexed by the service name of the node they belong to.
+    #[serde(default)]
+    pub node_keys: BTreeMap<String, String>,
+}
+
+impl RegistryBundle {
+    /// Create a bundle from the registry. Removed services are not included.
+    ///
+    /// If `include_keys` is set, the secret key for each node is read from its data directory.
+    /// Nodes that have never been started will not have a key yet.
+    pub fn from_registry(node_registry: &NodeRegistry, include_keys: bool) -> Result<Self> {
+        let nodes = node_registry
+            .nodes
+            .iter()
+            .filter(|node| node.status != ServiceStatus::Removed)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut node_keys = BTreeMap::new();
+        if include_keys {
+            for node in &nodes {
+                let key_path = node.data_dir_path.join(SECRET_KEY_FILE_NAME);
+                if !key_path.exists() {
+                    debug!("No secret key for {} at {key_path:?}", node.service_name);
+                    continue;
+                }
+                let key = std::fs::read(&key_path).inspect_err(|err| {
+                    error!("Could not read the secret key at {key_path:?}: {err:?}")
+                })?;
+                let _ = node_keys.insert(node.service_name.clone(), hex::encode(key));
+            }
+        }
+
+        Ok(RegistryBundle {
+            format_version: REGISTRY_BUNDLE_FORMAT_VERSION,
+            environment_variables: node_registry.environment_variables.clone(),
+            nat_status: node_registry.nat_status.clone(),
+            nodes,
+            node_keys,
+        })
+    }
+
+    /// Write the bundle to a file.
+    ///
+    /// On Unix, if the bundle contains secret keys, the file is only readable by its owner.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        write_file(path, json.as_bytes(), !self.node_keys.is_empty())
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        let bundle: RegistryBundle = serde_json::from_str(&contents)
+            .inspect_err(|err| error!("Error deserializing registry bundle: {err:?}"))?;
+        if bundle.format_version > REGISTRY_BUNDLE_FORMAT_VERSION {
+            return Err(eyre!(
+                "The bundle has format version {}, but this version of antctl only supports up to {}",
+                bundle.format_version,
+                REGISTRY_BUNDLE_FORMAT_VERSION
+            ));
+        }
+        Ok(bundle)
+    }
+}
+
+pub struct ImportOptions {
+    pub antnode_src_path: PathBuf,
+    pub bootstrap_cache_dir: Option<PathBuf>,
+    pub service_data_dir_path: PathBuf,
+    pub service_log_dir_path: PathBuf,
+    pub user: Option<String>,
+    pub user_mode: bool,
+    pub version: String,
+}
+
+/// Check that none of the ports used by the bundled nodes clash with the services already in the
+/// registry, or with each other.
+pub fn check_bundle_ports(bundle: &RegistryBundle, node_registry: &NodeRegistry) -> Result<()> {
+    let mut nodes = node_registry.nodes.clone();
+    for node in &bundle.nodes {
+        let ports = [
+            node.node_port,
+            node.metrics_port,
+            Some(node.rpc_socket_addr.port()),
+        ];
+        for port in ports.into_iter().flatten() {
+            check_port_availability(&PortRange::Single(port), &nodes).map_err(|err| {
+                eyre!("Cannot import {} from the bundle: {err}", node.service_name)
+            })?;
+        }
+        nodes.push(node.clone());
+    }
+    Ok(())
+}
+
+/// Install the bundled nodes as new services and add them to the registry.
+///
+/// Each node is numbered after the services already in the registry, and its data and log
+/// directories are placed under the paths in the options. The services are only defined; they are
+/// not started.
+///
+/// Returns the names of the new services.
+pub fn import_bundle(
+    bundle: &RegistryBundle,
+    options: &ImportOptions,
+    node_registry: &mut NodeRegistry,
+    service_control: &dyn ServiceControl,
+    verbosity: VerbosityLevel,
+) -> Result<Vec<String>> {
+    check_bundle_ports(bundle, node_registry)?;
+
+    if node_registry.environment_variables.is_none() {
+        node_registry
+            .environment_variables
+            .clone_from(&bundle.environment_variables);
+    }
+    if node_registry.nat_status.is_none() {
+        node_registry.nat_status.clone_from(&bundle.nat_status);
+    }
+
+    let antnode_file_name = options
+        .antnode_src_path
+        .file_name()
+        .ok_or_else(|| eyre!("Could not get filename from the antnode path"))?
+        .to_string_lossy()
+        .to_string();
+    let default_log_dir_path = get_user_antnode_data_dir()?;
+
+    let mut imported = Vec::new();
+    for node in &bundle.nodes {
+        let number = node_registry.nodes.len() as u16 + 1;
+        let service_name = format!("antnode{number}");
+        let data_dir_path = options.service_data_dir_path.join(&service_name);
+        // This follows the same layout as the `add` command.
+        let log_dir_path =
+            if options.user_mode && options.service_log_dir_path == default_log_dir_path {
+                options
+                    .service_log_dir_path
+                    .join(&service_name)
+                    .join("logs")
+            } else {
+                options.service_log_dir_path.join(&service_name)
+            };
+
+        if let Some(user) = &options.user {
+            create_owned_dir(data_dir_path.clone(), user)?;
+            create_owned_dir(log_dir_path.clone(), user)?;
+        } else {
+            std::fs::create_dir_all(&data_dir_path)?;
+            std::fs::create_dir_all(&log_dir_path)?;
+        }
+
+        let antnode_path = data_dir_path.join(&antnode_file_name);
+        std::fs::copy(&options.antnode_src_path, &antnode_path)?;
+
+        // Without its key, the node will generate a new one, and so a new peer ID, on startup.
+        let peer_id = match bundle.node_keys.get(&node.service_name) {
+            Some(key) => {
+                let key = hex::decode(key).map_err(|err| {
+                    eyre!("The key for {} is not valid hex: {err}", node.service_name)
+                })?;
+                let key_path = data_dir_path.join(SECRET_KEY_FILE_NAME);
+                write_file(&key_path, &key, true)?;
+                #[cfg(unix)]
+                if let Some(user) = &options.user {
+                    set_owner(&key_path, user)?;
+                }
+                node.peer_id
+            }
+            None => None,
+        };
+
+        let mut peers_args = node.peers_args.clone();
+        peers_args
+            .bootstrap_cache_dir
+            .clone_from(&options.bootstrap_cache_dir);
+
+        let install_ctx = InstallNodeServiceCtxBuilder {
+            antnode_path: antnode_path.clone(),
+            autostart: node.auto_restart,
+            data_dir_path: data_dir_path.clone(),
+            env_variables: node_registry.environment_variables.clone(),
+            evm_network: node.evm_network.clone(),
+            home_network: node.home_network,
+            log_dir_path: log_dir_path.clone(),
+            log_format: node.log_format,
+            max_archived_log_files: node.max_archived_log_files,
+            max_log_files: node.max_log_files,
+            metrics_port: node.metrics_port,
+            name: service_name.clone(),
+            network_id: node.network_id,
+            node_ip: node.node_ip,
+            node_port: node.node_port,
+            peers_args: peers_args.clone(),
+            rewards_address: node.rewards_address,
+            rpc_socket_addr: node.rpc_socket_addr,
+            service_user: options.user.clone(),
+            resource_limits: node.resource_limits.clone(),
+            upnp: node.upnp,
+        }
+        .build()?;
+        service_control
+            .install(install_ctx, options.user_mode)
+            .inspect_err(|err| error!("Failed to install {service_name}: {err:?}"))?;
+
+        node_registry.nodes.push(NodeServiceData {
+            antnode_path,
+            connected_peers: None,
+            data_dir_path,
+            listen_addr: None,
+            log_dir_path,
+            number,
+            peer_id,
+            peers_args,
+            pid: None,
+            reward_balance: None,
+            service_name: service_name.clone(),
+            status: ServiceStatus::Added,
+            supervisor_backend: crate::get_supervisor_backend(),
+            user: options.user.clone(),
+            user_mode: options.user_mode,
+            version: options.version.clone(),
+            ..node.clone()
+        });
+        imported.push(service_name.clone());
+
+        // The service is installed by now, so it stays in the registry even if this fails, which
+        // means it can still be removed.
+        if node.resource_limits.has_process_limits() {
+            service_control
+                .set_resource_limits(&service_name, &node.resource_limits, options.user_mode)
+                .inspect_err(|err| {
+                    error!("Failed to set the resource limits for {service_name}: {err:?}")
+                })?;
+        }
+
+        if verbosity != VerbosityLevel::Minimal {
+            println!(
+                "Imported {} as {service_name}{}",
+                node.service_name,
+                if peer_id.is_some() {
+                    " with its existing key"
+                } else {
+                    ""
+                }
+            );
+        }
+        info!("Imported {} as {service_name}", node.service_name);
+    }
+
+    Ok(imported)
+}
+
+/// Write the contents to a file, replacing any existing contents.
+///
+/// On Unix, a private file is only readable by its owner. The permissions are set before anything
+/// is written, so the contents are never readable by other users.
+fn write_file(path: &Path, contents: &[u8], private: bool) -> Result<()> {
+    let mut opt = std::fs::OpenOptions::new();
+    let _ = opt.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    if private {
+        use std::os::unix::fs::OpenOptionsExt;
+        let _ = opt.mode(0o600);
+    }
+    let mut file = opt.open(path)?;
+    // The mode only applies when the file is created, so an existing file is restricted here.
+    #[cfg(unix)]
+    if private {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    }
+    #[cfg(not(unix))]
+    let _ = private;
+    file.write_all(contents)?;
+    Ok(())
+}
+
+#[cfg(unix)]
+fn set_owner(path: &Path, owner: &str) -> Result<()> {
+    use nix::unistd::{chown, Gid, Uid};
+    use users::get_user_by_name;
+
+    let user = get_user_by_name(owner).ok_or_else(|| eyre!("User '{owner}' does not exist"))?;
+    chown(
+        path,
+        Some(Uid::from_raw(user.uid())),
+        Some(Gid::from_raw(user.primary_group_id())),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ant_bootstrap::PeersArgs;
+    use ant_evm::{EvmNetwork, RewardsAddress};
+    use ant_service_management::{
+        error::Result as ServiceControlResult, resources::ResourceLimits,
+        supervisor::SupervisorBackend,
+    };
+    use assert_fs::{prelude::*, TempDir};
+    use libp2p_identity::PeerId;
+    use mockall::{mock, predicate::*};
+    use service_manager::ServiceInstallCtx;
+    use std::{
+        net::{IpAddr, Ipv4Addr, SocketAddr},
+        str::FromStr,
+    };
+
+    mock! {
+        pub ServiceControl {}
+        impl ServiceControl for ServiceControl {
+            fn create_service_user(&self, username: &str) -> ServiceControlResult<()>;
+            fn get_available_port(&self) -> ServiceControlResult<u16>;
+            fn install(&self, install_ctx: ServiceInstallCtx, user_mode: bool) -> ServiceControlResult<()>;
+            fn get_process_pid(&self, bin_path: &Path) -> ServiceControlResult<u32>;
+            fn set_resource_limits(&self, service_name: &str, limits: &ResourceLimits, user_mode: bool) -> ServiceControlResult<()>;
+            fn start(&self, service_name: &str, user_mode: bool) -> ServiceControlResult<()>;
+            fn stop(&self, service_name: &str, user_mode: bool) -> ServiceControlResult<()>;
+            fn uninstall(&self, service_name: &str, user_mode: bool) -> ServiceControlResult<()>;
+            fn wait(&self, delay: u64);
+        }
+    }
+
+    fn node_data(number: u16, data_dir_path: PathBuf, node_port: u16) -> NodeServiceData {
+        let service_name = format!("antnode{number}");
+        NodeServiceData {
+            antnode_path: data_dir_path.join("antnode"),
+            auto_restart: false,
+            connected_peers: None,
+            data_dir_path,
+            evm_network: EvmNetwork::ArbitrumOne,
+            home_network: false,
+            listen_addr: None,
+            log_dir_path: PathBuf::from("/var/log/antnode").join(&service_name),
+            log_format: None,
+            max_archived_log_files: None,
+            max_log_files: None,
+            metrics_port: None,
+            network_id: None,
+            node_ip: None,
+            node_port: Some(node_port),
+            number,
+            peer_id: Some(
+                PeerId::from_str("12D3KooWS2tpXGGTmg2AHFiDh57yPQnat49YHnyqoggzXZWpqkCR").unwrap(),
+            ),
+            peers_args: PeersArgs::default(),
+            pid: Some(1000),
+            resource_limits: ResourceLimits::default(),
+            rewards_address: RewardsAddress::from_str("0x03B770D9cD32077cC0bF330c13C114a87643B124")
+                .unwrap(),
+            reward_balance: None,
+            rpc_socket_addr: SocketAddr::new(
+                IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
+                node_port + 1000,
+            ),
+            service_name,
+            status: ServiceStatus::Running,
+            supervisor_backend: SupervisorBackend::Os,
+            upnp: false,
+            user: Some("ant".to_string()),
+            user_mode: false,
+            version: "0.98.1".to_string(),
+        }
+    }
+
+    fn empty_registry(tmp_dir: &TempDir) -> NodeRegistry {
+        NodeRegistry {
+            auditor: None,
+            daemon: None,
+            environment_variables: None,
+            faucet: None,
+            nat_status: None,
+            nodes: vec![],
+            save_path: tmp_dir.child("node_reg.json").to_path_buf(),
+        }
+    }
+
+    #[test]
+    fn export_should_include_keys_only_when_requested() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let node1_dir = tmp_dir.child("antnode1");
+        node1_dir.create_dir_all()?;
+        node1_dir
+            .child(SECRET_KEY_FILE_NAME)
+            .write_binary(&[1, 2, 3])?;
+        let node2_dir = tmp_dir.child("antnode2");
+        node2_dir.create_dir_all()?;
+
+        let mut node_registry = empty_registry(&tmp_dir);
+        node_registry
+            .nodes
+            .push(node_data(1, node1_dir.to_path_buf(), 12001));
+        node_registry
+            .nodes
+            .push(node_data(2, node2_dir.to_path_buf(), 12002));
+        let mut removed = node_data(3, tmp_dir.child("antnode3").to_path_buf(), 12003);
+        removed.status = ServiceStatus::Removed;
+        node_registry.nodes.push(removed);
+
+        let bundle = RegistryBundle::from_registry(&node_registry, false)?;
+        assert_eq!(bundle.nodes.len(), 2);
+        assert!(bundle.node_keys.is_empty());
+
+        let bundle = RegistryBundle::from_registry(&node_registry, true)?;
+        assert_eq!(bundle.node_keys.len(), 1);
+        assert_eq!(bundle.node_keys["antnode1"], "010203");
+
+        let bundle_path = tmp_dir.child("bundle.json");
+        bundle_path.write_str("{}")?;
+        bundle.save(bundle_path.path())?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(bundle_path.path())?.permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        let loaded = RegistryBundle::load(bundle_path.path())?;
+        assert_eq!(loaded.nodes.len(), 2);
+        assert_eq!(loaded.node_keys, bundle.node_keys);
+        Ok(())
+    }
+
+    #[test]
+    fn import_should_remap_directories_and_restore_keys() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let antnode_src = tmp_dir.child("downloads").child("antnode");
+        antnode_src.write_binary(b"fake antnode")?;
+
+        let mut bundle = RegistryBundle {
+            format_version: REGISTRY_BUNDLE_FORMAT_VERSION,
+            environment_variables: Some(vec![("ANT_LOG".to_string(), "all".to_string())]),
+            nat_status: None,
+            nodes: vec![
+                node_data(1, PathBuf::from("/old/antnode1"), 12001),
+                node_data(2, PathBuf::from("/old/antnode2"), 12002),
+            ],
+            node_keys: BTreeMap::new(),
+        };
+        let _ = bundle
+            .node_keys
+            .insert("antnode1".to_string(), "010203".to_string());
+
+        let mut node_registry = empty_registry(&tmp_dir);
+        node_registry
+            .nodes
+            .push(node_data(1, tmp_dir.child("existing").to_path_buf(), 14001));
+
+        let mut mock_service_control = MockServiceControl::new();
+        mock_service_control
+            .expect_install()
+            .times(2)
+            .with(always(), eq(true))
+            .returning(|_, _| Ok(()));
+
+        let options = ImportOptions {
+            antnode_src_path: antnode_src.to_path_buf(),
+            bootstrap_cache_dir: None,
+            service_data_dir_path: tmp_dir.child("data").to_path_buf(),
+            service_log_dir_path: tmp_dir.child("logs").to_path_buf(),
+            user: None,
+            user_mode: true,
+            version: "0.99.0".to_string(),
+        };
+        let imported = import_bundle(
+            &bundle,
+            &options,
+            &mut node_registry,
+            &mock_service_control,
+            VerbosityLevel::Minimal,
+        )?;
+
+        assert_eq!(imported, vec!["antnode2", "antnode3"]);
+        assert_eq!(node_registry.nodes.len(), 3);
+        assert_eq!(
+            node_registry.environment_variables,
+            bundle.environment_variables
+        );
+
+        let restored = &node_registry.nodes[1];
+        assert_eq!(restored.service_name, "antnode2");
+        assert_eq!(restored.number, 2);
+        assert_eq!(
+            restored.data_dir_path,
+            tmp_dir.child("data/antnode2").path()
+        );
+        assert_eq!(restored.log_dir_path, tmp_dir.child("logs/antnode2").path());
+        assert_eq!(restored.status, ServiceStatus::Added);
+        assert_eq!(restored.pid, None);
+        assert_eq!(restored.peer_id, bundle.nodes[0].peer_id);
+        assert_eq!(restored.node_port, Some(12001));
+        assert_eq!(restored.version, "0.99.0");
+        assert!(restored.user_mode);
+        tmp_dir
+            .child("data/antnode2")
+            .child(SECRET_KEY_FILE_NAME)
+            .assert(&[1u8, 2, 3][..]);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let key_path = tmp_dir.child("data/antnode2").child(SECRET_KEY_FILE_NAME);
+            let mode = std::fs::metadata(key_path.path())?.permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        tmp_dir
+            .child("data/antnode2/antnode")
+            .assert(predicates::path::is_file());
+
+        // The second node had no key, so it will start with a new peer ID.
+        let fresh = &node_registry.nodes[2];
+        assert_eq!(fresh.peer_id, None);
+        tmp_dir
+            .child("data/antnode3")
+            .child(SECRET_KEY_FILE_NAME)
+            .assert(predicates::path::missing());
+        Ok(())
+    }
+
+    #[test]
+    fn import_should_fail_on_port_conflicts_before_installing_anything() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let antnode_src = tmp_dir.child("antnode");
+        antnode_src.write_binary(b"fake antnode")?;
+
+        let bundle = RegistryBundle {
+            format_version: REGISTRY_BUNDLE_FORMAT_VERSION,
+            environment_variables: None,
+            nat_status: None,
+            nodes: vec![
+                node_data(1, PathBuf::from("/old/antnode1"), 12001),
+                node_data(2, PathBuf::from("/old/antnode2"), 13001),
+            ],
+            node_keys: BTreeMap::new(),
+        };
+        let mut node_registry = empty_registry(&tmp_dir);
+        node_registry
+            .nodes
+            .push(node_data(1, tmp_dir.child("existing").to_path_buf(), 14001));
+
+        let mut mock_service_control = MockServiceControl::new();
+        mock_service_control.expect_install().never();
+
+        let options = ImportOptions {
+            antnode_src_path: antnode_src.to_path_buf(),
+            bootstrap_cache_dir: None,
+            service_data_dir_path: tmp_dir.child("data").to_path_buf(),
+            service_log_dir_path: tmp_dir.child("logs").to_path_buf(),
+            user: None,
+            user_mode: true,
+            version: "0.99.0".to_string(),
+        };
+        let result = import_bundle(
+            &bundle,
+            &options,
+            &mut node_registry,
+            &mock_service_control,
+            VerbosityLevel::Minimal,
+        );
+
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("Port 13001 is being used"));
+        assert_eq!(node_registry.nodes.len(), 1);
+        tmp_dir.child("data").assert(predicates::path::missing());
+        Ok(())
+    }
+
+    #[test]
+    fn import_should_keep_an_installed_service_when_setting_its_limits_fails() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let antnode_src = tmp_dir.child("antnode");
+        antnode_src.write_binary(b"fake antnode")?;
+
+        let mut node = node_data(1, PathBuf::from("/old/antnode1"), 12001);
+        node.resource_limits.max_memory = Some(1024);
+        let bundle = RegistryBundle {
+            format_version: REGISTRY_BUNDLE_FORMAT_VERSION,
+            environment_variables: None,
+            nat_status: None,
+            nodes: vec![node, node_data(2, PathBuf::from("/old/antnode2"), 12002)],
+            node_keys: BTreeMap::new(),
+        };
+        let mut node_registry = empty_registry(&tmp_dir);
+
+        let mut mock_service_control = MockServiceControl::new();
+        mock_service_control
+            .expect_install()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_service_control
+            .expect_set_resource_limits()
+            .times(1)
+            .with(eq("antnode1"), always(), eq(true))
+            .returning(|_, _, _| {
+                Err(ant_service_management::Error::Io(std::io::Error::other(
+                    "no cgroups",
+                )))
+            });
+
+        let options = ImportOptions {
+            antnode_src_path: antnode_src.to_path_buf(),
+            bootstrap_cache_dir: None,
+            service_data_dir_path: tmp_dir.child("data").to_path_buf(),
+            service_log_dir_path: tmp_dir.child("logs").to_path_buf(),
+            user: None,
+            user_mode: true,
+            version: "0.99.0".to_string(),
+        };
+        let result = import_bundle(
+            &bundle,
+            &options,
+            &mut node_registry,
+            &mock_service_control,
+            VerbosityLevel::Minimal,
+        );
+
+        assert!(result.is_err());
+        assert_eq!(node_registry.nodes.len(), 1);
+        assert_eq!(node_registry.nodes[0].service_name, "antnode1");
+        assert_eq!(node_registry.nodes[0].status, ServiceStatus::Added);
+        Ok(())
+    }
+}
@@ -0,0 +1,409 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::error::{Error, Result};
+use ant_bootstrap::{is_signed_by_trusted_key, parse_trusted_keys};
+use ant_releases::{Platform, ReleaseType};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::BTreeMap,
+    io::Read,
+    path::Path,
+    sync::{Mutex, OnceLock},
+    time::Duration,
+};
+
+/// The name of the environment variable that can be used to provide a comma-separated list of
+/// hex-encoded BLS public keys trusted to sign release manifests.
+pub const ANT_RELEASE_PUBLIC_KEYS_ENV: &str = "ANT_RELEASE_PUBLIC_KEYS";
+/// The name of the environment variable that can be used to fetch the release manifests from
+/// another location, e.g. a mirror of the release buckets.
+pub const ANT_RELEASE_MANIFEST_URL_ENV: &str = "ANT_RELEASE_MANIFEST_URL";
+/// The public keys trusted to sign release manifests, set when building the release binaries.
+const RELEASE_PUBLIC_KEYS_BUILD_TIME_VAL: Option<&str> = option_env!("ANT_RELEASE_PUBLIC_KEYS");
+
+const ANTCTL_S3_BASE_URL: &str = "https://antctl.s3.eu-west-2.amazonaws.com";
+const ANTNODE_S3_BASE_URL: &str = "https://antnode.s3.eu-west-2.amazonaws.com";
+const ANTNODE_RPC_CLIENT_S3_BASE_URL: &str =
+    "https://antnode-rpc-client.s3.eu-west-2.amazonaws.com";
+const ANT_S3_BASE_URL: &str = "https://autonomi-cli.s3.eu-west-2.amazonaws.com";
+const NAT_DETECTION_S3_BASE_URL: &str = "https://nat-detection.s3.eu-west-2.amazonaws.com";
+const NODE_LAUNCHPAD_S3_BASE_URL: &str = "https://node-launchpad.s3.eu-west-2.amazonaws.com";
+
+const MANIFEST_FETCH_TIMEOUT_SECS: u64 = 30;
+
+static SKIP_RELEASE_VERIFICATION: OnceLock<bool> = OnceLock::new();
+
+/// Skip the verification of downloaded releases for the lifetime of the process.
+///
+/// This should be called once, before any commands run. Subsequent calls have no effect.
+pub fn set_skip_release_verification(skip: bool) {
+    if SKIP_RELEASE_VERIFICATION.set(skip).is_err() {
+        warn!("Skipping the release verification has already been set");
+    }
+}
+
+pub fn skip_release_verification() -> bool {
+    SKIP_RELEASE_VERIFICATION.get().copied().unwrap_or_default()
+}
+
+/// The SHA-256 digests of the archives of a release, one per platform, signed by the release
+/// key.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedReleaseManifest {
+    /// The release type, e.g. `antnode`.
+    pub release_type: String,
+    /// The version of the release.
+    pub version: String,
+    /// Hex-encoded SHA-256 digest of the archive, keyed by platform,
+    /// e.g. `x86_64-unknown-linux-musl`.
+    pub digests: BTreeMap<String, String>,
+    /// Hex-encoded signature over the release type, the version and the digests.
+    pub signature: String,
+}
+
+impl SignedReleaseManifest {
+    /// Sign the digests of a release.
+    pub fn sign(
+        release_type: &ReleaseType,
+        version: &Version,
+        digests: BTreeMap<String, String>,
+        secret_key: &bls::SecretKey,
+    ) -> Self {
+        let release_type = release_type.to_string();
+        let version = version.to_string();
+        let signature =
+            secret_key.sign(Self::bytes_for_signature(&release_type, &version, &digests));
+        Self {
+            release_type,
+            version,
+            digests,
+            signature: hex::encode(signature.to_bytes()),
+        }
+    }
+
+    /// The bytes that are signed: the release type and the version, then each `platform=digest`
+    /// pair sorted by platform, each followed by a newline.
+    pub fn bytes_for_signature(
+        release_type: &str,
+        version: &str,
+        digests: &BTreeMap<String, String>,
+    ) -> Vec<u8> {
+        let mut bytes = format!("{release_type}\n{version}\n").into_bytes();
+        for (platform, digest) in digests {
+            bytes.extend_from_slice(format!("{platform}={digest}\n").as_bytes());
+        }
+        bytes
+    }
+
+    /// Check that the manifest has been signed by one of the trusted keys.
+    pub fn verify(&self, trusted_keys: &[bls::PublicKey]) -> Result<()> {
+        let bytes = Self::bytes_for_signature(&self.release_type, &self.version, &self.digests);
+        if is_signed_by_trusted_key(&self.signature, &bytes, trusted_keys) {
+            Ok(())
+        } else {
+            Err(Error::InvalidReleaseManifestSignature)
+        }
+    }
+}
+
+/// Verifies downloaded release archives against the signed release manifests.
+///
+/// Manifests are cached once fetched, so the same verifier should be used for all the archives
+/// of an operation.
+pub struct ReleaseVerifier {
+    manifest_base_url: Option<String>,
+    manifests: Mutex<BTreeMap<String, SignedReleaseManifest>>,
+    request_client: reqwest::Client,
+    trusted_keys: Vec<bls::PublicKey>,
+}
+
+impl ReleaseVerifier {
+    /// Create a verifier trusting the keys from `ANT_RELEASE_PUBLIC_KEYS`, fetching the manifests
+    /// from `ANT_RELEASE_MANIFEST_URL` if set, or else from the release buckets.
+    pub fn new() -> Result<Self> {
+        let request_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(MANIFEST_FETCH_TIMEOUT_SECS))
+            .build()?;
+        Ok(Self {
+            manifest_base_url: std::env::var(ANT_RELEASE_MANIFEST_URL_ENV).ok(),
+            manifests: Mutex::new(BTreeMap::new()),
+            request_client,
+            trusted_keys: default_trusted_keys()?,
+        })
+    }
+
+    /// Fetch the manifests from the provided location rather than the release buckets.
+    pub fn with_manifest_base_url(mut self, url: impl Into<String>) -> Self {
+        self.manifest_base_url = Some(url.into());
+        self
+    }
+
+    /// Trust manifests signed by the provided key.
+    pub fn insert_trusted_key(&mut self, key: bls::PublicKey) {
+        self.trusted_keys.push(key);
+    }
+
+    /// Whether any key is trusted to sign release manifests. Without one, no release can be
+    /// verified.
+    pub fn has_trusted_keys(&self) -> bool {
+        !self.trusted_keys.is_empty()
+    }
+
+    /// The URL of the manifest of a release, e.g.
+    /// `https://antnode.s3.eu-west-2.amazonaws.com/antnode-0.3.6.manifest.json`.
+    pub fn manifest_url(&self, release_type: &ReleaseType, version: &Version) -> String {
+        let base_url = self
+            .manifest_base_url
+            .as_deref()
+            .unwrap_or_else(|| release_bucket_url(release_type));
+        format!(
+            "{}/{}-{version}.manifest.json",
+            base_url.trim_end_matches('/'),
+            release_type.to_string().to_lowercase()
+        )
+    }
+
+    /// Fetch the manifest of a release and check its signature, unless it has already been
+    /// fetched.
+    pub async fn fetch_manifest(
+        &self,
+        release_type: &ReleaseType,
+        version: &Version,
+    ) -> Result<SignedReleaseManifest> {
+        let url = self.manifest_url(release_type, version);
+        let cached = self
+            .manifests
+            .lock()
+            .ok()
+            .and_then(|manifests| manifests.get(&url).cloned());
+        if let Some(manifest) = cached {
+            return Ok(manifest);
+        }
+
+        debug!("Fetching the release manifest from {url}");
+        let response = self.request_client.get(&url).send().await?;
+        if !response.status().is_success() {
+            error!(
+                "Failed to fetch the release manifest from {url}: {}",
+                response.status()
+            );
+            return Err(Error::ReleaseManifestNotFound(url));
+        }
+
+        let manifest: SignedReleaseManifest = response.json().await?;
+        manifest.verify(&self.trusted_keys)?;
+        if manifest.release_type != release_type.to_string()
+            || manifest.version != version.to_string()
+        {
+            error!(
+                "The manifest from {url} is for {} {}",
+                manifest.release_type, manifest.version
+            );
+            return Err(Error::ReleaseManifestMismatch(url));
+        }
+
+        if let Ok(mut manifests) = self.manifests.lock() {
+            let _ = manifests.insert(url, manifest.clone());
+        }
+        Ok(manifest)
+    }
+
+    /// Check the archive against the digest from the signed manifest of the release.
+    pub async fn verify_archive(
+        &self,
+        release_type: &ReleaseType,
+        version: &Version,
+        platform: &Platform,
+        archive_path: &Path,
+    ) -> Result<()> {
+        let manifest = self.fetch_manifest(release_type, version).await?;
+        let expected = manifest
+            .digests
+            .get(&platform.to_string())
+            .ok_or_else(|| Error::ReleasePlatformNotInManifest(platform.to_string()))?;
+
+        let actual = sha256_digest(archive_path)?;
+        if !actual.eq_ignore_ascii_case(expected) {
+            error!("The digest of {archive_path:?} is {actual}, the manifest has {expected}");
+            return Err(Error::ReleaseDigestMismatch {
+                archive: archive_path.to_path_buf(),
+                expected: expected.clone(),
+                actual,
+            });
+        }
+
+        info!("Verified {archive_path:?} against the {release_type} {version} release manifest");
+        Ok(())
+    }
+}
+
+/// The hex-encoded SHA-256 digest of a file.
+pub fn sha256_digest(path: &Path) -> Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+fn release_bucket_url(release_type: &ReleaseType) -> &'static str {
+    match release_type {
+        ReleaseType::Ant => ANT_S3_BASE_URL,
+        ReleaseType::AntCtl | ReleaseType::AntCtlDaemon => ANTCTL_S3_BASE_URL,
+        ReleaseType::AntNode => ANTNODE_S3_BASE_URL,
+        ReleaseType::AntNodeRpcClient => ANTNODE_RPC_CLIENT_S3_BASE_URL,
+        ReleaseType::NatDetection => NAT_DETECTION_S3_BASE_URL,
+        ReleaseType::NodeLaunchpad => NODE_LAUNCHPAD_S3_BASE_URL,
+    }
+}
+
+/// The keys trusted to sign release manifests, read from `ANT_RELEASE_PUBLIC_KEYS` at runtime,
+/// or else at build time.
+///
+/// Fails if any of the keys cannot be parsed.
+fn default_trusted_keys() -> Result<Vec<bls::PublicKey>> {
+    let keys = match std::env::var(ANT_RELEASE_PUBLIC_KEYS_ENV) {
+        Ok(keys) => keys,
+        Err(_) => RELEASE_PUBLIC_KEYS_BUILD_TIME_VAL
+            .unwrap_or_default()
+            .to_string(),
+    };
+    Ok(parse_trusted_keys(&keys)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_fs::prelude::*;
+    use assert_matches::assert_matches;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const PLATFORM: Platform = Platform::LinuxMusl;
+
+    fn signed_manifest(archive: &[u8], secret_key: &bls::SecretKey) -> SignedReleaseManifest {
+        let mut digests = BTreeMap::new();
+        let _ = digests.insert(PLATFORM.to_string(), hex::encode(Sha256::digest(archive)));
+        let _ = digests.insert("x86_64-apple-darwin".to_string(), hex::encode([0; 32]));
+        SignedReleaseManifest::sign(
+            &ReleaseType::AntNode,
+            &Version::new(0, 3, 6),
+            digests,
+            secret_key,
+        )
+    }
+
+    async fn serve_manifest(manifest: &SignedReleaseManifest) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/antnode-0.3.6.manifest.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(manifest))
+            // The manifest is only fetched once per verifier.
+            .expect(1)
+            .mount(&server)
+            .await;
+        server
+    }
+
+    fn verifier(server: &MockServer, key: bls::PublicKey) -> ReleaseVerifier {
+        let mut verifier = ReleaseVerifier::new()
+            .unwrap()
+            .with_manifest_base_url(server.uri());
+        verifier.insert_trusted_key(key);
+        verifier
+    }
+
+    #[tokio::test]
+    async fn archive_should_be_verified_against_the_signed_manifest() {
+        let secret_key = bls::SecretKey::random();
+        let tmp_dir = assert_fs::TempDir::new().unwrap();
+        let archive = tmp_dir.child("antnode-0.3.6-x86_64-unknown-linux-musl.tar.gz");
+        archive.write_binary(b"antnode archive").unwrap();
+
+        let server = serve_manifest(&signed_manifest(b"antnode archive", &secret_key)).await;
+        let verifier = verifier(&server, secret_key.public_key());
+        verifier
+            .verify_archive(
+                &ReleaseType::AntNode,
+                &Version::new(0, 3, 6),
+                &PLATFORM,
+                archive.path(),
+            )
+            .await
+            .unwrap();
+
+        archive.write_binary(b"tampered antnode archive").unwrap();
+        let result = verifier
+            .verify_archive(
+                &ReleaseType::AntNode,
+                &Version::new(0, 3, 6),
+                &PLATFORM,
+                archive.path(),
+            )
+            .await;
+        assert_matches!(result, Err(Error::ReleaseDigestMismatch { .. }));
+    }
+
+    #[tokio::test]
+    async fn manifest_from_untrusted_key_should_be_rejected() {
+        let tmp_dir = assert_fs::TempDir::new().unwrap();
+        let archive = tmp_dir.child("antnode-0.3.6-x86_64-unknown-linux-musl.tar.gz");
+        archive.write_binary(b"antnode archive").unwrap();
+
+        let mut manifest = signed_manifest(b"antnode archive", &bls::SecretKey::random());
+        let server = serve_manifest(&manifest).await;
+        let trusted_key = bls::SecretKey::random();
+        let verifier = verifier(&server, trusted_key.public_key());
+        let result = verifier
+            .verify_archive(
+                &ReleaseType::AntNode,
+                &Version::new(0, 3, 6),
+                &PLATFORM,
+                archive.path(),
+            )
+            .await;
+        assert_matches!(result, Err(Error::InvalidReleaseManifestSignature));
+
+        // The digests cannot be altered once signed.
+        let signed = SignedReleaseManifest::sign(
+            &ReleaseType::AntNode,
+            &Version::new(0, 3, 6),
+            manifest.digests.clone(),
+            &trusted_key,
+        );
+        manifest.signature = signed.signature;
+        let _ = manifest
+            .digests
+            .insert(PLATFORM.to_string(), hex::encode([1; 32]));
+        assert_matches!(
+            manifest.verify(&[trusted_key.public_key()]),
+            Err(Error::InvalidReleaseManifestSignature)
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_manifest_should_fail_verification() {
+        let server = MockServer::start().await;
+        let verifier = verifier(&server, bls::SecretKey::random().public_key());
+        let result = verifier
+            .fetch_manifest(&ReleaseType::AntNode, &Version::new(0, 3, 6))
+            .await;
+        assert_matches!(result, Err(Error::ReleaseManifestNotFound(_)));
+    }
+}
@@ -0,0 +1,368 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::{
+    error::{Error, Result},
+    ServiceManager, VerbosityLevel,
+};
+use ant_service_management::{
+    rpc::RpcActions, NodeService, NodeServiceData, UpgradeOptions, UpgradeResult,
+};
+use semver::Version;
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+/// Settings for upgrading the nodes one at a time, waiting for each upgraded node to recover
+/// before moving on to the next one.
+#[derive(Clone, Debug)]
+pub struct RollingUpgradeOptions {
+    /// How long an upgraded node has to recover before its health check fails.
+    pub health_check_timeout: Duration,
+    /// How often the health of an upgraded node is polled.
+    pub poll_interval: Duration,
+    /// The share of the connected peers and records the node had before the upgrade that it
+    /// must get back.
+    pub recovery_ratio: f64,
+    /// Roll a node that fails its health check back to its previous binary. The upgrade is
+    /// aborted either way.
+    pub rollback: bool,
+}
+
+impl Default for RollingUpgradeOptions {
+    fn default() -> Self {
+        Self {
+            health_check_timeout: Duration::from_secs(300),
+            poll_interval: Duration::from_secs(5),
+            recovery_ratio: 0.5,
+            rollback: true,
+        }
+    }
+}
+
+/// The peer and record counts reported by a node.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NodeHealth {
+    pub connected_peers: usize,
+    pub records: usize,
+}
+
+impl NodeHealth {
+    pub async fn fetch(rpc_actions: &dyn RpcActions) -> Result<Self> {
+        let network_info = rpc_actions.network_info().await?;
+        let records = rpc_actions.record_addresses().await?;
+        Ok(Self {
+            connected_peers: network_info.connected_peers.len(),
+            records: records.len(),
+        })
+    }
+
+    /// A node has recovered once it has at least one connected peer, and its peer and record
+    /// counts are back to the given share of the baseline.
+    pub fn has_recovered(&self, baseline: &NodeHealth, recovery_ratio: f64) -> bool {
+        let required = |count: usize| (count as f64 * recovery_ratio).ceil() as usize;
+        self.connected_peers >= required(baseline.connected_peers).max(1)
+            && self.records >= required(baseline.records)
+    }
+}
+
+/// The state of a node captured before upgrading it, to check that it recovers and to roll it
+/// back if it does not.
+#[derive(Clone, Debug)]
+pub struct PreUpgradeState {
+    pub health: NodeHealth,
+    pub version: String,
+    /// A copy of the binary the node was running before the upgrade.
+    pub backup_bin_path: PathBuf,
+}
+
+impl PreUpgradeState {
+    pub async fn capture(
+        service_data: &NodeServiceData,
+        rpc_actions: &dyn RpcActions,
+    ) -> Result<Self> {
+        let health = NodeHealth::fetch(rpc_actions).await.unwrap_or_else(|err| {
+            warn!(
+                "Could not obtain the health of {} before the upgrade, the baseline will be empty: {err}",
+                service_data.service_name
+            );
+            NodeHealth::default()
+        });
+
+        let mut backup_file_name = service_data
+            .antnode_path
+            .file_name()
+            .unwrap_or_default()
+            .to_os_string();
+        backup_file_name.push(".previous");
+        let backup_bin_path = service_data.antnode_path.with_file_name(backup_file_name);
+        debug!(
+            "Backing up {:?} to {backup_bin_path:?}",
+            service_data.antnode_path
+        );
+        let _ = std::fs::copy(&service_data.antnode_path, &backup_bin_path)?;
+
+        Ok(Self {
+            health,
+            version: service_data.version.clone(),
+            backup_bin_path,
+        })
+    }
+
+    pub fn remove_backup(&self) {
+        if let Err(err) = std::fs::remove_file(&self.backup_bin_path) {
+            warn!(
+                "Failed to remove the backup {:?}: {err}",
+                self.backup_bin_path
+            );
+        }
+    }
+}
+
+/// Wait until the node is connected to the network and its peer and record counts have
+/// recovered.
+pub async fn wait_for_recovery(
+    rpc_actions: &dyn RpcActions,
+    baseline: &NodeHealth,
+    options: &RollingUpgradeOptions,
+) -> Result<NodeHealth> {
+    let start = Instant::now();
+    rpc_actions
+        .is_node_connected_to_network(options.health_check_timeout)
+        .await
+        .map_err(|err| {
+            Error::NodeHealthCheckFailed(format!("the node did not connect to the network: {err}"))
+        })?;
+
+    let mut health = NodeHealth::default();
+    loop {
+        match NodeHealth::fetch(rpc_actions).await {
+            Ok(current) => {
+                if current.has_recovered(baseline, options.recovery_ratio) {
+                    debug!("The node has recovered: {current:?}, baseline: {baseline:?}");
+                    return Ok(current);
+                }
+                trace!("The node has not recovered yet: {current:?}, baseline: {baseline:?}");
+                health = current;
+            }
+            Err(err) => warn!("Could not obtain the health of the node: {err}"),
+        }
+
+        if start.elapsed() >= options.health_check_timeout {
+            return Err(Error::NodeHealthCheckFailed(format!(
+                "{} peers and {} records after {:?}, {} peers and {} records before the upgrade",
+                health.connected_peers,
+                health.records,
+                options.health_check_timeout,
+                baseline.connected_peers,
+                baseline.records
+            )));
+        }
+        tokio::time::sleep(options.poll_interval).await;
+    }
+}
+
+/// Check that an upgraded node recovers, rolling it back to its previous binary if it does not
+/// and rollbacks are enabled.
+///
+/// Any result other than `Upgraded` or `Forced` means the rolling upgrade should be aborted.
+pub async fn ensure_node_recovered(
+    service_manager: &mut ServiceManager<NodeService<'_>>,
+    upgrade_result: UpgradeResult,
+    upgrade_options: &UpgradeOptions,
+    pre_upgrade_state: &PreUpgradeState,
+    options: &RollingUpgradeOptions,
+) -> UpgradeResult {
+    let service_name = service_manager.service.service_data.service_name.clone();
+    let reason = match &upgrade_result {
+        UpgradeResult::Upgraded(..) | UpgradeResult::Forced(..) => {
+            if service_manager.verbosity != VerbosityLevel::Minimal {
+                println!("Waiting for {service_name} to recover...");
+            }
+            match wait_for_recovery(
+                service_manager.service.rpc_actions.as_ref(),
+                &pre_upgrade_state.health,
+                options,
+            )
+            .await
+            {
+                Ok(health) => {
+                    info!("{service_name} has recovered after the upgrade: {health:?}");
+                    return upgrade_result;
+                }
+                Err(err) => err.to_string(),
+            }
+        }
+        UpgradeResult::UpgradedButNotStarted(_, _, err) => err.clone(),
+        UpgradeResult::NotRequired | UpgradeResult::RolledBack(..) | UpgradeResult::Error(_) => {
+            return upgrade_result;
+        }
+    };
+    error!("{service_name} failed its health check after the upgrade: {reason}");
+
+    if !options.rollback {
+        return UpgradeResult::Error(format!("Health check failed: {reason}"));
+    }
+
+    let previous_version = match Version::parse(&pre_upgrade_state.version) {
+        Ok(version) => version,
+        Err(err) => {
+            return UpgradeResult::Error(format!(
+                "Health check failed: {reason}. Could not roll back: {err}"
+            ))
+        }
+    };
+    if service_manager.verbosity != VerbosityLevel::Minimal {
+        println!("Rolling {service_name} back to version {previous_version}...");
+    }
+    let rollback_options = UpgradeOptions {
+        force: true,
+        start_service: true,
+        target_bin_path: pre_upgrade_state.backup_bin_path.clone(),
+        target_version: previous_version,
+        ..upgrade_options.clone()
+    };
+    match service_manager.upgrade(rollback_options).await {
+        Ok(UpgradeResult::Forced(..)) => {
+            info!(
+                "{service_name} has been rolled back to version {}",
+                pre_upgrade_state.version
+            );
+            UpgradeResult::RolledBack(
+                pre_upgrade_state.version.clone(),
+                upgrade_options.target_version.to_string(),
+                reason,
+            )
+        }
+        Ok(result) => UpgradeResult::Error(format!(
+            "Health check failed: {reason}. The rollback did not complete: {result:?}"
+        )),
+        Err(err) => UpgradeResult::Error(format!(
+            "Health check failed: {reason}. Could not roll back: {err}"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ant_service_management::{
+        error::Result as ServiceControlResult,
+        rpc::{NetworkInfo, NodeInfo, RecordAddress, RewardTotal, RpcActions},
+    };
+    use assert_matches::assert_matches;
+    use async_trait::async_trait;
+    use libp2p::{kad::RecordKey, PeerId};
+    use mockall::mock;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    mock! {
+        pub RpcClient {}
+        #[async_trait]
+        impl RpcActions for RpcClient {
+            async fn node_info(&self) -> ServiceControlResult<NodeInfo>;
+            async fn network_info(&self) -> ServiceControlResult<NetworkInfo>;
+            async fn record_addresses(&self) -> ServiceControlResult<Vec<RecordAddress>>;
+            async fn rewards_ledger(&self, windows: Vec<Duration>) -> ServiceControlResult<Vec<RewardTotal>>;
+            async fn node_restart(&self, delay_millis: u64, retain_peer_id: bool) -> ServiceControlResult<()>;
+            async fn node_stop(&self, delay_millis: u64) -> ServiceControlResult<()>;
+            async fn node_update(&self, delay_millis: u64) -> ServiceControlResult<()>;
+            async fn is_node_connected_to_network(&self, timeout: Duration) -> ServiceControlResult<()>;
+            async fn update_log_level(&self, log_levels: String) -> ServiceControlResult<()>;
+        }
+    }
+
+    /// A node that reports one more peer on each call, up to `max_peers`.
+    fn recovering_node(max_peers: usize, records: usize) -> MockRpcClient {
+        let mut mock_rpc_client = MockRpcClient::new();
+        mock_rpc_client
+            .expect_is_node_connected_to_network()
+            .times(1)
+            .returning(|_| Ok(()));
+        let calls = Arc::new(AtomicUsize::new(0));
+        mock_rpc_client.expect_network_info().returning(move || {
+            let calls = calls.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(NetworkInfo {
+                connected_peers: (0..calls.min(max_peers))
+                    .map(|_| PeerId::random())
+                    .collect(),
+                listeners: vec![],
+            })
+        });
+        mock_rpc_client
+            .expect_record_addresses()
+            .returning(move || {
+                Ok((0..records)
+                    .map(|i| RecordAddress {
+                        key: RecordKey::new(&i.to_be_bytes()),
+                    })
+                    .collect())
+            });
+        mock_rpc_client
+    }
+
+    fn options() -> RollingUpgradeOptions {
+        RollingUpgradeOptions {
+            health_check_timeout: Duration::from_millis(200),
+            poll_interval: Duration::from_millis(10),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn wait_for_recovery_should_wait_for_the_peers_to_recover() {
+        let node = recovering_node(10, 100);
+        let baseline = NodeHealth {
+            connected_peers: 8,
+            records: 100,
+        };
+
+        let health = wait_for_recovery(&node, &baseline, &options())
+            .await
+            .unwrap();
+        assert_eq!(
+            health,
+            NodeHealth {
+                connected_peers: 4,
+                records: 100,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn wait_for_recovery_should_fail_if_the_records_do_not_recover() {
+        let node = recovering_node(10, 10);
+        let baseline = NodeHealth {
+            connected_peers: 8,
+            records: 100,
+        };
+
+        let result = wait_for_recovery(&node, &baseline, &options()).await;
+        assert_matches!(result, Err(Error::NodeHealthCheckFailed(_)));
+    }
+
+    #[tokio::test]
+    async fn wait_for_recovery_should_fail_if_the_node_is_not_connected() {
+        let mut node = MockRpcClient::new();
+        node.expect_is_node_connected_to_network()
+            .times(1)
+            .returning(|_| {
+                Err(ant_service_management::Error::RpcConnectionError(
+                    "http://127.0.0.1:12001".to_string(),
+                ))
+            });
+        node.expect_network_info().times(0);
+
+        let result = wait_for_recovery(&node, &NodeHealth::default(), &options()).await;
+        assert_matches!(result, Err(Error::NodeHealthCheckFailed(_)));
+    }
+}
@@ -7,11 +7,22 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::{
-    add_services::config::InstallNodeServiceCtxBuilder, config::create_owned_dir, ServiceManager,
-    VerbosityLevel,
+    add_services::config::{InstallNodeServiceCtxBuilder, PortRange},
+    cmd::{self, node::get_services_for_ops},
+    config::{self, create_owned_dir},
+    get_service_control_for,
+    rolling_upgrade::RollingUpgradeOptions,
+    ServiceManager, VerbosityLevel,
 };
+use ant_bootstrap::PeersArgs;
+use ant_evm::{get_evm_network, EvmNetwork, RewardsAddress};
 use ant_service_management::{
-    control::{ServiceControl, ServiceController},
+    antctl_proto::{
+        node_operation_progress::Stage, AddNodesRequest, MaintainRunningNodesRequest,
+        NodeAddOptions, NodeOperationProgress, NodeSelection, RemoveNodesRequest,
+        StartNodesRequest, StopNodesRequest, UpgradeNodesRequest,
+    },
+    resources::ResourceLimits,
     rpc::RpcClient,
     NodeRegistry, NodeService, NodeServiceData, ServiceStatus,
 };
@@ -20,6 +31,9 @@ use color_eyre::{
     Result,
 };
 use libp2p::PeerId;
+use std::{future::Future, net::Ipv4Addr, path::PathBuf, str::FromStr, time::Duration};
+use tokio::sync::mpsc;
+use tonic::Status;
 
 pub async fn restart_node_service(
     node_registry: &mut NodeRegistry,
@@ -41,19 +55,19 @@ pub async fn restart_node_service(
     let service = NodeService::new(current_node_mut, Box::new(rpc_client));
     let mut service_manager = ServiceManager::new(
         service,
-        Box::new(ServiceController {}),
+        get_service_control_for(current_node_clone.supervisor_backend)?,
         VerbosityLevel::Normal,
     );
     service_manager.stop().await?;
 
-    let service_control = ServiceController {};
     if retain_peer_id {
         debug!(
             "Retaining the peer id: {peer_id:?} for the node: {:?}",
             current_node_clone.service_name
         );
         // reuse the same port and root dir to retain peer id.
-        service_control
+        service_manager
+            .service_control
             .uninstall(&current_node_clone.service_name, false)
             .map_err(|err| {
                 eyre!(
@@ -81,18 +95,30 @@ pub async fn restart_node_service(
             rewards_address: current_node_clone.rewards_address,
             rpc_socket_addr: current_node_clone.rpc_socket_addr,
             service_user: current_node_clone.user.clone(),
+            resource_limits: current_node_clone.resource_limits.clone(),
             upnp: current_node_clone.upnp,
         }
         .build()?;
-        service_control.install(install_ctx, false).map_err(|err| {
-            eyre!(
-                "Error while installing node {:?} with: {err:?}",
-                current_node_clone.service_name
-            )
-        })?;
+        service_manager
+            .service_control
+            .install(install_ctx, false)
+            .map_err(|err| {
+                eyre!(
+                    "Error while installing node {:?} with: {err:?}",
+                    current_node_clone.service_name
+                )
+            })?;
+        if current_node_clone.resource_limits.has_process_limits() {
+            service_manager.service_control.set_resource_limits(
+                &current_node_clone.service_name,
+                &current_node_clone.resource_limits,
+                false,
+            )?;
+        }
         service_manager.start().await?;
     } else {
         debug!("Starting a new node since retain peer id is false.");
+        let service_control = service_manager.service_control;
         let new_node_number = nodes_len + 1;
         let new_service_name = format!("antnode{new_node_number}");
 
@@ -197,12 +223,20 @@ pub async fn restart_node_service(
             rpc_socket_addr: current_node_clone.rpc_socket_addr,
             antnode_path: antnode_path.clone(),
             service_user: current_node_clone.user.clone(),
+            resource_limits: current_node_clone.resource_limits.clone(),
             upnp: current_node_clone.upnp,
         }
         .build()?;
         service_control.install(install_ctx, false).map_err(|err| {
             eyre!("Error while installing node {new_service_name:?} with: {err:?}",)
         })?;
+        if current_node_clone.resource_limits.has_process_limits() {
+            service_control.set_resource_limits(
+                &new_service_name,
+                &current_node_clone.resource_limits,
+                false,
+            )?;
+        }
 
         let mut node = NodeServiceData {
             antnode_path,
@@ -224,11 +258,13 @@ pub async fn restart_node_service(
             peer_id: None,
             peers_args: current_node_clone.peers_args.clone(),
             pid: None,
+            resource_limits: current_node_clone.resource_limits.clone(),
             rewards_address: current_node_clone.rewards_address,
             reward_balance: current_node_clone.reward_balance,
             rpc_socket_addr: current_node_clone.rpc_socket_addr,
             service_name: new_service_name.clone(),
             status: ServiceStatus::Added,
+            supervisor_backend: current_node_clone.supervisor_backend,
             upnp: current_node_clone.upnp,
             user: current_node_clone.user.clone(),
             user_mode: false,
@@ -237,11 +273,8 @@ pub async fn restart_node_service(
 
         let rpc_client = RpcClient::from_socket_addr(node.rpc_socket_addr);
         let service = NodeService::new(&mut node, Box::new(rpc_client));
-        let mut service_manager = ServiceManager::new(
-            service,
-            Box::new(ServiceController {}),
-            VerbosityLevel::Normal,
-        );
+        let mut service_manager =
+            ServiceManager::new(service, service_control, VerbosityLevel::Normal);
         service_manager.start().await?;
         node_registry
             .nodes
@@ -250,3 +283,635 @@ pub async fn restart_node_service(
 
     Ok(())
 }
+
+pub type ProgressSender = mpsc::Sender<Result<NodeOperationProgress, Status>>;
+
+/// The arguments for adding nodes, parsed from the RPC representation.
+struct AddOptions {
+    auto_restart: bool,
+    auto_set_nat_flags: bool,
+    data_dir_path: Option<PathBuf>,
+    enable_metrics_server: bool,
+    env_variables: Option<Vec<(String, String)>>,
+    evm_network: Option<EvmNetwork>,
+    home_network: bool,
+    log_dir_path: Option<PathBuf>,
+    max_archived_log_files: Option<usize>,
+    max_log_files: Option<usize>,
+    metrics_port: Option<PortRange>,
+    network_id: Option<u8>,
+    node_ip: Option<Ipv4Addr>,
+    node_port: Option<PortRange>,
+    peers_args: PeersArgs,
+    resource_limits: ResourceLimits,
+    rewards_address: RewardsAddress,
+    rpc_address: Option<Ipv4Addr>,
+    rpc_port: Option<PortRange>,
+    src_path: Option<PathBuf>,
+    upnp: bool,
+    url: Option<String>,
+    user: Option<String>,
+    version: Option<String>,
+}
+
+impl TryFrom<NodeAddOptions> for AddOptions {
+    type Error = color_eyre::eyre::Error;
+
+    fn try_from(options: NodeAddOptions) -> Result<Self> {
+        let evm_network = match options.evm_network.as_str() {
+            "" => None,
+            "arbitrum-one" => Some(EvmNetwork::ArbitrumOne),
+            "arbitrum-sepolia" => Some(EvmNetwork::ArbitrumSepolia),
+            "arbitrum-sepolia-test" => Some(EvmNetwork::ArbitrumSepoliaTest),
+            "local" => Some(get_evm_network(true)?),
+            "custom" => {
+                let custom = options
+                    .custom_evm_network
+                    .ok_or_eyre("The custom EVM network details must be provided")?;
+                // `new_custom` panics on invalid addresses, so validate them first.
+                let _ = RewardsAddress::from_str(&custom.payment_token_address)?;
+                let _ = RewardsAddress::from_str(&custom.data_payments_address)?;
+                Some(EvmNetwork::new_custom(
+                    &custom.rpc_url,
+                    &custom.payment_token_address,
+                    &custom.data_payments_address,
+                ))
+            }
+            other => return Err(eyre!("Unknown EVM network '{other}'")),
+        };
+        let env_variables = if options.env_variables.is_empty() {
+            None
+        } else {
+            Some(options.env_variables.into_iter().collect())
+        };
+        let network_id = options
+            .network_id
+            .map(u8::try_from)
+            .transpose()
+            .map_err(|_| eyre!("The network ID must be between 0 and 255"))?;
+
+        Ok(AddOptions {
+            auto_restart: options.auto_restart,
+            auto_set_nat_flags: options.auto_set_nat_flags,
+            data_dir_path: options.data_dir_path.map(PathBuf::from),
+            enable_metrics_server: options.enable_metrics_server,
+            env_variables,
+            evm_network,
+            home_network: options.home_network,
+            log_dir_path: options.log_dir_path.map(PathBuf::from),
+            max_archived_log_files: options.max_archived_log_files.map(|n| n as usize),
+            max_log_files: options.max_log_files.map(|n| n as usize),
+            metrics_port: options
+                .metrics_port
+                .map(|p| PortRange::parse(&p))
+                .transpose()?,
+            network_id,
+            node_ip: options.node_ip.map(|ip| ip.parse()).transpose()?,
+            node_port: options
+                .node_port
+                .map(|p| PortRange::parse(&p))
+                .transpose()?,
+            peers_args: PeersArgs {
+                first: options.first,
+                addrs: options
+                    .peers
+                    .iter()
+                    .map(|addr| addr.parse())
+                    .collect::<std::result::Result<_, _>>()?,
+                network_contacts_url: options.network_contacts_url,
+                local: options.local,
+                disable_mainnet_contacts: options.disable_mainnet_contacts,
+                ignore_cache: options.ignore_cache,
+                ..Default::default()
+            },
+            resource_limits: ResourceLimits {
+                cpu_quota: options.cpu_quota,
+                max_memory: options.max_memory,
+                max_open_files: options.max_open_files,
+                storage_quota: options.storage_quota,
+                max_client_bandwidth: options.max_client_bandwidth,
+                max_replication_bandwidth: options.max_replication_bandwidth,
+            },
+            rewards_address: RewardsAddress::from_str(&options.rewards_address)?,
+            rpc_address: options.rpc_address.map(|ip| ip.parse()).transpose()?,
+            rpc_port: options.rpc_port.map(|p| PortRange::parse(&p)).transpose()?,
+            src_path: options.bin_path.map(PathBuf::from),
+            upnp: options.upnp,
+            url: options.url,
+            user: options.user,
+            version: options.version,
+        })
+    }
+}
+
+/// Add nodes on behalf of an RPC client, returning the names of the new services.
+pub async fn add_nodes(request: AddNodesRequest) -> Result<Vec<String>> {
+    let options = AddOptions::try_from(request.options.unwrap_or_default())?;
+    let count = u16::try_from(request.count).map_err(|_| eyre!("The count is too large"))?;
+    cmd::node::add(
+        options.auto_restart,
+        options.auto_set_nat_flags,
+        Some(count),
+        options.data_dir_path,
+        options.enable_metrics_server,
+        options.env_variables,
+        options.evm_network,
+        options.home_network,
+        options.log_dir_path,
+        None,
+        options.max_archived_log_files,
+        options.max_log_files,
+        options.metrics_port,
+        options.network_id,
+        options.node_ip,
+        options.node_port,
+        options.peers_args,
+        options.resource_limits,
+        options.rewards_address,
+        options.rpc_address,
+        options.rpc_port,
+        options.src_path,
+        options.upnp,
+        options.url,
+        options.user,
+        options.version,
+        VerbosityLevel::Minimal,
+    )
+    .await
+}
+
+pub async fn start_nodes(request: StartNodesRequest, progress: &ProgressSender) -> Result<()> {
+    let service_names = resolve_selection(request.selection)?;
+    for_each_node(
+        service_names,
+        request.interval_millis,
+        OnFailure::Continue,
+        progress,
+        |name| {
+            cmd::node::start(
+                request.connection_timeout_s,
+                None,
+                vec![],
+                vec![name],
+                VerbosityLevel::Minimal,
+            )
+        },
+    )
+    .await
+}
+
+pub async fn stop_nodes(request: StopNodesRequest, progress: &ProgressSender) -> Result<()> {
+    let service_names = resolve_selection(request.selection)?;
+    for_each_node(
+        service_names,
+        request.interval_millis,
+        OnFailure::Continue,
+        progress,
+        |name| cmd::node::stop(None, vec![], vec![name], VerbosityLevel::Minimal),
+    )
+    .await
+}
+
+pub async fn remove_nodes(request: RemoveNodesRequest, progress: &ProgressSender) -> Result<()> {
+    let service_names = resolve_selection(request.selection)?;
+    for_each_node(service_names, None, OnFailure::Continue, progress, |name| {
+        cmd::node::remove(
+            request.keep_directories,
+            vec![],
+            vec![name],
+            VerbosityLevel::Minimal,
+        )
+    })
+    .await
+}
+
+/// Upgrade the selected nodes one at a time.
+///
+/// The release archive is downloaded for the first node and the cached copy is used for the rest.
+pub async fn upgrade_nodes(request: UpgradeNodesRequest, progress: &ProgressSender) -> Result<()> {
+    let env_variables = if request.env_variables.is_empty() {
+        None
+    } else {
+        Some(request.env_variables.into_iter().collect::<Vec<_>>())
+    };
+    let rolling = match request.rolling {
+        Some(rolling) => {
+            if request.interval_millis.is_some() || request.do_not_start {
+                return Err(eyre!(
+                    "A rolling upgrade cannot be used with an interval or without starting the nodes"
+                ));
+            }
+            if !(0.0..=1.0).contains(&rolling.recovery_ratio) {
+                return Err(eyre!("The recovery ratio must be between 0 and 1"));
+            }
+            let defaults = RollingUpgradeOptions::default();
+            Some(RollingUpgradeOptions {
+                health_check_timeout: match rolling.health_check_timeout_s {
+                    0 => defaults.health_check_timeout,
+                    secs => Duration::from_secs(secs),
+                },
+                recovery_ratio: rolling.recovery_ratio,
+                rollback: !rolling.no_rollback,
+                ..defaults
+            })
+        }
+        None => None,
+    };
+    let service_names = resolve_selection(request.selection)?;
+    // A rolling upgrade stops at the first node that fails to upgrade or to recover.
+    let on_failure = if rolling.is_some() {
+        OnFailure::Abort
+    } else {
+        OnFailure::Continue
+    };
+    for_each_node(
+        service_names,
+        request.interval_millis,
+        on_failure,
+        progress,
+        |name| {
+            cmd::node::upgrade(
+                request.connection_timeout_s,
+                request.do_not_start,
+                None,
+                request.force,
+                None,
+                vec![],
+                env_variables.clone(),
+                rolling.clone(),
+                vec![name],
+                request.url.clone(),
+                request.version.clone(),
+                VerbosityLevel::Minimal,
+            )
+        },
+    )
+    .await
+}
+
+/// Stop and remove every node, then delete the node registry.
+pub async fn reset(progress: &ProgressSender) -> Result<()> {
+    let service_names = resolve_selection(None)?;
+    for_each_node(
+        service_names,
+        None,
+        OnFailure::Continue,
+        progress,
+        |name| async move {
+            cmd::node::stop(None, vec![], vec![name.clone()], VerbosityLevel::Minimal).await?;
+            cmd::node::remove(false, vec![], vec![name], VerbosityLevel::Minimal).await
+        },
+    )
+    .await?;
+    cmd::node::reset(true, VerbosityLevel::Minimal).await
+}
+
+/// Run `maintain_n_running_nodes`, then report each node whose status it changed.
+pub async fn maintain_running_nodes(
+    request: MaintainRunningNodesRequest,
+    progress: &ProgressSender,
+) -> Result<()> {
+    let options = AddOptions::try_from(request.options.unwrap_or_default())?;
+    let max_nodes_to_run = u16::try_from(request.max_nodes_to_run)
+        .map_err(|_| eyre!("The number of nodes to run is too large"))?;
+    let statuses_before = node_statuses()?;
+
+    let result = cmd::node::maintain_n_running_nodes(
+        options.auto_restart,
+        options.auto_set_nat_flags,
+        request.connection_timeout_s,
+        max_nodes_to_run,
+        options.data_dir_path,
+        options.enable_metrics_server,
+        options.env_variables,
+        options.evm_network,
+        options.home_network,
+        options.log_dir_path,
+        None,
+        options.max_archived_log_files,
+        options.max_log_files,
+        options.metrics_port,
+        options.network_id,
+        options.node_ip,
+        options.node_port,
+        options.peers_args,
+        options.resource_limits,
+        options.rewards_address,
+        options.rpc_address,
+        options.rpc_port,
+        options.src_path,
+        options.url,
+        options.upnp,
+        options.user,
+        options.version,
+        VerbosityLevel::Minimal,
+        request.interval_millis,
+    )
+    .await;
+
+    for (name, status) in node_statuses()? {
+        let previous = statuses_before
+            .iter()
+            .find(|(previous_name, _)| *previous_name == name)
+            .map(|(_, status)| status.clone());
+        if previous.as_ref() == Some(&status) {
+            continue;
+        }
+        let message = match previous {
+            Some(previous) => format!("{previous:?} -> {status:?}"),
+            None => format!("Added -> {status:?}"),
+        };
+        send_progress(progress, &name, Stage::Completed, message).await;
+    }
+    result
+}
+
+/// Convert the selection into service names, in the same way as the CLI commands.
+fn resolve_selection(selection: Option<NodeSelection>) -> Result<Vec<String>> {
+    let selection = selection.unwrap_or_default();
+    let peer_ids = selection
+        .peer_ids
+        .iter()
+        .map(|bytes| PeerId::from_bytes(bytes).map(|peer_id| peer_id.to_string()))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
+    let indices = get_services_for_ops(&node_registry, peer_ids, selection.service_names)?;
+    Ok(indices
+        .into_iter()
+        .map(|index| node_registry.nodes[index].service_name.clone())
+        .collect())
+}
+
+fn node_statuses() -> Result<Vec<(String, ServiceStatus)>> {
+    let node_registry = NodeRegistry::load(&config::get_node_registry_path()?)?;
+    Ok(node_registry
+        .nodes
+        .into_iter()
+        .map(|node| (node.service_name, node.status))
+        .collect())
+}
+
+/// What to do with the remaining services once the operation has failed for one of them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum OnFailure {
+    /// Apply the operation to the remaining services anyway.
+    Continue,
+    /// Report the remaining services as failed without applying the operation to them.
+    Abort,
+}
+
+/// Apply the operation to each service in turn, reporting when each one starts and finishes.
+async fn for_each_node<F, Fut>(
+    service_names: Vec<String>,
+    interval_millis: Option<u64>,
+    on_failure: OnFailure,
+    progress: &ProgressSender,
+    operation: F,
+) -> Result<()>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let mut failed = Vec::new();
+    for (i, name) in service_names.into_iter().enumerate() {
+        if !failed.is_empty() && on_failure == OnFailure::Abort {
+            send_progress(
+                progress,
+                &name,
+                Stage::Failed,
+                "The operation was aborted after an earlier failure".to_string(),
+            )
+            .await;
+            failed.push(name);
+            continue;
+        }
+        if i > 0 {
+            if let Some(interval) = interval_millis {
+                tokio::time::sleep(Duration::from_millis(interval)).await;
+            }
+        }
+        send_progress(progress, &name, Stage::Started, String::new()).await;
+        match operation(name.clone()).await {
+            Ok(()) => send_progress(progress, &name, Stage::Completed, String::new()).await,
+            Err(err) => {
+                error!("RPC operation failed for {name}: {err:?}");
+                send_progress(progress, &name, Stage::Failed, err.to_string()).await;
+                failed.push(name);
+            }
+        }
+    }
+    if !failed.is_empty() {
+        return Err(eyre!("The operation failed for: {}", failed.join(", ")));
+    }
+    Ok(())
+}
+
+async fn send_progress(progress: &ProgressSender, name: &str, stage: Stage, message: String) {
+    let update = NodeOperationProgress {
+        service_name: name.to_string(),
+        stage: stage as i32,
+        message,
+    };
+    if progress.send(Ok(update)).await.is_err() {
+        // The client has gone away, but the operation is still allowed to run to completion.
+        debug!("Could not send progress for {name}; the client disconnected");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ant_service_management::antctl_proto::RollingUpgrade;
+
+    fn invalid_selection() -> Option<NodeSelection> {
+        Some(NodeSelection {
+            peer_ids: vec![vec![0xff, 0x00, 0x01]],
+            service_names: vec![],
+        })
+    }
+
+    async fn drain(
+        mut receiver: mpsc::Receiver<Result<NodeOperationProgress, Status>>,
+    ) -> Vec<(String, Stage)> {
+        let mut updates = Vec::new();
+        while let Some(update) = receiver.recv().await {
+            let update = update.unwrap();
+            updates.push((update.service_name.clone(), update.stage()));
+        }
+        updates
+    }
+
+    #[tokio::test]
+    async fn for_each_node_should_report_every_node_and_continue_after_a_failure() {
+        let (sender, receiver) = mpsc::channel(16);
+        let result = for_each_node(
+            vec!["antnode1".to_string(), "antnode2".to_string()],
+            None,
+            OnFailure::Continue,
+            &sender,
+            |name| async move {
+                if name == "antnode1" {
+                    Err(eyre!("boom"))
+                } else {
+                    Ok(())
+                }
+            },
+        )
+        .await;
+        drop(sender);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The operation failed for: antnode1"
+        );
+        assert_eq!(
+            drain(receiver).await,
+            vec![
+                ("antnode1".to_string(), Stage::Started),
+                ("antnode1".to_string(), Stage::Failed),
+                ("antnode2".to_string(), Stage::Started),
+                ("antnode2".to_string(), Stage::Completed),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn for_each_node_should_report_the_remaining_nodes_as_failed_after_an_abort() {
+        let (sender, receiver) = mpsc::channel(16);
+        let result = for_each_node(
+            vec![
+                "antnode1".to_string(),
+                "antnode2".to_string(),
+                "antnode3".to_string(),
+            ],
+            None,
+            OnFailure::Abort,
+            &sender,
+            |name| async move {
+                if name == "antnode2" {
+                    Err(eyre!("boom"))
+                } else {
+                    Ok(())
+                }
+            },
+        )
+        .await;
+        drop(sender);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The operation failed for: antnode2, antnode3"
+        );
+        assert_eq!(
+            drain(receiver).await,
+            vec![
+                ("antnode1".to_string(), Stage::Started),
+                ("antnode1".to_string(), Stage::Completed),
+                ("antnode2".to_string(), Stage::Started),
+                ("antnode2".to_string(), Stage::Failed),
+                ("antnode3".to_string(), Stage::Failed),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn lifecycle_operations_should_reject_an_invalid_peer_id() {
+        let (sender, _receiver) = mpsc::channel(16);
+        assert!(start_nodes(
+            StartNodesRequest {
+                selection: invalid_selection(),
+                ..Default::default()
+            },
+            &sender,
+        )
+        .await
+        .is_err());
+        assert!(stop_nodes(
+            StopNodesRequest {
+                selection: invalid_selection(),
+                ..Default::default()
+            },
+            &sender,
+        )
+        .await
+        .is_err());
+        assert!(remove_nodes(
+            RemoveNodesRequest {
+                selection: invalid_selection(),
+                ..Default::default()
+            },
+            &sender,
+        )
+        .await
+        .is_err());
+        assert!(upgrade_nodes(
+            UpgradeNodesRequest {
+                selection: invalid_selection(),
+                ..Default::default()
+            },
+            &sender,
+        )
+        .await
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn upgrade_nodes_should_reject_invalid_rolling_options() {
+        let (sender, _receiver) = mpsc::channel(16);
+        let result = upgrade_nodes(
+            UpgradeNodesRequest {
+                interval_millis: Some(1000),
+                rolling: Some(RollingUpgrade::default()),
+                ..Default::default()
+            },
+            &sender,
+        )
+        .await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "A rolling upgrade cannot be used with an interval or without starting the nodes"
+        );
+
+        let result = upgrade_nodes(
+            UpgradeNodesRequest {
+                rolling: Some(RollingUpgrade {
+                    recovery_ratio: 1.5,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            &sender,
+        )
+        .await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The recovery ratio must be between 0 and 1"
+        );
+    }
+
+    #[tokio::test]
+    async fn add_nodes_should_reject_invalid_options() {
+        let result = add_nodes(AddNodesRequest {
+            count: 1,
+            options: Some(NodeAddOptions {
+                rewards_address: "not-an-address".to_string(),
+                ..Default::default()
+            }),
+        })
+        .await;
+        assert!(result.is_err());
+
+        let result = add_nodes(AddNodesRequest {
+            count: 1,
+            options: Some(NodeAddOptions {
+                evm_network: "unknown".to_string(),
+                ..Default::default()
+            }),
+        })
+        .await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Unknown EVM network 'unknown'"
+        );
+    }
+}
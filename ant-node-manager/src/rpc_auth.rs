@@ -0,0 +1,175 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Token based authentication and TLS for the `antctld` RPC interface.
+//!
+//! When the daemon is given a token, every request must carry it as a bearer token in the
+//! `authorization` metadata. This allows a fleet controller to manage the nodes on a machine
+//! remotely without anyone else who can reach the port being able to do the same.
+//!
+//! The token is only secret if the connection is, so the daemon refuses to use a token on a
+//! non-loopback address unless it is also given a certificate to serve the interface over TLS.
+
+use color_eyre::{eyre::eyre, Result};
+use std::{path::Path, sync::Arc};
+use tonic::{
+    metadata::MetadataValue,
+    service::Interceptor,
+    transport::{Certificate, ClientTlsConfig, Identity, ServerTlsConfig},
+    Request, Status,
+};
+
+/// The environment variable used to provide the token to both the daemon and its clients.
+pub const AUTH_TOKEN_ENV_VAR: &str = "ANTCTLD_AUTH_TOKEN";
+/// The environment variable used to provide the daemon with the path of its PEM encoded TLS
+/// certificate.
+pub const TLS_CERT_ENV_VAR: &str = "ANTCTLD_TLS_CERT";
+/// The environment variable used to provide the daemon with the path of the PEM encoded private
+/// key for its TLS certificate.
+pub const TLS_KEY_ENV_VAR: &str = "ANTCTLD_TLS_KEY";
+const AUTHORIZATION_METADATA_KEY: &str = "authorization";
+const BEARER_PREFIX: &str = "Bearer ";
+
+/// A server-side interceptor that rejects requests without the expected token.
+///
+/// If no token is configured, all requests are accepted.
+#[derive(Clone, Default)]
+pub struct AuthTokenValidator {
+    token: Option<Arc<String>>,
+}
+
+impl AuthTokenValidator {
+    pub fn new(token: Option<String>) -> Self {
+        AuthTokenValidator {
+            token: token.map(Arc::new),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.token.is_some()
+    }
+}
+
+impl Interceptor for AuthTokenValidator {
+    fn call(&mut self, request: Request<()>) -> std::result::Result<Request<()>, Status> {
+        let Some(expected) = &self.token else {
+            return Ok(request);
+        };
+        let provided = request
+            .metadata()
+            .get(AUTHORIZATION_METADATA_KEY)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix(BEARER_PREFIX));
+        match provided {
+            Some(provided) if constant_time_eq(provided.as_bytes(), expected.as_bytes()) => {
+                Ok(request)
+            }
+            Some(_) => {
+                warn!("Rejected an RPC request with an invalid auth token");
+                Err(Status::unauthenticated("Invalid auth token"))
+            }
+            None => {
+                warn!("Rejected an RPC request without an auth token");
+                Err(Status::unauthenticated("Missing auth token"))
+            }
+        }
+    }
+}
+
+/// A client-side interceptor that attaches the token to every request.
+#[derive(Clone, Default)]
+pub struct AuthTokenInjector {
+    header: Option<MetadataValue<tonic::metadata::Ascii>>,
+}
+
+impl AuthTokenInjector {
+    pub fn new(token: Option<String>) -> Result<Self> {
+        let header = token
+            .map(|token| {
+                format!("{BEARER_PREFIX}{token}")
+                    .parse()
+                    .map_err(|_| eyre!("The auth token must only contain visible ASCII characters"))
+            })
+            .transpose()?;
+        Ok(AuthTokenInjector { header })
+    }
+}
+
+impl Interceptor for AuthTokenInjector {
+    fn call(&mut self, mut request: Request<()>) -> std::result::Result<Request<()>, Status> {
+        if let Some(header) = &self.header {
+            let _ = request
+                .metadata_mut()
+                .insert(AUTHORIZATION_METADATA_KEY, header.clone());
+        }
+        Ok(request)
+    }
+}
+
+/// Load the PEM encoded certificate and private key the daemon serves the RPC interface with.
+pub fn server_tls_config(cert_path: &Path, key_path: &Path) -> Result<ServerTlsConfig> {
+    let cert = std::fs::read(cert_path)
+        .map_err(|err| eyre!("Could not read the TLS certificate at {cert_path:?}: {err}"))?;
+    let key = std::fs::read(key_path)
+        .map_err(|err| eyre!("Could not read the TLS private key at {key_path:?}: {err}"))?;
+    Ok(ServerTlsConfig::new().identity(Identity::from_pem(cert, key)))
+}
+
+/// Trust the PEM encoded CA certificate to verify the certificate of the daemon, which must be
+/// issued for the domain name.
+pub fn client_tls_config(ca_cert_path: &Path, domain_name: &str) -> Result<ClientTlsConfig> {
+    let ca_cert = std::fs::read(ca_cert_path)
+        .map_err(|err| eyre!("Could not read the TLS CA certificate at {ca_cert_path:?}: {err}"))?;
+    Ok(ClientTlsConfig::new()
+        .ca_certificate(Certificate::from_pem(ca_cert))
+        .domain_name(domain_name))
+}
+
+/// Compare the tokens without exiting early, so the comparison time doesn't reveal how much of a
+/// guessed token was correct.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::Code;
+
+    #[test]
+    fn validator_should_accept_requests_with_the_injected_token() {
+        let mut injector = AuthTokenInjector::new(Some("secret".to_string())).unwrap();
+        let mut validator = AuthTokenValidator::new(Some("secret".to_string()));
+
+        let request = injector.call(Request::new(())).unwrap();
+        assert!(validator.call(request).is_ok());
+    }
+
+    #[test]
+    fn validator_should_reject_missing_or_invalid_tokens() {
+        let mut validator = AuthTokenValidator::new(Some("secret".to_string()));
+
+        let err = validator.call(Request::new(())).unwrap_err();
+        assert_eq!(err.code(), Code::Unauthenticated);
+
+        let mut injector = AuthTokenInjector::new(Some("guess".to_string())).unwrap();
+        let request = injector.call(Request::new(())).unwrap();
+        let err = validator.call(request).unwrap_err();
+        assert_eq!(err.code(), Code::Unauthenticated);
+    }
+
+    #[test]
+    fn validator_without_a_token_should_accept_all_requests() {
+        let mut validator = AuthTokenValidator::new(None);
+        assert!(!validator.is_enabled());
+        assert!(validator.call(Request::new(())).is_ok());
+    }
+}
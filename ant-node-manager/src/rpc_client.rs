@@ -1,3 +1,4 @@
+use crate::rpc_auth::AuthTokenInjector;
 use ant_service_management::antctl_proto::ant_ctl_client::AntCtlClient;
 use ant_service_management::antctl_proto::NodeServiceRestartRequest;
 use color_eyre::eyre::bail;
@@ -6,24 +7,31 @@ use libp2p_identity::PeerId;
 use std::net::SocketAddr;
 use std::str::FromStr;
 use std::time::Duration;
-use tonic::transport::Channel;
+use tonic::codegen::InterceptedService;
+use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
 use tonic::Request;
 
+/// A client for the `antctld` RPC interface that attaches the auth token to each request.
+pub type AuthenticatedAntCtlClient = AntCtlClient<InterceptedService<Channel, AuthTokenInjector>>;
+
 struct DaemonRpcClient {
     addr: SocketAddr,
-    rpc: AntCtlClient<Channel>,
+    rpc: AuthenticatedAntCtlClient,
 }
 
 pub async fn restart_node(
     peer_ids: Vec<String>,
     rpc_server_address: SocketAddr,
     retain_peer_id: bool,
+    auth_token: Option<String>,
+    tls: Option<ClientTlsConfig>,
 ) -> Result<()> {
     for peer_id in peer_ids {
         debug!("Sending NodeServiceRestartRequest to {peer_id:?} at {rpc_server_address:?}");
         let str_bytes = PeerId::from_str(&peer_id)?.to_bytes();
 
-        let mut daemon_client = get_rpc_client(rpc_server_address).await?;
+        let mut daemon_client =
+            get_rpc_client(rpc_server_address, auth_token.clone(), tls.clone()).await?;
 
         let _response = daemon_client
             .rpc
@@ -44,14 +52,36 @@ pub async fn restart_node(
     Ok(())
 }
 
-async fn get_rpc_client(socket_addr: SocketAddr) -> Result<DaemonRpcClient> {
-    let endpoint = format!("https://{socket_addr}");
+/// Connect to the daemon, retrying for a short time if it isn't available yet.
+///
+/// The token is required if the daemon was started with one. The TLS config is required if the
+/// daemon serves the RPC interface over TLS, see [`crate::rpc_auth::client_tls_config`].
+pub async fn connect(
+    socket_addr: SocketAddr,
+    auth_token: Option<String>,
+    tls: Option<ClientTlsConfig>,
+) -> Result<AuthenticatedAntCtlClient> {
+    Ok(get_rpc_client(socket_addr, auth_token, tls).await?.rpc)
+}
+
+async fn get_rpc_client(
+    socket_addr: SocketAddr,
+    auth_token: Option<String>,
+    tls: Option<ClientTlsConfig>,
+) -> Result<DaemonRpcClient> {
+    let injector = AuthTokenInjector::new(auth_token)?;
+    let scheme = if tls.is_some() { "https" } else { "http" };
+    let endpoint = format!("{scheme}://{socket_addr}");
     let mut attempts = 0;
     loop {
-        if let Ok(rpc_client) = AntCtlClient::connect(endpoint.clone()).await {
+        let mut builder = Endpoint::new(endpoint.clone())?;
+        if let Some(tls) = &tls {
+            builder = builder.tls_config(tls.clone())?;
+        }
+        if let Ok(channel) = builder.connect().await {
             let rpc_client = DaemonRpcClient {
                 addr: socket_addr,
-                rpc: rpc_client,
+                rpc: AntCtlClient::with_interceptor(channel, injector),
             };
             return Ok(rpc_client);
         }
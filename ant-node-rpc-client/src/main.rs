@@ -52,6 +52,10 @@ enum Cmd {
     /// Note this blocks the app and it will print events as they are broadcasted by the node
     #[clap(name = "events")]
     Events,
+    /// Retrieve the totals of the rewards received by the node over the last hour, day, week
+    /// and 30 days, as recorded in its rewards ledger
+    #[clap(name = "rewards")]
+    Rewards,
     /// Restart the node after the specified delay
     #[clap(name = "restart")]
     Restart {
@@ -127,6 +131,7 @@ async fn main() -> Result<()> {
         Cmd::Info => node_info(addr).await,
         Cmd::Netinfo => network_info(addr).await,
         Cmd::Events => node_events(addr).await,
+        Cmd::Rewards => rewards(addr).await,
         Cmd::Restart {
             delay_millis,
             retain_peer_id,
@@ -197,6 +202,37 @@ pub async fn node_events(addr: SocketAddr) -> Result<()> {
     Ok(())
 }
 
+pub async fn rewards(addr: SocketAddr) -> Result<()> {
+    let endpoint = format!("https://{addr}");
+    let client = RpcClient::new(&endpoint);
+    let windows = [
+        ("Last hour", Duration::from_secs(3600)),
+        ("Last day", Duration::from_secs(24 * 3600)),
+        ("Last week", Duration::from_secs(7 * 24 * 3600)),
+        ("Last 30 days", Duration::from_secs(30 * 24 * 3600)),
+    ];
+    let totals = client
+        .rewards_ledger(windows.iter().map(|(_, window)| *window).collect())
+        .await?;
+
+    println!("Rewards received:");
+    println!("=================");
+    for total in totals.iter() {
+        let label = windows
+            .iter()
+            .find(|(_, window)| Some(*window) == total.window)
+            .map(|(label, _)| *label)
+            .unwrap_or("All time");
+        println!(
+            "{label}: {} atto tokens over {} payments",
+            total.amount.as_atto(),
+            total.payments
+        );
+    }
+
+    Ok(())
+}
+
 pub async fn record_addresses(addr: SocketAddr) -> Result<()> {
     let endpoint = format!("https://{addr}");
     let client = RpcClient::new(&endpoint);
@@ -17,13 +17,16 @@ use ant_bootstrap::{BootstrapCacheStore, PeersArgs};
 use ant_evm::{get_evm_network, EvmNetwork, RewardsAddress};
 use ant_logging::metrics::init_metrics;
 use ant_logging::{Level, LogFormat, LogOutputDest, ReloadHandle};
+use ant_networking::TransportConfig;
 use ant_node::utils::get_root_dir_and_keypair;
-use ant_node::{Marker, NodeBuilder, NodeEvent, NodeEventsReceiver};
+use ant_node::{Marker, NodeBuilder, NodeConfig, NodeEvent, NodeEventsReceiver};
 use ant_protocol::{
     node::get_antnode_root_dir,
     node_rpc::{NodeCtrl, StopResult},
     version,
 };
+#[cfg(unix)]
+use ant_service_management::resources::{apply_rlimits, ResourceLimits};
 use clap::{command, Parser};
 use color_eyre::{eyre::eyre, Result};
 use const_hex::traits::FromHex;
@@ -69,6 +72,13 @@ pub fn parse_log_output(val: &str) -> Result<LogOutputDestArg> {
     }
 }
 
+#[cfg(feature = "pnet")]
+fn parse_pre_shared_key_file(val: &str) -> Result<ant_networking::PreSharedKey> {
+    Ok(ant_networking::read_pre_shared_key(std::path::Path::new(
+        val,
+    ))?)
+}
+
 // Please do not remove the blank lines in these doc comments.
 // They are used for inserting line breaks when the help menu is rendered in the UI.
 #[derive(Parser, Debug)]
@@ -86,6 +96,30 @@ struct Opt {
     #[clap(long, default_value_t = false)]
     upnp: bool,
 
+    #[cfg(feature = "tcp")]
+    /// Also listen on the TCP transport, using the given port.
+    ///
+    /// QUIC remains the preferred transport; TCP is used by peers that are unable to reach the
+    /// node over QUIC, e.g. because UDP is blocked on their network.
+    #[clap(long)]
+    tcp_port: Option<u16>,
+
+    #[cfg(feature = "websocket")]
+    /// Also listen on the WebSocket transport, using the given port.
+    ///
+    /// This allows peers restricted to HTTP(S)-like traffic to reach the node.
+    #[clap(long)]
+    ws_port: Option<u16>,
+
+    #[cfg(feature = "pnet")]
+    /// Join a private network, using the pre-shared key read from the given file.
+    ///
+    /// The file uses the `/key/swarm/psk/1.0.0/` format of the other libp2p implementations.
+    /// Only peers holding the same key can connect to the node. QUIC is then disabled, and TCP
+    /// listens on the node port unless `--tcp-port` is used.
+    #[clap(long, value_name = "PATH", value_parser = parse_pre_shared_key_file)]
+    pnet_key_file: Option<ant_networking::PreSharedKey>,
+
     /// Specify the logging output destination.
     ///
     /// Valid values are "stdout", "data-dir", or a custom path.
@@ -154,6 +188,45 @@ struct Opt {
     #[clap(long, verbatim_doc_comment)]
     root_dir: Option<PathBuf>,
 
+    /// Specify a TOML file to tune the node and its networking layer.
+    ///
+    /// Any value missing from the file uses the default of the public network. This is intended for private
+    /// testnets and constrained deployments.
+    #[clap(long, verbatim_doc_comment)]
+    config: Option<PathBuf>,
+
+    /// Limit the bandwidth used by replication between nodes, in bytes per second.
+    ///
+    /// Overrides the value from the config file, if any.
+    #[clap(long)]
+    max_replication_bandwidth: Option<u64>,
+
+    /// Limit the bandwidth used to send records to clients fetching them directly, in bytes per
+    /// second.
+    ///
+    /// Overrides the value from the config file, if any.
+    #[clap(long)]
+    max_client_bandwidth: Option<u64>,
+
+    /// Limit the space used to store records, in bytes.
+    ///
+    /// The node will hold fewer records than it otherwise would so they always fit within this
+    /// space. Overrides the value from the config file, if any.
+    #[clap(long)]
+    max_storage: Option<u64>,
+
+    /// Limit the memory the node can use, in bytes.
+    ///
+    /// Applied as a limit on the data segment of the process, on Unix platforms only.
+    #[clap(long)]
+    max_memory: Option<u64>,
+
+    /// Limit the number of files the node can have open.
+    ///
+    /// Only applied on Unix platforms.
+    #[clap(long)]
+    max_open_files: Option<u64>,
+
     /// Specify the port to listen on.
     ///
     /// The special value `0` will cause the OS to assign a random port.
@@ -219,6 +292,10 @@ fn main() -> Result<()> {
     if let Some(network_id) = opt.network_id {
         version::set_network_id(network_id);
     }
+    #[cfg(feature = "pnet")]
+    if let Some(key) = &opt.pnet_key_file {
+        version::set_pnet_fingerprint(key.fingerprint().to_string());
+    }
 
     let identify_protocol_str = version::IDENTIFY_PROTOCOL_STR
         .read()
@@ -268,11 +345,31 @@ fn main() -> Result<()> {
 
     println!("EVM network: {evm_network:?}");
 
+    let mut node_config = match &opt.config {
+        Some(path) => {
+            let config = NodeConfig::load(path)?;
+            println!("Loaded node config from {path:?}");
+            config
+        }
+        None => NodeConfig::default(),
+    };
+    if let Some(limit) = opt.max_replication_bandwidth {
+        node_config.network.replication_bandwidth_limit = Some(limit);
+    }
+    if let Some(limit) = opt.max_client_bandwidth {
+        node_config.network.client_bandwidth_limit = Some(limit);
+    }
+    if let Some(limit) = opt.max_storage {
+        node_config.network.max_storage_bytes = Some(limit);
+    }
+    node_config.validate()?;
+
     let node_socket_addr = SocketAddr::new(opt.ip, opt.port);
     let (root_dir, keypair) = get_root_dir_and_keypair(&opt.root_dir)?;
 
     let (log_output_dest, log_reload_handle, _log_appender_guard) =
         init_logging(&opt, keypair.public().to_peer_id())?;
+    apply_resource_limits(&opt)?;
 
     let mut bootstrap_cache = BootstrapCacheStore::new_from_peers_args(&opt.peers, None)?;
     // If we are the first node, write initial cache to disk.
@@ -322,6 +419,15 @@ fn main() -> Result<()> {
         node_builder.initial_peers(initial_peers);
         node_builder.bootstrap_cache(bootstrap_cache);
         node_builder.is_behind_home_network(opt.home_network);
+        node_builder.config(node_config);
+        node_builder.transport_config(TransportConfig {
+            #[cfg(feature = "tcp")]
+            tcp: opt.tcp_port,
+            #[cfg(feature = "websocket")]
+            websocket: opt.ws_port,
+            #[cfg(feature = "pnet")]
+            pre_shared_key: opt.pnet_key_file,
+        });
         #[cfg(feature = "open-metrics")]
         let mut node_builder = node_builder;
         // if enable flag is provided or only if the port is specified then enable the server by setting Some()
@@ -353,6 +459,33 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+/// Apply the memory and open file limits to the node process.
+///
+/// Some service managers can't apply these limits before starting the node, so the node applies
+/// them to itself.
+#[cfg(unix)]
+fn apply_resource_limits(opt: &Opt) -> Result<()> {
+    let limits = ResourceLimits {
+        max_memory: opt.max_memory,
+        max_open_files: opt.max_open_files,
+        ..Default::default()
+    };
+    if limits.has_process_limits() {
+        apply_rlimits(&limits)
+            .map_err(|err| eyre!("Failed to apply the resource limits {limits}: {err}"))?;
+        info!("Applied the resource limits: {limits}");
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn apply_resource_limits(opt: &Opt) -> Result<()> {
+    if opt.max_memory.is_some() || opt.max_open_files.is_some() {
+        println!("The memory and open file limits are not supported on this platform, and will not be applied.");
+    }
+    Ok(())
+}
+
 /// Start a node with the given configuration.
 /// Returns:
 /// - `Ok(Some(_))` if we receive a restart request.
@@ -10,9 +10,10 @@ use ant_logging::ReloadHandle;
 use ant_node::RunningNode;
 use ant_protocol::antnode_proto::{
     ant_node_server::{AntNode, AntNodeServer},
-    k_buckets_response, KBucketsRequest, KBucketsResponse, NetworkInfoRequest, NetworkInfoResponse,
-    NodeEvent, NodeEventsRequest, NodeInfoRequest, NodeInfoResponse, RecordAddressesRequest,
-    RecordAddressesResponse, RestartRequest, RestartResponse, StopRequest, StopResponse,
+    k_buckets_response, rewards_ledger_response, KBucketsRequest, KBucketsResponse,
+    NetworkInfoRequest, NetworkInfoResponse, NodeEvent, NodeEventsRequest, NodeInfoRequest,
+    NodeInfoResponse, RecordAddressesRequest, RecordAddressesResponse, RestartRequest,
+    RestartResponse, RewardsLedgerRequest, RewardsLedgerResponse, StopRequest, StopResponse,
     UpdateLogLevelRequest, UpdateLogLevelResponse, UpdateRequest, UpdateResponse,
 };
 use ant_protocol::node_rpc::{NodeCtrl, StopResult};
@@ -185,6 +186,54 @@ impl AntNode for SafeNodeRpcService {
         Ok(Response::new(KBucketsResponse { kbuckets }))
     }
 
+    async fn rewards_ledger(
+        &self,
+        request: Request<RewardsLedgerRequest>,
+    ) -> Result<Response<RewardsLedgerResponse>, Status> {
+        debug!(
+            "RPC request received at {}: {:?}",
+            self.addr,
+            request.get_ref()
+        );
+
+        let windows: Vec<Duration> = request
+            .get_ref()
+            .window_secs
+            .iter()
+            .map(|secs| Duration::from_secs(*secs))
+            .collect();
+
+        let mut totals = self
+            .running_node
+            .rewards_ledger()
+            .totals(&windows)
+            .map_err(|err| {
+                Status::new(
+                    Code::Internal,
+                    format!("Failed to read the rewards ledger: {err}"),
+                )
+            })?;
+
+        // The last total always covers the whole ledger.
+        let overall = totals.pop();
+        let window_totals = totals
+            .into_iter()
+            .map(|total| rewards_ledger_response::WindowTotal {
+                window_secs: total.window.unwrap_or_default().as_secs(),
+                amount: total.amount.as_atto().to_string(),
+                payments: total.payments,
+            })
+            .collect();
+
+        Ok(Response::new(RewardsLedgerResponse {
+            window_totals,
+            total_amount: overall
+                .map(|total| total.amount.as_atto().to_string())
+                .unwrap_or_default(),
+            total_payments: overall.map(|total| total.payments).unwrap_or_default(),
+        }))
+    }
+
     async fn stop(&self, request: Request<StopRequest>) -> Result<Response<StopResponse>, Status> {
         debug!(
             "RPC request received at {}: {:?}",
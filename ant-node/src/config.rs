@@ -0,0 +1,148 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::{
+    error::{Error, Result},
+    node::{PERIODIC_REPLICATION_INTERVAL_MAX_S, STORE_CHALLENGE_INTERVAL_MAX_S},
+};
+use ant_networking::NetworkTuning;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Tuning parameters of the node, which can be loaded from a TOML file.
+///
+/// Any value missing from the file falls back to the default used by the public network:
+///
+/// ```toml
+/// periodic_replication_interval_max_s = 180
+/// store_challenge_interval_max_s = 7200
+///
+/// [network]
+/// request_timeout_s = 30
+/// replication_max_parallel_fetch = 20
+/// replication_fetch_timeout_s = 20
+/// max_concurrent_relay_connections = 4
+/// # Bytes per second, unlimited if not set.
+/// replication_bandwidth_limit = 1000000
+/// client_bandwidth_limit = 1000000
+/// # Bytes of records, the default max number of records if not set.
+/// max_storage_bytes = 50000000000
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NodeConfig {
+    /// Max interval, in seconds, between two periodic replications. The actual interval is
+    /// picked at random between half of this value and this value.
+    pub periodic_replication_interval_max_s: u64,
+    /// Max interval, in seconds, between two storage challenges. The actual interval is picked
+    /// at random between half of this value and this value.
+    pub store_challenge_interval_max_s: u64,
+    /// Tuning of the networking layer.
+    pub network: NetworkTuning,
+}
+
+impl Default for NodeConfig {
+    fn default() -> Self {
+        Self {
+            periodic_replication_interval_max_s: PERIODIC_REPLICATION_INTERVAL_MAX_S,
+            store_challenge_interval_max_s: STORE_CHALLENGE_INTERVAL_MAX_S,
+            network: NetworkTuning::default(),
+        }
+    }
+}
+
+impl NodeConfig {
+    /// Load the config from a TOML file and validate it.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| Error::InvalidConfig(format!("Failed to read {path:?}: {err}")))?;
+        let config: Self = toml::from_str(&content)
+            .map_err(|err| Error::InvalidConfig(format!("Failed to parse {path:?}: {err}")))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Check that the values can be used by the node.
+    pub fn validate(&self) -> Result<()> {
+        // The intervals are picked from `max / 2..max`, which must not be empty.
+        if self.periodic_replication_interval_max_s < 2 {
+            return Err(Error::InvalidConfig(
+                "periodic_replication_interval_max_s must be at least 2".to_string(),
+            ));
+        }
+        if self.store_challenge_interval_max_s < 2 {
+            return Err(Error::InvalidConfig(
+                "store_challenge_interval_max_s must be at least 2".to_string(),
+            ));
+        }
+        self.network.validate().map_err(Error::InvalidConfig)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_values_should_use_defaults() -> eyre::Result<()> {
+        let config: NodeConfig = toml::from_str(
+            r#"
+            store_challenge_interval_max_s = 600
+
+            [network]
+            replication_max_parallel_fetch = 5
+            "#,
+        )?;
+
+        assert_eq!(config.store_challenge_interval_max_s, 600);
+        assert_eq!(
+            config.periodic_replication_interval_max_s,
+            PERIODIC_REPLICATION_INTERVAL_MAX_S
+        );
+        assert_eq!(config.network.replication_max_parallel_fetch, 5);
+        assert_eq!(
+            config.network.request_timeout_s,
+            NetworkTuning::default().request_timeout_s
+        );
+        assert!(config.validate().is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_values_should_be_rejected() -> eyre::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("node.toml");
+
+        std::fs::write(&path, "periodic_replication_interval_max_s = 1")?;
+        assert!(matches!(
+            NodeConfig::load(&path),
+            Err(Error::InvalidConfig(_))
+        ));
+
+        std::fs::write(&path, "[network]\nrequest_timeout_s = 0")?;
+        assert!(matches!(
+            NodeConfig::load(&path),
+            Err(Error::InvalidConfig(_))
+        ));
+
+        std::fs::write(&path, "[network]\nmax_storage_bytes = 1024")?;
+        assert!(matches!(
+            NodeConfig::load(&path),
+            Err(Error::InvalidConfig(_))
+        ));
+
+        std::fs::write(&path, "unknown_field = 1")?;
+        assert!(matches!(
+            NodeConfig::load(&path),
+            Err(Error::InvalidConfig(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -18,8 +18,9 @@ const SCRATCHPAD_MAX_SIZE: usize = ant_protocol::storage::Scratchpad::MAX_SIZE;
 #[derive(Debug, Error)]
 #[allow(missing_docs)]
 pub enum Error {
+    /// Boxed, as it is much larger than the other variants.
     #[error("Network error {0}")]
-    Network(#[from] ant_networking::NetworkError),
+    Network(Box<ant_networking::NetworkError>),
 
     #[error("Protocol error {0}")]
     Protocol(#[from] ant_protocol::Error),
@@ -48,6 +49,10 @@ pub enum Error {
     #[error("Scratchpad too big: {0}, max size is {SCRATCHPAD_MAX_SIZE}")]
     ScratchpadTooBig(usize),
 
+    // ------------ Pointer Errors
+    #[error("A newer version of this Pointer already exists")]
+    IgnoringOutdatedPointerPut,
+
     #[error("Invalid signature")]
     InvalidSignature,
 
@@ -86,4 +91,14 @@ pub enum Error {
     InvalidRequest(String),
     #[error("EVM Network error: {0}")]
     EvmNetwork(String),
+    #[error("Rewards ledger error: {0}")]
+    RewardsLedger(String),
+    #[error("Invalid node config: {0}")]
+    InvalidConfig(String),
+}
+
+impl From<ant_networking::NetworkError> for Error {
+    fn from(err: ant_networking::NetworkError) -> Self {
+        Self::Network(Box::new(err))
+    }
 }
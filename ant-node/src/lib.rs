@@ -21,6 +21,7 @@
 #[macro_use]
 extern crate tracing;
 
+mod config;
 mod error;
 mod event;
 mod log_markers;
@@ -32,15 +33,18 @@ mod put_validation;
 mod python;
 mod quote;
 mod replication;
+mod rewards_ledger;
 #[allow(missing_docs)]
 pub mod spawn;
 #[allow(missing_docs)]
 pub mod utils;
 
 pub use self::{
+    config::NodeConfig,
     event::{NodeEvent, NodeEventsChannel, NodeEventsReceiver},
     log_markers::Marker,
     node::{NodeBuilder, PERIODIC_REPLICATION_INTERVAL_MAX_S},
+    rewards_ledger::{RewardEntry, RewardTotal, RewardsLedger},
 };
 
 use crate::error::{Error, Result};
@@ -53,6 +57,7 @@ use libp2p::{Multiaddr, PeerId};
 use std::{
     collections::{BTreeMap, HashSet},
     path::PathBuf,
+    sync::Arc,
 };
 use tokio::sync::watch;
 
@@ -65,6 +70,7 @@ pub struct RunningNode {
     node_events_channel: NodeEventsChannel,
     root_dir_path: PathBuf,
     rewards_address: RewardsAddress,
+    rewards_ledger: Arc<RewardsLedger>,
 }
 
 impl RunningNode {
@@ -150,6 +156,11 @@ impl RunningNode {
         &self.rewards_address
     }
 
+    /// Returns the ledger of the payments accepted by the node
+    pub fn rewards_ledger(&self) -> &RewardsLedger {
+        &self.rewards_ledger
+    }
+
     /// Shutdown the SwarmDriver loop and the node (NetworkEvents) loop.
     pub fn shutdown(self) {
         // Send the shutdown signal to the swarm driver and node loop
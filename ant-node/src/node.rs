@@ -11,7 +11,7 @@ use super::{
 };
 #[cfg(feature = "open-metrics")]
 use crate::metrics::NodeMetricsRecorder;
-use crate::RunningNode;
+use crate::{config::NodeConfig, rewards_ledger::RewardsLedger, RunningNode};
 use ant_bootstrap::BootstrapCacheStore;
 use ant_evm::EvmNetwork;
 use ant_evm::RewardsAddress;
@@ -19,6 +19,7 @@ use ant_evm::RewardsAddress;
 use ant_networking::MetricsRegistries;
 use ant_networking::{
     time::sleep, Instant, Network, NetworkBuilder, NetworkEvent, NodeIssue, SwarmDriver,
+    TransportConfig,
 };
 use ant_protocol::{
     error::Error as ProtocolError,
@@ -56,7 +57,7 @@ pub const PERIODIC_REPLICATION_INTERVAL_MAX_S: u64 = 180;
 
 /// Interval to trigger storage challenge.
 /// This is the max time it should take. Minimum interval at any node will be half this
-const STORE_CHALLENGE_INTERVAL_MAX_S: u64 = 7200;
+pub(crate) const STORE_CHALLENGE_INTERVAL_MAX_S: u64 = 7200;
 
 /// Interval to update the nodes uptime metric
 const UPTIME_METRICS_UPDATE_INTERVAL: Duration = Duration::from_secs(10);
@@ -94,6 +95,8 @@ pub struct NodeBuilder {
     /// Enable hole punching for nodes connecting from home networks.
     is_behind_home_network: bool,
     upnp: bool,
+    transport_config: TransportConfig,
+    config: NodeConfig,
 }
 
 impl NodeBuilder {
@@ -121,6 +124,8 @@ impl NodeBuilder {
             metrics_server_port: None,
             is_behind_home_network: false,
             upnp,
+            transport_config: TransportConfig::default(),
+            config: NodeConfig::default(),
         }
     }
 
@@ -145,6 +150,16 @@ impl NodeBuilder {
         self.is_behind_home_network = is_behind_home_network;
     }
 
+    /// Set the transports to enable alongside QUIC.
+    pub fn transport_config(&mut self, transport_config: TransportConfig) {
+        self.transport_config = transport_config;
+    }
+
+    /// Override the default tuning parameters. The config is expected to have been validated.
+    pub fn config(&mut self, config: NodeConfig) {
+        self.config = config;
+    }
+
     /// Asynchronously runs a new node instance, setting up the swarm driver,
     /// creating a data storage, and handling network events. Returns the
     /// created `RunningNode` which contains a `NodeEventsChannel` for listening
@@ -182,11 +197,14 @@ impl NodeBuilder {
         }
 
         network_builder.upnp(self.upnp);
+        network_builder.transport_config(self.transport_config);
+        network_builder.tuning(self.config.network);
 
         let (network, network_event_receiver, swarm_driver) =
             network_builder.build_node(self.root_dir.clone())?;
 
         let node_events_channel = NodeEventsChannel::default();
+        let rewards_ledger = Arc::new(RewardsLedger::open_or_in_memory(&self.root_dir));
 
         let node = NodeInner {
             network: network.clone(),
@@ -196,6 +214,8 @@ impl NodeBuilder {
             #[cfg(feature = "open-metrics")]
             metrics_recorder,
             evm_network: self.evm_network,
+            rewards_ledger: Arc::clone(&rewards_ledger),
+            config: self.config,
         };
 
         let node = Node {
@@ -214,6 +234,7 @@ impl NodeBuilder {
             node_events_channel,
             root_dir_path: self.root_dir,
             rewards_address: self.evm_address,
+            rewards_ledger,
         };
 
         Ok(running_node)
@@ -239,6 +260,8 @@ struct NodeInner {
     metrics_recorder: Option<NodeMetricsRecorder>,
     reward_address: RewardsAddress,
     evm_network: EvmNetwork,
+    rewards_ledger: Arc<RewardsLedger>,
+    config: NodeConfig,
 }
 
 impl Node {
@@ -273,6 +296,11 @@ impl Node {
         &self.inner.evm_network
     }
 
+    /// Returns the ledger of the payments accepted by the node
+    pub(crate) fn rewards_ledger(&self) -> &RewardsLedger {
+        &self.inner.rewards_ledger
+    }
+
     /// Runs a task for the provided `SwarmDriver` and spawns a task to process for `NetworkEvents`.
     /// Returns both tasks as JoinHandle<()>.
     fn run(
@@ -282,6 +310,9 @@ impl Node {
         mut shutdown_rx: watch::Receiver<bool>,
     ) {
         let mut rng = StdRng::from_entropy();
+        let periodic_replication_interval_max_s =
+            self.inner.config.periodic_replication_interval_max_s;
+        let store_challenge_interval_max_s = self.inner.config.store_challenge_interval_max_s;
 
         let peers_connected = Arc::new(AtomicUsize::new(0));
 
@@ -290,7 +321,7 @@ impl Node {
             // use a random inactivity timeout to ensure that the nodes do not sync when messages
             // are being transmitted.
             let replication_interval: u64 = rng.gen_range(
-                PERIODIC_REPLICATION_INTERVAL_MAX_S / 2..PERIODIC_REPLICATION_INTERVAL_MAX_S,
+                periodic_replication_interval_max_s / 2..periodic_replication_interval_max_s,
             );
             let replication_interval_time = Duration::from_secs(replication_interval);
             debug!("Replication interval set to {replication_interval_time:?}");
@@ -309,7 +340,7 @@ impl Node {
             // use a random neighbour storage challenge ticker to ensure
             // neighbours do not carryout challenges at the same time
             let storage_challenge_interval: u64 =
-                rng.gen_range(STORE_CHALLENGE_INTERVAL_MAX_S / 2..STORE_CHALLENGE_INTERVAL_MAX_S);
+                rng.gen_range(store_challenge_interval_max_s / 2..store_challenge_interval_max_s);
             let storage_challenge_interval_time = Duration::from_secs(storage_challenge_interval);
             debug!("Storage challenge interval set to {storage_challenge_interval_time:?}");
 
@@ -709,6 +740,28 @@ impl Node {
                 Self::respond_get_closest_peers(network, key, num_of_peers, range, sign_result)
                     .await
             }
+            Query::GetBootstrapPeers {
+                requester,
+                max_addrs,
+                ..
+            } => {
+                // Usually answered by the networking layer directly, from the same cache.
+                debug!("Got GetBootstrapPeers from {requester:?} for {max_addrs} addrs");
+                let addrs = match network
+                    .get_shareable_bootstrap_addrs(requester, max_addrs)
+                    .await
+                {
+                    Ok(addrs) => addrs,
+                    Err(err) => {
+                        warn!("Failed to get the shareable bootstrap addrs: {err:?}");
+                        vec![]
+                    }
+                };
+                QueryResponse::GetBootstrapPeers {
+                    holder: NetworkAddress::from_peer(network.peer_id()),
+                    addrs,
+                }
+            }
         };
         Response::Query(resp)
     }
@@ -6,11 +6,11 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-use std::collections::BTreeSet;
+use std::{collections::BTreeSet, time::SystemTime};
 
-use crate::{node::Node, Error, Marker, Result};
-use ant_evm::payment_vault::verify_data_payment;
-use ant_evm::ProofOfPayment;
+use crate::{node::Node, Error, Marker, Result, RewardEntry};
+use ant_evm::payment_vault::{get_payer_address, verify_data_payment_by_quote};
+use ant_evm::{Amount, AttoTokens, ProofOfPayment, QuoteHash};
 use ant_networking::NetworkError;
 use ant_protocol::storage::GraphEntry;
 use ant_protocol::{
@@ -21,6 +21,7 @@ use ant_protocol::{
     NetworkAddress, PrettyPrintRecordKey,
 };
 use libp2p::kad::{Record, RecordKey};
+use tokio::task::spawn;
 use xor_name::XorName;
 
 impl Node {
@@ -318,7 +319,9 @@ impl Node {
                         Some(payment),
                     )
                     .await;
-                if res.is_ok() {
+                // A paid PUT of a pointer we already hold a newer version of still counts as
+                // completed, as with Scratchpads.
+                if matches!(res, Ok(()) | Err(Error::IgnoringOutdatedPointerPut)) {
                     let content_hash = XorName::from_content(&record.value);
                     Marker::ValidPointerPutFromClient(&pretty_key).log();
 
@@ -452,6 +455,7 @@ impl Node {
     /// When a node receives an update packet:
     /// Verify Name: It MUST hash the provided public key and confirm it matches the name in the packet.
     /// Check Counter: It MUST ensure that the new counter value is strictly greater than the currently stored value to prevent replay attacks.
+    ///                A different Scratchpad with the same counter is only accepted if it wins the tie-break of [`Scratchpad::supersedes`].
     /// Verify Signature: It MUST use the public key to verify the BLS12-381 signature against the content hash and the counter.
     /// Accept or Reject: If all verifications succeed, the node MUST accept the packet and replace any previous version. Otherwise, it MUST reject the update.
     pub(crate) async fn validate_and_store_scratchpad_record(
@@ -476,7 +480,7 @@ impl Node {
         // check if the Scratchpad is present locally that we don't have a newer version
         if let Some(local_pad) = self.network().get_local_record(&scratchpad_key).await? {
             let local_pad = try_deserialize_record::<Scratchpad>(&local_pad)?;
-            if local_pad.counter() >= scratchpad.counter() {
+            if !scratchpad.supersedes(&local_pad) {
                 warn!("Rejecting Scratchpad PUT with counter less than or equal to the current counter");
                 return Err(Error::IgnoringOutdatedScratchpadPut);
             }
@@ -665,20 +669,24 @@ impl Node {
             )));
         }
 
-        let owned_payment_quotes = payment
+        let owned_payment_quotes: Vec<_> = payment
             .quotes_by_peer(&self_peer_id)
             .iter()
             .map(|quote| quote.hash())
             .collect();
         // check if payment is valid on chain
         let payments_to_verify = payment.digest();
-        let reward_amount =
-            verify_data_payment(self.evm_network(), owned_payment_quotes, payments_to_verify)
-                .await
-                .inspect_err(|e| {
-                    warn!("Failed to verify record payment: {e}");
-                })
-                .map_err(|e| Error::EvmNetwork(format!("Failed to verify record payment: {e}")))?;
+        let rewards = verify_data_payment_by_quote(
+            self.evm_network(),
+            owned_payment_quotes,
+            payments_to_verify,
+        )
+        .await
+        .inspect_err(|e| {
+            warn!("Failed to verify record payment: {e}");
+        })
+        .map_err(|e| Error::EvmNetwork(format!("Failed to verify record payment: {e}")))?;
+        let reward_amount: Amount = rewards.iter().map(|(_, amount)| *amount).sum();
 
         debug!("Payment of {reward_amount:?} is valid for record {pretty_key}");
 
@@ -697,12 +705,7 @@ impl Node {
                     .set(new_value);
             }
 
-            // TODO: currently ignored, re-enable once going to handle this.
-            // self.events_channel()
-            //     .broadcast(crate::NodeEvent::RewardReceived(
-            //         AttoTokens::from(reward_amount),
-            //         address.clone(),
-            //     ));
+            self.record_rewards(address.clone(), rewards);
 
             // vdash metric (if modified please notify at https://github.com/happybeing/vdash/issues):
             info!(
@@ -723,6 +726,53 @@ impl Node {
         Ok(())
     }
 
+    /// Record the rewards of a payment in the ledger, and broadcast them.
+    ///
+    /// This runs in the background, as finding out who paid for each quote queries the EVM
+    /// network.
+    fn record_rewards(&self, address: NetworkAddress, rewards: Vec<(QuoteHash, Amount)>) {
+        let node = self.clone();
+        let _handle = spawn(async move {
+            let pretty_key = PrettyPrintRecordKey::from(&address.to_record_key()).into_owned();
+            for (quote_hash, amount) in rewards {
+                if amount.is_zero() {
+                    continue;
+                }
+
+                let payer = match get_payer_address(node.evm_network(), quote_hash).await {
+                    Ok(payer) => payer,
+                    Err(err) => {
+                        warn!("Failed to find who paid for quote {quote_hash:?} of record {pretty_key}: {err}");
+                        None
+                    }
+                };
+
+                let entry = RewardEntry {
+                    record_address: address.clone(),
+                    quote_hash,
+                    amount: AttoTokens::from_atto(amount),
+                    payer,
+                    timestamp: SystemTime::now(),
+                };
+                match node.rewards_ledger().record(entry) {
+                    Ok(true) => {
+                        node.events_channel()
+                            .broadcast(crate::NodeEvent::RewardReceived(
+                                AttoTokens::from_atto(amount),
+                                address.clone(),
+                            ));
+                    }
+                    Ok(false) => {
+                        debug!("Payment for record {pretty_key} was already in the rewards ledger");
+                    }
+                    Err(err) => {
+                        error!("Failed to record payment for record {pretty_key} in the rewards ledger: {err}");
+                    }
+                }
+            }
+        });
+    }
+
     /// Get the local GraphEntries for the provided `GraphEntryAddress`
     /// This only fetches the GraphEntries from the local store and does not perform any network operations.
     async fn get_local_graphentries(&self, addr: GraphEntryAddress) -> Result<Vec<GraphEntry>> {
@@ -816,13 +866,17 @@ impl Node {
 
         // Keep the pointer with the highest counter
         if let Some(local_pointer) = self.get_local_pointer(pointer.address()).await {
-            if pointer.counter() <= local_pointer.counter() {
+            if pointer == local_pointer {
+                debug!("Pointer at {key:?} is already stored, nothing to do");
+                return Ok(());
+            }
+            if !pointer.supersedes(&local_pointer) {
                 info!(
                     "Ignoring Pointer PUT at {key:?} with counter less than or equal to the current counter ({} <= {})",
                     pointer.counter(),
                     local_pointer.counter()
                 );
-                return Ok(());
+                return Err(Error::IgnoringOutdatedPointerPut);
             }
         }
 
@@ -11,6 +11,7 @@ use ant_evm::ProofOfPayment;
 use ant_networking::{GetRecordCfg, Network, ResponseQuorum};
 use ant_protocol::storage::DataTypes;
 use ant_protocol::{
+    error::Error as ProtocolError,
     messages::{Query, QueryResponse, Request, Response},
     storage::ValidationType,
     NetworkAddress, PrettyPrintRecordKey,
@@ -19,7 +20,15 @@ use libp2p::{
     kad::{Record, RecordKey},
     PeerId,
 };
-use tokio::task::spawn;
+use std::time::Duration;
+use tokio::{task::spawn, time::sleep};
+
+/// The number of times a record is fetched from a holder that is throttling traffic, before
+/// fetching it from the network instead.
+const THROTTLED_FETCH_ATTEMPTS: u32 = 3;
+/// The delay before fetching a record again from a holder that is throttling traffic, growing
+/// with each attempt.
+const THROTTLED_FETCH_DELAY: Duration = Duration::from_secs(2);
 
 impl Node {
     /// Sends _all_ record keys every interval to all peers within the REPLICATE_RANGE.
@@ -47,24 +56,35 @@ impl Node {
                     requester,
                     key: NetworkAddress::from_record_key(&key),
                 });
-                let record_opt = if let Ok(resp) = node.network().send_request(req, holder).await {
+                let mut record_opt = None;
+                for attempt in 1..=THROTTLED_FETCH_ATTEMPTS {
+                    let Ok(resp) = node.network().send_request(req.clone(), holder).await else {
+                        break;
+                    };
                     match resp {
-                        Response::Query(QueryResponse::GetReplicatedRecord(result)) => match result
-                        {
-                            Ok((_holder, record_content)) => Some(record_content),
-                            Err(err) => {
-                                debug!("Failed fetch record {pretty_key:?} from node {holder:?}, with error {err:?}");
-                                None
-                            }
-                        },
+                        Response::Query(QueryResponse::GetReplicatedRecord(Ok((
+                            _holder,
+                            record_content,
+                        )))) => {
+                            record_opt = Some(record_content);
+                            break;
+                        }
+                        Response::Query(QueryResponse::GetReplicatedRecord(Err(
+                            ProtocolError::RecordThrottled { .. },
+                        ))) if attempt < THROTTLED_FETCH_ATTEMPTS => {
+                            debug!("Node {holder:?} is throttling traffic, fetching record {pretty_key:?} again later");
+                            sleep(THROTTLED_FETCH_DELAY * attempt).await;
+                        }
+                        Response::Query(QueryResponse::GetReplicatedRecord(Err(err))) => {
+                            debug!("Failed fetch record {pretty_key:?} from node {holder:?}, with error {err:?}");
+                            break;
+                        }
                         other => {
                             debug!("Cannot fetch record {pretty_key:?} from node {holder:?}, with response {other:?}");
-                            None
+                            break;
                         }
                     }
-                } else {
-                    None
-                };
+                }
 
                 let record = if let Some(record_content) = record_opt {
                     Record::new(key, record_content.to_vec())
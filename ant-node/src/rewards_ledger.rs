@@ -0,0 +1,400 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::error::{Error, Result};
+
+use ant_evm::{AttoTokens, QuoteHash, RewardsAddress};
+use ant_protocol::NetworkAddress;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashSet, VecDeque},
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    sync::{Mutex, RwLock},
+    time::{Duration, SystemTime},
+};
+
+/// File name of the ledger, stored within the node's root directory.
+const REWARDS_LEDGER_FILENAME: &str = "rewards_ledger.jsonl";
+
+/// How long the payments are kept in memory. Totals over longer windows are read from disk.
+const IN_MEMORY_RETENTION: Duration = Duration::from_secs(31 * 24 * 3600);
+
+/// A single payment accepted by the node.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RewardEntry {
+    /// Address of the record that was paid for.
+    pub record_address: NetworkAddress,
+    /// Hash of the quote issued by this node that the payment was made against.
+    pub quote_hash: QuoteHash,
+    /// The amount paid to this node for the quote.
+    pub amount: AttoTokens,
+    /// The EVM address that made the payment, if it could be found.
+    pub payer: Option<RewardsAddress>,
+    /// The local time at which the payment was accepted.
+    pub timestamp: SystemTime,
+}
+
+/// Sum of the rewards received within a time window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RewardTotal {
+    /// The time window, counted back from now. `None` covers the whole ledger.
+    pub window: Option<Duration>,
+    /// The total amount received within the window.
+    pub amount: AttoTokens,
+    /// The number of payments received within the window.
+    pub payments: u64,
+}
+
+impl RewardTotal {
+    fn new(window: Option<Duration>) -> Self {
+        Self {
+            window,
+            amount: AttoTokens::zero(),
+            payments: 0,
+        }
+    }
+
+    fn add(&mut self, amount: AttoTokens) {
+        self.amount = self
+            .amount
+            .checked_add(amount)
+            .unwrap_or(AttoTokens::from_atto(ant_evm::Amount::MAX));
+        self.payments += 1;
+    }
+}
+
+/// The payments kept in memory: the recent ones, and the total of all of them.
+struct Recent {
+    /// Time, amount and quote hash of the payments within the retention, oldest first.
+    payments: VecDeque<(SystemTime, AttoTokens, QuoteHash)>,
+    /// Quote hashes of the recent payments. Quotes expire long before the retention, so a
+    /// payment seen again can only be one of them.
+    quote_hashes: HashSet<QuoteHash>,
+    /// Total of the whole ledger.
+    overall: RewardTotal,
+}
+
+impl Recent {
+    fn push(
+        &mut self,
+        timestamp: SystemTime,
+        amount: AttoTokens,
+        quote_hash: QuoteHash,
+        now: SystemTime,
+    ) {
+        self.overall.add(amount);
+        if now.duration_since(timestamp).unwrap_or_default() > IN_MEMORY_RETENTION {
+            return;
+        }
+        let _ = self.quote_hashes.insert(quote_hash);
+        self.payments.push_back((timestamp, amount, quote_hash));
+    }
+
+    /// Drop the payments that are older than the retention. They are pushed as they are
+    /// accepted, so the oldest ones are at the front.
+    fn prune(&mut self, now: SystemTime) {
+        while let Some((timestamp, _, quote_hash)) = self.payments.front() {
+            if now.duration_since(*timestamp).unwrap_or_default() <= IN_MEMORY_RETENTION {
+                break;
+            }
+            let _ = self.quote_hashes.remove(quote_hash);
+            let _ = self.payments.pop_front();
+        }
+    }
+}
+
+/// Append-only, on-disk record of the payments accepted by the node.
+///
+/// Each entry is written as a JSON line, so the ledger can be inspected with standard tools.
+/// A quote hash is only ever recorded once, so a payment that is seen again, e.g. through
+/// replication, does not inflate the totals.
+pub struct RewardsLedger {
+    path: PathBuf,
+    /// The ledger file, opened for appending. `None` if the ledger could not be opened, in
+    /// which case the payments are only kept in memory.
+    file: Option<Mutex<File>>,
+    recent: RwLock<Recent>,
+}
+
+impl RewardsLedger {
+    /// Open the ledger in the provided root dir, loading any entries that were previously recorded.
+    ///
+    /// Lines that cannot be parsed are logged and skipped, rather than failing the node.
+    pub fn open(root_dir: &Path) -> Result<Self> {
+        let path = root_dir.join(REWARDS_LEDGER_FILENAME);
+        let now = SystemTime::now();
+        let mut recent = Recent {
+            payments: VecDeque::new(),
+            quote_hashes: HashSet::new(),
+            overall: RewardTotal::new(None),
+        };
+
+        let mut entries = 0;
+        if path.exists() {
+            for entry in read_entries(&path)? {
+                entries += 1;
+                recent.push(entry.timestamp, entry.amount, entry.quote_hash, now);
+            }
+        }
+        debug!("Loaded {entries} rewards ledger entries from {path:?}");
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|err| Error::RewardsLedger(format!("Failed to open {path:?}: {err}")))?;
+
+        Ok(Self {
+            path,
+            file: Some(Mutex::new(file)),
+            recent: RwLock::new(recent),
+        })
+    }
+
+    /// Open the ledger in the provided root dir, falling back to a ledger that is only kept in
+    /// memory if the file cannot be used, so that the node can still run.
+    pub fn open_or_in_memory(root_dir: &Path) -> Self {
+        match Self::open(root_dir) {
+            Ok(ledger) => ledger,
+            Err(err) => {
+                error!("Failed to open the rewards ledger, payments will not be persisted: {err}");
+                Self {
+                    path: root_dir.join(REWARDS_LEDGER_FILENAME),
+                    file: None,
+                    recent: RwLock::new(Recent {
+                        payments: VecDeque::new(),
+                        quote_hashes: HashSet::new(),
+                        overall: RewardTotal::new(None),
+                    }),
+                }
+            }
+        }
+    }
+
+    /// Returns the path of the ledger file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Record a new entry, persisting it to disk.
+    ///
+    /// Returns `false` if an entry for the same quote hash had already been recorded.
+    pub fn record(&self, entry: RewardEntry) -> Result<bool> {
+        let mut recent = self
+            .recent
+            .write()
+            .map_err(|_| Error::RewardsLedger("Ledger lock is poisoned".to_string()))?;
+        if recent.quote_hashes.contains(&entry.quote_hash) {
+            return Ok(false);
+        }
+
+        if let Some(file) = &self.file {
+            let mut line = serde_json::to_string(&entry)
+                .map_err(|err| Error::RewardsLedger(format!("Failed to serialize entry: {err}")))?;
+            line.push('\n');
+
+            file.lock()
+                .map_err(|_| Error::RewardsLedger("Ledger file lock is poisoned".to_string()))?
+                .write_all(line.as_bytes())
+                .map_err(|err| {
+                    Error::RewardsLedger(format!("Failed to write to {:?}: {err}", self.path))
+                })?;
+        }
+
+        let now = SystemTime::now();
+        recent.prune(now);
+        recent.push(entry.timestamp, entry.amount, entry.quote_hash, now);
+
+        Ok(true)
+    }
+
+    /// Returns all the entries of the ledger, oldest first, read from disk.
+    pub fn entries(&self) -> Result<Vec<RewardEntry>> {
+        if self.file.is_none() {
+            return Ok(vec![]);
+        }
+        read_entries(&self.path)
+    }
+
+    /// Returns the totals for each of the provided windows, counted back from now, followed by
+    /// the total of the whole ledger.
+    pub fn totals(&self, windows: &[Duration]) -> Result<Vec<RewardTotal>> {
+        let now = SystemTime::now();
+        let mut totals: Vec<RewardTotal> = windows
+            .iter()
+            .map(|window| RewardTotal::new(Some(*window)))
+            .collect();
+
+        let recent = self
+            .recent
+            .read()
+            .map_err(|_| Error::RewardsLedger("Ledger lock is poisoned".to_string()))?;
+
+        // Windows longer than the retention are only covered by the file.
+        let payments: Vec<(SystemTime, AttoTokens)> =
+            if self.file.is_some() && windows.iter().any(|w| *w > IN_MEMORY_RETENTION) {
+                read_entries(&self.path)?
+                    .into_iter()
+                    .map(|entry| (entry.timestamp, entry.amount))
+                    .collect()
+            } else {
+                recent
+                    .payments
+                    .iter()
+                    .map(|(timestamp, amount, _)| (*timestamp, *amount))
+                    .collect()
+            };
+
+        for (timestamp, amount) in payments {
+            // Entries from the future (clock adjustments) are treated as having just happened.
+            let age = now.duration_since(timestamp).unwrap_or_default();
+            for total in totals.iter_mut() {
+                if total.window.is_some_and(|window| age <= window) {
+                    total.add(amount);
+                }
+            }
+        }
+        totals.push(recent.overall);
+
+        Ok(totals)
+    }
+}
+
+/// Read the entries of the ledger file, skipping the ones that cannot be parsed.
+fn read_entries(path: &Path) -> Result<Vec<RewardEntry>> {
+    let file = File::open(path)
+        .map_err(|err| Error::RewardsLedger(format!("Failed to open {path:?}: {err}")))?;
+
+    let mut entries = vec![];
+    for (line_no, line) in BufReader::new(file).lines().enumerate() {
+        let line =
+            line.map_err(|err| Error::RewardsLedger(format!("Failed to read {path:?}: {err}")))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<RewardEntry>(&line) {
+            Ok(entry) => entries.push(entry),
+            Err(err) => {
+                warn!("Skipping corrupted rewards ledger entry at line {line_no}: {err}")
+            }
+        }
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use xor_name::XorName;
+
+    fn entry(quote_byte: u8, amount: u64, age: Duration) -> RewardEntry {
+        RewardEntry {
+            record_address: NetworkAddress::from_chunk_address(
+                ant_protocol::storage::ChunkAddress::new(XorName::random(&mut rand::thread_rng())),
+            ),
+            quote_hash: QuoteHash::from([quote_byte; 32]),
+            amount: AttoTokens::from_u64(amount),
+            payer: Some(RewardsAddress::repeat_byte(quote_byte)),
+            timestamp: SystemTime::now() - age,
+        }
+    }
+
+    #[test]
+    fn ledger_should_persist_and_reload_entries() -> eyre::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let ledger = RewardsLedger::open(dir.path())?;
+        assert!(ledger.record(entry(1, 10, Duration::ZERO))?);
+        assert!(ledger.record(entry(2, 20, Duration::ZERO))?);
+
+        let reloaded = RewardsLedger::open(dir.path())?;
+        assert_eq!(reloaded.entries()?, ledger.entries()?);
+        assert_eq!(reloaded.entries()?.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ledger_should_ignore_duplicate_quote_hashes() -> eyre::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let ledger = RewardsLedger::open(dir.path())?;
+        assert!(ledger.record(entry(1, 10, Duration::ZERO))?);
+        assert!(!ledger.record(entry(1, 10, Duration::ZERO))?);
+
+        let reloaded = RewardsLedger::open(dir.path())?;
+        assert!(!reloaded.record(entry(1, 10, Duration::ZERO))?);
+        assert_eq!(reloaded.entries()?.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn totals_should_be_grouped_by_window() -> eyre::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let ledger = RewardsLedger::open(dir.path())?;
+        let _ = ledger.record(entry(1, 1, Duration::from_secs(10)))?;
+        let _ = ledger.record(entry(2, 10, Duration::from_secs(2 * 3600)))?;
+        let _ = ledger.record(entry(3, 100, Duration::from_secs(2 * 86400)))?;
+
+        let totals = ledger.totals(&[Duration::from_secs(3600), Duration::from_secs(86400)])?;
+        assert_eq!(totals.len(), 3);
+
+        assert_eq!(totals[0].window, Some(Duration::from_secs(3600)));
+        assert_eq!(totals[0].amount, AttoTokens::from_u64(1));
+        assert_eq!(totals[0].payments, 1);
+
+        assert_eq!(totals[1].amount, AttoTokens::from_u64(11));
+        assert_eq!(totals[1].payments, 2);
+
+        assert_eq!(totals[2].window, None);
+        assert_eq!(totals[2].amount, AttoTokens::from_u64(111));
+        assert_eq!(totals[2].payments, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn old_entries_should_only_be_counted_from_disk() -> eyre::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let old = IN_MEMORY_RETENTION + Duration::from_secs(86400);
+
+        let ledger = RewardsLedger::open(dir.path())?;
+        let _ = ledger.record(entry(1, 1, Duration::ZERO))?;
+        let _ = ledger.record(entry(2, 10, old))?;
+        assert_eq!(ledger.recent.read().unwrap().payments.len(), 1);
+
+        let totals = ledger.totals(&[Duration::from_secs(3600), old * 2])?;
+        assert_eq!(totals[0].amount, AttoTokens::from_u64(1));
+        assert_eq!(totals[1].amount, AttoTokens::from_u64(11));
+        assert_eq!(totals[2].amount, AttoTokens::from_u64(11));
+
+        let reloaded = RewardsLedger::open(dir.path())?;
+        assert_eq!(reloaded.recent.read().unwrap().payments.len(), 1);
+        assert_eq!(reloaded.totals(&[])?[0].payments, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unusable_ledger_should_fall_back_to_memory() -> eyre::Result<()> {
+        let dir = tempfile::tempdir()?;
+        // A directory in place of the file cannot be opened for appending.
+        std::fs::create_dir(dir.path().join(REWARDS_LEDGER_FILENAME))?;
+
+        let ledger = RewardsLedger::open_or_in_memory(dir.path());
+        assert!(ledger.record(entry(1, 10, Duration::ZERO))?);
+        assert!(!ledger.record(entry(1, 10, Duration::ZERO))?);
+        assert_eq!(ledger.totals(&[])?[0].amount, AttoTokens::from_u64(10));
+
+        Ok(())
+    }
+}
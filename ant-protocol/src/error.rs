@@ -42,6 +42,9 @@ pub enum Error {
     /// The provided cypher text is invalid
     #[error("Provided cypher text is invalid")]
     ScratchpadCipherTextInvalid,
+    /// The Scratchpad is shared, but not with the key used to decrypt it
+    #[error("The Scratchpad is not shared with the provided key")]
+    ScratchpadNotSharedWithKey,
 
     // ---------- payment errors
     #[error("There was an error getting the storecost from kademlia store")]
@@ -58,6 +61,14 @@ pub enum Error {
         /// Key of the missing record
         key: Box<NetworkAddress>,
     },
+    /// The holder has used up its bandwidth budget, the record can be fetched again later.
+    #[error("Peer {holder:?} is throttling traffic, retry fetching Record {key:?} later")]
+    RecordThrottled {
+        /// Holder that being contacted
+        holder: Box<NetworkAddress>,
+        /// Key of the throttled record
+        key: Box<NetworkAddress>,
+    },
 
     // ---------- record errors
     // Could not Serialize/Deserialize RecordHeader from Record
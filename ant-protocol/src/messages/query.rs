@@ -71,6 +71,19 @@ pub enum Query {
         // For future econ usage,
         sign_result: bool,
     },
+    /// Retrieve a sample of the well-scored addresses from the peer's bootstrap cache.
+    ///
+    /// This should eventually lead to a [`GetBootstrapPeers`] response.
+    ///
+    /// [`GetBootstrapPeers`]: super::QueryResponse::GetBootstrapPeers
+    GetBootstrapPeers {
+        /// Sender of the query
+        requester: NetworkAddress,
+        /// The peer whose bootstrap cache is queried
+        holder: NetworkAddress,
+        /// Max number of addresses to be returned
+        max_addrs: usize,
+    },
 }
 
 impl Query {
@@ -84,6 +97,7 @@ impl Query {
             | Query::GetReplicatedRecord { key, .. }
             | Query::GetChunkExistenceProof { key, .. }
             | Query::GetClosestPeers { key, .. } => key.clone(),
+            Query::GetBootstrapPeers { holder, .. } => holder.clone(),
         }
     }
 }
@@ -131,6 +145,16 @@ impl std::fmt::Display for Query {
                     "Query::GetClosestPeers({key:?} {num_of_peers:?} {distance:?} {sign_result})"
                 )
             }
+            Query::GetBootstrapPeers {
+                requester,
+                holder,
+                max_addrs,
+            } => {
+                write!(
+                    f,
+                    "Query::GetBootstrapPeers({requester:?} {holder:?} {max_addrs})"
+                )
+            }
         }
     }
 }
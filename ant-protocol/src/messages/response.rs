@@ -66,6 +66,17 @@ pub enum QueryResponse {
         // Signature of signing the above (if requested), for future economic model usage.
         signature: Option<Vec<u8>>,
     },
+    // ===== GetBootstrapPeers =====
+    //
+    /// Response to [`GetBootstrapPeers`]
+    ///
+    /// [`GetBootstrapPeers`]: crate::messages::Query::GetBootstrapPeers
+    GetBootstrapPeers {
+        /// Address of the peer that shared its cache
+        holder: NetworkAddress,
+        /// The shared addresses, with their `success_count` and `failure_count`.
+        addrs: Vec<(Multiaddr, u32, u32)>,
+    },
 }
 
 // Debug implementation for QueryResponse, to avoid printing Vec<u8>
@@ -118,6 +129,13 @@ impl Debug for QueryResponse {
                     "GetClosestPeers target {target:?} close peers {addresses:?}"
                 )
             }
+            QueryResponse::GetBootstrapPeers { holder, addrs } => {
+                write!(
+                    f,
+                    "GetBootstrapPeers({} addrs from {holder:?})",
+                    addrs.len()
+                )
+            }
         }
     }
 }
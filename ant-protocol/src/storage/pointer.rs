@@ -116,6 +116,21 @@ impl Pointer {
         self.counter
     }
 
+    /// Whether this pointer should replace `other` when both are stored at the same address.
+    ///
+    /// The pointer with the higher counter wins. Two different pointers with the same counter are
+    /// ordered by the hash of their content, so that every node keeps the same one regardless of
+    /// the order they are received in.
+    pub fn supersedes(&self, other: &Pointer) -> bool {
+        let ordering_key = |pointer: &Pointer| {
+            (
+                pointer.counter,
+                XorName::from_content(&pointer.bytes_for_signature()),
+            )
+        };
+        ordering_key(self) > ordering_key(other)
+    }
+
     /// Verifies if the pointer has a valid signature
     pub fn verify_signature(&self) -> bool {
         let bytes = self.bytes_for_signature();
@@ -152,4 +167,28 @@ mod tests {
             Pointer::new_with_signature(owner_sk.public_key(), counter, target.clone(), sig);
         assert!(!wrong_pointer.verify_signature()); // Should be invalid with wrong signature
     }
+
+    #[test]
+    fn test_pointer_supersedes() {
+        let owner_sk = SecretKey::random();
+        let mut rng = thread_rng();
+        let target_a =
+            PointerTarget::GraphEntryAddress(GraphEntryAddress::new(XorName::random(&mut rng)));
+        let target_b =
+            PointerTarget::GraphEntryAddress(GraphEntryAddress::new(XorName::random(&mut rng)));
+
+        let older = Pointer::new(&owner_sk, 1, target_a.clone());
+        let newer = Pointer::new(&owner_sk, 2, target_a.clone());
+        assert!(newer.supersedes(&older));
+        assert!(!older.supersedes(&newer));
+        assert!(!newer.supersedes(&newer.clone()));
+
+        // With the same counter, exactly one of the two wins, whichever order they are compared in.
+        let competing_a = Pointer::new(&owner_sk, 3, target_a);
+        let competing_b = Pointer::new(&owner_sk, 3, target_b);
+        assert_ne!(
+            competing_a.supersedes(&competing_b),
+            competing_b.supersedes(&competing_a)
+        );
+    }
 }
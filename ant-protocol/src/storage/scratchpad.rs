@@ -15,6 +15,23 @@ use serde::{Deserialize, Serialize};
 
 use xor_name::XorName;
 
+/// Prefix of the encrypted data of a Scratchpad that is shared with several readers.
+const SHARED_PAYLOAD_PREFIX: &[u8] = b"SHARED-SCRATCHPAD-V1";
+
+/// The encrypted data of a Scratchpad that is shared with several readers.
+///
+/// The content is encrypted to a random content key, which is generated for each version of the
+/// Scratchpad. The content key is then encrypted to each of the readers, so that any of them can
+/// decrypt the content with their own secret key. Readers removed from the list cannot decrypt
+/// the versions written after their removal.
+#[derive(Serialize, Deserialize)]
+struct SharedPayload {
+    /// The content key, encrypted to each reader, along with the public key of the reader.
+    wrapped_keys: Vec<(PublicKey, Vec<u8>)>,
+    /// The content, encrypted to the public key of the content key.
+    encrypted_content: Vec<u8>,
+}
+
 /// Scratchpad, a mutable space for encrypted data on the Network
 #[derive(
     Hash, Eq, PartialEq, PartialOrd, Ord, Clone, custom_debug::Debug, Serialize, Deserialize,
@@ -35,6 +52,13 @@ pub struct Scratchpad {
     signature: Signature,
 }
 
+fn decrypt(sk: &SecretKey, encrypted: &[u8]) -> Result<Vec<u8>> {
+    let cipher =
+        Ciphertext::from_bytes(encrypted).map_err(|_| Error::ScratchpadCipherTextFailed)?;
+    sk.decrypt(&cipher)
+        .ok_or(Error::ScratchpadCipherTextInvalid)
+}
+
 impl Scratchpad {
     /// Max Scratchpad size is 4MB including the metadata
     pub const MAX_SIZE: usize = 4 * 1024 * 1024;
@@ -64,6 +88,33 @@ impl Scratchpad {
         }
     }
 
+    /// Creates a new instance of `Scratchpad` that can be read by each of the `readers` as well as
+    /// the owner. Encrypts the data, and signs all the elements.
+    pub fn new_shared(
+        owner: &SecretKey,
+        data_encoding: u64,
+        unencrypted_data: &Bytes,
+        counter: u64,
+        readers: &[PublicKey],
+    ) -> Self {
+        let pk = owner.public_key();
+        let encrypted_data = Self::encrypt_shared(&pk, readers, unencrypted_data);
+        let addr = ScratchpadAddress::new(pk);
+        let signature = owner.sign(Self::bytes_for_signature(
+            addr,
+            data_encoding,
+            &encrypted_data,
+            counter,
+        ));
+        Self {
+            address: addr,
+            encrypted_data,
+            data_encoding,
+            counter,
+            signature,
+        }
+    }
+
     /// Create a new Scratchpad without provding the secret key
     /// It is the caller's responsibility to ensure the signature is valid (signs [`Scratchpad::bytes_for_signature`]) and the data is encrypted
     /// It is recommended to use the [`Scratchpad::new`] method instead when possible
@@ -109,11 +160,16 @@ impl Scratchpad {
     }
 
     /// Updates the content and encrypts it, increments the counter, re-signs the scratchpad
+    ///
+    /// A shared Scratchpad stays shared with the same readers.
     pub fn update(&mut self, unencrypted_data: &Bytes, sk: &SecretKey) {
         self.counter += 1;
         let pk = self.owner();
         let address = ScratchpadAddress::new(*pk);
-        self.encrypted_data = Bytes::from(pk.encrypt(unencrypted_data).to_bytes());
+        self.encrypted_data = match self.shared_readers() {
+            Some(readers) => Self::encrypt_shared(pk, &readers, unencrypted_data),
+            None => Bytes::from(pk.encrypt(unencrypted_data).to_bytes()),
+        };
 
         let bytes_to_sign = Self::bytes_for_signature(
             address,
@@ -125,6 +181,24 @@ impl Scratchpad {
         debug_assert!(self.verify_signature(), "Must be valid after being signed. This is a bug, please report it by opening an issue on our github");
     }
 
+    /// Whether this Scratchpad should replace `other` when both are stored at the same address.
+    ///
+    /// The Scratchpad with the higher counter wins. Two different Scratchpads with the same counter
+    /// are ordered by the hash of their content, so that every node keeps the same one regardless
+    /// of the order they are received in.
+    pub fn supersedes(&self, other: &Scratchpad) -> bool {
+        let ordering_key = |pad: &Scratchpad| {
+            let signed_bytes = Self::bytes_for_signature(
+                pad.address,
+                pad.data_encoding,
+                &pad.encrypted_data,
+                pad.counter,
+            );
+            (pad.counter, XorName::from_content(&signed_bytes))
+        };
+        ordering_key(self) > ordering_key(other)
+    }
+
     /// Verifies that the Scratchpad signature is valid
     pub fn verify_signature(&self) -> bool {
         let signing_bytes = Self::bytes_for_signature(
@@ -142,13 +216,72 @@ impl Scratchpad {
     }
 
     /// Returns the encrypted_data, decrypted via the passed SecretKey
+    ///
+    /// For a shared Scratchpad, the key can be the owner's or any of the readers'.
     pub fn decrypt_data(&self, sk: &SecretKey) -> Result<Bytes> {
-        let cipher = Ciphertext::from_bytes(&self.encrypted_data)
-            .map_err(|_| Error::ScratchpadCipherTextFailed)?;
-        let bytes = sk
-            .decrypt(&cipher)
-            .ok_or(Error::ScratchpadCipherTextInvalid)?;
-        Ok(Bytes::from(bytes))
+        if let Some(payload) = self.shared_payload() {
+            let pk = sk.public_key();
+            let (_, wrapped_key) = payload
+                .wrapped_keys
+                .iter()
+                .find(|(reader, _)| *reader == pk)
+                .ok_or(Error::ScratchpadNotSharedWithKey)?;
+            let key_bytes: [u8; bls::SK_SIZE] = decrypt(sk, wrapped_key)?
+                .try_into()
+                .map_err(|_| Error::ScratchpadCipherTextInvalid)?;
+            let content_key =
+                SecretKey::from_bytes(key_bytes).map_err(|_| Error::ScratchpadCipherTextInvalid)?;
+            return Ok(Bytes::from(decrypt(
+                &content_key,
+                &payload.encrypted_content,
+            )?));
+        }
+        Ok(Bytes::from(decrypt(sk, &self.encrypted_data)?))
+    }
+
+    /// Returns the keys that can decrypt a shared Scratchpad, including the owner's, or `None` if
+    /// the Scratchpad can only be decrypted by its owner.
+    pub fn shared_readers(&self) -> Option<Vec<PublicKey>> {
+        self.shared_payload().map(|payload| {
+            payload
+                .wrapped_keys
+                .into_iter()
+                .map(|(reader, _)| reader)
+                .collect()
+        })
+    }
+
+    fn shared_payload(&self) -> Option<SharedPayload> {
+        let payload = self.encrypted_data.strip_prefix(SHARED_PAYLOAD_PREFIX)?;
+        rmp_serde::from_slice(payload).ok()
+    }
+
+    /// Encrypt the data to a fresh content key, and the content key to the owner and each reader.
+    fn encrypt_shared(owner: &PublicKey, readers: &[PublicKey], unencrypted_data: &Bytes) -> Bytes {
+        let content_key = SecretKey::random();
+        let key_bytes = content_key.to_bytes();
+
+        let mut recipients = vec![*owner];
+        for reader in readers {
+            if !recipients.contains(reader) {
+                recipients.push(*reader);
+            }
+        }
+        let payload = SharedPayload {
+            wrapped_keys: recipients
+                .into_iter()
+                .map(|reader| (reader, reader.encrypt(key_bytes).to_bytes()))
+                .collect(),
+            encrypted_content: content_key
+                .public_key()
+                .encrypt(unencrypted_data)
+                .to_bytes(),
+        };
+
+        let mut encrypted_data = SHARED_PAYLOAD_PREFIX.to_vec();
+        // Serialising a struct of plain vectors and keys cannot fail.
+        encrypted_data.extend(rmp_serde::to_vec(&payload).unwrap_or_default());
+        Bytes::from(encrypted_data)
     }
 
     /// Returns the encrypted_data hash
@@ -222,4 +355,56 @@ mod tests {
         let decrypted_data = scratchpad.decrypt_data(&sk).unwrap();
         assert_eq!(decrypted_data, raw_data);
     }
+
+    #[test]
+    fn test_shared_scratchpad_encryption() {
+        let owner = SecretKey::random();
+        let reader = SecretKey::random();
+        let outsider = SecretKey::random();
+        let raw_data = Bytes::from_static(b"data to be shared");
+
+        let mut scratchpad =
+            Scratchpad::new_shared(&owner, 42, &raw_data, 0, &[reader.public_key()]);
+        assert!(scratchpad.verify_signature());
+        assert_eq!(
+            scratchpad.shared_readers(),
+            Some(vec![owner.public_key(), reader.public_key()])
+        );
+        assert_eq!(scratchpad.decrypt_data(&owner), Ok(raw_data.clone()));
+        assert_eq!(scratchpad.decrypt_data(&reader), Ok(raw_data));
+        assert_eq!(
+            scratchpad.decrypt_data(&outsider),
+            Err(Error::ScratchpadNotSharedWithKey)
+        );
+
+        // Updating keeps the readers.
+        let raw_data2 = Bytes::from_static(b"data to be shared v2");
+        scratchpad.update(&raw_data2, &owner);
+        assert!(scratchpad.verify_signature());
+        assert_eq!(scratchpad.decrypt_data(&reader), Ok(raw_data2));
+
+        // A Scratchpad that is not shared has no readers.
+        let private_data = Bytes::from_static(b"private");
+        let private = Scratchpad::new(&owner, 42, &private_data, 0);
+        assert_eq!(private.shared_readers(), None);
+        assert_ne!(private.decrypt_data(&reader), Ok(private_data));
+    }
+
+    #[test]
+    fn test_scratchpad_supersedes() {
+        let sk = SecretKey::random();
+        let older = Scratchpad::new(&sk, 42, &Bytes::from_static(b"v1"), 1);
+        let newer = Scratchpad::new(&sk, 42, &Bytes::from_static(b"v2"), 2);
+        assert!(newer.supersedes(&older));
+        assert!(!older.supersedes(&newer));
+        assert!(!newer.supersedes(&newer.clone()));
+
+        // With the same counter, exactly one of the two wins, whichever order they are compared in.
+        let competing_a = Scratchpad::new(&sk, 42, &Bytes::from_static(b"a"), 3);
+        let competing_b = Scratchpad::new(&sk, 42, &Bytes::from_static(b"b"), 3);
+        assert_ne!(
+            competing_a.supersedes(&competing_b),
+            competing_b.supersedes(&competing_a)
+        );
+    }
 }
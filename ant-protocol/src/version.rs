@@ -9,11 +9,21 @@
 use lazy_static::lazy_static;
 use std::sync::RwLock;
 
+/// The revision of the messages exchanged between peers, on top of the crate version.
+///
+/// It has to be bumped whenever a message is added or changed within the same `A.B` version,
+/// so that peers only ever exchange messages that both sides can decode.
+pub const PROTOCOL_REVISION: u32 = 1;
+
 lazy_static! {
     /// The network_id is used to differentiate between different networks.
     /// The default is set to 1 and it represents the mainnet.
     pub static ref NETWORK_ID: RwLock<u8> = RwLock::new(1);
 
+    /// The fingerprint of the pre-shared key of a private network, if the node or client is
+    /// part of one.
+    pub static ref PNET_FINGERPRINT: RwLock<Option<String>> = RwLock::new(None);
+
     /// The node version used during Identify Behaviour.
     pub static ref IDENTIFY_NODE_VERSION_STR: RwLock<String> =
         RwLock::new(format!(
@@ -33,7 +43,7 @@ lazy_static! {
     /// The req/response protocol version
     pub static ref REQ_RESPONSE_VERSION_STR: RwLock<String> =
         RwLock::new(format!(
-            "/ant/{}/{}",
+            "/ant/{}r{PROTOCOL_REVISION}/{}",
             get_truncate_version_str(),
             *NETWORK_ID.read().expect("Failed to obtain read lock for NETWORK_ID"),
         ));
@@ -41,7 +51,7 @@ lazy_static! {
     /// The identify protocol version
     pub static ref IDENTIFY_PROTOCOL_STR: RwLock<String> =
         RwLock::new(format!(
-            "ant/{}/{}",
+            "ant/{}r{PROTOCOL_REVISION}/{}",
             get_truncate_version_str(),
             *NETWORK_ID.read().expect("Failed to obtain read lock for NETWORK_ID"),
         ));
@@ -71,6 +81,27 @@ pub fn get_network_id() -> String {
     )
 }
 
+/// Set the fingerprint of the pre-shared key used to join a private network.
+///
+/// This keeps state that is shared between networks, such as the bootstrap cache, apart from
+/// the public network. Like `set_network_id`, it should be called before starting the node or
+/// client.
+pub fn set_pnet_fingerprint(fingerprint: String) {
+    info!("Setting private network key fingerprint to: {fingerprint}");
+    let mut pnet_fingerprint = PNET_FINGERPRINT
+        .write()
+        .expect("Failed to obtain write lock for PNET_FINGERPRINT");
+    *pnet_fingerprint = Some(fingerprint);
+}
+
+/// Get the fingerprint of the pre-shared key of the private network, if any.
+pub fn get_pnet_fingerprint() -> Option<String> {
+    PNET_FINGERPRINT
+        .read()
+        .expect("Failed to obtain read lock for PNET_FINGERPRINT")
+        .clone()
+}
+
 // Protocol support shall be downward compatible for patch only version update.
 // i.e. versions of `A.B.X` or `A.B.X-alpha.Y` shall be considered as a same protocol of `A.B`
 pub fn get_truncate_version_str() -> String {
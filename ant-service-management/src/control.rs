@@ -6,10 +6,13 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-use crate::error::{Error, Result};
+use crate::{
+    error::{Error, Result},
+    resources::ResourceLimits,
+};
 use service_manager::{
     ServiceInstallCtx, ServiceLabel, ServiceLevel, ServiceManager, ServiceStartCtx, ServiceStopCtx,
-    ServiceUninstallCtx,
+    ServiceUninstallCtx, TypedServiceManager,
 };
 use std::{
     net::{SocketAddr, TcpListener},
@@ -29,6 +32,17 @@ pub trait ServiceControl: Sync {
     fn get_available_port(&self) -> Result<u16>;
     fn install(&self, install_ctx: ServiceInstallCtx, user_mode: bool) -> Result<()>;
     fn get_process_pid(&self, path: &Path) -> Result<u32>;
+    /// Apply the CPU, memory and open file limits to an installed service.
+    ///
+    /// The limits have to be applied again if the service is re-installed. The memory and open
+    /// file limits are also applied by `antnode` itself, so backends that can't enforce the
+    /// others leave the service unchanged and log a warning.
+    fn set_resource_limits(
+        &self,
+        service_name: &str,
+        limits: &ResourceLimits,
+        user_mode: bool,
+    ) -> Result<()>;
     fn start(&self, service_name: &str, user_mode: bool) -> Result<()>;
     fn stop(&self, service_name: &str, user_mode: bool) -> Result<()>;
     fn uninstall(&self, service_name: &str, user_mode: bool) -> Result<()>;
@@ -217,6 +231,34 @@ impl ServiceControl for ServiceController {
         Ok(())
     }
 
+    fn set_resource_limits(
+        &self,
+        service_name: &str,
+        limits: &ResourceLimits,
+        user_mode: bool,
+    ) -> Result<()> {
+        let manager = TypedServiceManager::native()
+            .inspect_err(|err| error!("Could not get native ServiceManage: {err:?}"))?;
+        if let TypedServiceManager::Systemd(_) = manager {
+            let label: ServiceLabel = service_name.parse()?;
+            let unit_dir = systemd_unit_dir(user_mode)?;
+            crate::resources::write_systemd_drop_in(&unit_dir, &label.to_script_name(), limits)
+                .inspect_err(|err| {
+                    error!("Failed to write the resource limits for {service_name}: {err:?}")
+                })?;
+            return systemctl_daemon_reload(user_mode);
+        }
+
+        // The memory and open file limits are passed to antnode, which applies them as rlimits.
+        if limits.cpu_quota.is_some() {
+            warn!("The service manager can't enforce a CPU quota for {service_name}");
+        }
+        if cfg!(not(unix)) && (limits.max_memory.is_some() || limits.max_open_files.is_some()) {
+            warn!("The memory and open file limits can't be enforced on this platform for {service_name}");
+        }
+        Ok(())
+    }
+
     fn start(&self, service_name: &str, user_mode: bool) -> Result<()> {
         debug!("Starting service: {service_name}");
         let label: ServiceLabel = service_name.parse()?;
@@ -261,8 +303,13 @@ impl ServiceControl for ServiceController {
                 .set_level(ServiceLevel::User)
                 .inspect_err(|err| error!("Could not set service to user mode: {err:?}"))?;
         }
-        match manager.uninstall(ServiceUninstallCtx { label }) {
-            Ok(()) => Ok(()),
+        match manager.uninstall(ServiceUninstallCtx {
+            label: label.clone(),
+        }) {
+            Ok(()) => {
+                remove_systemd_drop_ins(&label, user_mode);
+                Ok(())
+            }
             Err(err) => {
                 if std::io::ErrorKind::NotFound == err.kind() {
                     error!("Error while uninstall service, service file might have been removed manually: {service_name}");
@@ -294,3 +341,47 @@ impl ServiceControl for ServiceController {
         std::thread::sleep(std::time::Duration::from_millis(delay));
     }
 }
+
+fn systemd_unit_dir(user_mode: bool) -> Result<std::path::PathBuf> {
+    if user_mode {
+        Ok(service_manager::systemd_user_dir_path()?)
+    } else {
+        Ok(service_manager::systemd_global_dir_path())
+    }
+}
+
+fn systemctl_daemon_reload(user_mode: bool) -> Result<()> {
+    let mut command = std::process::Command::new("systemctl");
+    if user_mode {
+        let _ = command.arg("--user");
+    }
+    let output = command
+        .arg("daemon-reload")
+        .output()
+        .inspect_err(|err| error!("Failed to execute systemctl daemon-reload: {err:?}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        error!("systemctl daemon-reload failed: {stderr}");
+        return Err(Error::Io(std::io::Error::other(format!(
+            "systemctl daemon-reload failed: {stderr}"
+        ))));
+    }
+    Ok(())
+}
+
+/// Remove any drop-in files left behind for a systemd unit, so they don't apply to a new service
+/// that is later installed with the same name.
+fn remove_systemd_drop_ins(label: &ServiceLabel, user_mode: bool) {
+    let Ok(TypedServiceManager::Systemd(_)) = TypedServiceManager::native() else {
+        return;
+    };
+    let Ok(unit_dir) = systemd_unit_dir(user_mode) else {
+        return;
+    };
+    let drop_in_dir = crate::resources::systemd_drop_in_dir(&unit_dir, &label.to_script_name());
+    if drop_in_dir.exists() {
+        if let Err(err) = std::fs::remove_dir_all(&drop_in_dir) {
+            warn!("Failed to remove the drop-in directory at {drop_in_dir:?}: {err:?}");
+        }
+    }
+}
@@ -9,6 +9,7 @@
 use crate::{
     control::ServiceControl,
     error::{Error, Result},
+    resources::ResourceLimits,
     ServiceStateActions, ServiceStatus, UpgradeOptions,
 };
 use async_trait::async_trait;
@@ -97,6 +98,10 @@ impl ServiceStateActions for DaemonService<'_> {
         self.service_data.pid
     }
 
+    fn resource_limits(&self) -> ResourceLimits {
+        ResourceLimits::default()
+    }
+
     fn on_remove(&mut self) {
         self.service_data.status = ServiceStatus::Removed;
     }
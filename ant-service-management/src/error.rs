@@ -42,6 +42,8 @@ pub enum Error {
     RpcNodeUpdateError(String),
     #[error("Could not obtain record addresses through RPC: {0}")]
     RpcRecordAddressError(String),
+    #[error("Could not obtain the rewards ledger through RPC: {0}")]
+    RpcRewardsLedgerError(String),
     #[error("Could not find process at '{0}'")]
     ServiceProcessNotFound(String),
     #[error("The service '{0}' does not exists and cannot be removed.")]
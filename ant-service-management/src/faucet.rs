@@ -7,7 +7,8 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::{
-    control::ServiceControl, error::Result, ServiceStateActions, ServiceStatus, UpgradeOptions,
+    control::ServiceControl, error::Result, resources::ResourceLimits, ServiceStateActions,
+    ServiceStatus, UpgradeOptions,
 };
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -90,6 +91,10 @@ impl ServiceStateActions for FaucetService<'_> {
         self.service_data.pid
     }
 
+    fn resource_limits(&self) -> ResourceLimits {
+        ResourceLimits::default()
+    }
+
     fn on_remove(&mut self) {
         self.service_data.status = ServiceStatus::Removed;
     }
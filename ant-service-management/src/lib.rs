@@ -12,7 +12,9 @@ pub mod daemon;
 pub mod error;
 pub mod faucet;
 pub mod node;
+pub mod resources;
 pub mod rpc;
+pub mod supervisor;
 
 #[macro_use]
 extern crate tracing;
@@ -23,6 +25,7 @@ pub mod antctl_proto {
 
 use async_trait::async_trait;
 use auditor::AuditorServiceData;
+use resources::ResourceLimits;
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use service_manager::ServiceInstallCtx;
@@ -59,6 +62,8 @@ pub enum NatDetectionStatus {
 pub enum UpgradeResult {
     Forced(String, String),
     NotRequired,
+    /// The upgraded service failed its health check and was rolled back to the previous version.
+    RolledBack(String, String, String),
     Upgraded(String, String),
     UpgradedButNotStarted(String, String, String),
     Error(String),
@@ -83,6 +88,7 @@ pub trait ServiceStateActions {
     fn log_dir_path(&self) -> PathBuf;
     fn name(&self) -> String;
     fn pid(&self) -> Option<u32>;
+    fn resource_limits(&self) -> ResourceLimits;
     fn on_remove(&mut self);
     async fn on_start(&mut self, pid: Option<u32>, full_refresh: bool) -> Result<()>;
     async fn on_stop(&mut self) -> Result<()>;
@@ -6,7 +6,10 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
-use crate::{error::Result, rpc::RpcActions, ServiceStateActions, ServiceStatus, UpgradeOptions};
+use crate::{
+    error::Result, resources::ResourceLimits, rpc::RpcActions, supervisor::SupervisorBackend,
+    ServiceStateActions, ServiceStatus, UpgradeOptions,
+};
 use ant_bootstrap::PeersArgs;
 use ant_evm::{AttoTokens, EvmNetwork, RewardsAddress};
 use ant_logging::LogFormat;
@@ -109,6 +112,28 @@ impl ServiceStateActions for NodeService<'_> {
             args.push(OsString::from("--max-log-files"));
             args.push(OsString::from(max_log_files.to_string()));
         }
+        if let Some(max_replication_bandwidth) =
+            self.service_data.resource_limits.max_replication_bandwidth
+        {
+            args.push(OsString::from("--max-replication-bandwidth"));
+            args.push(OsString::from(max_replication_bandwidth.to_string()));
+        }
+        if let Some(max_client_bandwidth) = self.service_data.resource_limits.max_client_bandwidth {
+            args.push(OsString::from("--max-client-bandwidth"));
+            args.push(OsString::from(max_client_bandwidth.to_string()));
+        }
+        if let Some(storage_quota) = self.service_data.resource_limits.storage_quota {
+            args.push(OsString::from("--max-storage"));
+            args.push(OsString::from(storage_quota.to_string()));
+        }
+        if let Some(max_memory) = self.service_data.resource_limits.max_memory {
+            args.push(OsString::from("--max-memory"));
+            args.push(OsString::from(max_memory.to_string()));
+        }
+        if let Some(max_open_files) = self.service_data.resource_limits.max_open_files {
+            args.push(OsString::from("--max-open-files"));
+            args.push(OsString::from(max_open_files.to_string()));
+        }
 
         args.push(OsString::from("--rewards-address"));
         args.push(OsString::from(
@@ -161,6 +186,10 @@ impl ServiceStateActions for NodeService<'_> {
         self.service_data.pid
     }
 
+    fn resource_limits(&self) -> ResourceLimits {
+        self.service_data.resource_limits.clone()
+    }
+
     fn on_remove(&mut self) {
         self.service_data.status = ServiceStatus::Removed;
     }
@@ -297,11 +326,15 @@ pub struct NodeServiceData {
     pub peers_args: PeersArgs,
     pub pid: Option<u32>,
     #[serde(default)]
+    pub resource_limits: ResourceLimits,
+    #[serde(default)]
     pub rewards_address: RewardsAddress,
     pub reward_balance: Option<AttoTokens>,
     pub rpc_socket_addr: SocketAddr,
     pub service_name: String,
     pub status: ServiceStatus,
+    #[serde(default)]
+    pub supervisor_backend: SupervisorBackend,
     #[serde(default = "default_upnp")]
     pub upnp: bool,
     pub user: Option<String>,
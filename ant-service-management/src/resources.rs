@@ -0,0 +1,230 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Limits on the resources each node service can consume.
+//!
+//! The storage quota and the bandwidth limits are passed to `antnode`, which enforces them itself.
+//! The memory and open file limits are also passed to `antnode`, which applies them to itself as
+//! rlimits on startup, so they hold whatever the service backend. With systemd, all the process
+//! limits are also applied as resource directives in a drop-in file, which is the only way the CPU
+//! quota is enforced.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// The name of the drop-in file holding the systemd resource directives for a service.
+const SYSTEMD_DROP_IN_FILE_NAME: &str = "resource-limits.conf";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceLimits {
+    /// The share of CPU time the process can use, as a percentage of a single core.
+    ///
+    /// For example, 50 allows half a core and 200 allows two full cores.
+    pub cpu_quota: Option<u32>,
+    /// The max memory the process can use, in bytes.
+    pub max_memory: Option<u64>,
+    /// The max number of file descriptors the process can have open.
+    pub max_open_files: Option<u64>,
+    /// The max space the node can use to store records, in bytes.
+    pub storage_quota: Option<u64>,
+    /// The max bandwidth used to send records to clients fetching them directly, in bytes per
+    /// second.
+    pub max_client_bandwidth: Option<u64>,
+    /// The max bandwidth used by replication between nodes, in bytes per second.
+    pub max_replication_bandwidth: Option<u64>,
+}
+
+impl ResourceLimits {
+    pub fn is_empty(&self) -> bool {
+        *self == ResourceLimits::default()
+    }
+
+    /// Whether any of the limits have to be applied to the process by the service backend.
+    ///
+    /// The storage quota and the bandwidth limits are enforced by the node itself.
+    pub fn has_process_limits(&self) -> bool {
+        self.cpu_quota.is_some() || self.max_memory.is_some() || self.max_open_files.is_some()
+    }
+
+    /// Render the limits as the contents of a systemd drop-in file.
+    pub fn to_systemd_drop_in(&self) -> String {
+        let mut contents = String::from("[Service]\n");
+        if let Some(cpu_quota) = self.cpu_quota {
+            contents.push_str(&format!("CPUQuota={cpu_quota}%\n"));
+        }
+        if let Some(max_memory) = self.max_memory {
+            contents.push_str(&format!("MemoryMax={max_memory}\n"));
+        }
+        if let Some(max_open_files) = self.max_open_files {
+            contents.push_str(&format!("LimitNOFILE={max_open_files}\n"));
+        }
+        contents
+    }
+}
+
+/// Apply the memory and open file limits to the current process.
+///
+/// The memory limit is applied to the data segment, which covers the heap and other private
+/// memory. Limiting the whole address space would count the large regions the allocator reserves
+/// but never uses.
+#[cfg(unix)]
+pub fn apply_rlimits(limits: &ResourceLimits) -> std::io::Result<()> {
+    use nix::sys::resource::{setrlimit, Resource};
+
+    if let Some(max_memory) = limits.max_memory {
+        setrlimit(Resource::RLIMIT_DATA, max_memory, max_memory)?;
+    }
+    if let Some(max_open_files) = limits.max_open_files {
+        setrlimit(Resource::RLIMIT_NOFILE, max_open_files, max_open_files)?;
+    }
+    Ok(())
+}
+
+impl std::fmt::Display for ResourceLimits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(cpu_quota) = self.cpu_quota {
+            parts.push(format!("CPU {cpu_quota}%"));
+        }
+        if let Some(max_memory) = self.max_memory {
+            parts.push(format!("memory {}", format_bytes(max_memory)));
+        }
+        if let Some(max_open_files) = self.max_open_files {
+            parts.push(format!("open files {max_open_files}"));
+        }
+        if let Some(storage_quota) = self.storage_quota {
+            parts.push(format!("storage {}", format_bytes(storage_quota)));
+        }
+        if let Some(max_client_bandwidth) = self.max_client_bandwidth {
+            parts.push(format!("client {}/s", format_bytes(max_client_bandwidth)));
+        }
+        if let Some(max_replication_bandwidth) = self.max_replication_bandwidth {
+            parts.push(format!(
+                "replication {}/s",
+                format_bytes(max_replication_bandwidth)
+            ));
+        }
+        if parts.is_empty() {
+            write!(f, "-")
+        } else {
+            write!(f, "{}", parts.join(", "))
+        }
+    }
+}
+
+/// Parse a size in bytes, with an optional binary unit suffix, e.g., `512M` or `2GiB`.
+pub fn parse_byte_size(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    let split_at = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (number, unit) = s.split_at(split_at);
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("'{s}' is not a valid size"))?;
+    let multiplier: u64 = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "K" | "KB" | "KIB" => 1024,
+        "M" | "MB" | "MIB" => 1024 * 1024,
+        "G" | "GB" | "GIB" => 1024 * 1024 * 1024,
+        "T" | "TB" | "TIB" => 1024 * 1024 * 1024 * 1024,
+        _ => {
+            return Err(format!(
+                "'{unit}' is not a valid size unit; use K, M, G or T"
+            ))
+        }
+    };
+    number
+        .checked_mul(multiplier)
+        .ok_or_else(|| format!("'{s}' is too large"))
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["TiB", "GiB", "MiB", "KiB"];
+    for (i, unit) in UNITS.iter().enumerate() {
+        let size = 1u64 << (10 * (UNITS.len() - i));
+        if bytes >= size && bytes.is_multiple_of(size) {
+            return format!("{}{unit}", bytes / size);
+        }
+    }
+    format!("{bytes}B")
+}
+
+/// Get the directory for the drop-in files of a systemd unit.
+pub fn systemd_drop_in_dir(unit_dir: &Path, service_name: &str) -> PathBuf {
+    unit_dir.join(format!("{service_name}.service.d"))
+}
+
+/// Write the resource directives for a service to a drop-in file for its systemd unit.
+///
+/// Any existing drop-in is removed if there are no limits to apply.
+pub fn write_systemd_drop_in(
+    unit_dir: &Path,
+    service_name: &str,
+    limits: &ResourceLimits,
+) -> std::io::Result<()> {
+    let drop_in_dir = systemd_drop_in_dir(unit_dir, service_name);
+    let drop_in_path = drop_in_dir.join(SYSTEMD_DROP_IN_FILE_NAME);
+    if !limits.has_process_limits() {
+        if drop_in_path.exists() {
+            std::fs::remove_file(&drop_in_path)?;
+        }
+        return Ok(());
+    }
+    std::fs::create_dir_all(&drop_in_dir)?;
+    std::fs::write(&drop_in_path, limits.to_systemd_drop_in())?;
+    debug!("Wrote the resource limits for {service_name} to {drop_in_path:?}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_fs::{prelude::*, TempDir};
+
+    #[test]
+    fn parse_byte_size_should_accept_units() {
+        assert_eq!(parse_byte_size("1024"), Ok(1024));
+        assert_eq!(parse_byte_size("512M"), Ok(512 * 1024 * 1024));
+        assert_eq!(parse_byte_size("2GiB"), Ok(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_byte_size("1t"), Ok(1024 * 1024 * 1024 * 1024));
+        assert!(parse_byte_size("2X").is_err());
+        assert!(parse_byte_size("G").is_err());
+        assert!(parse_byte_size("99999999999T").is_err());
+    }
+
+    #[test]
+    fn drop_in_should_contain_only_the_process_limits() -> Result<(), Box<dyn std::error::Error>> {
+        let tmp_dir = TempDir::new()?;
+        let limits = ResourceLimits {
+            cpu_quota: Some(150),
+            max_memory: Some(2 * 1024 * 1024 * 1024),
+            max_open_files: None,
+            storage_quota: Some(10 * 1024 * 1024 * 1024),
+            max_client_bandwidth: Some(1024 * 1024),
+            max_replication_bandwidth: None,
+        };
+        write_systemd_drop_in(tmp_dir.path(), "antnode1", &limits)?;
+
+        let drop_in = tmp_dir
+            .child("antnode1.service.d")
+            .child(SYSTEMD_DROP_IN_FILE_NAME);
+        drop_in.assert("[Service]\nCPUQuota=150%\nMemoryMax=2147483648\n");
+        assert_eq!(
+            limits.to_string(),
+            "CPU 150%, memory 2GiB, storage 10GiB, client 1MiB/s".to_string()
+        );
+
+        let storage_only = ResourceLimits {
+            storage_quota: Some(1024),
+            max_replication_bandwidth: Some(1024),
+            ..Default::default()
+        };
+        write_systemd_drop_in(tmp_dir.path(), "antnode1", &storage_only)?;
+        assert!(!drop_in.path().exists());
+        Ok(())
+    }
+}
@@ -7,10 +7,12 @@
 // permissions and limitations relating to use of the SAFE Network Software.
 
 use crate::error::{Error, Result};
+use ant_evm::{Amount, AttoTokens};
 use ant_protocol::{
     antnode_proto::{
         ant_node_client::AntNodeClient, NetworkInfoRequest, NodeInfoRequest,
-        RecordAddressesRequest, RestartRequest, StopRequest, UpdateLogLevelRequest, UpdateRequest,
+        RecordAddressesRequest, RestartRequest, RewardsLedgerRequest, StopRequest,
+        UpdateLogLevelRequest, UpdateRequest,
     },
     CLOSE_GROUP_SIZE,
 };
@@ -43,11 +45,20 @@ pub struct RecordAddress {
     pub key: RecordKey,
 }
 
+#[derive(Debug, Clone)]
+pub struct RewardTotal {
+    /// The time window, counted back from now. `None` covers the whole ledger.
+    pub window: Option<Duration>,
+    pub amount: AttoTokens,
+    pub payments: u64,
+}
+
 #[async_trait]
 pub trait RpcActions: Sync {
     async fn node_info(&self) -> Result<NodeInfo>;
     async fn network_info(&self) -> Result<NetworkInfo>;
     async fn record_addresses(&self) -> Result<Vec<RecordAddress>>;
+    async fn rewards_ledger(&self, windows: Vec<Duration>) -> Result<Vec<RewardTotal>>;
     async fn node_restart(&self, delay_millis: u64, retain_peer_id: bool) -> Result<()>;
     async fn node_stop(&self, delay_millis: u64) -> Result<()>;
     async fn node_update(&self, delay_millis: u64) -> Result<()>;
@@ -188,6 +199,41 @@ impl RpcActions for RpcClient {
         Ok(record_addresses)
     }
 
+    async fn rewards_ledger(&self, windows: Vec<Duration>) -> Result<Vec<RewardTotal>> {
+        let mut client = self.connect_with_retry().await?;
+        let response = client
+            .rewards_ledger(Request::new(RewardsLedgerRequest {
+                window_secs: windows.iter().map(|window| window.as_secs()).collect(),
+            }))
+            .await
+            .map_err(|e| {
+                error!("Could not obtain the rewards ledger through RPC: {e:?}");
+                Error::RpcRewardsLedgerError(e.to_string())
+            })?;
+        let response = response.get_ref();
+
+        let parse_amount = |amount: &str| {
+            Amount::from_str(amount)
+                .map(AttoTokens::from_atto)
+                .map_err(|e| Error::RpcRewardsLedgerError(e.to_string()))
+        };
+
+        let mut totals = vec![];
+        for total in response.window_totals.iter() {
+            totals.push(RewardTotal {
+                window: Some(Duration::from_secs(total.window_secs)),
+                amount: parse_amount(&total.amount)?,
+                payments: total.payments,
+            });
+        }
+        totals.push(RewardTotal {
+            window: None,
+            amount: parse_amount(&response.total_amount)?,
+            payments: response.total_payments,
+        });
+        Ok(totals)
+    }
+
     async fn node_restart(&self, delay_millis: u64, retain_peer_id: bool) -> Result<()> {
         let mut client = self.connect_with_retry().await?;
         let _response = client
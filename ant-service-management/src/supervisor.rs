@@ -0,0 +1,854 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! A built-in process supervisor that can be used in place of the OS service manager.
+//!
+//! Containers and other minimal environments often have no systemd, launchd or OpenRC available.
+//! In those cases the [`BuiltinServiceController`] records each service definition under a state
+//! directory and launches the processes itself, while a long running [`Supervisor`] watches them
+//! and restarts any that exit unexpectedly.
+//!
+//! Each service gets its own directory underneath the state directory:
+//!
+//! ```text
+//! <state_dir>/
+//!   supervisor.pid
+//!   <service_name>/
+//!     service.json
+//!     pid
+//!     stdout.log
+//!     stderr.log
+//! ```
+//!
+//! The PID files hold the PID on the first line and the path of the executable on the second, so
+//! a PID that has been reused by an unrelated process after a reboot is not mistaken for the
+//! service.
+
+use crate::{
+    control::ServiceControl,
+    error::{Error, Result},
+    resources::ResourceLimits,
+};
+use serde::{Deserialize, Serialize};
+use service_manager::ServiceInstallCtx;
+use std::{
+    collections::HashMap,
+    fs::{File, OpenOptions},
+    net::{SocketAddr, TcpListener},
+    path::{Path, PathBuf},
+    process::{Child, Command, Stdio},
+    time::{Duration, Instant},
+};
+use sysinfo::{Pid, ProcessStatus, Signal, System};
+
+const PID_FILE_NAME: &str = "pid";
+const SERVICE_FILE_NAME: &str = "service.json";
+const STDERR_LOG_FILE_NAME: &str = "stderr.log";
+const STDOUT_LOG_FILE_NAME: &str = "stdout.log";
+const SUPERVISOR_PID_FILE_NAME: &str = "supervisor.pid";
+/// How long a process is given to exit after receiving SIGTERM before it is killed.
+const STOP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The mechanism used to run and supervise the node processes.
+///
+/// It is recorded for each node service, so later commands use the backend the service was added
+/// with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SupervisorBackend {
+    /// Use the OS service manager, e.g., systemd, launchd, OpenRC or WinSW.
+    #[default]
+    Os,
+    /// Use the built-in process supervisor, for environments without a service manager.
+    Builtin,
+}
+
+impl std::fmt::Display for SupervisorBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SupervisorBackend::Os => write!(f, "os"),
+            SupervisorBackend::Builtin => write!(f, "builtin"),
+        }
+    }
+}
+
+impl std::str::FromStr for SupervisorBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "os" => Ok(SupervisorBackend::Os),
+            "builtin" => Ok(SupervisorBackend::Builtin),
+            _ => Err(format!(
+                "Unknown supervisor backend '{s}'. Valid values are 'os' or 'builtin'"
+            )),
+        }
+    }
+}
+
+/// The definition of a service managed by the built-in supervisor.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BuiltinServiceSpec {
+    pub name: String,
+    pub program: PathBuf,
+    pub args: Vec<String>,
+    pub autostart: bool,
+    pub environment: Vec<(String, String)>,
+    pub working_directory: Option<PathBuf>,
+    /// Whether the service should currently be running.
+    ///
+    /// The supervisor only restarts services that are meant to be running, so this is cleared
+    /// when a service is explicitly stopped.
+    pub running: bool,
+    /// Applied as rlimits when the process is launched.
+    #[serde(default)]
+    pub resource_limits: ResourceLimits,
+}
+
+impl BuiltinServiceSpec {
+    pub fn load(service_dir: &Path) -> Result<Self> {
+        let file = File::open(service_dir.join(SERVICE_FILE_NAME))?;
+        Ok(serde_json::from_reader(file)?)
+    }
+
+    pub fn save(&self, service_dir: &Path) -> Result<()> {
+        std::fs::create_dir_all(service_dir)?;
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(service_dir.join(SERVICE_FILE_NAME), json)?;
+        Ok(())
+    }
+}
+
+impl From<ServiceInstallCtx> for BuiltinServiceSpec {
+    fn from(ctx: ServiceInstallCtx) -> Self {
+        BuiltinServiceSpec {
+            name: ctx.label.to_string(),
+            program: ctx.program,
+            args: ctx
+                .args
+                .iter()
+                .map(|arg| arg.to_string_lossy().to_string())
+                .collect(),
+            autostart: ctx.autostart,
+            environment: ctx.environment.unwrap_or_default(),
+            working_directory: ctx.working_directory,
+            running: false,
+            resource_limits: ResourceLimits::default(),
+        }
+    }
+}
+
+/// A `ServiceControl` implementation that manages processes directly rather than through the OS
+/// service manager.
+///
+/// The `user_mode` arguments are ignored: processes always run as the user invoking the
+/// controller, and the location of the state directory decides whether the services are shared.
+pub struct BuiltinServiceController {
+    pub state_dir: PathBuf,
+}
+
+impl BuiltinServiceController {
+    pub fn new(state_dir: PathBuf) -> Self {
+        BuiltinServiceController { state_dir }
+    }
+
+    pub fn service_dir(&self, service_name: &str) -> PathBuf {
+        self.state_dir.join(service_name)
+    }
+
+    /// Get the paths of the log files that capture the stdout and stderr of a service.
+    pub fn log_paths(&self, service_name: &str) -> (PathBuf, PathBuf) {
+        let service_dir = self.service_dir(service_name);
+        (
+            service_dir.join(STDOUT_LOG_FILE_NAME),
+            service_dir.join(STDERR_LOG_FILE_NAME),
+        )
+    }
+
+    fn load_spec(&self, service_name: &str) -> Result<BuiltinServiceSpec> {
+        let service_dir = self.service_dir(service_name);
+        if !service_dir.join(SERVICE_FILE_NAME).exists() {
+            error!("The builtin supervisor has no service named {service_name}");
+            return Err(Error::ServiceDoesNotExists(service_name.to_string()));
+        }
+        BuiltinServiceSpec::load(&service_dir)
+    }
+}
+
+impl ServiceControl for BuiltinServiceController {
+    fn create_service_user(&self, username: &str) -> Result<()> {
+        debug!("The builtin supervisor runs services as the current user; ignoring {username}");
+        Ok(())
+    }
+
+    fn get_available_port(&self) -> Result<u16> {
+        let addr: SocketAddr = "127.0.0.1:0".parse()?;
+
+        let socket = TcpListener::bind(addr)?;
+        let port = socket.local_addr()?.port();
+        drop(socket);
+        trace!("Got available port: {port}");
+
+        Ok(port)
+    }
+
+    fn get_process_pid(&self, bin_path: &Path) -> Result<u32> {
+        debug!(
+            "Searching builtin services for a process with binary at {}",
+            bin_path.to_string_lossy()
+        );
+        for (service_dir, spec) in list_services(&self.state_dir)? {
+            if spec.program != bin_path {
+                continue;
+            }
+            if let Some(pid) = read_live_pid(&service_dir.join(PID_FILE_NAME)) {
+                trace!("Found process {bin_path:?} with PID: {pid}");
+                return Ok(pid);
+            }
+        }
+        error!(
+            "No builtin service process was located with a path at {}",
+            bin_path.to_string_lossy()
+        );
+        Err(Error::ServiceProcessNotFound(
+            bin_path.to_string_lossy().to_string(),
+        ))
+    }
+
+    fn install(&self, install_ctx: ServiceInstallCtx, _user_mode: bool) -> Result<()> {
+        debug!("Installing builtin service: {install_ctx:?}");
+        let spec = BuiltinServiceSpec::from(install_ctx);
+        spec.save(&self.service_dir(&spec.name))
+            .inspect_err(|err| error!("Error while installing builtin service: {err:?}"))?;
+        Ok(())
+    }
+
+    fn set_resource_limits(
+        &self,
+        service_name: &str,
+        limits: &ResourceLimits,
+        _user_mode: bool,
+    ) -> Result<()> {
+        debug!("Setting resource limits for builtin service {service_name}: {limits:?}");
+        let mut spec = self.load_spec(service_name)?;
+        spec.resource_limits = limits.clone();
+        spec.save(&self.service_dir(service_name))?;
+
+        if cfg!(not(unix)) && limits.has_process_limits() {
+            warn!("The builtin supervisor can't enforce resource limits on this platform for {service_name}");
+        } else if limits.cpu_quota.is_some() {
+            warn!("The builtin supervisor can't enforce a CPU quota for {service_name}");
+        }
+        Ok(())
+    }
+
+    fn start(&self, service_name: &str, _user_mode: bool) -> Result<()> {
+        debug!("Starting builtin service: {service_name}");
+        let service_dir = self.service_dir(service_name);
+        let mut spec = self.load_spec(service_name)?;
+
+        let already_running = read_live_pid(&service_dir.join(PID_FILE_NAME)).is_some();
+        if !already_running {
+            let child = spawn_service(&spec, &service_dir)
+                .inspect_err(|err| error!("Error while starting builtin service: {err:?}"))?;
+            reap_in_background(child);
+        }
+
+        spec.running = true;
+        spec.save(&service_dir)?;
+        Ok(())
+    }
+
+    fn stop(&self, service_name: &str, _user_mode: bool) -> Result<()> {
+        debug!("Stopping builtin service: {service_name}");
+        let service_dir = self.service_dir(service_name);
+        let mut spec = self.load_spec(service_name)?;
+
+        // Clear the flag before killing the process, so the supervisor doesn't restart it.
+        spec.running = false;
+        spec.save(&service_dir)?;
+
+        let pid_path = service_dir.join(PID_FILE_NAME);
+        if let Some(pid) = read_live_pid(&pid_path) {
+            terminate_process(pid, STOP_TIMEOUT);
+        }
+        if pid_path.exists() {
+            std::fs::remove_file(&pid_path)?;
+        }
+        Ok(())
+    }
+
+    fn uninstall(&self, service_name: &str, user_mode: bool) -> Result<()> {
+        debug!("Uninstalling builtin service: {service_name}");
+        let service_dir = self.service_dir(service_name);
+        if !service_dir.exists() {
+            error!("Error while uninstalling builtin service, it does not exist: {service_name}");
+            return Err(Error::ServiceDoesNotExists(service_name.to_string()));
+        }
+        if service_dir.join(SERVICE_FILE_NAME).exists() {
+            self.stop(service_name, user_mode)?;
+        }
+        std::fs::remove_dir_all(&service_dir)?;
+        Ok(())
+    }
+
+    /// Provide a delay for the service to start or stop.
+    ///
+    /// This is wrapped mainly just for unit testing.
+    fn wait(&self, delay: u64) {
+        trace!("Waiting for {delay} milliseconds");
+        std::thread::sleep(Duration::from_millis(delay));
+    }
+}
+
+/// Exponential backoff applied when restarting a service that has exited.
+#[derive(Clone, Debug)]
+pub struct RestartBackoff {
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    /// A process that stays up for this long has its failure count reset.
+    pub stable_period: Duration,
+}
+
+impl Default for RestartBackoff {
+    fn default() -> Self {
+        RestartBackoff {
+            initial_delay: Duration::from_secs(2),
+            max_delay: Duration::from_secs(300),
+            stable_period: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RestartBackoff {
+    /// The delay before the next restart, given the number of consecutive failures so far.
+    pub fn delay(&self, failures: u32) -> Duration {
+        let factor = 2u32.saturating_pow(failures);
+        self.initial_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+}
+
+#[derive(Default)]
+struct RestartState {
+    child: Option<Child>,
+    failures: u32,
+    next_restart: Option<Instant>,
+    started_at: Option<Instant>,
+}
+
+/// Monitors the services defined under a state directory and restarts them when they exit.
+///
+/// Only one supervisor should run against a given state directory. It is intended to run in the
+/// foreground of a long running process, e.g., as the entrypoint of a container.
+pub struct Supervisor {
+    pub backoff: RestartBackoff,
+    pub state_dir: PathBuf,
+    services: HashMap<String, RestartState>,
+    /// Children whose services were stopped or removed, kept until they have exited and been
+    /// reaped.
+    exited: Vec<Child>,
+}
+
+impl Supervisor {
+    pub fn new(state_dir: PathBuf, backoff: RestartBackoff) -> Self {
+        Supervisor {
+            backoff,
+            state_dir,
+            services: HashMap::new(),
+            exited: Vec::new(),
+        }
+    }
+
+    /// Run the supervisor until the process is terminated.
+    ///
+    /// Any services marked for autostart are started first.
+    pub fn run(&mut self, poll_interval: Duration) -> Result<()> {
+        std::fs::create_dir_all(&self.state_dir)?;
+        write_pid_file(
+            &self.state_dir.join(SUPERVISOR_PID_FILE_NAME),
+            std::process::id(),
+            &std::env::current_exe()?,
+        )?;
+        info!("Supervising services under {:?}", self.state_dir);
+
+        for (service_dir, mut spec) in list_services(&self.state_dir)? {
+            if spec.autostart && !spec.running {
+                info!("Autostarting service {}", spec.name);
+                spec.running = true;
+                spec.save(&service_dir)?;
+            }
+        }
+
+        loop {
+            if let Err(err) = self.supervise_once() {
+                error!("Error while supervising services: {err:?}");
+            }
+            std::thread::sleep(poll_interval);
+        }
+    }
+
+    /// Check every service once, scheduling or performing restarts as required.
+    pub fn supervise_once(&mut self) -> Result<()> {
+        let now = Instant::now();
+        let services = list_services(&self.state_dir)?;
+
+        // Keep hold of any children we launched whose services have since been stopped or
+        // removed, and reap them once they have exited.
+        let exited = &mut self.exited;
+        self.services.retain(|name, state| {
+            let wanted = services
+                .iter()
+                .any(|(_, spec)| &spec.name == name && spec.running);
+            if !wanted {
+                exited.extend(state.child.take());
+            }
+            wanted
+        });
+        self.exited
+            .retain_mut(|child| matches!(child.try_wait(), Ok(None)));
+
+        for (service_dir, spec) in services {
+            if !spec.running {
+                continue;
+            }
+            let state = self.services.entry(spec.name.clone()).or_default();
+
+            let alive = match state.child.as_mut() {
+                Some(child) => matches!(child.try_wait(), Ok(None)),
+                None => read_live_pid(&service_dir.join(PID_FILE_NAME)).is_some(),
+            };
+            if alive {
+                if state.started_at.is_some_and(|started| {
+                    now.duration_since(started) >= self.backoff.stable_period
+                }) {
+                    state.failures = 0;
+                }
+                continue;
+            }
+
+            match state.next_restart {
+                None => {
+                    let delay = self.backoff.delay(state.failures);
+                    warn!(
+                        "Service {} is not running; restarting in {delay:?}",
+                        spec.name
+                    );
+                    state.child = None;
+                    state.next_restart = Some(now + delay);
+                    state.failures = state.failures.saturating_add(1);
+                }
+                Some(next_restart) if next_restart <= now => {
+                    // The service may have been stopped while we were waiting to restart it.
+                    if !BuiltinServiceSpec::load(&service_dir)?.running {
+                        continue;
+                    }
+                    info!("Restarting service {}", spec.name);
+                    state.next_restart = None;
+                    match spawn_service(&spec, &service_dir) {
+                        Ok(child) => {
+                            state.child = Some(child);
+                            state.started_at = Some(now);
+                        }
+                        Err(err) => {
+                            error!("Failed to restart service {}: {err:?}", spec.name);
+                        }
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Determine whether a supervisor process is running against the given state directory.
+pub fn is_supervisor_running(state_dir: &Path) -> bool {
+    read_live_pid(&state_dir.join(SUPERVISOR_PID_FILE_NAME)).is_some()
+}
+
+/// Wait for the child on a background thread, so it doesn't linger as a zombie once it exits.
+///
+/// If the calling process exits first, the child is reparented and reaped by init instead.
+pub fn reap_in_background(mut child: Child) {
+    let pid = child.id();
+    let spawned = std::thread::Builder::new()
+        .name(format!("reaper-{pid}"))
+        .spawn(move || {
+            if let Err(err) = child.wait() {
+                warn!("Failed to wait for process {pid}: {err:?}");
+            }
+        });
+    if let Err(err) = spawned {
+        warn!("Failed to spawn a thread to reap process {pid}: {err:?}");
+    }
+}
+
+fn list_services(state_dir: &Path) -> Result<Vec<(PathBuf, BuiltinServiceSpec)>> {
+    let mut services = Vec::new();
+    if !state_dir.exists() {
+        return Ok(services);
+    }
+    for entry in std::fs::read_dir(state_dir)? {
+        let path = entry?.path();
+        if !path.join(SERVICE_FILE_NAME).exists() {
+            continue;
+        }
+        match BuiltinServiceSpec::load(&path) {
+            Ok(spec) => services.push((path, spec)),
+            Err(err) => warn!("Skipping unreadable service definition at {path:?}: {err:?}"),
+        }
+    }
+    Ok(services)
+}
+
+/// Launch the service process, appending its output to the log files in the service directory
+/// and recording its PID.
+fn spawn_service(spec: &BuiltinServiceSpec, service_dir: &Path) -> Result<Child> {
+    std::fs::create_dir_all(service_dir)?;
+    let stdout = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(service_dir.join(STDOUT_LOG_FILE_NAME))?;
+    let stderr = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(service_dir.join(STDERR_LOG_FILE_NAME))?;
+
+    let mut command = Command::new(&spec.program);
+    let _ = command
+        .args(&spec.args)
+        .envs(spec.environment.iter().cloned())
+        .stdin(Stdio::null())
+        .stdout(stdout)
+        .stderr(stderr);
+    if let Some(working_directory) = &spec.working_directory {
+        let _ = command.current_dir(working_directory);
+    }
+    #[cfg(unix)]
+    {
+        // Use a separate process group so the service doesn't receive signals, e.g., Ctrl-C,
+        // intended for the process that launched it.
+        use std::os::unix::process::CommandExt;
+        let _ = command.process_group(0);
+
+        let limits = spec.resource_limits.clone();
+        if limits.has_process_limits() {
+            // SAFETY: the closure runs in the forked child before exec, so it only makes the
+            // setrlimit system call, which is async-signal-safe, and doesn't allocate.
+            #[allow(unsafe_code)]
+            unsafe {
+                let _ = command.pre_exec(move || crate::resources::apply_rlimits(&limits));
+            }
+        }
+    }
+
+    let child = command.spawn()?;
+    debug!("Spawned {} with PID {}", spec.name, child.id());
+    write_pid_file(&service_dir.join(PID_FILE_NAME), child.id(), &spec.program)?;
+    Ok(child)
+}
+
+fn write_pid_file(path: &Path, pid: u32, program: &Path) -> Result<()> {
+    std::fs::write(path, format!("{pid}\n{}\n", program.to_string_lossy()))?;
+    Ok(())
+}
+
+fn read_pid_file(path: &Path) -> Option<(u32, Option<PathBuf>)> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    let mut lines = contents.lines();
+    let pid = lines.next()?.trim().parse().ok()?;
+    let program = lines
+        .next()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(PathBuf::from);
+    Some((pid, program))
+}
+
+/// Read the PID file, returning the PID only if the process is alive and still running the
+/// recorded executable.
+fn read_live_pid(path: &Path) -> Option<u32> {
+    let (pid, program) = read_pid_file(path)?;
+    is_process_alive(pid, program.as_deref()).then_some(pid)
+}
+
+/// Determine whether the process is alive and, if the program is given, running that program.
+///
+/// The check against the program is skipped if the executable of the process can't be read, e.g.
+/// when it belongs to another user.
+fn is_process_alive(pid: u32, program: Option<&Path>) -> bool {
+    let mut system = System::new();
+    let pid = Pid::from_u32(pid);
+    if !system.refresh_process(pid) {
+        return false;
+    }
+    let Some(process) = system.process(pid) else {
+        return false;
+    };
+    if matches!(process.status(), ProcessStatus::Zombie) {
+        return false;
+    }
+    match (program, process.exe()) {
+        (Some(program), Some(exe)) => {
+            let program = program
+                .canonicalize()
+                .unwrap_or_else(|_| program.to_path_buf());
+            if program != exe {
+                debug!("PID {pid} has been reused by {exe:?}, it is no longer {program:?}");
+                return false;
+            }
+            true
+        }
+        _ => true,
+    }
+}
+
+/// Ask the process to exit, then kill it if it hasn't done so within the timeout.
+fn terminate_process(pid: u32, timeout: Duration) {
+    let mut system = System::new();
+    let sys_pid = Pid::from_u32(pid);
+    if !system.refresh_process(sys_pid) {
+        return;
+    }
+    let Some(process) = system.process(sys_pid) else {
+        return;
+    };
+    if process.kill_with(Signal::Term).is_none() {
+        // The platform doesn't support SIGTERM, so there is nothing gentler we can do.
+        let _ = process.kill();
+        return;
+    }
+
+    let deadline = Instant::now() + timeout;
+    while Instant::now() < deadline {
+        if !is_process_alive(pid, None) {
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    warn!("Process {pid} did not exit within {timeout:?}; killing it");
+    if system.refresh_process(sys_pid) {
+        if let Some(process) = system.process(sys_pid) {
+            let _ = process.kill();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_fs::TempDir;
+    use service_manager::ServiceLabel;
+    use std::ffi::OsString;
+
+    fn install_ctx(name: &str, program: &str, args: &[&str]) -> ServiceInstallCtx {
+        ServiceInstallCtx {
+            label: name.parse::<ServiceLabel>().unwrap(),
+            program: PathBuf::from(program),
+            args: args.iter().map(OsString::from).collect(),
+            contents: None,
+            username: None,
+            working_directory: None,
+            environment: Some(vec![("ANT_LOG".to_string(), "all".to_string())]),
+            autostart: false,
+        }
+    }
+
+    #[test]
+    fn restart_backoff_should_double_up_to_the_max_delay() {
+        let backoff = RestartBackoff {
+            initial_delay: Duration::from_secs(2),
+            max_delay: Duration::from_secs(30),
+            stable_period: Duration::from_secs(60),
+        };
+
+        assert_eq!(backoff.delay(0), Duration::from_secs(2));
+        assert_eq!(backoff.delay(1), Duration::from_secs(4));
+        assert_eq!(backoff.delay(3), Duration::from_secs(16));
+        assert_eq!(backoff.delay(4), Duration::from_secs(30));
+        assert_eq!(backoff.delay(u32::MAX), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn install_and_uninstall_should_manage_the_service_definition() -> Result<()> {
+        let tmp_dir = TempDir::new().unwrap();
+        let controller = BuiltinServiceController::new(tmp_dir.to_path_buf());
+
+        controller.install(install_ctx("antnode1", "/bin/sleep", &["30"]), false)?;
+        let spec = BuiltinServiceSpec::load(&controller.service_dir("antnode1"))?;
+        assert_eq!(spec.name, "antnode1");
+        assert_eq!(spec.program, PathBuf::from("/bin/sleep"));
+        assert_eq!(spec.args, vec!["30".to_string()]);
+        assert_eq!(
+            spec.environment,
+            vec![("ANT_LOG".to_string(), "all".to_string())]
+        );
+        assert!(!spec.running);
+
+        controller.uninstall("antnode1", false)?;
+        assert!(!controller.service_dir("antnode1").exists());
+        assert!(matches!(
+            controller.uninstall("antnode1", false),
+            Err(Error::ServiceDoesNotExists(_))
+        ));
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn start_should_apply_the_resource_limits() -> Result<()> {
+        let tmp_dir = TempDir::new().unwrap();
+        let controller = BuiltinServiceController::new(tmp_dir.to_path_buf());
+        controller.install(
+            install_ctx("antnode1", "/bin/sh", &["-c", "ulimit -n"]),
+            false,
+        )?;
+        let limits = ResourceLimits {
+            max_open_files: Some(123),
+            ..Default::default()
+        };
+        controller.set_resource_limits("antnode1", &limits, false)?;
+        assert_eq!(
+            BuiltinServiceSpec::load(&controller.service_dir("antnode1"))?.resource_limits,
+            limits
+        );
+
+        controller.start("antnode1", false)?;
+        let (stdout_log, _) = controller.log_paths("antnode1");
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut output = String::new();
+        while output.trim().is_empty() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(50));
+            output = std::fs::read_to_string(&stdout_log)?;
+        }
+        assert_eq!(output.trim(), "123");
+
+        controller.uninstall("antnode1", false)?;
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn start_and_stop_should_track_the_process_pid() -> Result<()> {
+        let tmp_dir = TempDir::new().unwrap();
+        let controller = BuiltinServiceController::new(tmp_dir.to_path_buf());
+        controller.install(install_ctx("antnode1", "/bin/sleep", &["30"]), false)?;
+
+        controller.start("antnode1", false)?;
+        let pid = controller.get_process_pid(Path::new("/bin/sleep"))?;
+        assert!(is_process_alive(pid, Some(Path::new("/bin/sleep"))));
+        assert!(BuiltinServiceSpec::load(&controller.service_dir("antnode1"))?.running);
+        let (stdout_log, stderr_log) = controller.log_paths("antnode1");
+        assert!(stdout_log.exists());
+        assert!(stderr_log.exists());
+
+        controller.stop("antnode1", false)?;
+        assert!(!BuiltinServiceSpec::load(&controller.service_dir("antnode1"))?.running);
+        assert!(matches!(
+            controller.get_process_pid(Path::new("/bin/sleep")),
+            Err(Error::ServiceProcessNotFound(_))
+        ));
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn supervisor_should_restart_exited_services_after_backoff() -> Result<()> {
+        let tmp_dir = TempDir::new().unwrap();
+        let controller = BuiltinServiceController::new(tmp_dir.to_path_buf());
+        controller.install(
+            install_ctx("antnode1", "/bin/sh", &["-c", "echo up"]),
+            false,
+        )?;
+        controller.start("antnode1", false)?;
+        let first_pid = read_pid_file(&controller.service_dir("antnode1").join(PID_FILE_NAME));
+
+        let mut supervisor = Supervisor::new(
+            tmp_dir.to_path_buf(),
+            RestartBackoff {
+                initial_delay: Duration::ZERO,
+                max_delay: Duration::ZERO,
+                stable_period: Duration::from_secs(60),
+            },
+        );
+        std::thread::sleep(Duration::from_millis(500));
+
+        // The first pass notices the exit and schedules the restart; the second performs it.
+        supervisor.supervise_once()?;
+        supervisor.supervise_once()?;
+        let second_pid = read_pid_file(&controller.service_dir("antnode1").join(PID_FILE_NAME));
+        assert_ne!(first_pid, second_pid);
+        assert_eq!(supervisor.services["antnode1"].failures, 1);
+
+        std::thread::sleep(Duration::from_millis(500));
+        let (stdout_log, _) = controller.log_paths("antnode1");
+        assert_eq!(std::fs::read_to_string(stdout_log)?, "up\nup\n");
+
+        // A stopped service is left alone, and its exited process is reaped.
+        controller.stop("antnode1", false)?;
+        supervisor.supervise_once()?;
+        supervisor.supervise_once()?;
+        assert!(!supervisor.services.contains_key("antnode1"));
+        assert!(supervisor.exited.is_empty());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn supervisor_should_reap_the_processes_of_stopped_services() -> Result<()> {
+        let tmp_dir = TempDir::new().unwrap();
+        let controller = BuiltinServiceController::new(tmp_dir.to_path_buf());
+        controller.install(install_ctx("antnode1", "/bin/sleep", &["30"]), false)?;
+        controller.start("antnode1", false)?;
+        controller.stop("antnode1", false)?;
+
+        // Give the supervisor its own child, as though it had restarted the service.
+        let mut supervisor = Supervisor::new(tmp_dir.to_path_buf(), RestartBackoff::default());
+        let mut spec = BuiltinServiceSpec::load(&controller.service_dir("antnode1"))?;
+        spec.running = true;
+        let child = spawn_service(&spec, &controller.service_dir("antnode1"))?;
+        let pid = child.id();
+        let _ = supervisor.services.insert(
+            "antnode1".to_string(),
+            RestartState {
+                child: Some(child),
+                ..Default::default()
+            },
+        );
+
+        // The service is not meant to be running, so the child is held until it exits.
+        supervisor.supervise_once()?;
+        assert_eq!(supervisor.exited.len(), 1);
+        terminate_process(pid, STOP_TIMEOUT);
+        supervisor.supervise_once()?;
+        assert!(supervisor.exited.is_empty());
+        assert!(!is_process_alive(pid, None));
+        Ok(())
+    }
+
+    #[test]
+    fn pid_file_should_not_match_a_reused_pid() -> Result<()> {
+        let tmp_dir = TempDir::new().unwrap();
+        let pid_path = tmp_dir.join(PID_FILE_NAME);
+
+        // The PID of the test process is alive, but it isn't running the recorded program.
+        write_pid_file(&pid_path, std::process::id(), Path::new("/bin/sleep"))?;
+        assert_eq!(read_live_pid(&pid_path), None);
+
+        write_pid_file(&pid_path, std::process::id(), &std::env::current_exe()?)?;
+        assert_eq!(read_live_pid(&pid_path), Some(std::process::id()));
+
+        // PID files written without the program are only checked for a live process.
+        std::fs::write(&pid_path, std::process::id().to_string())?;
+        assert_eq!(read_live_pid(&pid_path), Some(std::process::id()));
+        Ok(())
+    }
+}
@@ -0,0 +1,465 @@
+/* Copyright 2025 MaidSafe.net limited.
+ *
+ * This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+ * Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+ * under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied. Please review the Licences for the specific language governing
+ * permissions and limitations relating to use of the SAFE Network Software.
+ */
+
+#ifndef AUTONOMI_H
+#define AUTONOMI_H
+
+/* Generated by cbindgen from the autonomi-c crate, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Outcome of a call.
+ */
+typedef enum AntStatus {
+  /**
+   * The call succeeded, or the task completed successfully.
+   */
+  ANT_STATUS_OK = 0,
+  /**
+   * The task has not completed yet.
+   */
+  ANT_STATUS_PENDING = 1,
+  /**
+   * An argument was null or could not be parsed.
+   */
+  ANT_STATUS_INVALID_ARGUMENT = 2,
+  /**
+   * The operation failed.
+   */
+  ANT_STATUS_FAILED = 3,
+} AntStatus;
+
+/**
+ * Kind of data a pointer points to.
+ */
+typedef enum AntPointerTargetKind {
+  ANT_POINTER_TARGET_KIND_CHUNK = 0,
+  ANT_POINTER_TARGET_KIND_GRAPH_ENTRY = 1,
+  ANT_POINTER_TARGET_KIND_POINTER = 2,
+  /**
+   * Scratchpads are addressed by the hex of their owner's public key.
+   */
+  ANT_POINTER_TARGET_KIND_SCRATCHPAD = 3,
+} AntPointerTargetKind;
+
+/**
+ * A client connected to the network.
+ */
+typedef struct AntClient AntClient;
+
+/**
+ * A public archive, listing the files of a directory.
+ */
+typedef struct AntPublicArchive AntPublicArchive;
+
+/**
+ * An operation running in the background, see the crate documentation.
+ */
+typedef struct AntTask AntTask;
+
+/**
+ * An EVM wallet, paying for uploads.
+ */
+typedef struct AntWallet AntWallet;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Message of the last error on the calling thread, or null if there was none.
+ *
+ * The message is borrowed, and stays valid until the next error on this thread.
+ */
+const char *ant_last_error(void);
+
+/**
+ * Release a string returned by the API. Does nothing if `s` is null.
+ */
+void ant_string_free(char *s);
+
+/**
+ * Status of a task, [`AntStatus::Pending`] while it runs.
+ */
+AntStatus ant_task_poll(const AntTask *task);
+
+/**
+ * Block until a task completes, returning its status.
+ */
+AntStatus ant_task_wait(const AntTask *task);
+
+/**
+ * Error message of a failed task, or null if it did not fail.
+ */
+const char *ant_task_error(const AntTask *task);
+
+/**
+ * Release a task. Does nothing if `task` is null.
+ */
+void ant_task_free(AntTask *task);
+
+/**
+ * Amount of tokens paid, or the cost quoted, by the task. Null if it has none.
+ */
+const char *ant_task_amount(const AntTask *task);
+
+/**
+ * Address produced by the task, or null if it has none. For private data, this is the hex of
+ * the data map which gives access to it.
+ */
+const char *ant_task_address(const AntTask *task);
+
+/**
+ * Bytes fetched by the task, or null if it has none. Their length is written to `len`.
+ */
+const uint8_t *ant_task_bytes(const AntTask *task, size_t *len);
+
+/**
+ * Counter of the data fetched by the task, written to `counter`. Returns false if it has none.
+ */
+bool ant_task_counter(const AntTask *task, uint64_t *counter);
+
+/**
+ * Content type of the scratchpad fetched by the task, written to `content_type`. Returns false
+ * if it has none.
+ */
+bool ant_task_content_type(const AntTask *task, uint64_t *content_type);
+
+/**
+ * Target of the pointer fetched by the task, or null if it has none. The kind of the target is
+ * written to `kind`.
+ */
+const char *ant_task_pointer_target(const AntTask *task, AntPointerTargetKind *kind);
+
+/**
+ * Take the client connected by the task, to be released with
+ * [`crate::client::ant_client_free`]. Null if it has none, or it was already taken.
+ */
+AntClient *ant_task_take_client(const AntTask *task);
+
+/**
+ * Take the archive fetched by the task, to be released with
+ * [`crate::files::ant_public_archive_free`]. Null if it has none, or it was already taken.
+ */
+AntPublicArchive *ant_task_take_public_archive(const AntTask *task);
+
+/**
+ * Connect to the network with the default configuration.
+ *
+ * Once complete, take the client from the task with [`crate::task::ant_task_take_client`].
+ */
+AntTask *ant_client_init(void);
+
+/**
+ * Connect to a local network, see [`ant_client_init`].
+ */
+AntTask *ant_client_init_local(void);
+
+/**
+ * Connect to the network through the given peers, an array of `peers_len` multiaddresses. See
+ * [`ant_client_init`].
+ */
+AntTask *ant_client_init_with_peers(const char *const *peers, size_t peers_len);
+
+/**
+ * Release a client. Does nothing if `client` is null.
+ *
+ * Tasks started with the client keep running until they complete.
+ */
+void ant_client_free(AntClient *client);
+
+/**
+ * Load the wallet of a hex private key, on the local EVM network if `local` is true, or on the
+ * network configured in the environment otherwise. The wallet is written to `wallet`.
+ */
+AntStatus ant_wallet_from_private_key(const char *private_key, bool local, AntWallet **wallet);
+
+/**
+ * Address of a wallet, or null if `wallet` is null.
+ */
+char *ant_wallet_address(const AntWallet *wallet);
+
+/**
+ * Fetch the token balance of a wallet.
+ *
+ * Outputs: the balance, as an amount.
+ */
+AntTask *ant_wallet_balance(const AntWallet *wallet);
+
+/**
+ * Release a wallet. Does nothing if `wallet` is null.
+ */
+void ant_wallet_free(AntWallet *wallet);
+
+/**
+ * Generate a random secret key, as hex.
+ */
+char *ant_secret_key_random(void);
+
+/**
+ * Public key of a hex secret key, as hex, or null if `secret_key` is invalid.
+ */
+char *ant_public_key_from_secret_key(const char *secret_key);
+
+/**
+ * Upload `len` bytes of public data, paid for by `wallet`.
+ *
+ * Outputs: the amount paid, and the address of the data.
+ */
+AntTask *ant_data_put_public(const AntClient *client,
+                             const uint8_t *data,
+                             size_t len,
+                             const AntWallet *wallet);
+
+/**
+ * Download public data from its hex address.
+ *
+ * Outputs: the bytes of the data.
+ */
+AntTask *ant_data_get_public(const AntClient *client, const char *address);
+
+/**
+ * Upload `len` bytes of private data, paid for by `wallet`. Only the data map gives access to
+ * the data, keep it secret.
+ *
+ * Outputs: the amount paid, and the hex of the data map as address.
+ */
+AntTask *ant_data_put(const AntClient *client,
+                      const uint8_t *data,
+                      size_t len,
+                      const AntWallet *wallet);
+
+/**
+ * Download private data from the hex of its data map.
+ *
+ * Outputs: the bytes of the data.
+ */
+AntTask *ant_data_get(const AntClient *client, const char *data_map);
+
+/**
+ * Get the cost of uploading `len` bytes of data.
+ *
+ * Outputs: the cost, as an amount.
+ */
+AntTask *ant_data_cost(const AntClient *client, const uint8_t *data, size_t len);
+
+/**
+ * Upload a file publicly, paid for by `wallet`.
+ *
+ * Outputs: the amount paid, and the address of the file.
+ */
+AntTask *ant_file_upload_public(const AntClient *client, const char *path, const AntWallet *wallet);
+
+/**
+ * Download a public file from its hex address to `dest_path`.
+ */
+AntTask *ant_file_download_public(const AntClient *client,
+                                  const char *address,
+                                  const char *dest_path);
+
+/**
+ * Upload a file privately, paid for by `wallet`.
+ *
+ * Outputs: the amount paid, and the hex of the data map of the file as address.
+ */
+AntTask *ant_file_upload(const AntClient *client, const char *path, const AntWallet *wallet);
+
+/**
+ * Download a private file from the hex of its data map to `dest_path`.
+ */
+AntTask *ant_file_download(const AntClient *client, const char *data_map, const char *dest_path);
+
+/**
+ * Upload the files of a directory publicly, along with a public archive listing them, paid for
+ * by `wallet`.
+ *
+ * Outputs: the amount paid, and the address of the archive.
+ */
+AntTask *ant_dir_upload_public(const AntClient *client,
+                               const char *dir_path,
+                               const AntWallet *wallet);
+
+/**
+ * Download the files of a public archive from its hex address into `dest_path`.
+ */
+AntTask *ant_dir_download_public(const AntClient *client,
+                                 const char *address,
+                                 const char *dest_path);
+
+/**
+ * Upload the files of a directory privately, along with a private archive listing them, paid
+ * for by `wallet`.
+ *
+ * Outputs: the amount paid, and the hex of the data map of the archive as address.
+ */
+AntTask *ant_dir_upload(const AntClient *client, const char *dir_path, const AntWallet *wallet);
+
+/**
+ * Download the files of a private archive from the hex of its data map into `dest_path`.
+ */
+AntTask *ant_dir_download(const AntClient *client, const char *data_map, const char *dest_path);
+
+/**
+ * Fetch a public archive from its hex address.
+ *
+ * Outputs: the archive, taken with [`crate::task::ant_task_take_public_archive`].
+ */
+AntTask *ant_archive_get_public(const AntClient *client, const char *address);
+
+/**
+ * Number of files in an archive.
+ */
+size_t ant_public_archive_len(const AntPublicArchive *archive);
+
+/**
+ * Path of the file at `index` in an archive, or null if out of bounds. Files are sorted by path.
+ */
+const char *ant_public_archive_path(const AntPublicArchive *archive, size_t index);
+
+/**
+ * Address of the file at `index` in an archive, or null if out of bounds.
+ */
+const char *ant_public_archive_address(const AntPublicArchive *archive, size_t index);
+
+/**
+ * Size in bytes of the file at `index` in an archive, or 0 if out of bounds.
+ */
+uint64_t ant_public_archive_size(const AntPublicArchive *archive, size_t index);
+
+/**
+ * Release an archive. Does nothing if `archive` is null.
+ */
+void ant_public_archive_free(AntPublicArchive *archive);
+
+/**
+ * Derive the hex key of a register from its owner's hex secret key and a name, or null if
+ * `owner` is invalid.
+ *
+ * The register is owned by the derived key, keep track of the names used to find it again.
+ */
+char *ant_register_key_from_name(const char *owner, const char *name);
+
+/**
+ * Create a register owned by the hex key `owner`, holding `len` bytes of any size, paid for by
+ * `wallet`.
+ *
+ * Outputs: the amount paid, and the address of the register.
+ */
+AntTask *ant_register_create(const AntClient *client,
+                             const char *owner,
+                             const uint8_t *value,
+                             size_t len,
+                             const AntWallet *wallet);
+
+/**
+ * Update the value of a register owned by the hex key `owner`, paid for by `wallet`.
+ *
+ * Outputs: the amount paid.
+ */
+AntTask *ant_register_update(const AntClient *client,
+                             const char *owner,
+                             const uint8_t *value,
+                             size_t len,
+                             const AntWallet *wallet);
+
+/**
+ * Fetch the current value of a register from its hex address.
+ *
+ * Outputs: the bytes of the value.
+ */
+AntTask *ant_register_get(const AntClient *client, const char *address);
+
+/**
+ * Get the cost of creating a register owned by the hex public key `owner`.
+ *
+ * Outputs: the cost, as an amount.
+ */
+AntTask *ant_register_cost(const AntClient *client, const char *owner);
+
+/**
+ * Create a pointer owned by the hex key `owner`, pointing to the hex address `target` of the
+ * given kind, paid for by `wallet`.
+ *
+ * Outputs: the amount paid, and the address of the pointer.
+ */
+AntTask *ant_pointer_create(const AntClient *client,
+                            const char *owner,
+                            AntPointerTargetKind target_kind,
+                            const char *target,
+                            const AntWallet *wallet);
+
+/**
+ * Point the pointer owned by the hex key `owner` to a new target. Updates are free.
+ */
+AntTask *ant_pointer_update(const AntClient *client,
+                            const char *owner,
+                            AntPointerTargetKind target_kind,
+                            const char *target);
+
+/**
+ * Fetch a pointer from its hex address.
+ *
+ * Outputs: the counter of the pointer, which increases with each update, and its target.
+ */
+AntTask *ant_pointer_get(const AntClient *client, const char *address);
+
+/**
+ * Get the cost of creating a pointer owned by the hex public key `owner`.
+ *
+ * Outputs: the cost, as an amount.
+ */
+AntTask *ant_pointer_cost(const AntClient *client, const char *owner);
+
+/**
+ * Create a scratchpad owned by the hex key `owner`, holding `len` bytes encrypted with that key,
+ * paid for by `wallet`. The content type is up to the caller.
+ *
+ * Outputs: the amount paid, and the address of the scratchpad.
+ */
+AntTask *ant_scratchpad_create(const AntClient *client,
+                               const char *owner,
+                               uint64_t content_type,
+                               const uint8_t *data,
+                               size_t len,
+                               const AntWallet *wallet);
+
+/**
+ * Replace the content of the scratchpad owned by the hex key `owner`. Updates are free.
+ */
+AntTask *ant_scratchpad_update(const AntClient *client,
+                               const char *owner,
+                               uint64_t content_type,
+                               const uint8_t *data,
+                               size_t len);
+
+/**
+ * Fetch the scratchpad owned by the hex key `owner`, and decrypt its content.
+ *
+ * Outputs: the decrypted bytes, the counter of the scratchpad, which increases with each
+ * update, and its content type.
+ */
+AntTask *ant_scratchpad_get(const AntClient *client, const char *owner);
+
+/**
+ * Get the cost of creating a scratchpad owned by the hex public key `owner`.
+ *
+ * Outputs: the cost, as an amount.
+ */
+AntTask *ant_scratchpad_cost(const AntClient *client, const char *owner);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* AUTONOMI_H */
@@ -0,0 +1,95 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Connecting to the network.
+
+use std::ffi::c_char;
+use std::str::FromStr;
+
+use autonomi::{Client, Multiaddr};
+
+use crate::task::{AntTask, Output};
+use crate::{arg_ref, arg_str, task_arg};
+
+/// A client connected to the network.
+pub struct AntClient {
+    pub(crate) inner: Client,
+}
+
+impl AntClient {
+    pub(crate) fn into_raw(inner: Client) -> *mut AntClient {
+        Box::into_raw(Box::new(AntClient { inner }))
+    }
+
+    /// Borrow the client argument of an operation.
+    pub(crate) unsafe fn arg<'a>(client: *const AntClient) -> Result<&'a Client, String> {
+        arg_ref(client, "client").map(|client| &client.inner)
+    }
+}
+
+fn connected(client: Client) -> Output {
+    Output {
+        client: Some(client),
+        ..Default::default()
+    }
+}
+
+/// Connect to the network with the default configuration.
+///
+/// Once complete, take the client from the task with [`crate::task::ant_task_take_client`].
+#[no_mangle]
+pub extern "C" fn ant_client_init() -> *mut AntTask {
+    AntTask::spawn(async {
+        let client = Client::init().await.map_err(|err| err.to_string())?;
+        Ok(connected(client))
+    })
+}
+
+/// Connect to a local network, see [`ant_client_init`].
+#[no_mangle]
+pub extern "C" fn ant_client_init_local() -> *mut AntTask {
+    AntTask::spawn(async {
+        let client = Client::init_local().await.map_err(|err| err.to_string())?;
+        Ok(connected(client))
+    })
+}
+
+/// Connect to the network through the given peers, an array of `peers_len` multiaddresses. See
+/// [`ant_client_init`].
+#[no_mangle]
+pub unsafe extern "C" fn ant_client_init_with_peers(
+    peers: *const *const c_char,
+    peers_len: usize,
+) -> *mut AntTask {
+    if peers.is_null() && peers_len > 0 {
+        return AntTask::invalid_argument("`peers` is null".to_string());
+    }
+    let mut addrs = Vec::with_capacity(peers_len);
+    for i in 0..peers_len {
+        let peer = task_arg!(arg_str(*peers.add(i), "peers"));
+        let addr = task_arg!(Multiaddr::from_str(peer)
+            .map_err(|err| format!("Failed to parse peer address `{peer}`: {err}")));
+        addrs.push(addr);
+    }
+    AntTask::spawn(async move {
+        let client = Client::init_with_peers(addrs)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(connected(client))
+    })
+}
+
+/// Release a client. Does nothing if `client` is null.
+///
+/// Tasks started with the client keep running until they complete.
+#[no_mangle]
+pub unsafe extern "C" fn ant_client_free(client: *mut AntClient) {
+    if !client.is_null() {
+        drop(Box::from_raw(client));
+    }
+}
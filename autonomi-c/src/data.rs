@@ -0,0 +1,123 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Immutable data, public or private.
+
+use std::ffi::c_char;
+
+use autonomi::client::address::{addr_to_str, str_to_addr};
+use autonomi::client::chunk::DataMapChunk;
+use autonomi::Bytes;
+
+use crate::client::AntClient;
+use crate::task::{AntTask, Output};
+use crate::wallet::AntWallet;
+use crate::{arg_bytes, arg_hex, task_arg};
+
+/// Upload `len` bytes of public data, paid for by `wallet`.
+///
+/// Outputs: the amount paid, and the address of the data.
+#[no_mangle]
+pub unsafe extern "C" fn ant_data_put_public(
+    client: *const AntClient,
+    data: *const u8,
+    len: usize,
+    wallet: *const AntWallet,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let data = Bytes::copy_from_slice(task_arg!(arg_bytes(data, len, "data")));
+    let payment = task_arg!(AntWallet::payment(wallet));
+    AntTask::spawn(async move {
+        let (cost, addr) = client
+            .data_put_public(data, payment)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::cost_and_address(cost, addr_to_str(addr)))
+    })
+}
+
+/// Download public data from its hex address.
+///
+/// Outputs: the bytes of the data.
+#[no_mangle]
+pub unsafe extern "C" fn ant_data_get_public(
+    client: *const AntClient,
+    address: *const c_char,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let addr = task_arg!(arg_hex(address, "address", str_to_addr));
+    AntTask::spawn(async move {
+        let data = client
+            .data_get_public(&addr)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::bytes(data))
+    })
+}
+
+/// Upload `len` bytes of private data, paid for by `wallet`. Only the data map gives access to
+/// the data, keep it secret.
+///
+/// Outputs: the amount paid, and the hex of the data map as address.
+#[no_mangle]
+pub unsafe extern "C" fn ant_data_put(
+    client: *const AntClient,
+    data: *const u8,
+    len: usize,
+    wallet: *const AntWallet,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let data = Bytes::copy_from_slice(task_arg!(arg_bytes(data, len, "data")));
+    let payment = task_arg!(AntWallet::payment(wallet));
+    AntTask::spawn(async move {
+        let (cost, data_map) = client
+            .data_put(data, payment)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::cost_and_address(cost, data_map.to_hex()))
+    })
+}
+
+/// Download private data from the hex of its data map.
+///
+/// Outputs: the bytes of the data.
+#[no_mangle]
+pub unsafe extern "C" fn ant_data_get(
+    client: *const AntClient,
+    data_map: *const c_char,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let data_map = task_arg!(arg_hex(data_map, "data_map", DataMapChunk::from_hex));
+    AntTask::spawn(async move {
+        let data = client
+            .data_get(&data_map)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::bytes(data))
+    })
+}
+
+/// Get the cost of uploading `len` bytes of data.
+///
+/// Outputs: the cost, as an amount.
+#[no_mangle]
+pub unsafe extern "C" fn ant_data_cost(
+    client: *const AntClient,
+    data: *const u8,
+    len: usize,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let data = Bytes::copy_from_slice(task_arg!(arg_bytes(data, len, "data")));
+    AntTask::spawn(async move {
+        let cost = client
+            .data_cost(data)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::cost(cost))
+    })
+}
@@ -0,0 +1,306 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Files and directories, uploaded along with an archive listing them.
+
+use std::ffi::{c_char, CString};
+use std::path::PathBuf;
+
+use autonomi::client::address::{addr_to_str, str_to_addr};
+use autonomi::client::chunk::DataMapChunk;
+use autonomi::files::PublicArchive;
+
+use crate::client::AntClient;
+use crate::task::{AntTask, Output};
+use crate::wallet::AntWallet;
+use crate::{arg_hex, arg_ref, arg_str, c_string, task_arg};
+
+/// A public archive, listing the files of a directory.
+pub struct AntPublicArchive {
+    files: Vec<ArchiveFile>,
+}
+
+struct ArchiveFile {
+    path: CString,
+    address: CString,
+    size: u64,
+}
+
+impl AntPublicArchive {
+    pub(crate) fn into_raw(archive: PublicArchive) -> *mut AntPublicArchive {
+        let files = archive
+            .iter()
+            .map(|(path, addr, meta)| ArchiveFile {
+                path: c_string(path.to_string_lossy().into_owned()),
+                address: c_string(addr_to_str(*addr)),
+                size: meta.size,
+            })
+            .collect();
+        Box::into_raw(Box::new(AntPublicArchive { files }))
+    }
+
+    unsafe fn file<'a>(archive: *const AntPublicArchive, index: usize) -> Option<&'a ArchiveFile> {
+        arg_ref(archive, "archive").ok()?.files.get(index)
+    }
+}
+
+unsafe fn arg_path(ptr: *const c_char, name: &str) -> Result<PathBuf, String> {
+    arg_str(ptr, name).map(PathBuf::from)
+}
+
+/// Upload a file publicly, paid for by `wallet`.
+///
+/// Outputs: the amount paid, and the address of the file.
+#[no_mangle]
+pub unsafe extern "C" fn ant_file_upload_public(
+    client: *const AntClient,
+    path: *const c_char,
+    wallet: *const AntWallet,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let path = task_arg!(arg_path(path, "path"));
+    let wallet = task_arg!(AntWallet::arg(wallet)).clone();
+    AntTask::spawn(async move {
+        let (cost, addr) = client
+            .file_upload_public(path, &wallet)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::cost_and_address(cost, addr_to_str(addr)))
+    })
+}
+
+/// Download a public file from its hex address to `dest_path`.
+#[no_mangle]
+pub unsafe extern "C" fn ant_file_download_public(
+    client: *const AntClient,
+    address: *const c_char,
+    dest_path: *const c_char,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let addr = task_arg!(arg_hex(address, "address", str_to_addr));
+    let dest_path = task_arg!(arg_path(dest_path, "dest_path"));
+    AntTask::spawn(async move {
+        client
+            .file_download_public(&addr, dest_path)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::default())
+    })
+}
+
+/// Upload a file privately, paid for by `wallet`.
+///
+/// Outputs: the amount paid, and the hex of the data map of the file as address.
+#[no_mangle]
+pub unsafe extern "C" fn ant_file_upload(
+    client: *const AntClient,
+    path: *const c_char,
+    wallet: *const AntWallet,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let path = task_arg!(arg_path(path, "path"));
+    let wallet = task_arg!(AntWallet::arg(wallet)).clone();
+    AntTask::spawn(async move {
+        let (cost, data_map) = client
+            .file_upload(path, &wallet)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::cost_and_address(cost, data_map.to_hex()))
+    })
+}
+
+/// Download a private file from the hex of its data map to `dest_path`.
+#[no_mangle]
+pub unsafe extern "C" fn ant_file_download(
+    client: *const AntClient,
+    data_map: *const c_char,
+    dest_path: *const c_char,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let data_map = task_arg!(arg_hex(data_map, "data_map", DataMapChunk::from_hex));
+    let dest_path = task_arg!(arg_path(dest_path, "dest_path"));
+    AntTask::spawn(async move {
+        client
+            .file_download(&data_map, dest_path)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::default())
+    })
+}
+
+/// Upload the files of a directory publicly, along with a public archive listing them, paid for
+/// by `wallet`.
+///
+/// Outputs: the amount paid, and the address of the archive.
+#[no_mangle]
+pub unsafe extern "C" fn ant_dir_upload_public(
+    client: *const AntClient,
+    dir_path: *const c_char,
+    wallet: *const AntWallet,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let dir_path = task_arg!(arg_path(dir_path, "dir_path"));
+    let wallet = task_arg!(AntWallet::arg(wallet)).clone();
+    AntTask::spawn(async move {
+        let (cost, addr) = client
+            .dir_and_archive_upload_public(dir_path, &wallet)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::cost_and_address(cost, addr_to_str(addr)))
+    })
+}
+
+/// Download the files of a public archive from its hex address into `dest_path`.
+#[no_mangle]
+pub unsafe extern "C" fn ant_dir_download_public(
+    client: *const AntClient,
+    address: *const c_char,
+    dest_path: *const c_char,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let addr = task_arg!(arg_hex(address, "address", str_to_addr));
+    let dest_path = task_arg!(arg_path(dest_path, "dest_path"));
+    AntTask::spawn(async move {
+        client
+            .dir_download_public(&addr, dest_path)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::default())
+    })
+}
+
+/// Upload the files of a directory privately, along with a private archive listing them, paid
+/// for by `wallet`.
+///
+/// Outputs: the amount paid, and the hex of the data map of the archive as address.
+#[no_mangle]
+pub unsafe extern "C" fn ant_dir_upload(
+    client: *const AntClient,
+    dir_path: *const c_char,
+    wallet: *const AntWallet,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let dir_path = task_arg!(arg_path(dir_path, "dir_path"));
+    let wallet = task_arg!(AntWallet::arg(wallet)).clone();
+    AntTask::spawn(async move {
+        let (cost, data_map) = client
+            .dir_and_archive_upload(dir_path, &wallet)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::cost_and_address(cost, data_map.to_hex()))
+    })
+}
+
+/// Download the files of a private archive from the hex of its data map into `dest_path`.
+#[no_mangle]
+pub unsafe extern "C" fn ant_dir_download(
+    client: *const AntClient,
+    data_map: *const c_char,
+    dest_path: *const c_char,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let data_map = task_arg!(arg_hex(data_map, "data_map", DataMapChunk::from_hex));
+    let dest_path = task_arg!(arg_path(dest_path, "dest_path"));
+    AntTask::spawn(async move {
+        client
+            .dir_download(&data_map, dest_path)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::default())
+    })
+}
+
+/// Fetch a public archive from its hex address.
+///
+/// Outputs: the archive, taken with [`crate::task::ant_task_take_public_archive`].
+#[no_mangle]
+pub unsafe extern "C" fn ant_archive_get_public(
+    client: *const AntClient,
+    address: *const c_char,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let addr = task_arg!(arg_hex(address, "address", str_to_addr));
+    AntTask::spawn(async move {
+        let archive = client
+            .archive_get_public(&addr)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output {
+            archive: Some(archive),
+            ..Default::default()
+        })
+    })
+}
+
+/// Number of files in an archive.
+#[no_mangle]
+pub unsafe extern "C" fn ant_public_archive_len(archive: *const AntPublicArchive) -> usize {
+    arg_ref(archive, "archive").map_or(0, |archive| archive.files.len())
+}
+
+/// Path of the file at `index` in an archive, or null if out of bounds. Files are sorted by path.
+#[no_mangle]
+pub unsafe extern "C" fn ant_public_archive_path(
+    archive: *const AntPublicArchive,
+    index: usize,
+) -> *const c_char {
+    AntPublicArchive::file(archive, index).map_or(std::ptr::null(), |file| file.path.as_ptr())
+}
+
+/// Address of the file at `index` in an archive, or null if out of bounds.
+#[no_mangle]
+pub unsafe extern "C" fn ant_public_archive_address(
+    archive: *const AntPublicArchive,
+    index: usize,
+) -> *const c_char {
+    AntPublicArchive::file(archive, index).map_or(std::ptr::null(), |file| file.address.as_ptr())
+}
+
+/// Size in bytes of the file at `index` in an archive, or 0 if out of bounds.
+#[no_mangle]
+pub unsafe extern "C" fn ant_public_archive_size(
+    archive: *const AntPublicArchive,
+    index: usize,
+) -> u64 {
+    AntPublicArchive::file(archive, index).map_or(0, |file| file.size)
+}
+
+/// Release an archive. Does nothing if `archive` is null.
+#[no_mangle]
+pub unsafe extern "C" fn ant_public_archive_free(archive: *mut AntPublicArchive) {
+    if !archive.is_null() {
+        drop(Box::from_raw(archive));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use autonomi::files::Metadata;
+    use std::ffi::CStr;
+
+    #[test]
+    fn archive_files_are_listed() {
+        let addr = str_to_addr(&"ab".repeat(32)).unwrap();
+        let mut archive = PublicArchive::new();
+        archive.add_file(PathBuf::from("dir/b.txt"), addr, Metadata::new_with_size(2));
+        archive.add_file(PathBuf::from("dir/a.txt"), addr, Metadata::new_with_size(1));
+
+        unsafe {
+            let archive = AntPublicArchive::into_raw(archive);
+            assert_eq!(ant_public_archive_len(archive), 2);
+            let path = CStr::from_ptr(ant_public_archive_path(archive, 0));
+            assert_eq!(path.to_str().unwrap(), "dir/a.txt");
+            let address = CStr::from_ptr(ant_public_archive_address(archive, 1));
+            assert_eq!(address.to_str().unwrap(), addr_to_str(addr));
+            assert_eq!(ant_public_archive_size(archive, 1), 2);
+            assert!(ant_public_archive_path(archive, 2).is_null());
+            ant_public_archive_free(archive);
+        }
+    }
+}
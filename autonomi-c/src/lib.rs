@@ -0,0 +1,217 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! C API for the Autonomi client.
+//!
+//! The C declarations are in `include/autonomi.h`, generated from this crate with cbindgen.
+//! Addresses and keys are passed as hex strings, and amounts of tokens as decimal strings.
+//!
+//! # Memory ownership
+//!
+//! - Handles (`AntClient`, `AntWallet`, `AntTask` and `AntPublicArchive`) returned by the API are
+//!   owned by the caller, and must be released exactly once with their `_free` function.
+//! - Strings returned as `char *` are owned by the caller, and must be released with
+//!   [`ant_string_free`].
+//! - Strings and bytes returned as `const` pointers are borrowed from the handle they were
+//!   obtained from, and stay valid until that handle is freed.
+//! - Arguments are only borrowed for the duration of the call, the API keeps copies of what it
+//!   needs. They can be released as soon as the call returns, even if it started a task.
+//!
+//! # Asynchronous operations
+//!
+//! Operations going through the network start in the background and return an `AntTask` right
+//! away. Its completion is checked with [`task::ant_task_poll`], or waited for with
+//! [`task::ant_task_wait`]. Once complete, the outputs of the operation are read from the task
+//! with the `ant_task_*` accessors. Freeing a task that has not completed yet does not cancel the
+//! operation, but discards its outputs.
+//!
+//! # Errors
+//!
+//! Functions return an [`AntStatus`], or a null pointer, on failure. The message of the last
+//! error on the calling thread is given by [`ant_last_error`]. Tasks never fail to be created:
+//! invalid arguments complete the task right away, with its own error message given by
+//! [`task::ant_task_error`].
+//!
+//! # Safety
+//!
+//! The requirements are the same for all functions:
+//! - pointers must be null or valid for the duration of the call, null arguments being rejected
+//!   as invalid;
+//! - strings must be nul terminated, and hold UTF-8;
+//! - handles must not be used after being freed.
+//!
+//! Handles can be shared between threads.
+
+// Exposing a C API takes raw pointers everywhere.
+#![allow(unsafe_code)]
+// The safety requirements are shared by all functions, and documented above.
+#![allow(clippy::missing_safety_doc)]
+
+pub mod client;
+pub mod data;
+pub mod files;
+pub mod pointer;
+pub mod register;
+pub mod scratchpad;
+pub mod task;
+pub mod wallet;
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::fmt::Display;
+
+/// Outcome of a call.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AntStatus {
+    /// The call succeeded, or the task completed successfully.
+    Ok = 0,
+    /// The task has not completed yet.
+    Pending = 1,
+    /// An argument was null or could not be parsed.
+    InvalidArgument = 2,
+    /// The operation failed.
+    Failed = 3,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Message of the last error on the calling thread, or null if there was none.
+///
+/// The message is borrowed, and stays valid until the next error on this thread.
+#[no_mangle]
+pub extern "C" fn ant_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(std::ptr::null(), |msg| msg.as_ptr())
+    })
+}
+
+/// Release a string returned by the API. Does nothing if `s` is null.
+#[no_mangle]
+pub unsafe extern "C" fn ant_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Record the error of a call, returning its status.
+pub(crate) fn fail(status: AntStatus, msg: impl Display) -> AntStatus {
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(c_string(msg.to_string())));
+    status
+}
+
+/// Convert a string to a C string, dropping any interior nul byte.
+pub(crate) fn c_string(s: String) -> CString {
+    CString::new(s).unwrap_or_else(|err| {
+        let mut bytes = err.into_vec();
+        bytes.retain(|b| *b != 0);
+        CString::new(bytes).unwrap_or_default()
+    })
+}
+
+/// Hand a string over to the caller, who releases it with [`ant_string_free`].
+pub(crate) fn owned_string(s: String) -> *mut c_char {
+    c_string(s).into_raw()
+}
+
+/// Borrow a string argument.
+pub(crate) unsafe fn arg_str<'a>(ptr: *const c_char, name: &str) -> Result<&'a str, String> {
+    if ptr.is_null() {
+        return Err(format!("`{name}` is null"));
+    }
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map_err(|_| format!("`{name}` is not valid UTF-8"))
+}
+
+/// Borrow a byte buffer argument, which may be null if it is empty.
+pub(crate) unsafe fn arg_bytes<'a>(
+    ptr: *const u8,
+    len: usize,
+    name: &str,
+) -> Result<&'a [u8], String> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+    if ptr.is_null() {
+        return Err(format!("`{name}` is null"));
+    }
+    Ok(std::slice::from_raw_parts(ptr, len))
+}
+
+/// Borrow a handle argument.
+pub(crate) unsafe fn arg_ref<'a, T>(ptr: *const T, name: &str) -> Result<&'a T, String> {
+    ptr.as_ref().ok_or_else(|| format!("`{name}` is null"))
+}
+
+/// Parse a hex argument with one of the `from_hex` functions.
+pub(crate) unsafe fn arg_hex<T, E: Display>(
+    ptr: *const c_char,
+    name: &str,
+    from_hex: impl FnOnce(&str) -> Result<T, E>,
+) -> Result<T, String> {
+    let hex = arg_str(ptr, name)?;
+    from_hex(hex).map_err(|err| format!("Failed to parse `{name}`: {err}"))
+}
+
+/// Unwrap the arguments of a function returning a task, completing the task with the error if
+/// they are invalid.
+macro_rules! task_arg {
+    ($arg:expr) => {
+        match $arg {
+            Ok(arg) => arg,
+            Err(err) => return $crate::task::AntTask::invalid_argument(err),
+        }
+    };
+}
+pub(crate) use task_arg;
+
+/// Unwrap the arguments of a function returning a status, recording the error if they are
+/// invalid.
+macro_rules! status_arg {
+    ($arg:expr) => {
+        match $arg {
+            Ok(arg) => arg,
+            Err(err) => return $crate::fail($crate::AntStatus::InvalidArgument, err),
+        }
+    };
+}
+pub(crate) use status_arg;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn last_error_is_per_thread() {
+        assert_eq!(
+            fail(AntStatus::InvalidArgument, "oops"),
+            AntStatus::InvalidArgument
+        );
+        let msg = unsafe { CStr::from_ptr(ant_last_error()) };
+        assert_eq!(msg.to_str().unwrap(), "oops");
+
+        std::thread::spawn(|| assert!(ant_last_error().is_null()))
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn null_arguments_are_rejected() {
+        let err = unsafe { arg_str(std::ptr::null(), "address") }.unwrap_err();
+        assert_eq!(err, "`address` is null");
+        assert!(unsafe { arg_bytes(std::ptr::null(), 1, "data") }.is_err());
+        assert!(unsafe { arg_bytes(std::ptr::null(), 0, "data") }
+            .unwrap()
+            .is_empty());
+    }
+}
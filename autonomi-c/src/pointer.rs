@@ -0,0 +1,174 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Pointers, mutable addresses pointing to other data.
+
+use std::ffi::c_char;
+
+use autonomi::client::address::str_to_addr;
+use autonomi::pointer::{PointerAddress, PointerTarget};
+use autonomi::{ChunkAddress, GraphEntryAddress, ScratchpadAddress};
+
+use crate::client::AntClient;
+use crate::task::{AntTask, Output};
+use crate::wallet::{arg_public_key, arg_secret_key, AntWallet};
+use crate::{arg_hex, c_string, task_arg};
+
+/// Kind of data a pointer points to.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AntPointerTargetKind {
+    Chunk = 0,
+    GraphEntry = 1,
+    Pointer = 2,
+    /// Scratchpads are addressed by the hex of their owner's public key.
+    Scratchpad = 3,
+}
+
+unsafe fn arg_target(
+    kind: AntPointerTargetKind,
+    address: *const c_char,
+) -> Result<PointerTarget, String> {
+    let target = match kind {
+        AntPointerTargetKind::Chunk => {
+            PointerTarget::ChunkAddress(ChunkAddress::new(arg_hex(address, "target", str_to_addr)?))
+        }
+        AntPointerTargetKind::GraphEntry => PointerTarget::GraphEntryAddress(
+            GraphEntryAddress::new(arg_hex(address, "target", str_to_addr)?),
+        ),
+        AntPointerTargetKind::Pointer => PointerTarget::PointerAddress(PointerAddress::new(
+            arg_hex(address, "target", str_to_addr)?,
+        )),
+        AntPointerTargetKind::Scratchpad => PointerTarget::ScratchpadAddress(arg_hex(
+            address,
+            "target",
+            ScratchpadAddress::from_hex,
+        )?),
+    };
+    Ok(target)
+}
+
+fn target_output(target: &PointerTarget) -> (AntPointerTargetKind, String) {
+    match target {
+        PointerTarget::ChunkAddress(addr) => (AntPointerTargetKind::Chunk, addr.to_hex()),
+        PointerTarget::GraphEntryAddress(addr) => (AntPointerTargetKind::GraphEntry, addr.to_hex()),
+        PointerTarget::PointerAddress(addr) => (AntPointerTargetKind::Pointer, addr.to_hex()),
+        PointerTarget::ScratchpadAddress(addr) => (AntPointerTargetKind::Scratchpad, addr.to_hex()),
+    }
+}
+
+/// Create a pointer owned by the hex key `owner`, pointing to the hex address `target` of the
+/// given kind, paid for by `wallet`.
+///
+/// Outputs: the amount paid, and the address of the pointer.
+#[no_mangle]
+pub unsafe extern "C" fn ant_pointer_create(
+    client: *const AntClient,
+    owner: *const c_char,
+    target_kind: AntPointerTargetKind,
+    target: *const c_char,
+    wallet: *const AntWallet,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let owner = task_arg!(arg_secret_key(owner, "owner"));
+    let target = task_arg!(arg_target(target_kind, target));
+    let payment = task_arg!(AntWallet::payment(wallet));
+    AntTask::spawn(async move {
+        let (cost, addr) = client
+            .pointer_create(&owner, target, payment)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::cost_and_address(cost, addr.to_hex()))
+    })
+}
+
+/// Point the pointer owned by the hex key `owner` to a new target. Updates are free.
+#[no_mangle]
+pub unsafe extern "C" fn ant_pointer_update(
+    client: *const AntClient,
+    owner: *const c_char,
+    target_kind: AntPointerTargetKind,
+    target: *const c_char,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let owner = task_arg!(arg_secret_key(owner, "owner"));
+    let target = task_arg!(arg_target(target_kind, target));
+    AntTask::spawn(async move {
+        client
+            .pointer_update(&owner, target)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::default())
+    })
+}
+
+/// Fetch a pointer from its hex address.
+///
+/// Outputs: the counter of the pointer, which increases with each update, and its target.
+#[no_mangle]
+pub unsafe extern "C" fn ant_pointer_get(
+    client: *const AntClient,
+    address: *const c_char,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let addr = PointerAddress::new(task_arg!(arg_hex(address, "address", str_to_addr)));
+    AntTask::spawn(async move {
+        let pointer = client
+            .pointer_get(&addr)
+            .await
+            .map_err(|err| err.to_string())?;
+        let (kind, target) = target_output(pointer.target());
+        Ok(Output {
+            counter: Some(pointer.counter().into()),
+            pointer_target: Some((kind, c_string(target))),
+            ..Default::default()
+        })
+    })
+}
+
+/// Get the cost of creating a pointer owned by the hex public key `owner`.
+///
+/// Outputs: the cost, as an amount.
+#[no_mangle]
+pub unsafe extern "C" fn ant_pointer_cost(
+    client: *const AntClient,
+    owner: *const c_char,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let owner = task_arg!(arg_public_key(owner, "owner"));
+    AntTask::spawn(async move {
+        let cost = client
+            .pointer_cost(&owner)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::cost(cost))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    #[test]
+    fn targets_round_trip() {
+        let addr = "ab".repeat(32);
+        let hex = CString::new(addr.clone()).unwrap();
+        for kind in [
+            AntPointerTargetKind::Chunk,
+            AntPointerTargetKind::GraphEntry,
+            AntPointerTargetKind::Pointer,
+        ] {
+            let target = unsafe { arg_target(kind, hex.as_ptr()) }.unwrap();
+            assert_eq!(target_output(&target), (kind, addr.clone()));
+        }
+
+        // scratchpads are addressed by public keys
+        assert!(unsafe { arg_target(AntPointerTargetKind::Scratchpad, hex.as_ptr()) }.is_err());
+    }
+}
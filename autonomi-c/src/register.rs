@@ -0,0 +1,127 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Registers, mutable values keeping the history of their updates.
+
+use std::ffi::c_char;
+
+use autonomi::register::RegisterAddress;
+use autonomi::{Bytes, Client};
+
+use crate::client::AntClient;
+use crate::task::{AntTask, Output};
+use crate::wallet::{arg_public_key, arg_secret_key, AntWallet};
+use crate::{arg_bytes, arg_hex, arg_str, fail, owned_string, task_arg, AntStatus};
+
+/// Derive the hex key of a register from its owner's hex secret key and a name, or null if
+/// `owner` is invalid.
+///
+/// The register is owned by the derived key, keep track of the names used to find it again.
+#[no_mangle]
+pub unsafe extern "C" fn ant_register_key_from_name(
+    owner: *const c_char,
+    name: *const c_char,
+) -> *mut c_char {
+    let key = arg_secret_key(owner, "owner")
+        .and_then(|owner| arg_str(name, "name").map(|name| (owner, name)))
+        .map(|(owner, name)| Client::register_key_from_name(&owner, name));
+    match key {
+        Ok(key) => owned_string(key.to_hex()),
+        Err(err) => {
+            fail(AntStatus::InvalidArgument, err);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Create a register owned by the hex key `owner`, holding `len` bytes of any size, paid for by
+/// `wallet`.
+///
+/// Outputs: the amount paid, and the address of the register.
+#[no_mangle]
+pub unsafe extern "C" fn ant_register_create(
+    client: *const AntClient,
+    owner: *const c_char,
+    value: *const u8,
+    len: usize,
+    wallet: *const AntWallet,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let owner = task_arg!(arg_secret_key(owner, "owner"));
+    let value = Bytes::copy_from_slice(task_arg!(arg_bytes(value, len, "value")));
+    let payment = task_arg!(AntWallet::payment(wallet));
+    AntTask::spawn(async move {
+        let (cost, addr) = client
+            .register_create_bytes(&owner, value, payment)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::cost_and_address(cost, addr.to_hex()))
+    })
+}
+
+/// Update the value of a register owned by the hex key `owner`, paid for by `wallet`.
+///
+/// Outputs: the amount paid.
+#[no_mangle]
+pub unsafe extern "C" fn ant_register_update(
+    client: *const AntClient,
+    owner: *const c_char,
+    value: *const u8,
+    len: usize,
+    wallet: *const AntWallet,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let owner = task_arg!(arg_secret_key(owner, "owner"));
+    let value = Bytes::copy_from_slice(task_arg!(arg_bytes(value, len, "value")));
+    let payment = task_arg!(AntWallet::payment(wallet));
+    AntTask::spawn(async move {
+        let cost = client
+            .register_update_bytes(&owner, value, payment)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::cost(cost))
+    })
+}
+
+/// Fetch the current value of a register from its hex address.
+///
+/// Outputs: the bytes of the value.
+#[no_mangle]
+pub unsafe extern "C" fn ant_register_get(
+    client: *const AntClient,
+    address: *const c_char,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let addr = task_arg!(arg_hex(address, "address", RegisterAddress::from_hex));
+    AntTask::spawn(async move {
+        let value = client
+            .register_get_bytes(&addr)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::bytes(value))
+    })
+}
+
+/// Get the cost of creating a register owned by the hex public key `owner`.
+///
+/// Outputs: the cost, as an amount.
+#[no_mangle]
+pub unsafe extern "C" fn ant_register_cost(
+    client: *const AntClient,
+    owner: *const c_char,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let owner = task_arg!(arg_public_key(owner, "owner"));
+    AntTask::spawn(async move {
+        let cost = client
+            .register_cost(&owner)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::cost(cost))
+    })
+}
@@ -0,0 +1,111 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Scratchpads, mutable encrypted data stored at their owner's public key.
+
+use std::ffi::c_char;
+
+use autonomi::Bytes;
+
+use crate::client::AntClient;
+use crate::task::{AntTask, Output};
+use crate::wallet::{arg_public_key, arg_secret_key, AntWallet};
+use crate::{arg_bytes, task_arg};
+
+/// Create a scratchpad owned by the hex key `owner`, holding `len` bytes encrypted with that key,
+/// paid for by `wallet`. The content type is up to the caller.
+///
+/// Outputs: the amount paid, and the address of the scratchpad.
+#[no_mangle]
+pub unsafe extern "C" fn ant_scratchpad_create(
+    client: *const AntClient,
+    owner: *const c_char,
+    content_type: u64,
+    data: *const u8,
+    len: usize,
+    wallet: *const AntWallet,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let owner = task_arg!(arg_secret_key(owner, "owner"));
+    let data = Bytes::copy_from_slice(task_arg!(arg_bytes(data, len, "data")));
+    let payment = task_arg!(AntWallet::payment(wallet));
+    AntTask::spawn(async move {
+        let (cost, addr) = client
+            .scratchpad_create(&owner, content_type, &data, payment)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::cost_and_address(cost, addr.to_hex()))
+    })
+}
+
+/// Replace the content of the scratchpad owned by the hex key `owner`. Updates are free.
+#[no_mangle]
+pub unsafe extern "C" fn ant_scratchpad_update(
+    client: *const AntClient,
+    owner: *const c_char,
+    content_type: u64,
+    data: *const u8,
+    len: usize,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let owner = task_arg!(arg_secret_key(owner, "owner"));
+    let data = Bytes::copy_from_slice(task_arg!(arg_bytes(data, len, "data")));
+    AntTask::spawn(async move {
+        client
+            .scratchpad_update(&owner, content_type, &data)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::default())
+    })
+}
+
+/// Fetch the scratchpad owned by the hex key `owner`, and decrypt its content.
+///
+/// Outputs: the decrypted bytes, the counter of the scratchpad, which increases with each
+/// update, and its content type.
+#[no_mangle]
+pub unsafe extern "C" fn ant_scratchpad_get(
+    client: *const AntClient,
+    owner: *const c_char,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let owner = task_arg!(arg_secret_key(owner, "owner"));
+    AntTask::spawn(async move {
+        let scratchpad = client
+            .scratchpad_get_from_public_key(&owner.public_key())
+            .await
+            .map_err(|err| err.to_string())?;
+        let data = scratchpad
+            .decrypt_data(&owner)
+            .map_err(|err| format!("Failed to decrypt scratchpad: {err}"))?;
+        Ok(Output {
+            counter: Some(scratchpad.counter()),
+            content_type: Some(scratchpad.data_encoding()),
+            ..Output::bytes(data)
+        })
+    })
+}
+
+/// Get the cost of creating a scratchpad owned by the hex public key `owner`.
+///
+/// Outputs: the cost, as an amount.
+#[no_mangle]
+pub unsafe extern "C" fn ant_scratchpad_cost(
+    client: *const AntClient,
+    owner: *const c_char,
+) -> *mut AntTask {
+    let client = task_arg!(AntClient::arg(client)).clone();
+    let owner = task_arg!(arg_public_key(owner, "owner"));
+    AntTask::spawn(async move {
+        let cost = client
+            .scratchpad_cost(&owner)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(Output::cost(cost))
+    })
+}
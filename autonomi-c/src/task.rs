@@ -0,0 +1,370 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Handles of the operations running in the background.
+
+use std::ffi::{c_char, CString};
+use std::future::Future;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, OnceLock};
+
+use autonomi::files::PublicArchive;
+use autonomi::{AttoTokens, Bytes, Client};
+use tokio::runtime::Runtime;
+
+use crate::client::AntClient;
+use crate::files::AntPublicArchive;
+use crate::pointer::AntPointerTargetKind;
+use crate::{c_string, AntStatus};
+
+/// Runtime driving the tasks, started on first use.
+///
+/// If it cannot be started, every task fails with the reason.
+fn runtime() -> Result<&'static Runtime, String> {
+    static RUNTIME: OnceLock<Result<Runtime, String>> = OnceLock::new();
+    RUNTIME
+        .get_or_init(|| {
+            tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .map_err(|err| format!("Failed to start the tokio runtime: {err}"))
+        })
+        .as_ref()
+        .map_err(Clone::clone)
+}
+
+/// Outputs of a completed operation. Each operation documents which ones it sets.
+#[derive(Default)]
+pub(crate) struct Output {
+    pub amount: Option<CString>,
+    pub address: Option<CString>,
+    pub bytes: Option<Bytes>,
+    pub counter: Option<u64>,
+    pub content_type: Option<u64>,
+    pub pointer_target: Option<(AntPointerTargetKind, CString)>,
+    pub client: Option<Client>,
+    pub archive: Option<PublicArchive>,
+}
+
+impl Output {
+    pub fn cost(cost: AttoTokens) -> Self {
+        Self {
+            amount: Some(c_string(cost.to_string())),
+            ..Default::default()
+        }
+    }
+
+    pub fn cost_and_address(cost: AttoTokens, address: String) -> Self {
+        Self {
+            address: Some(c_string(address)),
+            ..Self::cost(cost)
+        }
+    }
+
+    pub fn bytes(bytes: Bytes) -> Self {
+        Self {
+            bytes: Some(bytes),
+            ..Default::default()
+        }
+    }
+}
+
+enum TaskState {
+    Pending,
+    Done(Result<Box<Output>, (AntStatus, CString)>),
+}
+
+/// An operation running in the background, see the crate documentation.
+pub struct AntTask {
+    state: Mutex<TaskState>,
+    done: Condvar,
+}
+
+impl AntTask {
+    /// Start an operation, handing its task over to the caller.
+    ///
+    /// If the operation panics, the task fails rather than staying pending forever.
+    pub(crate) fn spawn<F>(operation: F) -> *mut AntTask
+    where
+        F: Future<Output = Result<Output, String>> + Send + 'static,
+    {
+        let runtime = match runtime() {
+            Ok(runtime) => runtime,
+            Err(err) => return Self::failed(AntStatus::Failed, err),
+        };
+        let task = Arc::new(AntTask {
+            state: Mutex::new(TaskState::Pending),
+            done: Condvar::new(),
+        });
+        let running = Arc::clone(&task);
+        let operation = runtime.spawn(operation);
+        runtime.spawn(async move {
+            let result = match operation.await {
+                Ok(result) => result,
+                Err(err) => Err(format!("The operation did not complete: {err}")),
+            };
+            running.complete(
+                result
+                    .map(Box::new)
+                    .map_err(|err| (AntStatus::Failed, c_string(err))),
+            );
+        });
+        Arc::into_raw(task).cast_mut()
+    }
+
+    /// Hand a task that failed before starting over to the caller.
+    pub(crate) fn invalid_argument(err: String) -> *mut AntTask {
+        Self::failed(AntStatus::InvalidArgument, err)
+    }
+
+    fn failed(status: AntStatus, err: String) -> *mut AntTask {
+        let task = AntTask {
+            state: Mutex::new(TaskState::Done(Err((status, c_string(err))))),
+            done: Condvar::new(),
+        };
+        Arc::into_raw(Arc::new(task)).cast_mut()
+    }
+
+    fn complete(&self, result: Result<Box<Output>, (AntStatus, CString)>) {
+        *self.lock() = TaskState::Done(result);
+        self.done.notify_all();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, TaskState> {
+        // the state is only ever replaced as a whole, it cannot be left inconsistent
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn status(state: &TaskState) -> AntStatus {
+        match state {
+            TaskState::Pending => AntStatus::Pending,
+            TaskState::Done(Ok(_)) => AntStatus::Ok,
+            TaskState::Done(Err((status, _))) => *status,
+        }
+    }
+
+    /// Read an output of the completed task.
+    ///
+    /// The outputs are never moved nor dropped before the task is freed, so pointers into them
+    /// stay valid as long as the task.
+    unsafe fn output<T>(
+        task: *const AntTask,
+        get: impl FnOnce(&mut Output) -> Option<T>,
+    ) -> Option<T> {
+        let task = task.as_ref()?;
+        match &mut *task.lock() {
+            TaskState::Done(Ok(output)) => get(output),
+            _ => None,
+        }
+    }
+}
+
+/// Status of a task, [`AntStatus::Pending`] while it runs.
+#[no_mangle]
+pub unsafe extern "C" fn ant_task_poll(task: *const AntTask) -> AntStatus {
+    match task.as_ref() {
+        Some(task) => AntTask::status(&task.lock()),
+        None => AntStatus::InvalidArgument,
+    }
+}
+
+/// Block until a task completes, returning its status.
+#[no_mangle]
+pub unsafe extern "C" fn ant_task_wait(task: *const AntTask) -> AntStatus {
+    let Some(task) = task.as_ref() else {
+        return AntStatus::InvalidArgument;
+    };
+    let mut state = task.lock();
+    while matches!(*state, TaskState::Pending) {
+        state = task
+            .done
+            .wait(state)
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+    }
+    AntTask::status(&state)
+}
+
+/// Error message of a failed task, or null if it did not fail.
+#[no_mangle]
+pub unsafe extern "C" fn ant_task_error(task: *const AntTask) -> *const c_char {
+    let Some(task) = task.as_ref() else {
+        return std::ptr::null();
+    };
+    match &*task.lock() {
+        TaskState::Done(Err((_, msg))) => msg.as_ptr(),
+        _ => std::ptr::null(),
+    }
+}
+
+/// Release a task. Does nothing if `task` is null.
+#[no_mangle]
+pub unsafe extern "C" fn ant_task_free(task: *mut AntTask) {
+    if !task.is_null() {
+        drop(Arc::from_raw(task.cast_const()));
+    }
+}
+
+/// Amount of tokens paid, or the cost quoted, by the task. Null if it has none.
+#[no_mangle]
+pub unsafe extern "C" fn ant_task_amount(task: *const AntTask) -> *const c_char {
+    AntTask::output(task, |out| out.amount.as_ref().map(|s| s.as_ptr())).unwrap_or(std::ptr::null())
+}
+
+/// Address produced by the task, or null if it has none. For private data, this is the hex of
+/// the data map which gives access to it.
+#[no_mangle]
+pub unsafe extern "C" fn ant_task_address(task: *const AntTask) -> *const c_char {
+    AntTask::output(task, |out| out.address.as_ref().map(|s| s.as_ptr()))
+        .unwrap_or(std::ptr::null())
+}
+
+/// Bytes fetched by the task, or null if it has none. Their length is written to `len`.
+#[no_mangle]
+pub unsafe extern "C" fn ant_task_bytes(task: *const AntTask, len: *mut usize) -> *const u8 {
+    let bytes = AntTask::output(task, |out| {
+        out.bytes.as_ref().map(|b| (b.as_ptr(), b.len()))
+    });
+    let (ptr, bytes_len) = bytes.unwrap_or((std::ptr::null(), 0));
+    if let Some(len) = len.as_mut() {
+        *len = bytes_len;
+    }
+    ptr
+}
+
+/// Counter of the data fetched by the task, written to `counter`. Returns false if it has none.
+#[no_mangle]
+pub unsafe extern "C" fn ant_task_counter(task: *const AntTask, counter: *mut u64) -> bool {
+    match (AntTask::output(task, |out| out.counter), counter.as_mut()) {
+        (Some(value), Some(counter)) => {
+            *counter = value;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Content type of the scratchpad fetched by the task, written to `content_type`. Returns false
+/// if it has none.
+#[no_mangle]
+pub unsafe extern "C" fn ant_task_content_type(
+    task: *const AntTask,
+    content_type: *mut u64,
+) -> bool {
+    match (
+        AntTask::output(task, |out| out.content_type),
+        content_type.as_mut(),
+    ) {
+        (Some(value), Some(content_type)) => {
+            *content_type = value;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Target of the pointer fetched by the task, or null if it has none. The kind of the target is
+/// written to `kind`.
+#[no_mangle]
+pub unsafe extern "C" fn ant_task_pointer_target(
+    task: *const AntTask,
+    kind: *mut AntPointerTargetKind,
+) -> *const c_char {
+    let target = AntTask::output(task, |out| {
+        out.pointer_target
+            .as_ref()
+            .map(|(kind, address)| (*kind, address.as_ptr()))
+    });
+    match (target, kind.as_mut()) {
+        (Some((target_kind, address)), Some(kind)) => {
+            *kind = target_kind;
+            address
+        }
+        _ => std::ptr::null(),
+    }
+}
+
+/// Take the client connected by the task, to be released with
+/// [`crate::client::ant_client_free`]. Null if it has none, or it was already taken.
+#[no_mangle]
+pub unsafe extern "C" fn ant_task_take_client(task: *const AntTask) -> *mut AntClient {
+    AntTask::output(task, |out| out.client.take()).map_or(std::ptr::null_mut(), AntClient::into_raw)
+}
+
+/// Take the archive fetched by the task, to be released with
+/// [`crate::files::ant_public_archive_free`]. Null if it has none, or it was already taken.
+#[no_mangle]
+pub unsafe extern "C" fn ant_task_take_public_archive(
+    task: *const AntTask,
+) -> *mut AntPublicArchive {
+    AntTask::output(task, |out| out.archive.take())
+        .map_or(std::ptr::null_mut(), AntPublicArchive::into_raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn completed_task_outputs() {
+        let task = AntTask::spawn(async {
+            Ok(Output::cost_and_address(
+                AttoTokens::from_u64(3),
+                "abcd".to_string(),
+            ))
+        });
+        unsafe {
+            assert_eq!(ant_task_wait(task), AntStatus::Ok);
+            assert_eq!(ant_task_poll(task), AntStatus::Ok);
+            assert!(ant_task_error(task).is_null());
+            assert_eq!(
+                CStr::from_ptr(ant_task_amount(task)).to_str().unwrap(),
+                AttoTokens::from_u64(3).to_string()
+            );
+            assert_eq!(
+                CStr::from_ptr(ant_task_address(task)).to_str().unwrap(),
+                "abcd"
+            );
+
+            let mut len = 1;
+            assert!(ant_task_bytes(task, &mut len).is_null());
+            assert_eq!(len, 0);
+            let mut counter = 0;
+            assert!(!ant_task_counter(task, &mut counter));
+            assert!(ant_task_take_client(task).is_null());
+            ant_task_free(task);
+        }
+    }
+
+    #[test]
+    fn failed_task_error() {
+        let task = AntTask::spawn(async { Err("network down".to_string()) });
+        unsafe {
+            assert_eq!(ant_task_wait(task), AntStatus::Failed);
+            let msg = CStr::from_ptr(ant_task_error(task));
+            assert_eq!(msg.to_str().unwrap(), "network down");
+            assert!(ant_task_amount(task).is_null());
+            ant_task_free(task);
+        }
+
+        let task = AntTask::spawn(async { panic!("boom") });
+        unsafe {
+            assert_eq!(ant_task_wait(task), AntStatus::Failed);
+            let msg = CStr::from_ptr(ant_task_error(task));
+            assert!(msg.to_str().unwrap().contains("panicked"));
+            ant_task_free(task);
+        }
+
+        let task = AntTask::invalid_argument("`data` is null".to_string());
+        unsafe {
+            assert_eq!(ant_task_poll(task), AntStatus::InvalidArgument);
+            ant_task_free(task);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Wallets paying for uploads, and keys owning mutable data.
+
+use std::ffi::c_char;
+
+use autonomi::client::payment::PaymentOption;
+use autonomi::{Network, PublicKey, SecretKey, Wallet};
+
+use crate::task::{AntTask, Output};
+use crate::{
+    arg_hex, arg_ref, arg_str, c_string, fail, owned_string, status_arg, task_arg, AntStatus,
+};
+
+/// An EVM wallet, paying for uploads.
+pub struct AntWallet {
+    pub(crate) inner: Wallet,
+}
+
+impl AntWallet {
+    /// Borrow the wallet argument of an operation.
+    pub(crate) unsafe fn arg<'a>(wallet: *const AntWallet) -> Result<&'a Wallet, String> {
+        arg_ref(wallet, "wallet").map(|wallet| &wallet.inner)
+    }
+
+    /// Pay with the wallet argument of an operation.
+    pub(crate) unsafe fn payment(wallet: *const AntWallet) -> Result<PaymentOption, String> {
+        Self::arg(wallet).map(PaymentOption::from)
+    }
+}
+
+/// Parse a secret key argument.
+pub(crate) unsafe fn arg_secret_key(ptr: *const c_char, name: &str) -> Result<SecretKey, String> {
+    arg_hex(ptr, name, SecretKey::from_hex)
+}
+
+/// Parse a public key argument.
+pub(crate) unsafe fn arg_public_key(ptr: *const c_char, name: &str) -> Result<PublicKey, String> {
+    arg_hex(ptr, name, PublicKey::from_hex)
+}
+
+/// Load the wallet of a hex private key, on the local EVM network if `local` is true, or on the
+/// network configured in the environment otherwise. The wallet is written to `wallet`.
+#[no_mangle]
+pub unsafe extern "C" fn ant_wallet_from_private_key(
+    private_key: *const c_char,
+    local: bool,
+    wallet: *mut *mut AntWallet,
+) -> AntStatus {
+    let private_key = status_arg!(arg_str(private_key, "private_key"));
+    if wallet.is_null() {
+        return fail(AntStatus::InvalidArgument, "`wallet` is null");
+    }
+    let network = match Network::new(local) {
+        Ok(network) => network,
+        Err(err) => return fail(AntStatus::Failed, format!("Failed to get network: {err}")),
+    };
+    let inner = status_arg!(Wallet::new_from_private_key(network, private_key)
+        .map_err(|err| format!("Failed to parse `private_key`: {err}")));
+    *wallet = Box::into_raw(Box::new(AntWallet { inner }));
+    AntStatus::Ok
+}
+
+/// Address of a wallet, or null if `wallet` is null.
+#[no_mangle]
+pub unsafe extern "C" fn ant_wallet_address(wallet: *const AntWallet) -> *mut c_char {
+    match AntWallet::arg(wallet) {
+        Ok(wallet) => owned_string(format!("{:?}", wallet.address())),
+        Err(err) => {
+            fail(AntStatus::InvalidArgument, err);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Fetch the token balance of a wallet.
+///
+/// Outputs: the balance, as an amount.
+#[no_mangle]
+pub unsafe extern "C" fn ant_wallet_balance(wallet: *const AntWallet) -> *mut AntTask {
+    let wallet = task_arg!(AntWallet::arg(wallet)).clone();
+    AntTask::spawn(async move {
+        let balance = wallet
+            .balance_of_tokens()
+            .await
+            .map_err(|err| format!("Failed to get balance: {err}"))?;
+        Ok(Output {
+            amount: Some(c_string(balance.to_string())),
+            ..Default::default()
+        })
+    })
+}
+
+/// Release a wallet. Does nothing if `wallet` is null.
+#[no_mangle]
+pub unsafe extern "C" fn ant_wallet_free(wallet: *mut AntWallet) {
+    if !wallet.is_null() {
+        drop(Box::from_raw(wallet));
+    }
+}
+
+/// Generate a random secret key, as hex.
+#[no_mangle]
+pub extern "C" fn ant_secret_key_random() -> *mut c_char {
+    owned_string(SecretKey::random().to_hex())
+}
+
+/// Public key of a hex secret key, as hex, or null if `secret_key` is invalid.
+#[no_mangle]
+pub unsafe extern "C" fn ant_public_key_from_secret_key(secret_key: *const c_char) -> *mut c_char {
+    match arg_secret_key(secret_key, "secret_key") {
+        Ok(key) => owned_string(key.public_key().to_hex()),
+        Err(err) => {
+            fail(AntStatus::InvalidArgument, err);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::{CStr, CString};
+
+    #[test]
+    fn public_key_from_secret_key() {
+        let key = SecretKey::random();
+        let hex = CString::new(key.to_hex()).unwrap();
+        unsafe {
+            let public_key = ant_public_key_from_secret_key(hex.as_ptr());
+            assert_eq!(
+                CStr::from_ptr(public_key).to_str().unwrap(),
+                key.public_key().to_hex()
+            );
+            crate::ant_string_free(public_key);
+
+            let invalid = CString::new("not hex").unwrap();
+            assert!(ant_public_key_from_secret_key(invalid.as_ptr()).is_null());
+            assert!(!crate::ant_last_error().is_null());
+        }
+    }
+}
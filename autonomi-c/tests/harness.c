@@ -0,0 +1,359 @@
+/* Copyright 2025 MaidSafe.net limited.
+ *
+ * This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+ * Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+ * under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied. Please review the Licences for the specific language governing
+ * permissions and limitations relating to use of the SAFE Network Software.
+ */
+
+/*
+ * Exercises the C API against a local network, started with `cargo run --bin evm-testnet` and
+ * `cargo run --bin antctl -- local run --build --clean --rewards-address <address>`.
+ *
+ * Build and run it with `just autonomi-c-harness`. The wallet paying for uploads can be set with
+ * the `SECRET_KEY` environment variable, and defaults to the deployer wallet of the local EVM
+ * testnet.
+ */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <time.h>
+#include <unistd.h>
+#include <sys/stat.h>
+
+#include "autonomi.h"
+
+#define DEFAULT_WALLET_PRIVATE_KEY \
+    "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                          \
+    do {                                                                     \
+        if (!(cond)) {                                                       \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, \
+                    #cond);                                                  \
+            failures++;                                                      \
+        }                                                                    \
+    } while (0)
+
+/* Wait for a task to complete, reporting its error if it failed. */
+static AntTask *wait_ok(AntTask *task, const char *what) {
+    AntStatus status = ant_task_wait(task);
+    if (status != ANT_STATUS_OK) {
+        fprintf(stderr, "%s failed (%d): %s\n", what, status, ant_task_error(task));
+        failures++;
+    } else {
+        printf("%s: ok\n", what);
+    }
+    return task;
+}
+
+/* Run a task to completion, then release it. */
+static void run(AntTask *task, const char *what) {
+    ant_task_free(wait_ok(task, what));
+}
+
+/* Wait for the data to be replicated. */
+static void replication(void) {
+    sleep(5);
+}
+
+/* Copy the address output of a task, to be released with free. */
+static char *take_address(AntTask *task) {
+    const char *address = ant_task_address(task);
+    return address ? strdup(address) : NULL;
+}
+
+static int bytes_equal(AntTask *task, const char *expected) {
+    size_t len = 0;
+    const uint8_t *bytes = ant_task_bytes(task, &len);
+    return bytes && len == strlen(expected) && memcmp(bytes, expected, len) == 0;
+}
+
+static void test_invalid_arguments(AntClient *client) {
+    AntTask *task = ant_data_get_public(client, "not an address");
+    CHECK(ant_task_poll(task) == ANT_STATUS_INVALID_ARGUMENT);
+    CHECK(ant_task_error(task) != NULL);
+    CHECK(ant_task_bytes(task, NULL) == NULL);
+    ant_task_free(task);
+
+    task = ant_data_get_public(NULL, NULL);
+    CHECK(ant_task_wait(task) == ANT_STATUS_INVALID_ARGUMENT);
+    ant_task_free(task);
+
+    CHECK(ant_public_key_from_secret_key("not a key") == NULL);
+    CHECK(ant_last_error() != NULL);
+}
+
+static void test_data(AntClient *client, AntWallet *wallet) {
+    char content[64];
+    snprintf(content, sizeof(content), "Hello, Autonomi! %ld", (long)time(NULL));
+
+    AntTask *task = ant_data_cost(client, (const uint8_t *)content, strlen(content));
+    wait_ok(task, "data cost");
+    CHECK(ant_task_amount(task) != NULL);
+    ant_task_free(task);
+
+    /* public data */
+    task = wait_ok(ant_data_put_public(client, (const uint8_t *)content, strlen(content), wallet),
+                   "data put public");
+    CHECK(ant_task_amount(task) != NULL);
+    char *address = take_address(task);
+    ant_task_free(task);
+    CHECK(address != NULL);
+    replication();
+
+    task = wait_ok(ant_data_get_public(client, address), "data get public");
+    CHECK(bytes_equal(task, content));
+    ant_task_free(task);
+    free(address);
+
+    /* private data */
+    task = wait_ok(ant_data_put(client, (const uint8_t *)content, strlen(content), wallet),
+                   "data put");
+    char *data_map = take_address(task);
+    ant_task_free(task);
+    CHECK(data_map != NULL);
+    replication();
+
+    task = wait_ok(ant_data_get(client, data_map), "data get");
+    CHECK(bytes_equal(task, content));
+    ant_task_free(task);
+    free(data_map);
+}
+
+static void write_file(const char *path, const char *content) {
+    FILE *file = fopen(path, "w");
+    CHECK(file != NULL);
+    if (file) {
+        fputs(content, file);
+        fclose(file);
+    }
+}
+
+static int file_equal(const char *path, const char *expected) {
+    char buf[256] = {0};
+    FILE *file = fopen(path, "r");
+    if (!file) {
+        return 0;
+    }
+    size_t len = fread(buf, 1, sizeof(buf) - 1, file);
+    fclose(file);
+    return len == strlen(expected) && memcmp(buf, expected, len) == 0;
+}
+
+static void test_files(AntClient *client, AntWallet *wallet) {
+    char dir[] = "/tmp/autonomi-c-XXXXXX";
+    CHECK(mkdtemp(dir) != NULL);
+
+    char upload_dir[128], file_path[160], download_dir[128], download_path[192];
+    snprintf(upload_dir, sizeof(upload_dir), "%s/upload", dir);
+    snprintf(file_path, sizeof(file_path), "%s/hello.txt", upload_dir);
+    snprintf(download_dir, sizeof(download_dir), "%s/download", dir);
+    mkdir(upload_dir, 0700);
+
+    char content[64];
+    snprintf(content, sizeof(content), "file content %ld", (long)time(NULL));
+    write_file(file_path, content);
+
+    /* single file */
+    AntTask *task = wait_ok(ant_file_upload_public(client, file_path, wallet), "file upload public");
+    char *file_address = take_address(task);
+    ant_task_free(task);
+    replication();
+
+    snprintf(download_path, sizeof(download_path), "%s/file.txt", dir);
+    run(ant_file_download_public(client, file_address, download_path), "file download public");
+    CHECK(file_equal(download_path, content));
+
+    /* directory, with a public archive */
+    task = wait_ok(ant_dir_upload_public(client, upload_dir, wallet), "dir upload public");
+    char *archive_address = take_address(task);
+    ant_task_free(task);
+    replication();
+
+    task = wait_ok(ant_archive_get_public(client, archive_address), "archive get public");
+    AntPublicArchive *archive = ant_task_take_public_archive(task);
+    CHECK(ant_task_take_public_archive(task) == NULL);
+    ant_task_free(task);
+    CHECK(archive != NULL);
+    CHECK(ant_public_archive_len(archive) == 1);
+    CHECK(strcmp(ant_public_archive_address(archive, 0), file_address) == 0);
+    CHECK(ant_public_archive_size(archive, 0) == strlen(content));
+    CHECK(ant_public_archive_path(archive, 1) == NULL);
+    ant_public_archive_free(archive);
+
+    run(ant_dir_download_public(client, archive_address, download_dir), "dir download public");
+    snprintf(download_path, sizeof(download_path), "%s/download/hello.txt", dir);
+    CHECK(file_equal(download_path, content));
+
+    /* directory, with a private archive */
+    task = wait_ok(ant_dir_upload(client, upload_dir, wallet), "dir upload");
+    char *archive_data_map = take_address(task);
+    ant_task_free(task);
+    replication();
+
+    snprintf(download_dir, sizeof(download_dir), "%s/private", dir);
+    run(ant_dir_download(client, archive_data_map, download_dir), "dir download");
+    snprintf(download_path, sizeof(download_path), "%s/private/hello.txt", dir);
+    CHECK(file_equal(download_path, content));
+
+    free(file_address);
+    free(archive_address);
+    free(archive_data_map);
+}
+
+static void test_registers(AntClient *client, AntWallet *wallet) {
+    char *owner = ant_secret_key_random();
+    char *key = ant_register_key_from_name(owner, "register1");
+    CHECK(key != NULL);
+
+    char *public_key = ant_public_key_from_secret_key(key);
+    AntTask *task = wait_ok(ant_register_cost(client, public_key), "register cost");
+    CHECK(ant_task_amount(task) != NULL);
+    ant_task_free(task);
+
+    const char *first = "Hello, World!";
+    task = wait_ok(ant_register_create(client, key, (const uint8_t *)first, strlen(first), wallet),
+                   "register create");
+    char *address = take_address(task);
+    ant_task_free(task);
+    replication();
+
+    task = wait_ok(ant_register_get(client, address), "register get");
+    CHECK(bytes_equal(task, first));
+    ant_task_free(task);
+
+    /* values do not have to fit in a single register entry */
+    char large[1001];
+    memset(large, 'a', sizeof(large) - 1);
+    large[sizeof(large) - 1] = '\0';
+    run(ant_register_update(client, key, (const uint8_t *)large, strlen(large), wallet),
+        "register update");
+    replication();
+
+    task = wait_ok(ant_register_get(client, address), "register get updated");
+    CHECK(bytes_equal(task, large));
+    ant_task_free(task);
+
+    free(address);
+    ant_string_free(public_key);
+    ant_string_free(key);
+    ant_string_free(owner);
+}
+
+static void test_pointers(AntClient *client, AntWallet *wallet) {
+    char *owner = ant_secret_key_random();
+    char target[65];
+    memset(target, 'a', 64);
+    target[64] = '\0';
+
+    AntTask *task = wait_ok(
+        ant_pointer_create(client, owner, ANT_POINTER_TARGET_KIND_CHUNK, target, wallet),
+        "pointer create");
+    char *address = take_address(task);
+    ant_task_free(task);
+    replication();
+
+    task = wait_ok(ant_pointer_get(client, address), "pointer get");
+    uint64_t counter = 1;
+    AntPointerTargetKind kind = ANT_POINTER_TARGET_KIND_POINTER;
+    CHECK(ant_task_counter(task, &counter) && counter == 0);
+    const char *got = ant_task_pointer_target(task, &kind);
+    CHECK(got != NULL && strcmp(got, target) == 0);
+    CHECK(kind == ANT_POINTER_TARGET_KIND_CHUNK);
+    ant_task_free(task);
+
+    run(ant_pointer_update(client, owner, ANT_POINTER_TARGET_KIND_POINTER, address),
+        "pointer update");
+    replication();
+
+    task = wait_ok(ant_pointer_get(client, address), "pointer get updated");
+    CHECK(ant_task_counter(task, &counter) && counter == 1);
+    got = ant_task_pointer_target(task, &kind);
+    CHECK(got != NULL && strcmp(got, address) == 0);
+    CHECK(kind == ANT_POINTER_TARGET_KIND_POINTER);
+    ant_task_free(task);
+
+    free(address);
+    ant_string_free(owner);
+}
+
+static void test_scratchpads(AntClient *client, AntWallet *wallet) {
+    char *owner = ant_secret_key_random();
+    const uint64_t content_type = 42;
+
+    run(ant_scratchpad_create(client, owner, content_type, (const uint8_t *)"first", 5, wallet),
+        "scratchpad create");
+    replication();
+
+    AntTask *task = wait_ok(ant_scratchpad_get(client, owner), "scratchpad get");
+    uint64_t got_content_type = 0;
+    CHECK(bytes_equal(task, "first"));
+    CHECK(ant_task_content_type(task, &got_content_type) && got_content_type == content_type);
+    ant_task_free(task);
+
+    run(ant_scratchpad_update(client, owner, content_type, (const uint8_t *)"second", 6),
+        "scratchpad update");
+    replication();
+
+    task = wait_ok(ant_scratchpad_get(client, owner), "scratchpad get updated");
+    uint64_t counter = 0;
+    CHECK(bytes_equal(task, "second"));
+    CHECK(ant_task_counter(task, &counter) && counter == 1);
+    ant_task_free(task);
+
+    ant_string_free(owner);
+}
+
+int main(void) {
+    const char *private_key = getenv("SECRET_KEY");
+    if (!private_key) {
+        private_key = DEFAULT_WALLET_PRIVATE_KEY;
+    }
+
+    AntWallet *wallet = NULL;
+    if (ant_wallet_from_private_key(private_key, true, &wallet) != ANT_STATUS_OK) {
+        fprintf(stderr, "failed to load wallet: %s\n", ant_last_error());
+        return 1;
+    }
+    char *wallet_address = ant_wallet_address(wallet);
+    printf("wallet: %s\n", wallet_address);
+    ant_string_free(wallet_address);
+
+    AntTask *task = wait_ok(ant_wallet_balance(wallet), "wallet balance");
+    printf("balance: %s\n", ant_task_amount(task));
+    ant_task_free(task);
+
+    /* poll until connected, rather than blocking */
+    task = ant_client_init_local();
+    while (ant_task_poll(task) == ANT_STATUS_PENDING) {
+        usleep(100 * 1000);
+    }
+    AntClient *client = ant_task_take_client(wait_ok(task, "client init local"));
+    ant_task_free(task);
+    if (!client) {
+        ant_wallet_free(wallet);
+        return 1;
+    }
+
+    test_invalid_arguments(client);
+    test_data(client, wallet);
+    test_files(client, wallet);
+    test_registers(client, wallet);
+    test_pointers(client, wallet);
+    test_scratchpads(client, wallet);
+
+    ant_client_free(client);
+    ant_wallet_free(wallet);
+
+    if (failures) {
+        fprintf(stderr, "%d checks failed\n", failures);
+        return 1;
+    }
+    printf("all checks passed\n");
+    return 0;
+}
@@ -0,0 +1,533 @@
+"""Type stubs for the Autonomi client.
+
+Amounts of tokens are decimal strings, and addresses are hex strings unless a dedicated address
+type is used. Methods going through the network are coroutines.
+"""
+
+from os import PathLike
+from typing import AsyncIterator, Optional, Union
+
+StrPath = Union[str, PathLike[str]]
+
+class Client:
+    @staticmethod
+    async def init() -> Client: ...
+    @staticmethod
+    async def init_local() -> Client: ...
+    @staticmethod
+    async def init_with_peers(peers: list[str]) -> Client: ...
+    @staticmethod
+    async def init_with_config(config: ClientConfig) -> Client: ...
+    def enable_client_events(self) -> ClientEventReceiver: ...
+    def evm_network(self) -> Network: ...
+
+    # Chunks
+    async def chunk_cost(self, addr: ChunkAddress) -> str: ...
+    async def chunk_get(self, addr: ChunkAddress) -> bytes: ...
+    async def chunk_put(self, data: bytes, payment: PaymentOption) -> tuple[str, ChunkAddress]: ...
+    async def upload_chunks_with_retries(
+        self, chunks: list[Chunk], receipt: Receipt
+    ) -> list[tuple[Chunk, str]]: ...
+
+    # Quotes
+    async def get_store_quotes(
+        self, data_type: DataTypes, content_addrs: list[tuple[str, int]]
+    ) -> StoreQuote: ...
+    async def get_quotes_for_content_addresses(
+        self, data_type: DataTypes, content_addrs: list[tuple[str, int]]
+    ) -> tuple[StoreQuote, list[QuotePayment], list[str]]: ...
+
+    # Graph entries
+    async def graph_entry_get(self, addr: str) -> GraphEntry: ...
+    async def graph_entry_check_existance(self, addr: str) -> bool: ...
+    async def graph_entry_put(
+        self, entry: GraphEntry, payment_option: PaymentOption
+    ) -> tuple[str, str]: ...
+    async def graph_entry_cost(self, key: PublicKey) -> str: ...
+
+    # Scratchpads
+    async def scratchpad_get_from_public_key(self, public_key: PublicKey) -> Scratchpad: ...
+    async def scratchpad_get(self, addr: str) -> Scratchpad: ...
+    async def scratchpad_check_existance(self, addr: str) -> bool: ...
+    async def scratchpad_put(
+        self, scratchpad: Scratchpad, payment_option: PaymentOption
+    ) -> tuple[str, str]: ...
+    async def scratchpad_create(
+        self,
+        owner: SecretKey,
+        content_type: int,
+        initial_data: bytes,
+        payment_option: PaymentOption,
+    ) -> tuple[str, str]: ...
+    async def scratchpad_update(self, owner: SecretKey, content_type: int, data: bytes) -> None: ...
+    async def scratchpad_update_if(
+        self, owner: SecretKey, content_type: int, data: bytes, expected_counter: int
+    ) -> None: ...
+    async def scratchpad_create_shared(
+        self,
+        owner: SecretKey,
+        content_type: int,
+        initial_data: bytes,
+        readers: list[PublicKey],
+        payment_option: PaymentOption,
+    ) -> tuple[str, str]: ...
+    async def scratchpad_update_shared(
+        self, owner: SecretKey, content_type: int, data: bytes, readers: list[PublicKey]
+    ) -> None: ...
+    async def scratchpad_add_readers(self, owner: SecretKey, readers: list[PublicKey]) -> None: ...
+    async def scratchpad_revoke_readers(
+        self, owner: SecretKey, readers: list[PublicKey]
+    ) -> None: ...
+    @staticmethod
+    def scratchpad_verify(scratchpad: Scratchpad) -> None: ...
+    async def scratchpad_cost(self, public_key: PublicKey) -> str: ...
+
+    # Archives and files
+    async def archive_cost(self, archive: PublicArchive) -> str: ...
+    async def archive_get(self, data_map: DataMapChunk) -> PrivateArchive: ...
+    async def archive_put(
+        self, archive: PrivateArchive, payment: PaymentOption
+    ) -> tuple[str, DataMapChunk]: ...
+    async def archive_put_public(self, archive: PublicArchive, wallet: Wallet) -> tuple[str, str]: ...
+    async def archive_get_public(self, addr: str) -> PublicArchive: ...
+    async def file_cost(self, path: StrPath) -> str: ...
+    async def file_upload(self, path: StrPath, wallet: Wallet) -> tuple[str, DataMapChunk]: ...
+    async def file_upload_public(self, path: StrPath, wallet: Wallet) -> tuple[str, str]: ...
+    async def file_download(self, data_map: DataMapChunk, path: StrPath) -> None: ...
+    async def file_download_public(self, addr: str, path: StrPath) -> None: ...
+    async def dir_upload(self, dir_path: StrPath, wallet: Wallet) -> tuple[str, PrivateArchive]: ...
+    async def dir_upload_public(
+        self, dir_path: StrPath, wallet: Wallet
+    ) -> tuple[str, PublicArchive]: ...
+    async def dir_download(self, data_map: DataMapChunk, dir_path: StrPath) -> None: ...
+    async def dir_download_public(self, addr: str, dir_path: StrPath) -> None: ...
+    async def dir_and_archive_upload(
+        self, dir_path: StrPath, wallet: Wallet
+    ) -> tuple[str, DataMapChunk]: ...
+    async def dir_and_archive_upload_public(
+        self, dir_path: StrPath, wallet: Wallet
+    ) -> tuple[str, str]: ...
+
+    # Data
+    async def data_put(self, data: bytes, payment: PaymentOption) -> tuple[str, DataMapChunk]: ...
+    async def data_get(self, access: DataMapChunk) -> bytes: ...
+    async def data_cost(self, data: bytes) -> str: ...
+    async def data_put_public(self, data: bytes, payment: PaymentOption) -> tuple[str, str]: ...
+    async def data_get_public(self, addr: str) -> bytes: ...
+
+    # Vaults
+    async def vault_cost(self, key: VaultSecretKey, max_expected_size: int) -> str: ...
+    async def write_bytes_to_vault(
+        self, data: bytes, payment: PaymentOption, key: VaultSecretKey, content_type: int
+    ) -> str: ...
+    async def fetch_and_decrypt_vault(self, key: VaultSecretKey) -> tuple[bytes, int]: ...
+    async def get_user_data_from_vault(self, key: VaultSecretKey) -> UserData: ...
+    async def put_user_data_to_vault(
+        self, key: VaultSecretKey, payment: PaymentOption, user_data: UserData
+    ) -> str: ...
+
+    # Registers
+    def register_history(self, addr: str) -> RegisterHistory: ...
+    @staticmethod
+    def register_key_from_name(owner: SecretKey, name: str) -> SecretKey: ...
+    @staticmethod
+    def register_value_from_bytes(bytes: bytes) -> bytes: ...
+    async def register_create(
+        self, owner: SecretKey, value: bytes, payment: PaymentOption
+    ) -> tuple[str, str]: ...
+    async def register_update(self, owner: SecretKey, value: bytes, payment: PaymentOption) -> str: ...
+    async def register_get(self, addr: str) -> bytes: ...
+    async def register_cost(self, owner: PublicKey) -> str: ...
+    async def register_create_bytes(
+        self, owner: SecretKey, value: bytes, payment: PaymentOption
+    ) -> tuple[str, str]: ...
+    async def register_update_bytes(
+        self, owner: SecretKey, value: bytes, payment: PaymentOption
+    ) -> str: ...
+    async def register_get_bytes(self, addr: str) -> bytes: ...
+    async def register_value_from_data(
+        self, data: bytes, payment: PaymentOption
+    ) -> tuple[bytes, str]: ...
+    async def register_value_to_data(self, value: bytes) -> bytes: ...
+    async def register_fork_heads(self, addr: str) -> list[bytes]: ...
+    async def register_resolve_fork(
+        self, owner: SecretKey, value: bytes, payment: PaymentOption
+    ) -> str: ...
+
+    # Blobs
+    async def blob_create(
+        self, owner: SecretKey, content_type: int, data: bytes, payment: PaymentOption
+    ) -> tuple[str, str]: ...
+    async def blob_get(self, owner: SecretKey) -> tuple[bytes, int]: ...
+    async def blob_update(self, owner: SecretKey, data: bytes, payment: PaymentOption) -> str: ...
+    async def blob_cost(self, owner: SecretKey, data: bytes) -> str: ...
+
+    # Maps
+    @staticmethod
+    def map_key_from_name(owner: SecretKey, name: str) -> SecretKey: ...
+    async def map_create(self, owner: SecretKey, payment: PaymentOption) -> tuple[str, str]: ...
+    async def map_insert(
+        self, owner: SecretKey, key: str, value: bytes, payment: PaymentOption
+    ) -> str: ...
+    async def map_remove(self, owner: SecretKey, key: str) -> None: ...
+    async def map_get(self, owner: SecretKey, key: str) -> Optional[bytes]: ...
+    async def map_iter(self, owner: SecretKey) -> list[tuple[str, bytes]]: ...
+
+    # Pointers
+    async def pointer_get(self, addr: PointerAddress) -> Pointer: ...
+    async def pointer_check_existance(self, addr: PointerAddress) -> bool: ...
+    async def pointer_put(self, pointer: Pointer, payment_option: PaymentOption) -> PointerAddress: ...
+    async def pointer_create(
+        self, owner: SecretKey, target: PointerTarget, payment_option: PaymentOption
+    ) -> tuple[str, PointerAddress]: ...
+    async def pointer_update(self, owner: SecretKey, target: PointerTarget) -> None: ...
+    async def pointer_update_if(
+        self, owner: SecretKey, target: PointerTarget, expected_counter: int
+    ) -> None: ...
+    @staticmethod
+    def pointer_verify(pointer: Pointer) -> None: ...
+    async def pointer_cost(self, key: PublicKey) -> str: ...
+
+    # Watching
+    def watch(self, address: WatchAddress) -> Watcher: ...
+    def watch_with_config(self, address: WatchAddress, config: WatchConfig) -> Watcher: ...
+
+class ClientConfig:
+    @staticmethod
+    def new() -> ClientConfig: ...
+    local: bool
+    peers: Optional[list[str]]
+    network: Network
+    strategy: ClientOperatingStrategy
+
+class ClientOperatingStrategy:
+    def __init__(self) -> None: ...
+    chunks: Strategy
+    graph_entry: Strategy
+    pointer: Strategy
+    scratchpad: Strategy
+
+class Strategy:
+    """Quorums are "one", "majority", "all" or a number of nodes. Retry strategies are "none",
+    "quick", "balanced", "persistent" or a number of attempts."""
+
+    put_quorum: str
+    put_retry: str
+    verification_quorum: str
+    verification_retry: str
+    get_quorum: str
+    get_retry: str
+
+class ClientEventReceiver:
+    async def recv(self) -> Optional[ClientEvent]: ...
+    def __aiter__(self) -> AsyncIterator[ClientEvent]: ...
+    async def __anext__(self) -> ClientEvent: ...
+
+class ClientEvent:
+    @property
+    def upload_summary(self) -> Optional[UploadSummary]: ...
+
+class UploadSummary:
+    @property
+    def records_paid(self) -> int: ...
+    @property
+    def records_already_paid(self) -> int: ...
+    @property
+    def tokens_spent(self) -> str: ...
+
+class Network:
+    def __init__(self, local: bool) -> None: ...
+
+class Wallet:
+    def __init__(self, private_key: str) -> None: ...
+    @staticmethod
+    def new_from_private_key(network: Network, private_key: str) -> Wallet: ...
+    def address(self) -> str: ...
+    async def balance(self) -> str: ...
+    async def balance_of_gas(self) -> str: ...
+    def network(self) -> Network: ...
+    async def pay_for_quotes(self, payments: list[QuotePayment]) -> dict[str, str]: ...
+
+class PaymentOption:
+    @staticmethod
+    def wallet(wallet: Wallet) -> PaymentOption: ...
+    @staticmethod
+    def receipt(receipt: Receipt) -> PaymentOption: ...
+
+class SecretKey:
+    def __init__(self) -> None: ...
+    @staticmethod
+    def from_hex(hex_str: str) -> SecretKey: ...
+    def public_key(self) -> PublicKey: ...
+    def to_hex(self) -> str: ...
+
+class PublicKey:
+    def __init__(self) -> None: ...
+    @staticmethod
+    def from_hex(hex_str: str) -> PublicKey: ...
+    def to_hex(self) -> str: ...
+
+class VaultSecretKey:
+    def __init__(self) -> None: ...
+    @staticmethod
+    def from_hex(hex_str: str) -> VaultSecretKey: ...
+    def to_hex(self) -> str: ...
+
+class MainSecretKey:
+    def __init__(self, key: SecretKey) -> None: ...
+    @staticmethod
+    def random() -> MainSecretKey: ...
+    def public_key(self) -> MainPubkey: ...
+    def sign(self, msg: bytes) -> Signature: ...
+    def derive_key(self, index: DerivationIndex) -> DerivedSecretKey: ...
+    def random_derived_key(self) -> DerivedSecretKey: ...
+    def to_bytes(self) -> bytes: ...
+    def to_secret_key(self) -> SecretKey: ...
+
+class MainPubkey:
+    def __init__(self, key: PublicKey) -> None: ...
+    @staticmethod
+    def from_hex(hex: str) -> MainPubkey: ...
+    def verify(self, signature: Signature, msg: bytes) -> bool: ...
+    def derive_key(self, index: DerivationIndex) -> DerivedPubkey: ...
+    def to_bytes(self) -> bytes: ...
+    def to_hex(self) -> str: ...
+    def to_public_key(self) -> PublicKey: ...
+
+class DerivationIndex:
+    @staticmethod
+    def random() -> DerivationIndex: ...
+    @staticmethod
+    def from_bytes(bytes: bytes) -> DerivationIndex: ...
+    def to_bytes(self) -> bytes: ...
+
+class DerivedSecretKey:
+    def __init__(self, key: SecretKey) -> None: ...
+    def public_key(self) -> DerivedPubkey: ...
+    def sign(self, msg: bytes) -> Signature: ...
+    def to_secret_key(self) -> SecretKey: ...
+
+class DerivedPubkey:
+    def __init__(self, key: PublicKey) -> None: ...
+    @staticmethod
+    def from_hex(hex: str) -> DerivedPubkey: ...
+    def verify(self, signature: Signature, msg: bytes) -> bool: ...
+    def to_bytes(self) -> bytes: ...
+    def to_hex(self) -> str: ...
+    def to_public_key(self) -> PublicKey: ...
+
+class Signature:
+    @staticmethod
+    def from_bytes(bytes: bytes) -> Signature: ...
+    def to_bytes(self) -> bytes: ...
+
+class UserData:
+    def __init__(self) -> None: ...
+    def file_archives(self) -> list[tuple[str, str]]: ...
+    def private_file_archives(self) -> list[tuple[str, str]]: ...
+    def register_addresses(self) -> list[tuple[str, str]]: ...
+    def add_file_archive(self, archive: str, name: Optional[str] = None) -> Optional[str]: ...
+    def add_private_file_archive(
+        self, archive: DataMapChunk, name: Optional[str] = None
+    ) -> Optional[str]: ...
+    def add_register(self, register: str, name: str) -> Optional[str]: ...
+    def remove_file_archive(self, archive: str) -> Optional[str]: ...
+    def remove_private_file_archive(self, archive: DataMapChunk) -> Optional[str]: ...
+
+class DataMapChunk:
+    @staticmethod
+    def from_hex(hex: str) -> DataMapChunk: ...
+    def to_hex(self) -> str: ...
+    def address(self) -> str: ...
+
+class Chunk:
+    def __init__(self, value: bytes) -> None: ...
+    @property
+    def value(self) -> bytes: ...
+    def address(self) -> ChunkAddress: ...
+    def size(self) -> int: ...
+
+class ChunkAddress:
+    def __init__(self, addr: str) -> None: ...
+    @staticmethod
+    def from_chunk_address(addr: str) -> ChunkAddress: ...
+    @property
+    def hex(self) -> str: ...
+
+class Pointer:
+    def __init__(self, key: SecretKey, counter: int, target: PointerTarget) -> None: ...
+    def address(self) -> PointerAddress: ...
+    @property
+    def hex(self) -> str: ...
+    @property
+    def target(self) -> PointerTarget: ...
+    @property
+    def counter(self) -> int: ...
+    @property
+    def owner(self) -> PublicKey: ...
+
+class PointerAddress:
+    @staticmethod
+    def from_hex(hex: str) -> PointerAddress: ...
+    @property
+    def hex(self) -> str: ...
+
+class PointerTarget:
+    @staticmethod
+    def from_hex(hex: str) -> PointerTarget: ...
+    @staticmethod
+    def from_chunk_address(addr: ChunkAddress) -> PointerTarget: ...
+    @property
+    def hex(self) -> str: ...
+    @property
+    def target(self) -> PointerTarget: ...
+
+class GraphEntry:
+    def __init__(
+        self,
+        owner: SecretKey,
+        parents: list[PublicKey],
+        content: bytes,
+        descendants: list[tuple[PublicKey, bytes]],
+    ) -> None: ...
+    def address(self) -> str: ...
+    @property
+    def owner(self) -> PublicKey: ...
+    @property
+    def parents(self) -> list[PublicKey]: ...
+    @property
+    def content(self) -> bytes: ...
+    @property
+    def descendants(self) -> list[tuple[PublicKey, bytes]]: ...
+    def verify_signature(self) -> bool: ...
+
+class Scratchpad:
+    def __init__(self, owner: SecretKey, data_encoding: int, data: bytes, counter: int) -> None: ...
+    @staticmethod
+    def new_shared(
+        owner: SecretKey,
+        data_encoding: int,
+        data: bytes,
+        counter: int,
+        readers: list[PublicKey],
+    ) -> Scratchpad: ...
+    def address(self) -> str: ...
+    @property
+    def owner(self) -> PublicKey: ...
+    @property
+    def counter(self) -> int: ...
+    @property
+    def data_encoding(self) -> int: ...
+    @property
+    def encrypted_data(self) -> bytes: ...
+    def shared_readers(self) -> Optional[list[PublicKey]]: ...
+    def decrypt_data(self, key: SecretKey) -> bytes: ...
+    def verify_signature(self) -> bool: ...
+
+class RegisterHistory:
+    """Iterates over the values of a register, from the first to the latest."""
+
+    def __aiter__(self) -> AsyncIterator[bytes]: ...
+    async def __anext__(self) -> bytes: ...
+    async def next(self) -> Optional[bytes]: ...
+    async def collect(self) -> list[bytes]: ...
+    async def next_bytes(self) -> Optional[bytes]: ...
+    async def collect_bytes(self) -> list[bytes]: ...
+
+class Metadata:
+    def __init__(self, size: int) -> None: ...
+    created: int
+    modified: int
+    size: int
+
+class PublicArchive:
+    def __init__(self) -> None: ...
+    def rename_file(self, old_path: StrPath, new_path: StrPath) -> None: ...
+    def add_file(self, path: StrPath, addr: str, metadata: Metadata) -> None: ...
+    def files(self) -> list[tuple[str, Metadata]]: ...
+    def addresses(self) -> list[str]: ...
+
+class PrivateArchive:
+    def __init__(self) -> None: ...
+    def rename_file(self, old_path: StrPath, new_path: StrPath) -> None: ...
+    def add_file(self, path: StrPath, data_map: DataMapChunk, metadata: Metadata) -> None: ...
+    def files(self) -> list[tuple[str, Metadata]]: ...
+    def data_maps(self) -> list[DataMapChunk]: ...
+
+class DataTypes:
+    Chunk: DataTypes
+    GraphEntry: DataTypes
+    Pointer: DataTypes
+    Scratchpad: DataTypes
+
+class StoreQuote:
+    def price(self) -> str: ...
+    def addresses(self) -> list[str]: ...
+    def payments(self) -> list[QuotePayment]: ...
+    def __len__(self) -> int: ...
+
+class QuotePayment:
+    def __init__(self, quote_hash: str, rewards_address: str, amount: str) -> None: ...
+    @property
+    def quote_hash(self) -> str: ...
+    @property
+    def rewards_address(self) -> str: ...
+    @property
+    def amount(self) -> str: ...
+
+class Receipt:
+    def addresses(self) -> list[str]: ...
+    def __len__(self) -> int: ...
+
+class PayForQuotesCalldata:
+    @property
+    def batched_calldata(self) -> list[tuple[bytes, list[str]]]: ...
+    @property
+    def to(self) -> str: ...
+    @property
+    def approve_spender(self) -> str: ...
+    @property
+    def approve_amount(self) -> str: ...
+
+class WatchAddress:
+    @staticmethod
+    def pointer(addr: PointerAddress) -> WatchAddress: ...
+    @staticmethod
+    def scratchpad(addr: str) -> WatchAddress: ...
+    @staticmethod
+    def register(addr: str) -> WatchAddress: ...
+    @staticmethod
+    def graph_entry(addr: str) -> WatchAddress: ...
+
+class WatchConfig:
+    def __init__(
+        self, min_interval: Optional[float] = None, max_interval: Optional[float] = None
+    ) -> None: ...
+    min_interval: float
+    max_interval: float
+
+class WatchUpdate:
+    @property
+    def pointer(self) -> Optional[Pointer]: ...
+    @property
+    def scratchpad(self) -> Optional[Scratchpad]: ...
+    @property
+    def register_counter(self) -> Optional[int]: ...
+    @property
+    def register_values(self) -> Optional[list[bytes]]: ...
+    @property
+    def graph_entries(self) -> Optional[list[GraphEntry]]: ...
+
+class Watcher:
+    def __aiter__(self) -> AsyncIterator[WatchUpdate]: ...
+    async def __anext__(self) -> WatchUpdate: ...
+
+def encrypt(data: bytes) -> tuple[bytes, list[bytes]]: ...
+def encrypt_data(data: bytes) -> tuple[Chunk, list[Chunk]]: ...
+def receipt_from_store_quotes(quotes: StoreQuote) -> Receipt: ...
+def pay_for_quotes_calldata(network: Network, payments: list[QuotePayment]) -> PayForQuotesCalldata: ...
+def approve_to_spend_tokens_calldata(network: Network, spender: str, amount: str) -> tuple[bytes, str]: ...
+def transfer_tokens_calldata(network: Network, receiver: str, amount: str) -> tuple[bytes, str]: ...
+def derive_vault_key(evm_sk_hex: str) -> VaultSecretKey: ...
+def vault_key_from_signature_hex(signature_hex: str) -> VaultSecretKey: ...
+def app_name_to_vault_content_type(name: str) -> int: ...
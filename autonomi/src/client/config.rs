@@ -13,7 +13,9 @@ use libp2p::{kad::Record, Multiaddr, PeerId};
 use rand::{thread_rng, Rng};
 use std::{collections::HashSet, num::NonZero};
 
-pub use ant_networking::{ResponseQuorum, RetryStrategy};
+#[cfg(feature = "pnet")]
+pub use ant_networking::{read_pre_shared_key, PreSharedKey};
+pub use ant_networking::{ResponseQuorum, RetryStrategy, TransportConfig};
 
 /// Configuration for the [`crate::Client`] which can be provided through: [`crate::Client::init_with_config`].
 #[derive(Debug, Clone, Default)]
@@ -31,6 +33,11 @@ pub struct ClientConfig {
 
     /// Strategy for data operations by the client.
     pub strategy: ClientOperatingStrategy,
+
+    /// Transports to dial peers with when QUIC is unavailable.
+    ///
+    /// QUIC is always enabled and preferred. The ports are ignored, as the client does not listen.
+    pub transports: TransportConfig,
 }
 
 impl ClientConfig {
@@ -40,6 +47,7 @@ impl ClientConfig {
             peers,
             evm_network: EvmNetwork::new(true).unwrap_or_default(),
             strategy: Default::default(),
+            transports: Default::default(),
         }
     }
 }
@@ -44,6 +44,13 @@ pub enum PointerError {
     PointerAlreadyExists(PointerAddress),
     #[error("Pointer cannot be updated as it does not exist, please create it first or wait for it to be created")]
     CannotUpdateNewPointer,
+    #[error("Pointer was updated concurrently: expected counter {expected_counter}, but the latest pointer has counter {}", current.counter())]
+    Conflict {
+        /// The counter the caller expected the pointer to be at.
+        expected_counter: u32,
+        /// The latest version of the pointer found on the network.
+        current: Box<Pointer>,
+    },
 }
 
 impl Client {
@@ -212,34 +219,103 @@ impl Client {
         target: PointerTarget,
     ) -> Result<(), PointerError> {
         let address = PointerAddress::from_owner(owner.public_key());
-        let current = match self.pointer_get(&address).await {
-            Ok(pointer) => Some(pointer),
+        let pointer = if let Some(p) = self.pointer_get_latest(&address).await? {
+            let version = p.counter() + 1;
+            Pointer::new(owner, version, target)
+        } else {
+            warn!("Pointer at address {address:?} cannot be updated as it does not exist, please create it first or wait for it to be created");
+            return Err(PointerError::CannotUpdateNewPointer);
+        };
+
+        self.pointer_store_update(&pointer).await
+    }
+
+    /// Update an existing pointer to point to a new target, only if it is still at `expected_counter`.
+    ///
+    /// This is a compare-and-swap: if the latest pointer on the network has a different counter,
+    /// or another update with the same counter was kept by the nodes instead of ours, the update
+    /// fails with [`PointerError::Conflict`] carrying the latest pointer. The caller can then
+    /// decide how to merge their change and retry with the new counter.
+    pub async fn pointer_update_if(
+        &self,
+        owner: &SecretKey,
+        target: PointerTarget,
+        expected_counter: u32,
+    ) -> Result<(), PointerError> {
+        let address = PointerAddress::from_owner(owner.public_key());
+        let current = match self.pointer_get_latest(&address).await? {
+            Some(current) => current,
+            None => {
+                warn!("Pointer at address {address:?} cannot be updated as it does not exist, please create it first or wait for it to be created");
+                return Err(PointerError::CannotUpdateNewPointer);
+            }
+        };
+        if current.counter() != expected_counter {
+            debug!(
+                "Pointer at address {address:?} is at counter {} rather than the expected {expected_counter}",
+                current.counter()
+            );
+            return Err(PointerError::Conflict {
+                expected_counter,
+                current: Box::new(current),
+            });
+        }
+
+        let pointer = Pointer::new(owner, expected_counter + 1, target);
+        let put_result = self.pointer_store_update(&pointer).await;
+
+        // Nodes only keep one of the competing updates with the same counter, so check ours won.
+        // If it did not, the PUT may also have been rejected, in which case the conflict is the
+        // more useful error.
+        match self.pointer_get_latest(&address).await {
+            Ok(Some(latest)) if latest != pointer && latest.counter() > expected_counter => {
+                debug!("Pointer update at address {address:?} lost to a concurrent update");
+                Err(PointerError::Conflict {
+                    expected_counter,
+                    current: Box::new(latest),
+                })
+            }
+            _ => put_result,
+        }
+    }
+
+    /// Get the latest version of a pointer, if there is one.
+    ///
+    /// If the nodes hold different versions, the one that supersedes the others is returned.
+    async fn pointer_get_latest(
+        &self,
+        address: &PointerAddress,
+    ) -> Result<Option<Pointer>, PointerError> {
+        match self.pointer_get(address).await {
+            Ok(pointer) => Ok(Some(pointer)),
             Err(PointerError::Network(NetworkError::GetRecordError(
                 GetRecordError::RecordNotFound,
-            ))) => None,
+            ))) => Ok(None),
             Err(PointerError::Network(NetworkError::GetRecordError(
                 GetRecordError::SplitRecord { result_map },
-            ))) => result_map
+            ))) => Ok(result_map
                 .values()
                 .filter_map(|(record, _)| try_deserialize_record::<Pointer>(record).ok())
-                .max_by_key(|pointer: &Pointer| pointer.counter()),
-            Err(err) => {
-                return Err(err);
-            }
-        };
+                .filter(|pointer| pointer.verify_signature())
+                .reduce(|latest, pointer| {
+                    if pointer.supersedes(&latest) {
+                        pointer
+                    } else {
+                        latest
+                    }
+                })),
+            Err(err) => Err(err),
+        }
+    }
 
-        let pointer = if let Some(p) = current {
-            let version = p.counter() + 1;
-            Pointer::new(owner, version, target)
-        } else {
-            warn!("Pointer at address {address:?} cannot be updated as it does not exist, please create it first or wait for it to be created");
-            return Err(PointerError::CannotUpdateNewPointer);
-        };
+    /// Store an update to an already paid for pointer.
+    async fn pointer_store_update(&self, pointer: &Pointer) -> Result<(), PointerError> {
+        let address = pointer.address();
 
         // prepare the record to be stored
         let record = Record {
             key: NetworkAddress::from_pointer_address(address).to_record_key(),
-            value: try_serialize_record(&pointer, RecordKind::DataOnly(DataTypes::Pointer))
+            value: try_serialize_record(pointer, RecordKind::DataOnly(DataTypes::Pointer))
                 .map_err(|_| PointerError::Serialization)?
                 .to_vec(),
             publisher: None,
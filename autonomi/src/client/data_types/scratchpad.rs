@@ -44,6 +44,29 @@ pub enum ScratchpadError {
     ScratchpadTooBig(usize),
     #[error("Scratchpad signature is not valid")]
     BadSignature,
+    #[error("Failed to decrypt scratchpad: {0}")]
+    Decryption(ant_protocol::Error),
+    #[error("Scratchpad was updated concurrently: expected counter {expected_counter}, but the latest scratchpad has counter {}", current.counter())]
+    Conflict {
+        /// The counter the caller expected the scratchpad to be at.
+        expected_counter: u64,
+        /// The latest version of the scratchpad found on the network.
+        current: Box<Scratchpad>,
+    },
+}
+
+/// The version of the scratchpad that follows `current`, readable by the same readers.
+fn next_scratchpad(
+    owner: &SecretKey,
+    content_type: u64,
+    data: &Bytes,
+    current: &Scratchpad,
+) -> Scratchpad {
+    let counter = current.counter() + 1;
+    match current.shared_readers() {
+        Some(readers) => Scratchpad::new_shared(owner, content_type, data, counter, &readers),
+        None => Scratchpad::new(owner, content_type, data, counter),
+    }
 }
 
 impl Client {
@@ -98,9 +121,19 @@ impl Client {
                 // make sure we only have one of latest version
                 let pad = match &latest_pads[..] {
                     [one] => one,
-                    [multi, ..] => {
-                        error!("Got multiple conflicting scratchpads for {scratch_key:?} with the latest version, returning the first one");
-                        multi
+                    [first, rest @ ..] => {
+                        // Pick the same one the nodes settle on.
+                        error!("Got multiple conflicting scratchpads for {scratch_key:?} with the latest version, returning the one that supersedes the others");
+                        rest.iter().fold(
+                            first,
+                            |latest, pad| {
+                                if pad.supersedes(latest) {
+                                    pad
+                                } else {
+                                    latest
+                                }
+                            },
+                        )
                     }
                     [] => {
                         error!("Got empty scratchpad vector for {scratch_key:?}");
@@ -256,6 +289,7 @@ impl Client {
     /// The scratchpad needs to be created first with [`Client::scratchpad_create`].
     /// This operation is free as the scratchpad was already paid for at creation.
     /// Only the latest version of the scratchpad is kept on the Network, previous versions will be overwritten and unrecoverable.
+    /// If the scratchpad is shared, the new version can still be read by the same readers.
     pub async fn scratchpad_update(
         &self,
         owner: &SecretKey,
@@ -263,37 +297,205 @@ impl Client {
         data: &Bytes,
     ) -> Result<(), ScratchpadError> {
         let address = ScratchpadAddress::new(owner.public_key());
-        let current = match self.scratchpad_get(&address).await {
-            Ok(scratchpad) => Some(scratchpad),
-            Err(ScratchpadError::Network(NetworkError::GetRecordError(
-                GetRecordError::RecordNotFound,
-            ))) => None,
-            Err(ScratchpadError::Network(NetworkError::GetRecordError(
-                GetRecordError::SplitRecord { result_map },
-            ))) => result_map
-                .values()
-                .filter_map(|(record, _)| try_deserialize_record::<Scratchpad>(record).ok())
-                .max_by_key(|scratchpad: &Scratchpad| scratchpad.counter()),
-            Err(err) => {
-                return Err(err);
-            }
+        let scratchpad = if let Some(p) = self.scratchpad_get_latest(&address).await? {
+            next_scratchpad(owner, content_type, data, &p)
+        } else {
+            warn!("Scratchpad at address {address:?} cannot be updated as it does not exist, please create it first or wait for it to be created");
+            return Err(ScratchpadError::CannotUpdateNewScratchpad);
         };
 
-        let scratchpad = if let Some(p) = current {
+        self.scratchpad_store_update(&scratchpad).await
+    }
+
+    /// Create a new scratchpad that can be read by each of the `readers` as well as the owner.
+    ///
+    /// Readers decrypt the content with their own secret key through [`Scratchpad::decrypt_data`],
+    /// so the owner's secret key never has to be shared. Only the owner can update it.
+    ///
+    /// Returns the cost and the address of the scratchpad.
+    pub async fn scratchpad_create_shared(
+        &self,
+        owner: &SecretKey,
+        content_type: u64,
+        initial_data: &Bytes,
+        readers: &[PublicKey],
+        payment_option: PaymentOption,
+    ) -> Result<(AttoTokens, ScratchpadAddress), ScratchpadError> {
+        let address = ScratchpadAddress::new(owner.public_key());
+        let already_exists = self.scratchpad_check_existance(&address).await?;
+        if already_exists {
+            return Err(ScratchpadError::ScratchpadAlreadyExists(address));
+        }
+
+        let counter = 0;
+        let scratchpad =
+            Scratchpad::new_shared(owner, content_type, initial_data, counter, readers);
+        self.scratchpad_put(scratchpad, payment_option).await
+    }
+
+    /// Update an existing scratchpad, making it readable by exactly the given `readers` and the owner.
+    ///
+    /// Each update is encrypted to a new content key, so readers that are no longer in the list
+    /// cannot read this version or any later one.
+    pub async fn scratchpad_update_shared(
+        &self,
+        owner: &SecretKey,
+        content_type: u64,
+        data: &Bytes,
+        readers: &[PublicKey],
+    ) -> Result<(), ScratchpadError> {
+        let address = ScratchpadAddress::new(owner.public_key());
+        let scratchpad = if let Some(p) = self.scratchpad_get_latest(&address).await? {
             let version = p.counter() + 1;
-            Scratchpad::new(owner, content_type, data, version)
+            Scratchpad::new_shared(owner, content_type, data, version, readers)
         } else {
             warn!("Scratchpad at address {address:?} cannot be updated as it does not exist, please create it first or wait for it to be created");
             return Err(ScratchpadError::CannotUpdateNewScratchpad);
         };
 
+        self.scratchpad_store_update(&scratchpad).await
+    }
+
+    /// Let more readers decrypt an existing scratchpad, keeping its current content.
+    pub async fn scratchpad_add_readers(
+        &self,
+        owner: &SecretKey,
+        readers: &[PublicKey],
+    ) -> Result<(), ScratchpadError> {
+        // Duplicates are dropped when the content key is wrapped.
+        self.scratchpad_change_readers(owner, |current| current.extend_from_slice(readers))
+            .await
+    }
+
+    /// Stop readers from decrypting an existing scratchpad, keeping its current content.
+    ///
+    /// The revoked readers can still decrypt the versions they could read before.
+    pub async fn scratchpad_revoke_readers(
+        &self,
+        owner: &SecretKey,
+        readers: &[PublicKey],
+    ) -> Result<(), ScratchpadError> {
+        self.scratchpad_change_readers(owner, |current| {
+            current.retain(|reader| !readers.contains(reader));
+        })
+        .await
+    }
+
+    /// Re-encrypt the current content of a scratchpad for a changed set of readers.
+    async fn scratchpad_change_readers(
+        &self,
+        owner: &SecretKey,
+        change: impl FnOnce(&mut Vec<PublicKey>),
+    ) -> Result<(), ScratchpadError> {
+        let address = ScratchpadAddress::new(owner.public_key());
+        let current = match self.scratchpad_get_latest(&address).await? {
+            Some(current) => current,
+            None => {
+                warn!("Scratchpad at address {address:?} cannot be updated as it does not exist, please create it first or wait for it to be created");
+                return Err(ScratchpadError::CannotUpdateNewScratchpad);
+            }
+        };
+        let data = current
+            .decrypt_data(owner)
+            .map_err(ScratchpadError::Decryption)?;
+
+        let mut readers = current.shared_readers().unwrap_or_default();
+        change(&mut readers);
+        debug!(
+            "Sharing scratchpad at address {address:?} with {} readers",
+            readers.len()
+        );
+
+        let scratchpad = Scratchpad::new_shared(
+            owner,
+            current.data_encoding(),
+            &data,
+            current.counter() + 1,
+            &readers,
+        );
+        self.scratchpad_store_update(&scratchpad).await
+    }
+
+    /// Update an existing scratchpad, only if it is still at `expected_counter`.
+    ///
+    /// This is a compare-and-swap: if the latest scratchpad on the network has a different
+    /// counter, or another update with the same counter was kept by the nodes instead of ours, the
+    /// update fails with [`ScratchpadError::Conflict`] carrying the latest scratchpad. The caller
+    /// can then merge their change with its content and retry with the new counter.
+    ///
+    /// If the scratchpad is shared, the new version can still be read by the same readers.
+    pub async fn scratchpad_update_if(
+        &self,
+        owner: &SecretKey,
+        content_type: u64,
+        data: &Bytes,
+        expected_counter: u64,
+    ) -> Result<(), ScratchpadError> {
+        let address = ScratchpadAddress::new(owner.public_key());
+        let current = match self.scratchpad_get_latest(&address).await? {
+            Some(current) => current,
+            None => {
+                warn!("Scratchpad at address {address:?} cannot be updated as it does not exist, please create it first or wait for it to be created");
+                return Err(ScratchpadError::CannotUpdateNewScratchpad);
+            }
+        };
+        if current.counter() != expected_counter {
+            debug!(
+                "Scratchpad at address {address:?} is at counter {} rather than the expected {expected_counter}",
+                current.counter()
+            );
+            return Err(ScratchpadError::Conflict {
+                expected_counter,
+                current: Box::new(current),
+            });
+        }
+
+        let scratchpad = next_scratchpad(owner, content_type, data, &current);
+        let put_result = self.scratchpad_store_update(&scratchpad).await;
+
+        // Nodes only keep one of the competing updates with the same counter, so check ours won.
+        // If it did not, the PUT may also have been rejected, in which case the conflict is the
+        // more useful error.
+        match self.scratchpad_get_latest(&address).await {
+            Ok(Some(latest)) if latest != scratchpad && latest.counter() > expected_counter => {
+                debug!("Scratchpad update at address {address:?} lost to a concurrent update");
+                Err(ScratchpadError::Conflict {
+                    expected_counter,
+                    current: Box::new(latest),
+                })
+            }
+            _ => put_result,
+        }
+    }
+
+    /// Get the latest version of a scratchpad, if there is one.
+    async fn scratchpad_get_latest(
+        &self,
+        address: &ScratchpadAddress,
+    ) -> Result<Option<Scratchpad>, ScratchpadError> {
+        match self.scratchpad_get(address).await {
+            Ok(scratchpad) => Ok(Some(scratchpad)),
+            Err(ScratchpadError::Network(NetworkError::GetRecordError(
+                GetRecordError::RecordNotFound,
+            ))) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Store an update to an already paid for scratchpad.
+    async fn scratchpad_store_update(
+        &self,
+        scratchpad: &Scratchpad,
+    ) -> Result<(), ScratchpadError> {
+        let address = scratchpad.address();
+
         // make sure the scratchpad is valid
-        Self::scratchpad_verify(&scratchpad)?;
+        Self::scratchpad_verify(scratchpad)?;
 
         // prepare the record to be stored
         let record = Record {
-            key: NetworkAddress::from_scratchpad_address(address).to_record_key(),
-            value: try_serialize_record(&scratchpad, RecordKind::DataOnly(DataTypes::Scratchpad))
+            key: NetworkAddress::from_scratchpad_address(*address).to_record_key(),
+            value: try_serialize_record(scratchpad, RecordKind::DataOnly(DataTypes::Scratchpad))
                 .map_err(|_| ScratchpadError::Serialization)?
                 .to_vec(),
             publisher: None,
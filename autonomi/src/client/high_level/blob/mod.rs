@@ -0,0 +1,400 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::client::data_types::scratchpad::{ScratchpadAddress, ScratchpadError};
+use crate::client::high_level::files::FILE_UPLOAD_BATCH_SIZE;
+use crate::client::high_level::vault::{split_bytes, MAX_CONTENT_PER_SCRATCHPAD};
+use crate::client::key_derivation::{DerivationIndex, MainSecretKey};
+use crate::client::payment::PaymentOption;
+use crate::client::quote::CostError;
+use crate::client::utils::process_tasks_with_max_concurrency;
+use crate::client::Client;
+use ant_evm::{AttoTokens, U256};
+use ant_networking::{GetRecordError, NetworkError};
+use ant_protocol::storage::Scratchpad;
+use ant_protocol::Bytes;
+use serde::{Deserialize, Serialize};
+use xor_name::XorName;
+
+use crate::SecretKey;
+
+/// Prefix of the derivation indexes of the segment keys
+const SEGMENT_DERIVATION_PREFIX: &str = "mutable-blob-segment-";
+
+#[derive(Debug, thiserror::Error)]
+pub enum BlobError {
+    #[error("Blob Scratchpad related error: {0}")]
+    Scratchpad(#[from] ScratchpadError),
+    #[error("Blob Cost related error: {0}")]
+    Cost(#[from] CostError),
+    #[error("Protocol: {0}")]
+    Protocol(#[from] ant_protocol::Error),
+    #[error("Blob not found, please create it first or wait for it to be created")]
+    NotFound,
+    #[error("Blob already exists at this address: {0:?}")]
+    BlobAlreadyExists(ScratchpadAddress),
+    #[error("Failed to serialize the blob manifest: {0}")]
+    Serialization(#[from] rmp_serde::encode::Error),
+    #[error("Failed to deserialize the blob manifest: {0}")]
+    Deserialization(#[from] rmp_serde::decode::Error),
+    #[error("Blob of {0} bytes has too many segments to be listed in its manifest")]
+    TooBig(usize),
+    #[error(
+        "Segment {0} does not match the blob manifest, the blob may be in the middle of an update"
+    )]
+    SegmentMismatch(usize),
+}
+
+/// The list of segments of a blob, stored in the Scratchpad at the owner's key.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct BlobManifest {
+    /// The hash of the content of each segment, in order.
+    ///
+    /// The key of each segment's Scratchpad is derived from its hash, see [`segment_key`].
+    segment_hashes: Vec<XorName>,
+}
+
+impl BlobManifest {
+    fn new(segments: &[Bytes]) -> Self {
+        Self {
+            segment_hashes: segments
+                .iter()
+                .map(|segment| XorName::from_content(segment))
+                .collect(),
+        }
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, rmp_serde::decode::Error> {
+        rmp_serde::from_slice(bytes)
+    }
+
+    fn to_bytes(&self) -> Result<Bytes, rmp_serde::encode::Error> {
+        Ok(Bytes::from(rmp_serde::to_vec(self)?))
+    }
+
+    /// The indexes of the segments of `next` that are not already stored for this manifest.
+    ///
+    /// A segment with the same content as another is only listed once, as they share a Scratchpad.
+    fn new_segments(&self, next: &BlobManifest) -> Vec<usize> {
+        let mut seen: Vec<&XorName> = self.segment_hashes.iter().collect();
+        let mut new_segments = Vec::new();
+        for (index, hash) in next.segment_hashes.iter().enumerate() {
+            if !seen.contains(&hash) {
+                seen.push(hash);
+                new_segments.push(index);
+            }
+        }
+        new_segments
+    }
+}
+
+/// Get the key of the Scratchpad holding a segment of the blob with the given content hash.
+///
+/// Segments are never modified once stored: new content gets a new key, so the segments listed
+/// in a manifest stay readable while another version of the blob is being written.
+fn segment_key(owner: &SecretKey, hash: &XorName) -> SecretKey {
+    let mut derivation_bytes = SEGMENT_DERIVATION_PREFIX.as_bytes().to_vec();
+    derivation_bytes.extend_from_slice(&hash.0);
+    let derivation = XorName::from_content(&derivation_bytes);
+    MainSecretKey::new(owner.clone())
+        .derive_key(&DerivationIndex::from_bytes(derivation.0))
+        .into()
+}
+
+impl Client {
+    /// Create a new mutable blob holding `data`, which can be of any size.
+    ///
+    /// The blob is stored in a manifest Scratchpad at the owner's public key, so the owner key
+    /// should not be used for another Scratchpad. The data is split into segments, each
+    /// encrypted and stored in a Scratchpad owned by a key derived from the owner key.
+    ///
+    /// The manifest is written last, so if this fails part way it can be retried: the segments
+    /// that were already stored are reused.
+    ///
+    /// Returns the cost and the address of the manifest.
+    pub async fn blob_create(
+        &self,
+        owner: &SecretKey,
+        content_type: u64,
+        data: Bytes,
+        payment_option: PaymentOption,
+    ) -> Result<(AttoTokens, ScratchpadAddress), BlobError> {
+        let address = ScratchpadAddress::new(owner.public_key());
+        if self.scratchpad_check_existance(&address).await? {
+            return Err(BlobError::BlobAlreadyExists(address));
+        }
+
+        let data_len = data.len();
+        let segments = split_bytes(data);
+        let manifest = BlobManifest::new(&segments);
+        let manifest_bytes = manifest.to_bytes()?;
+        if manifest_bytes.len() > MAX_CONTENT_PER_SCRATCHPAD {
+            return Err(BlobError::TooBig(data_len));
+        }
+
+        info!(
+            "Creating blob at {address:?} with {} segments",
+            segments.len()
+        );
+        let segments_cost = self
+            .blob_write_segments(
+                owner,
+                content_type,
+                &segments,
+                &BlobManifest::default(),
+                &manifest,
+                payment_option.clone(),
+            )
+            .await?;
+        let (manifest_cost, address) = self
+            .scratchpad_create(owner, content_type, &manifest_bytes, payment_option)
+            .await?;
+
+        let total_cost = AttoTokens::from_atto(segments_cost.as_atto() + manifest_cost.as_atto());
+        Ok((total_cost, address))
+    }
+
+    /// Fetch and decrypt a mutable blob.
+    ///
+    /// Returns the content and its content type.
+    pub async fn blob_get(&self, owner: &SecretKey) -> Result<(Bytes, u64), BlobError> {
+        let (manifest_pad, manifest) = self.blob_get_manifest(owner).await?;
+        debug!(
+            "Fetching {} segments of blob at {:?}",
+            manifest.segment_hashes.len(),
+            manifest_pad.address()
+        );
+
+        let fetch_futures: Vec<_> = manifest
+            .segment_hashes
+            .iter()
+            .enumerate()
+            .map(|(index, hash)| async move {
+                let segment_key = segment_key(owner, hash);
+                let address = ScratchpadAddress::new(segment_key.public_key());
+                let segment = self
+                    .scratchpad_get(&address)
+                    .await?
+                    .decrypt_data(&segment_key)?;
+                if XorName::from_content(&segment) != *hash {
+                    return Err(BlobError::SegmentMismatch(index));
+                }
+                Ok((index, segment))
+            })
+            .collect();
+
+        let mut segments =
+            process_tasks_with_max_concurrency(fetch_futures, *FILE_UPLOAD_BATCH_SIZE)
+                .await
+                .into_iter()
+                .collect::<Result<Vec<_>, _>>()?;
+        segments.sort_by_key(|(index, _)| *index);
+
+        let data: Vec<Bytes> = segments.into_iter().map(|(_, segment)| segment).collect();
+        Ok((Bytes::from(data.concat()), manifest_pad.data_encoding()))
+    }
+
+    /// Replace the content of a mutable blob.
+    ///
+    /// Only the segments whose content changed are written, each to a new Scratchpad that has to
+    /// be paid for. Existing segments are never modified, so the previous version stays readable
+    /// until the manifest is swapped to the new one. A concurrent update by another writer is
+    /// detected when the manifest is written, in which case this fails with a
+    /// [`ScratchpadError::Conflict`] and the blob is left as the other writer made it.
+    ///
+    /// Returns the cost of the update.
+    pub async fn blob_update(
+        &self,
+        owner: &SecretKey,
+        data: Bytes,
+        payment_option: PaymentOption,
+    ) -> Result<AttoTokens, BlobError> {
+        let (manifest_pad, previous) = self.blob_get_manifest(owner).await?;
+        let content_type = manifest_pad.data_encoding();
+
+        let data_len = data.len();
+        let segments = split_bytes(data);
+        let manifest = BlobManifest::new(&segments);
+        if manifest == previous {
+            info!("Blob at {:?} is unchanged", manifest_pad.address());
+            return Ok(AttoTokens::zero());
+        }
+        let manifest_bytes = manifest.to_bytes()?;
+        if manifest_bytes.len() > MAX_CONTENT_PER_SCRATCHPAD {
+            return Err(BlobError::TooBig(data_len));
+        }
+
+        let cost = self
+            .blob_write_segments(
+                owner,
+                content_type,
+                &segments,
+                &previous,
+                &manifest,
+                payment_option,
+            )
+            .await?;
+        self.scratchpad_update_if(owner, content_type, &manifest_bytes, manifest_pad.counter())
+            .await?;
+
+        info!(
+            "Updated blob at {:?}, now with {} segments",
+            manifest_pad.address(),
+            segments.len()
+        );
+        Ok(cost)
+    }
+
+    /// Get the cost of writing `data` to the blob of the owner, creating the blob if needed.
+    ///
+    /// A quick estimation of cost: num_of_new_scratchpads * scratchpad_cost
+    pub async fn blob_cost(
+        &self,
+        owner: &SecretKey,
+        data: &Bytes,
+    ) -> Result<AttoTokens, BlobError> {
+        let manifest = BlobManifest::new(&split_bytes(data.clone()));
+        let num_of_scratchpads = match self.blob_get_manifest(owner).await {
+            Ok((_, previous)) => previous.new_segments(&manifest).len(),
+            // The manifest has to be paid for too
+            Err(BlobError::NotFound) => BlobManifest::default().new_segments(&manifest).len() + 1,
+            Err(err) => return Err(err),
+        };
+        if num_of_scratchpads == 0 {
+            return Ok(AttoTokens::zero());
+        }
+
+        let scratchpad_cost = self.scratchpad_cost(&owner.public_key()).await?;
+        let total_cost = U256::from(num_of_scratchpads) * scratchpad_cost.as_atto();
+        Ok(AttoTokens::from_atto(total_cost))
+    }
+
+    async fn blob_get_manifest(
+        &self,
+        owner: &SecretKey,
+    ) -> Result<(Scratchpad, BlobManifest), BlobError> {
+        let address = ScratchpadAddress::new(owner.public_key());
+        let manifest_pad = match self.scratchpad_get(&address).await {
+            Ok(pad) => pad,
+            Err(ScratchpadError::Network(NetworkError::GetRecordError(
+                GetRecordError::RecordNotFound,
+            ))) => return Err(BlobError::NotFound),
+            Err(err) => return Err(err.into()),
+        };
+        let manifest = BlobManifest::from_bytes(&manifest_pad.decrypt_data(owner)?)?;
+        Ok((manifest_pad, manifest))
+    }
+
+    /// Store the segments of `next` that are not in the previous manifest, returning the cost paid.
+    ///
+    /// A segment that is already stored, for instance by an earlier attempt, is not paid for again.
+    async fn blob_write_segments(
+        &self,
+        owner: &SecretKey,
+        content_type: u64,
+        segments: &[Bytes],
+        previous: &BlobManifest,
+        next: &BlobManifest,
+        payment_option: PaymentOption,
+    ) -> Result<AttoTokens, BlobError> {
+        let writes = previous.new_segments(next);
+        debug!(
+            "Writing {} of {} blob segments",
+            writes.len(),
+            segments.len()
+        );
+
+        let write_futures: Vec<_> = writes
+            .into_iter()
+            .map(|index| {
+                let segment_key = segment_key(owner, &next.segment_hashes[index]);
+                let segment = &segments[index];
+                let payment_option = payment_option.clone();
+                async move {
+                    match self
+                        .scratchpad_create(&segment_key, content_type, segment, payment_option)
+                        .await
+                    {
+                        Ok((price, addr)) => {
+                            debug!(
+                                "Created blob segment {index} at {addr:?} with cost of {price:?}"
+                            );
+                            Ok::<_, BlobError>(price)
+                        }
+                        // The key is derived from the content, so the segment is already stored.
+                        Err(ScratchpadError::ScratchpadAlreadyExists(addr)) => {
+                            debug!("Blob segment {index} is already stored at {addr:?}");
+                            Ok(AttoTokens::zero())
+                        }
+                        Err(err) => Err(err.into()),
+                    }
+                }
+            })
+            .collect();
+
+        let mut total_cost = AttoTokens::zero();
+        for result in
+            process_tasks_with_max_concurrency(write_futures, *FILE_UPLOAD_BATCH_SIZE).await
+        {
+            total_cost = AttoTokens::from_atto(total_cost.as_atto() + result?.as_atto());
+        }
+        Ok(total_cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_segments_with_new_content_are_written() {
+        let segments = vec![Bytes::from("one"), Bytes::from("two"), Bytes::from("three")];
+        let previous = BlobManifest::new(&segments);
+        assert_eq!(
+            BlobManifest::default().new_segments(&previous),
+            vec![0, 1, 2]
+        );
+        assert!(previous.new_segments(&previous).is_empty());
+
+        // Change the second segment and add a fourth.
+        let mut grown = segments.clone();
+        grown[1] = Bytes::from("TWO");
+        grown.push(Bytes::from("four"));
+        assert_eq!(
+            previous.new_segments(&BlobManifest::new(&grown)),
+            vec![1, 3]
+        );
+
+        // Moving segments around or repeating them does not store anything new.
+        let reordered = vec![Bytes::from("three"), Bytes::from("one"), Bytes::from("one")];
+        assert!(previous
+            .new_segments(&BlobManifest::new(&reordered))
+            .is_empty());
+
+        // A segment repeated in the new content is only written once.
+        let repeated = vec![Bytes::from("five"), Bytes::from("five")];
+        assert_eq!(
+            previous.new_segments(&BlobManifest::new(&repeated)),
+            vec![0]
+        );
+    }
+
+    #[test]
+    fn segment_keys_are_derived_from_the_content() {
+        let owner = SecretKey::random();
+        let one = XorName::from_content(b"one");
+        let two = XorName::from_content(b"two");
+        assert_eq!(segment_key(&owner, &one), segment_key(&owner, &one));
+        assert_ne!(segment_key(&owner, &one), segment_key(&owner, &two));
+        assert_ne!(segment_key(&owner, &one), owner);
+        assert_ne!(
+            segment_key(&owner, &one),
+            segment_key(&SecretKey::random(), &one)
+        );
+    }
+}
@@ -0,0 +1,516 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::client::data_types::graph::{GraphContent, GraphEntry, GraphEntryAddress, GraphError};
+use crate::client::data_types::scratchpad::{ScratchpadAddress, ScratchpadError};
+use crate::client::high_level::vault::MAX_CONTENT_PER_SCRATCHPAD;
+use crate::client::key_derivation::{DerivationIndex, MainSecretKey};
+use crate::client::payment::PaymentOption;
+use crate::client::Client;
+use crate::AttoTokens;
+use ant_networking::{GetRecordError, NetworkError};
+use ant_protocol::storage::Scratchpad;
+use ant_protocol::Bytes;
+use serde::{Deserialize, Serialize};
+use std::collections::{btree_map, BTreeMap};
+use thiserror::Error;
+use xor_name::XorName;
+
+pub use crate::{PublicKey, SecretKey};
+
+/// The number of Scratchpad pages the entries of a map are spread over.
+///
+/// Each page is only created (and paid for) when the first key falling into it is inserted.
+pub const MAP_PAGE_COUNT: usize = 16;
+
+/// Marks the root [`GraphEntry`] of a map, and the version of the map layout.
+const MAP_ROOT_CONTENT: GraphContent = *b"autonomi-map-v1\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0";
+
+/// The content type of the Scratchpad pages of a map.
+const MAP_PAGE_CONTENT_TYPE: u64 = u64::from_be_bytes(*b"autmappg");
+
+/// How many times a write is applied again to a concurrently updated page before giving up.
+const MAX_WRITE_ATTEMPTS: usize = 5;
+
+#[derive(Error, Debug)]
+pub enum MapError {
+    #[error("Underlying GraphError: {0}")]
+    GraphError(#[from] GraphError),
+    #[error("Underlying ScratchpadError: {0}")]
+    ScratchpadError(#[from] ScratchpadError),
+    #[error("Protocol: {0}")]
+    Protocol(#[from] ant_protocol::Error),
+    #[error("Failed to serialize map page: {0}")]
+    Serialization(#[from] rmp_serde::encode::Error),
+    #[error("Failed to deserialize map page: {0}")]
+    Deserialization(#[from] rmp_serde::decode::Error),
+    #[error("Map not found, please create it first or wait for it to be created")]
+    NotFound,
+    #[error("Map already exists at this address: {0:?}")]
+    AlreadyExists(GraphEntryAddress),
+    #[error("Corrupt map: {0}")]
+    Corrupt(String),
+    #[error("Map page {0} is full, its entries do not fit in a Scratchpad")]
+    PageFull(usize),
+    #[error(
+        "Map page {0} kept being updated concurrently, gave up after {MAX_WRITE_ATTEMPTS} attempts"
+    )]
+    TooManyConflicts(usize),
+}
+
+/// The value of a key in a map page, or a tombstone if the key was removed.
+///
+/// Each write of a key increments its clock.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct MapEntry {
+    clock: u64,
+    value: Option<Bytes>,
+}
+
+/// A page of a map: the entries whose keys fall into one of its Scratchpads.
+///
+/// Writes are always applied to the latest version of a page. If the page is updated
+/// concurrently, the write is applied again to the new latest version rather than merged with it,
+/// as the version it was first applied to may since have been compacted.
+///
+/// Once a page no longer fits in its Scratchpad, its tombstones are dropped to make room. The
+/// greatest clock of the dropped tombstones is kept as the floor of the page, which the clocks of
+/// the keys written again start from.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct MapPage {
+    entries: BTreeMap<String, MapEntry>,
+    #[serde(default)]
+    floor: u64,
+}
+
+impl MapPage {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, rmp_serde::decode::Error> {
+        rmp_serde::from_slice(bytes)
+    }
+
+    fn to_bytes(&self) -> Result<Bytes, rmp_serde::encode::Error> {
+        Ok(Bytes::from(rmp_serde::to_vec(self)?))
+    }
+
+    /// Set the value of a key, or remove it with `None`.
+    ///
+    /// Returns `false` if the page is left unchanged, i.e. when removing a missing key.
+    fn write(&mut self, key: &str, value: Option<Bytes>) -> bool {
+        let (clock, current) = match self.entries.get(key) {
+            Some(entry) => (entry.clock, entry.value.as_ref()),
+            None => (self.floor, None),
+        };
+        if value.is_none() && current.is_none() {
+            return false;
+        }
+        let _ = self.entries.insert(
+            key.to_string(),
+            MapEntry {
+                clock: clock + 1,
+                value,
+            },
+        );
+        true
+    }
+
+    /// Drop the tombstones of the page, raising its floor to the greatest of their clocks.
+    ///
+    /// Returns `false` if the page has no tombstones.
+    fn compact(&mut self) -> bool {
+        let tombstone_count = self
+            .entries
+            .values()
+            .filter(|entry| entry.value.is_none())
+            .count();
+        if tombstone_count == 0 {
+            return false;
+        }
+        let floor = self
+            .entries
+            .values()
+            .filter(|entry| entry.value.is_none())
+            .map(|entry| entry.clock)
+            .fold(self.floor, u64::max);
+        self.entries.retain(|_, entry| entry.value.is_some());
+        self.floor = floor;
+        debug!("Dropped {tombstone_count} tombstones from a map page, its floor is now {floor}");
+        true
+    }
+
+    /// Serialize the page for its Scratchpad, compacting it if it does not fit.
+    ///
+    /// Returns `None` if the page does not fit even once compacted.
+    fn compact_to_fit(&mut self) -> Result<Option<Bytes>, rmp_serde::encode::Error> {
+        let bytes = self.to_bytes()?;
+        if bytes.len() <= MAX_CONTENT_PER_SCRATCHPAD {
+            return Ok(Some(bytes));
+        }
+        if self.compact() {
+            let bytes = self.to_bytes()?;
+            if bytes.len() <= MAX_CONTENT_PER_SCRATCHPAD {
+                return Ok(Some(bytes));
+            }
+        }
+        Ok(None)
+    }
+
+    fn get(&self, key: &str) -> Option<&Bytes> {
+        self.entries.get(key).and_then(|entry| entry.value.as_ref())
+    }
+
+    fn into_values(self) -> impl Iterator<Item = (String, Bytes)> {
+        self.entries
+            .into_iter()
+            .filter_map(|(key, entry)| entry.value.map(|value| (key, value)))
+    }
+}
+
+/// Get the index of the page holding a key.
+fn page_index(key: &str, page_count: usize) -> usize {
+    XorName::from_content(key.as_bytes()).0[0] as usize % page_count
+}
+
+/// Get the derivation index of the key of a page.
+fn page_derivation_index(index: usize) -> DerivationIndex {
+    DerivationIndex::from_bytes(XorName::from_content(format!("map-page-{index}").as_bytes()).0)
+}
+
+impl Client {
+    /// Create a new map key from a SecretKey and a name.
+    ///
+    /// This derives a new [`SecretKey`] from the owner's [`SecretKey`] using the name.
+    /// Note that you will need to keep track of the names you used to create the map key.
+    pub fn map_key_from_name(owner: &SecretKey, name: &str) -> SecretKey {
+        let main_key = MainSecretKey::new(owner.clone());
+        let derivation_index =
+            DerivationIndex::from_bytes(XorName::from_content(format!("map:{name}").as_bytes()).0);
+        main_key.derive_key(&derivation_index).into()
+    }
+
+    /// Create a new, empty map.
+    ///
+    /// A map is a private key-value store, from strings to bytes of any kind. Its root is a
+    /// [`GraphEntry`] at the owner's [`PublicKey`], listing the Scratchpad pages its entries are
+    /// spread over. Since the root is stored at the owner's key, the key should not be used for
+    /// a register, see [`Client::map_key_from_name`] to derive a dedicated one.
+    ///
+    /// Returns the cost and the address of the map's root.
+    pub async fn map_create(
+        &self,
+        owner: &SecretKey,
+        payment_option: PaymentOption,
+    ) -> Result<(AttoTokens, GraphEntryAddress), MapError> {
+        let main_key = MainSecretKey::new(owner.clone());
+        let pages = (0..MAP_PAGE_COUNT)
+            .map(|index| {
+                let derivation_index = page_derivation_index(index);
+                let page_pk = main_key.public_key().derive_key(&derivation_index);
+                (page_pk.into(), derivation_index.into_bytes())
+            })
+            .collect();
+        let root = GraphEntry::new(owner, vec![], MAP_ROOT_CONTENT, pages);
+
+        let (cost, address) = match self.graph_entry_put(root, payment_option).await {
+            Ok(res) => res,
+            Err(GraphError::AlreadyExists(address)) => {
+                return Err(MapError::AlreadyExists(address))
+            }
+            Err(err) => return Err(err.into()),
+        };
+        info!("Created map at {address:?}");
+        Ok((cost, address))
+    }
+
+    /// Set the value of a key in a map, replacing any previous value.
+    ///
+    /// The first key written to each page of the map requires paying for the page, later writes
+    /// to the page are free. Concurrent writes to the map by other clients are kept.
+    ///
+    /// Returns the cost of the insertion.
+    pub async fn map_insert(
+        &self,
+        owner: &SecretKey,
+        key: &str,
+        value: Bytes,
+        payment_option: PaymentOption,
+    ) -> Result<AttoTokens, MapError> {
+        self.map_write(owner, key, Some(value), Some(payment_option))
+            .await
+    }
+
+    /// Remove a key from a map.
+    ///
+    /// This is free, removing a key that is not in the map does nothing.
+    pub async fn map_remove(&self, owner: &SecretKey, key: &str) -> Result<(), MapError> {
+        self.map_write(owner, key, None, None).await?;
+        Ok(())
+    }
+
+    /// Get the value of a key in a map, if it is set.
+    pub async fn map_get(&self, owner: &SecretKey, key: &str) -> Result<Option<Bytes>, MapError> {
+        let pages = self.map_pages(owner).await?;
+        let page_key = &pages[page_index(key, pages.len())];
+        let page = match self.map_get_page(page_key).await? {
+            Some((_, page)) => page,
+            None => return Ok(None),
+        };
+        Ok(page.get(key).cloned())
+    }
+
+    /// Get all the keys set in a map along with their values, in the order of the keys.
+    pub async fn map_iter(
+        &self,
+        owner: &SecretKey,
+    ) -> Result<btree_map::IntoIter<String, Bytes>, MapError> {
+        let pages = self.map_pages(owner).await?;
+        let fetches = pages.iter().map(|page_key| self.map_get_page(page_key));
+        let mut values = BTreeMap::new();
+        for (_, page) in futures::future::try_join_all(fetches)
+            .await?
+            .into_iter()
+            .flatten()
+        {
+            values.extend(page.into_values());
+        }
+        Ok(values.into_iter())
+    }
+
+    /// Get the keys of the Scratchpad pages of a map from its root.
+    async fn map_pages(&self, owner: &SecretKey) -> Result<Vec<SecretKey>, MapError> {
+        let address = GraphEntryAddress::from_owner(owner.public_key());
+        let root = match self.graph_entry_get(&address).await {
+            Ok(root) => root,
+            // The root is only ever written once, so all the entries found are the same
+            Err(GraphError::Fork(entries)) => entries
+                .into_iter()
+                .next()
+                .ok_or_else(|| MapError::Corrupt(format!("No root found at {address:?}")))?,
+            Err(GraphError::Network(NetworkError::GetRecordError(
+                GetRecordError::RecordNotFound,
+            ))) => return Err(MapError::NotFound),
+            Err(err) => return Err(err.into()),
+        };
+        if root.content != MAP_ROOT_CONTENT || root.descendants.is_empty() {
+            return Err(MapError::Corrupt(format!(
+                "The GraphEntry at {address:?} is not the root of a map"
+            )));
+        }
+
+        let main_key = MainSecretKey::new(owner.clone());
+        let mut pages = Vec::with_capacity(root.descendants.len());
+        for (page_pk, derivation_index) in &root.descendants {
+            let page_key: SecretKey = main_key
+                .derive_key(&DerivationIndex::from_bytes(*derivation_index))
+                .into();
+            if page_key.public_key() != *page_pk {
+                return Err(MapError::Corrupt(format!(
+                    "Page {page_pk:?} of the map at {address:?} is not owned by the map owner"
+                )));
+            }
+            pages.push(page_key);
+        }
+        Ok(pages)
+    }
+
+    /// Get and decrypt a page of a map, if it has been created.
+    async fn map_get_page(
+        &self,
+        page_key: &SecretKey,
+    ) -> Result<Option<(Scratchpad, MapPage)>, MapError> {
+        let address = ScratchpadAddress::new(page_key.public_key());
+        let scratchpad = match self.scratchpad_get(&address).await {
+            Ok(scratchpad) => scratchpad,
+            Err(ScratchpadError::Network(NetworkError::GetRecordError(
+                GetRecordError::RecordNotFound,
+            ))) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let page = MapPage::from_bytes(&scratchpad.decrypt_data(page_key)?)?;
+        Ok(Some((scratchpad, page)))
+    }
+
+    /// Write a key to the page it falls into, creating the page if needed.
+    ///
+    /// If the page was updated concurrently, the write is applied again to the latest version of
+    /// the page, so neither our write nor the concurrent one is lost.
+    async fn map_write(
+        &self,
+        owner: &SecretKey,
+        key: &str,
+        value: Option<Bytes>,
+        payment_option: Option<PaymentOption>,
+    ) -> Result<AttoTokens, MapError> {
+        let pages = self.map_pages(owner).await?;
+        let index = page_index(key, pages.len());
+        let page_key = &pages[index];
+
+        for attempt in 1..=MAX_WRITE_ATTEMPTS {
+            let (current_pad, mut page) = match self.map_get_page(page_key).await? {
+                Some((pad, page)) => (Some(pad), page),
+                None => (None, MapPage::default()),
+            };
+            if !page.write(key, value.clone()) {
+                debug!("Key {key:?} is not in map page {index}, nothing to write");
+                return Ok(AttoTokens::zero());
+            }
+            let bytes = page.compact_to_fit()?.ok_or(MapError::PageFull(index))?;
+
+            let res = match (current_pad, &payment_option) {
+                (Some(pad), _) => self
+                    .scratchpad_update_if(page_key, MAP_PAGE_CONTENT_TYPE, &bytes, pad.counter())
+                    .await
+                    .map(|_| AttoTokens::zero()),
+                (None, Some(payment_option)) => self
+                    .scratchpad_create(
+                        page_key,
+                        MAP_PAGE_CONTENT_TYPE,
+                        &bytes,
+                        payment_option.clone(),
+                    )
+                    .await
+                    .map(|(cost, _)| cost),
+                // Removals never create pages, as a missing page has no key to remove
+                (None, None) => return Ok(AttoTokens::zero()),
+            };
+            match res {
+                Ok(cost) => {
+                    debug!("Wrote key {key:?} to map page {index} with cost of {cost:?}");
+                    return Ok(cost);
+                }
+                Err(ScratchpadError::Conflict { .. })
+                | Err(ScratchpadError::ScratchpadAlreadyExists(_)) => {
+                    warn!("Map page {index} was written concurrently (attempt {attempt}), writing to its latest version");
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+        Err(MapError::TooManyConflicts(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concurrent_writes_are_applied_to_the_latest_page() {
+        let mut base = MapPage::default();
+        assert!(base.write("apple", Some(Bytes::from("red"))));
+        assert!(base.write("pear", Some(Bytes::from("green"))));
+
+        // a writer updates the page first
+        let mut latest = base.clone();
+        assert!(latest.write("apple", Some(Bytes::from("yellow"))));
+        assert!(latest.write("plum", Some(Bytes::from("purple"))));
+
+        // the writes of another one conflict, and are applied again to the latest version
+        assert!(latest.write("pear", None));
+        assert!(!latest.write("missing", None));
+
+        assert_eq!(latest.get("apple"), Some(&Bytes::from("yellow")));
+        assert_eq!(latest.get("pear"), None);
+        assert_eq!(
+            latest.into_values().collect::<Vec<_>>(),
+            vec![
+                ("apple".to_string(), Bytes::from("yellow")),
+                ("plum".to_string(), Bytes::from("purple")),
+            ]
+        );
+    }
+
+    #[test]
+    fn removed_keys_can_be_written_again() {
+        let mut page = MapPage::default();
+        assert!(page.write("key", Some(Bytes::from("one"))));
+        assert!(page.write("key", None));
+        assert_eq!(page.get("key"), None);
+        assert!(page.write("key", Some(Bytes::from("two"))));
+        assert_eq!(page.get("key"), Some(&Bytes::from("two")));
+        assert_eq!(page.entries["key"].clock, 3);
+
+        let bytes = page.to_bytes().unwrap();
+        assert_eq!(MapPage::from_bytes(&bytes).unwrap(), page);
+    }
+
+    #[test]
+    fn insert_survives_concurrent_compaction() {
+        let mut base = MapPage::default();
+        for i in 0..50 {
+            assert!(base.write(&i.to_string(), Some(Bytes::from("value"))));
+            assert!(base.write(&i.to_string(), None));
+        }
+        for i in 0..50 {
+            assert!(base.write(&i.to_string(), Some(Bytes::from("again"))));
+            assert!(base.write(&i.to_string(), None));
+        }
+
+        // we insert a new key while another writer compacts the page
+        let mut ours = base.clone();
+        assert!(ours.write("new", Some(Bytes::from("value"))));
+        let mut latest = base;
+        assert!(latest.compact());
+        assert!(latest.floor > ours.entries["new"].clock);
+
+        // our write conflicts, and is applied again to the compacted page
+        assert!(latest.write("new", Some(Bytes::from("value"))));
+        assert_eq!(latest.get("new"), Some(&Bytes::from("value")));
+        let bytes = latest.compact_to_fit().unwrap().unwrap();
+        let page = MapPage::from_bytes(&bytes).unwrap();
+        assert_eq!(
+            page.into_values().collect::<Vec<_>>(),
+            vec![("new".to_string(), Bytes::from("value"))]
+        );
+    }
+
+    #[test]
+    fn map_key_from_name_is_not_the_register_key() {
+        let owner = SecretKey::random();
+        let map_key = Client::map_key_from_name(&owner, "index");
+        assert_eq!(map_key, Client::map_key_from_name(&owner, "index"));
+        assert_ne!(map_key, Client::register_key_from_name(&owner, "index"));
+        assert!(page_index("index", MAP_PAGE_COUNT) < MAP_PAGE_COUNT);
+    }
+
+    #[test]
+    fn page_is_compacted_when_removed_keys_fill_it() {
+        let key = |i: usize| format!("{i:064}");
+        let mut page = MapPage::default();
+
+        // insert and remove more keys than the tombstones of a page can hold
+        let mut i = 0;
+        let mut compacted = false;
+        while !compacted {
+            for _ in 0..10_000 {
+                assert!(page.write(&key(i), Some(Bytes::from("value"))));
+                assert!(page.write(&key(i), None));
+                i += 1;
+            }
+            let bytes = page.compact_to_fit().unwrap().unwrap();
+            assert!(bytes.len() <= MAX_CONTENT_PER_SCRATCHPAD);
+            compacted = page.floor > 0;
+        }
+        assert!(i > 50_000);
+        assert!(page.entries.is_empty());
+
+        // removed keys can be inserted again, with clocks above the floor
+        assert!(page.write(&key(0), Some(Bytes::from("again"))));
+        assert!(!page.write(&key(1), None));
+        assert!(page.entries[&key(0)].clock > page.floor);
+        assert_eq!(page.get(&key(0)), Some(&Bytes::from("again")));
+        assert_eq!(page.into_values().count(), 1);
+    }
+
+    #[test]
+    fn page_with_too_many_values_is_full() {
+        let mut page = MapPage::default();
+        let value = Bytes::from(vec![0u8; MAX_CONTENT_PER_SCRATCHPAD / 2]);
+        assert!(page.write("one", Some(value.clone())));
+        assert!(page.write("two", Some(value)));
+        assert_eq!(page.compact_to_fit().unwrap(), None);
+    }
+}
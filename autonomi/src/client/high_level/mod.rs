@@ -6,8 +6,17 @@
 // KIND, either express or implied. Please review the Licences for the specific language governing
 // permissions and limitations relating to use of the SAFE Network Software.
 
+/// Mutable blobs hold private data of any size, spread over as many Scratchpads as needed.
+/// A manifest Scratchpad at the owner's [`crate::PublicKey`] lists the hash of each segment,
+/// so that an update only rewrites the segments whose content changed.
+pub mod blob;
 pub mod data;
 pub mod files;
+
+/// Maps are private key-value stores that can be written by concurrent clients.
+/// A root [`crate::GraphEntry`] at the owner's [`crate::PublicKey`] lists the Scratchpad pages
+/// holding the entries, and concurrent versions of a page are merged key by key.
+pub mod map;
 pub mod vault;
 
 /// Registers are a mutable piece of data on the Network.
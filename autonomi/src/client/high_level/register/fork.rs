@@ -0,0 +1,181 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use ant_networking::{GetRecordError, NetworkError};
+
+use crate::client::data_types::graph::{GraphEntry, GraphEntryAddress, GraphError};
+use crate::client::data_types::pointer::{PointerError, PointerTarget};
+use crate::client::high_level::register::{
+    get_derivation_from_graph_entry, RegisterAddress, RegisterError, RegisterValue, SecretKey,
+};
+use crate::client::key_derivation::{DerivationIndex, MainSecretKey};
+use crate::client::payment::PaymentOption;
+use crate::client::Client;
+use crate::AttoTokens;
+
+impl Client {
+    /// Get the competing values at the head of a register.
+    ///
+    /// A register forks when it is updated concurrently, in which case there is more than one
+    /// value at its head, see [`Client::register_resolve_fork`] to resolve it.
+    /// The values are sorted, so that they are listed in the same order every time.
+    /// A register that is not forked has a single value.
+    pub async fn register_fork_heads(
+        &self,
+        addr: &RegisterAddress,
+    ) -> Result<Vec<RegisterValue>, RegisterError> {
+        let head_addr = self.register_head_entry_address(addr).await?;
+        let heads = self.register_head_entries(&head_addr).await?;
+        Ok(head_values(&heads))
+    }
+
+    /// Resolve a fork of a register by writing a reconciling entry after all the competing heads.
+    ///
+    /// The `resolve` strategy is given the competing values, sorted as by
+    /// [`Client::register_fork_heads`], and returns the value of the reconciling entry: it can
+    /// pick one of them as the winner, or merge them into a new value. Values larger than a
+    /// [`RegisterValue`] can be stored beforehand with [`Client::register_value_from_data`].
+    ///
+    /// Fails with [`RegisterError::NotForked`] if the register is not forked.
+    ///
+    /// If another client wrote an entry after the fork first, for instance by resolving it
+    /// concurrently, the register head is moved to that entry and ours is not written. This then
+    /// succeeds at no cost, unless the register is forked again after that entry, in which case it
+    /// fails with [`RegisterError::Fork`].
+    pub async fn register_resolve_fork(
+        &self,
+        owner: &SecretKey,
+        resolve: impl FnOnce(&[RegisterValue]) -> RegisterValue,
+        payment_option: PaymentOption,
+    ) -> Result<AttoTokens, RegisterError> {
+        let addr = RegisterAddress::new(owner.public_key());
+        let head_addr = match self.register_head_entry_address(&addr).await {
+            Ok(head_addr) => head_addr,
+            Err(RegisterError::PointerError(PointerError::Network(
+                NetworkError::GetRecordError(GetRecordError::RecordNotFound),
+            ))) => return Err(RegisterError::CannotUpdateNewRegister),
+            Err(err) => return Err(err),
+        };
+        let heads = self.register_head_entries(&head_addr).await?;
+        if heads.len() < 2 {
+            return Err(RegisterError::NotForked);
+        }
+        let value = resolve(&head_values(&heads));
+        info!(
+            "Resolving fork of register {addr} with {} competing heads",
+            heads.len()
+        );
+
+        // Continue from the smallest derivation index, as updates of a forked register do
+        let derivation = heads
+            .iter()
+            .filter_map(|entry| get_derivation_from_graph_entry(entry).ok())
+            .min()
+            .ok_or(RegisterError::Corrupt(format!(
+                "No valid descendants found for FORKED entry at {head_addr:?}"
+            )))?;
+
+        // The competing heads are all at the same address, so they share the same owner, through
+        // which the reconciling entry references each of them as its parent.
+        let main_key = MainSecretKey::new(owner.clone());
+        let parents = vec![heads[0].owner];
+        let next_derivation = DerivationIndex::random(&mut rand::thread_rng());
+        let next_pk = main_key.public_key().derive_key(&next_derivation);
+        let descendants = vec![(next_pk.into(), next_derivation.into_bytes())];
+        let entry_key = main_key.derive_key(&derivation);
+        let entry = GraphEntry::new(&entry_key.into(), parents, value, descendants);
+
+        let pointer_key = self.register_head_pointer_sk(&main_key.into());
+        let (cost, entry_addr) = match self.graph_entry_put(entry, payment_option).await {
+            Ok(res) => res,
+            Err(GraphError::AlreadyExists(address)) => {
+                // another update was already written after the fork, move the pointer to it
+                info!("Fork of register {addr} was already followed by the entry at {address:?}");
+                let target = PointerTarget::GraphEntryAddress(address);
+                self.pointer_update(&pointer_key, target).await?;
+                let heads = self.register_head_entries(&address).await?;
+                if heads.len() > 1 {
+                    return Err(RegisterError::Fork(head_values(&heads)));
+                }
+                return Ok(AttoTokens::zero());
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let target = PointerTarget::GraphEntryAddress(entry_addr);
+        self.pointer_update(&pointer_key, target).await?;
+        info!("Resolved fork of register {addr} with entry at {entry_addr:?}");
+        Ok(cost)
+    }
+
+    /// Get the address of the entry the register's head pointer points to.
+    async fn register_head_entry_address(
+        &self,
+        addr: &RegisterAddress,
+    ) -> Result<GraphEntryAddress, RegisterError> {
+        let pointer_addr = self.register_head_pointer_address(addr);
+        debug!("Getting pointer of register head at {pointer_addr:?}");
+        let pointer = self.pointer_get(&pointer_addr).await?;
+        match pointer.target() {
+            PointerTarget::GraphEntryAddress(addr) => Ok(*addr),
+            other => Err(RegisterError::InvalidHeadPointer(other.clone())),
+        }
+    }
+
+    /// Get the counter of the register's head pointer along with the competing values at its
+    /// head, which together tell whether the register changed.
+    pub(crate) async fn register_head_version(
+        &self,
+        addr: &RegisterAddress,
+    ) -> Result<(u32, Vec<RegisterValue>), RegisterError> {
+        let pointer_addr = self.register_head_pointer_address(addr);
+        let pointer = self.pointer_get(&pointer_addr).await?;
+        let head_addr = match pointer.target() {
+            PointerTarget::GraphEntryAddress(addr) => addr,
+            other => return Err(RegisterError::InvalidHeadPointer(other.clone())),
+        };
+        let heads = self.register_head_entries(head_addr).await?;
+        Ok((pointer.counter(), head_values(&heads)))
+    }
+
+    /// Get the entries at the head of a register, more than one if it is forked.
+    async fn register_head_entries(
+        &self,
+        head_addr: &GraphEntryAddress,
+    ) -> Result<Vec<GraphEntry>, RegisterError> {
+        match self.graph_entry_get(head_addr).await {
+            Ok(entry) => Ok(vec![entry]),
+            Err(GraphError::Fork(entries)) => Ok(entries),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+/// Get the distinct values of the entries at the head of a register, sorted.
+fn head_values(heads: &[GraphEntry]) -> Vec<RegisterValue> {
+    let mut values: Vec<RegisterValue> = heads.iter().map(|entry| entry.content).collect();
+    values.sort();
+    values.dedup();
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn head_values_are_sorted_and_distinct() {
+        let key = SecretKey::random();
+        let entry = |value: u8, descendant: u8| {
+            let descendants = vec![(SecretKey::random().public_key(), [descendant; 32])];
+            GraphEntry::new(&key, vec![], [value; 32], descendants)
+        };
+        let heads = vec![entry(3, 0), entry(1, 1), entry(3, 2)];
+        assert_eq!(head_values(&heads), vec![[1; 32], [3; 32]]);
+    }
+}
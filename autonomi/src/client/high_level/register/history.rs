@@ -14,6 +14,7 @@ use crate::client::high_level::register::{
 };
 use crate::client::key_derivation::MainPubkey;
 use crate::client::Client;
+use crate::Bytes;
 
 /// A handle to the register history
 #[derive(Clone)]
@@ -63,6 +64,26 @@ impl RegisterHistory {
         }
         Ok(values)
     }
+
+    /// Fetch and go to the next value from the history of a register created with
+    /// [`Client::register_create_bytes`], fetching the values that are not stored inline.
+    /// Returns `Ok(None)` when we reached the end
+    pub async fn next_bytes(&mut self) -> Result<Option<Bytes>, RegisterError> {
+        match self.next().await? {
+            Some(value) => Ok(Some(self.client.register_value_to_data(&value).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Get all the values from the history of a register created with
+    /// [`Client::register_create_bytes`], starting from the first to the latest entry
+    pub async fn collect_bytes(&mut self) -> Result<Vec<Bytes>, RegisterError> {
+        let mut values = Vec::new();
+        for value in self.collect().await? {
+            values.push(self.client.register_value_to_data(&value).await?);
+        }
+        Ok(values)
+    }
 }
 
 impl Client {
@@ -11,17 +11,20 @@ use crate::client::data_types::pointer::{PointerAddress, PointerError, PointerTa
 use crate::client::key_derivation::{DerivationIndex, MainPubkey, MainSecretKey};
 use crate::client::payment::PaymentOption;
 use crate::client::quote::CostError;
-use crate::client::Client;
+use crate::client::{Client, GetError, PutError};
 use crate::AttoTokens;
 use ant_networking::{GetRecordError, NetworkError};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use xor_name::XorName;
 
+mod fork;
 mod history;
+mod value;
 
 pub use crate::{PublicKey, SecretKey};
 pub use history::RegisterHistory;
+pub use value::{InvalidTypedValue, RegisterValueType, REGISTER_INLINE_VALUE_SIZE};
 
 /// A Register is addressed at a [`RegisterAddress`] which is in fact the owner's [`PublicKey`].
 /// There can only be one register stored at [`PublicKey`].
@@ -85,8 +88,10 @@ pub enum RegisterError {
     InvalidCost,
     #[error("Invalid head pointer, was expecting a GraphEntryAddress but got: {0:?}")]
     InvalidHeadPointer(PointerTarget),
-    #[error("Forked register, this can happen if the register has been updated concurrently, you can solve this by updating the register again with a new value. Concurrent entries: {0:?}")]
+    #[error("Forked register, this can happen if the register has been updated concurrently, you can solve this with `Client::register_resolve_fork` or by updating the register again with a new value. Concurrent entries: {0:?}")]
     Fork(Vec<[u8; 32]>),
+    #[error("Register is not forked, there is nothing to resolve")]
+    NotForked,
     #[error("Corrupt register: {0}")]
     Corrupt(String),
     #[error("Register cannot be updated as it does not exist, please create it first or wait for it to be created")]
@@ -95,6 +100,16 @@ pub enum RegisterError {
         "Invalid register value length: {0}, expected something within {REGISTER_VALUE_SIZE} bytes"
     )]
     InvalidRegisterValueLength(usize),
+    #[error("Failed to store the register value: {0}")]
+    ValuePut(#[from] PutError),
+    #[error("Failed to get the register value: {0}")]
+    ValueGet(#[from] GetError),
+    /// The register holds a value written with [`Client::register_update`], rather than one of
+    /// any size written with [`Client::register_update_bytes`].
+    #[error("The register value was not written as a value of any size")]
+    RawValue,
+    #[error("Invalid typed register value: {0}")]
+    InvalidTypedValue(#[from] InvalidTypedValue),
 }
 
 /// Hard coded derivation index for the register head pointer
@@ -0,0 +1,331 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::client::data_types::chunk::{Chunk, ChunkAddress};
+use crate::client::data_types::pointer::PointerAddress;
+use crate::client::high_level::data::DataAddr;
+use crate::client::high_level::files::archive_public::ArchiveAddr;
+use crate::client::high_level::register::{
+    RegisterAddress, RegisterError, RegisterValue, REGISTER_VALUE_SIZE,
+};
+use crate::client::payment::PaymentOption;
+use crate::client::{Client, GetError};
+use crate::{AttoTokens, Bytes, SecretKey};
+use ant_networking::{GetRecordError, NetworkError};
+use serde::{Deserialize, Serialize};
+use xor_name::XorName;
+
+/// Marks a [`RegisterValue`] that holds its value inline.
+///
+/// Any other [`RegisterValue`] written by [`Client::register_create_bytes`] or
+/// [`Client::register_update_bytes`] is the address of a chunk holding the value.
+const INLINE_VALUE_MAGIC: [u8; 4] = *b"RGV\x01";
+
+/// The max size of a value stored inline in a register entry, larger values are stored in a chunk.
+pub const REGISTER_INLINE_VALUE_SIZE: usize = REGISTER_VALUE_SIZE - INLINE_VALUE_MAGIC.len() - 1;
+
+/// The max size of a value stored in a single chunk, larger values are stored as public data.
+const MAX_CHUNK_VALUE_SIZE: usize = Chunk::DEFAULT_MAX_SIZE - 1024;
+
+/// A register value that is too large to be stored inline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+enum ExternalValue {
+    Bytes(Bytes),
+    Data(DataAddr),
+}
+
+/// The content of the chunk a register entry refers to for a value too large to be stored inline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct ExternalValueChunk {
+    value: ExternalValue,
+    /// Changed until the chunk address can't be mistaken for an inline value.
+    nonce: u32,
+}
+
+impl ExternalValueChunk {
+    fn into_chunk(value: ExternalValue) -> Result<Chunk, rmp_serde::encode::Error> {
+        let mut external = ExternalValueChunk { value, nonce: 0 };
+        loop {
+            let bytes = rmp_serde::to_vec(&external)?;
+            let chunk = Chunk::new(Bytes::from(bytes));
+            if !chunk.name().0.starts_with(&INLINE_VALUE_MAGIC) {
+                return Ok(chunk);
+            }
+            external.nonce += 1;
+        }
+    }
+}
+
+/// Store a value inline in a [`RegisterValue`], if it fits.
+fn inline_value(value: &[u8]) -> Option<RegisterValue> {
+    if value.len() > REGISTER_INLINE_VALUE_SIZE {
+        return None;
+    }
+    let mut content: RegisterValue = [0; REGISTER_VALUE_SIZE];
+    let (magic, rest) = content.split_at_mut(INLINE_VALUE_MAGIC.len());
+    magic.copy_from_slice(&INLINE_VALUE_MAGIC);
+    rest[0] = value.len() as u8;
+    rest[1..=value.len()].copy_from_slice(value);
+    Some(content)
+}
+
+/// Get the value stored inline in a [`RegisterValue`].
+///
+/// Returns `None` if it does not hold a valid inline value.
+fn inline_value_of(content: &RegisterValue) -> Option<Bytes> {
+    let rest = content.strip_prefix(&INLINE_VALUE_MAGIC)?;
+    let len = rest[0] as usize;
+    rest.get(1..=len).map(Bytes::copy_from_slice)
+}
+
+/// The error returned when a register value can't be turned into the expected type.
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub struct InvalidTypedValue(pub String);
+
+/// A type that can be stored in a register with [`Client::register_create_typed`].
+pub trait RegisterValueType: Sized {
+    fn to_register_bytes(&self) -> Bytes;
+    fn from_register_bytes(bytes: Bytes) -> Result<Self, InvalidTypedValue>;
+}
+
+impl RegisterValueType for String {
+    fn to_register_bytes(&self) -> Bytes {
+        Bytes::from(self.clone())
+    }
+
+    fn from_register_bytes(bytes: Bytes) -> Result<Self, InvalidTypedValue> {
+        String::from_utf8(bytes.to_vec())
+            .map_err(|err| InvalidTypedValue(format!("not a UTF-8 string: {err}")))
+    }
+}
+
+impl RegisterValueType for ArchiveAddr {
+    fn to_register_bytes(&self) -> Bytes {
+        Bytes::copy_from_slice(&self.0)
+    }
+
+    fn from_register_bytes(bytes: Bytes) -> Result<Self, InvalidTypedValue> {
+        let addr: [u8; 32] = bytes.as_ref().try_into().map_err(|_| {
+            InvalidTypedValue(format!(
+                "not an archive address, expected 32 bytes but got {}",
+                bytes.len()
+            ))
+        })?;
+        Ok(XorName(addr))
+    }
+}
+
+impl RegisterValueType for PointerAddress {
+    fn to_register_bytes(&self) -> Bytes {
+        Bytes::copy_from_slice(&self.xorname().0)
+    }
+
+    fn from_register_bytes(bytes: Bytes) -> Result<Self, InvalidTypedValue> {
+        let addr = ArchiveAddr::from_register_bytes(bytes)
+            .map_err(|_| InvalidTypedValue("not a pointer address".to_string()))?;
+        Ok(PointerAddress::new(addr))
+    }
+}
+
+impl Client {
+    /// Create a new register with an initial value of any size.
+    ///
+    /// Values of up to [`REGISTER_INLINE_VALUE_SIZE`] bytes are stored in the register itself,
+    /// larger ones are stored in chunks that are paid for along with the register. Such registers
+    /// must be read with [`Client::register_get_bytes`].
+    pub async fn register_create_bytes(
+        &self,
+        owner: &SecretKey,
+        initial_value: Bytes,
+        payment_option: PaymentOption,
+    ) -> Result<(AttoTokens, RegisterAddress), RegisterError> {
+        let (content, value_cost) = self
+            .register_value_from_data(initial_value, payment_option.clone())
+            .await?;
+        let (cost, addr) = self.register_create(owner, content, payment_option).await?;
+        let total_cost = cost
+            .checked_add(value_cost)
+            .ok_or(RegisterError::InvalidCost)?;
+        Ok((total_cost, addr))
+    }
+
+    /// Update the value of a register created with [`Client::register_create_bytes`].
+    pub async fn register_update_bytes(
+        &self,
+        owner: &SecretKey,
+        new_value: Bytes,
+        payment_option: PaymentOption,
+    ) -> Result<AttoTokens, RegisterError> {
+        let (content, value_cost) = self
+            .register_value_from_data(new_value, payment_option.clone())
+            .await?;
+        let cost = self.register_update(owner, content, payment_option).await?;
+        cost.checked_add(value_cost)
+            .ok_or(RegisterError::InvalidCost)
+    }
+
+    /// Get the current value of a register created with [`Client::register_create_bytes`].
+    pub async fn register_get_bytes(&self, addr: &RegisterAddress) -> Result<Bytes, RegisterError> {
+        let content = self.register_get(addr).await?;
+        self.register_value_to_data(&content).await
+    }
+
+    /// Create a new register holding a typed value, such as a [`String`], an [`ArchiveAddr`] or a
+    /// [`PointerAddress`].
+    pub async fn register_create_typed<T: RegisterValueType>(
+        &self,
+        owner: &SecretKey,
+        initial_value: &T,
+        payment_option: PaymentOption,
+    ) -> Result<(AttoTokens, RegisterAddress), RegisterError> {
+        self.register_create_bytes(owner, initial_value.to_register_bytes(), payment_option)
+            .await
+    }
+
+    /// Update the value of a register created with [`Client::register_create_typed`].
+    pub async fn register_update_typed<T: RegisterValueType>(
+        &self,
+        owner: &SecretKey,
+        new_value: &T,
+        payment_option: PaymentOption,
+    ) -> Result<AttoTokens, RegisterError> {
+        self.register_update_bytes(owner, new_value.to_register_bytes(), payment_option)
+            .await
+    }
+
+    /// Get the current value of a register created with [`Client::register_create_typed`].
+    pub async fn register_get_typed<T: RegisterValueType>(
+        &self,
+        addr: &RegisterAddress,
+    ) -> Result<T, RegisterError> {
+        Ok(T::from_register_bytes(
+            self.register_get_bytes(addr).await?,
+        )?)
+    }
+
+    /// Turn a value of any size into a [`RegisterValue`], storing it in chunks if it is too large
+    /// to be stored inline.
+    ///
+    /// This is done by [`Client::register_update_bytes`], and can be used to resolve a fork with a
+    /// new value through [`Client::register_resolve_fork`].
+    pub async fn register_value_from_data(
+        &self,
+        value: Bytes,
+        payment_option: PaymentOption,
+    ) -> Result<(RegisterValue, AttoTokens), RegisterError> {
+        if let Some(content) = inline_value(&value) {
+            return Ok((content, AttoTokens::zero()));
+        }
+
+        let (external, data_cost) = if value.len() <= MAX_CHUNK_VALUE_SIZE {
+            (ExternalValue::Bytes(value), AttoTokens::zero())
+        } else {
+            let (cost, addr) = self.data_put_public(value, payment_option.clone()).await?;
+            (ExternalValue::Data(addr), cost)
+        };
+        let chunk = ExternalValueChunk::into_chunk(external).map_err(|err| {
+            RegisterError::Corrupt(format!("Failed to serialize register value: {err}"))
+        })?;
+        let (chunk_cost, addr) = self.chunk_put(&chunk, payment_option).await?;
+        debug!("Stored register value in chunk at {addr:?}");
+
+        let total_cost = chunk_cost
+            .checked_add(data_cost)
+            .ok_or(RegisterError::InvalidCost)?;
+        Ok((addr.xorname().0, total_cost))
+    }
+
+    /// Get the value a [`RegisterValue`] holds, fetching it from the network if it is not inline.
+    ///
+    /// Returns [`RegisterError::RawValue`] if it is neither inline nor the address of a value
+    /// chunk, as for the values written with [`Client::register_update`].
+    pub async fn register_value_to_data(
+        &self,
+        content: &RegisterValue,
+    ) -> Result<Bytes, RegisterError> {
+        if content.starts_with(&INLINE_VALUE_MAGIC) {
+            return inline_value_of(content).ok_or_else(|| {
+                RegisterError::Corrupt(format!("Invalid inline register value: {content:?}"))
+            });
+        }
+
+        let chunk = match self.chunk_get(&ChunkAddress::new(XorName(*content))).await {
+            Ok(chunk) => chunk,
+            Err(GetError::Network(NetworkError::GetRecordError(
+                GetRecordError::RecordNotFound,
+            ))) => {
+                debug!("No value chunk found for register value {content:?}, it is a raw value");
+                return Err(RegisterError::RawValue);
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let external: ExternalValueChunk = rmp_serde::from_slice(chunk.value()).map_err(|err| {
+            debug!("Register value {content:?} is not the address of a value chunk: {err}");
+            RegisterError::RawValue
+        })?;
+        match external.value {
+            ExternalValue::Bytes(value) => Ok(value),
+            ExternalValue::Data(addr) => Ok(self.data_get_public(&addr).await?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inline_values_round_trip() {
+        for value in [
+            &b""[..],
+            b"https://autonomi.com",
+            &[7; REGISTER_INLINE_VALUE_SIZE],
+        ] {
+            let content = inline_value(value).expect("value should fit inline");
+            let decoded = inline_value_of(&content).unwrap();
+            assert_eq!(decoded, Bytes::copy_from_slice(value));
+        }
+        assert!(inline_value(&[7; REGISTER_INLINE_VALUE_SIZE + 1]).is_none());
+
+        // values written with the raw register API are not mistaken for inline ones
+        let raw = Client::register_value_from_bytes(b"hello").unwrap();
+        assert!(inline_value_of(&raw).is_none());
+    }
+
+    #[test]
+    fn external_value_chunks_are_not_mistaken_for_inline_values() {
+        let value = ExternalValue::Bytes(Bytes::from(vec![1; 100]));
+        let chunk = ExternalValueChunk::into_chunk(value.clone()).unwrap();
+        assert!(inline_value_of(&chunk.name().0).is_none());
+        let external: ExternalValueChunk = rmp_serde::from_slice(chunk.value()).unwrap();
+        assert_eq!(external.value, value);
+    }
+
+    #[test]
+    fn typed_values_round_trip() {
+        let string = "{\"url\": \"https://autonomi.com\"}".to_string();
+        assert_eq!(
+            String::from_register_bytes(string.to_register_bytes()).unwrap(),
+            string
+        );
+
+        let archive = XorName::random(&mut rand::thread_rng());
+        assert_eq!(
+            ArchiveAddr::from_register_bytes(archive.to_register_bytes()).unwrap(),
+            archive
+        );
+
+        let pointer = PointerAddress::new(XorName::random(&mut rand::thread_rng()));
+        assert_eq!(
+            PointerAddress::from_register_bytes(pointer.to_register_bytes()).unwrap(),
+            pointer
+        );
+        assert!(PointerAddress::from_register_bytes(Bytes::from("short")).is_err());
+    }
+}
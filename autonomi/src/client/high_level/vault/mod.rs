@@ -38,7 +38,7 @@ use tracing::info;
 pub type VaultContentType = u64;
 
 /// Defines the max size of content can be written into per ScratchPad
-const MAX_CONTENT_PER_SCRATCHPAD: usize = Scratchpad::MAX_SIZE - 1024;
+pub(crate) const MAX_CONTENT_PER_SCRATCHPAD: usize = Scratchpad::MAX_SIZE - 1024;
 
 /// Defines the max number of Scratchpads that one GraphEntry can point to
 /// The current value is assuming GraphEntry max_size to be 100KB.
@@ -408,7 +408,7 @@ impl Client {
     }
 }
 
-fn split_bytes(input: Bytes) -> Vec<Bytes> {
+pub(crate) fn split_bytes(input: Bytes) -> Vec<Bytes> {
     let mut contents = Vec::new();
     let mut offset = 0;
 
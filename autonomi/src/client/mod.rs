@@ -23,8 +23,10 @@ pub use data_types::scratchpad;
 /// High-level types built on top of the basic Network data types.
 /// Includes data, files and personnal data vaults
 mod high_level;
+pub use high_level::blob;
 pub use high_level::data;
 pub use high_level::files;
+pub use high_level::map;
 pub use high_level::register;
 pub use high_level::vault;
 
@@ -33,6 +35,7 @@ pub mod config;
 pub mod key_derivation;
 pub mod payment;
 pub mod quote;
+pub mod watch;
 
 #[cfg(feature = "external-signer")]
 #[cfg_attr(docsrs, doc(cfg(feature = "external-signer")))]
@@ -48,12 +51,12 @@ use ant_networking::{
     interval, multiaddr_is_global, Network, NetworkBuilder, NetworkError, NetworkEvent,
 };
 use ant_protocol::{version::IDENTIFY_PROTOCOL_STR, NetworkAddress};
-use config::{ClientConfig, ClientOperatingStrategy};
+use config::{ClientConfig, ClientOperatingStrategy, TransportConfig};
 use libp2p::{identity::Keypair, Multiaddr};
 use payment::PayError;
 use quote::CostError;
 use std::{collections::HashSet, time::Duration};
-use tokio::sync::{mpsc, watch};
+use tokio::sync::mpsc;
 
 /// Time before considering the connection timed out.
 pub const CONNECT_TIMEOUT_SECS: u64 = 10;
@@ -86,7 +89,7 @@ pub struct Client {
     /// The configuration for operations on the client.
     config: ClientOperatingStrategy,
     // Shutdown signal for child tasks. Sends signal when dropped.
-    _shutdown_tx: watch::Sender<bool>,
+    _shutdown_tx: tokio::sync::watch::Sender<bool>,
 }
 
 /// Error returned by [`Client::init`].
@@ -180,6 +183,7 @@ impl Client {
             peers: Some(peers),
             evm_network: EvmNetwork::new(local).unwrap_or_default(),
             strategy: Default::default(),
+            transports: Default::default(),
         })
         .await
     }
@@ -199,7 +203,8 @@ impl Client {
     /// # }
     /// ```
     pub async fn init_with_config(config: ClientConfig) -> Result<Self, ConnectError> {
-        let (shutdown_tx, network, event_receiver) = build_client_and_run_swarm(config.local);
+        let (shutdown_tx, network, event_receiver) =
+            build_client_and_run_swarm(config.local, config.transports);
 
         let peers_args = PeersArgs {
             disable_mainnet_contacts: config.local,
@@ -259,8 +264,14 @@ impl Client {
 
 fn build_client_and_run_swarm(
     local: bool,
-) -> (watch::Sender<bool>, Network, mpsc::Receiver<NetworkEvent>) {
+    transports: TransportConfig,
+) -> (
+    tokio::sync::watch::Sender<bool>,
+    Network,
+    mpsc::Receiver<NetworkEvent>,
+) {
     let mut network_builder = NetworkBuilder::new(Keypair::generate_ed25519(), local);
+    network_builder.transport_config(transports);
 
     if let Ok(mut config) = BootstrapCacheConfig::default_config(local) {
         if local {
@@ -277,7 +288,7 @@ fn build_client_and_run_swarm(
 
     // TODO: Implement graceful SwarmDriver shutdown for client.
     // Create a shutdown signal channel
-    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let (shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
 
     let _swarm_driver = ant_networking::time::spawn(swarm_driver.run(shutdown_rx));
 
@@ -289,7 +300,7 @@ fn build_client_and_run_swarm(
 async fn handle_event_receiver(
     mut event_receiver: mpsc::Receiver<NetworkEvent>,
     sender: futures::channel::oneshot::Sender<Result<(), ConnectError>>,
-    mut shutdown_rx: watch::Receiver<bool>,
+    mut shutdown_rx: tokio::sync::watch::Receiver<bool>,
 ) {
     // We switch this to `None` when we've sent the oneshot 'connect' result.
     let mut sender = Some(sender);
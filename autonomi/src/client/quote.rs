@@ -25,6 +25,7 @@ pub use ant_protocol::storage::DataTypes;
 const GET_MARKET_PRICE_BATCH_LIMIT: usize = 2000;
 
 /// A quote for a single address
+#[derive(Clone)]
 pub struct QuoteForAddress(pub(crate) Vec<(PeerId, PaymentQuote, Amount)>);
 
 impl QuoteForAddress {
@@ -34,6 +35,7 @@ impl QuoteForAddress {
 }
 
 /// A quote for many addresses
+#[derive(Clone)]
 pub struct StoreQuote(pub HashMap<XorName, QuoteForAddress>);
 
 impl StoreQuote {
@@ -0,0 +1,349 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use std::time::Duration;
+
+use ant_networking::{GetRecordError, NetworkError};
+use futures::Stream;
+
+use crate::client::data_types::graph::{GraphEntry, GraphEntryAddress, GraphError};
+use crate::client::data_types::pointer::{Pointer, PointerAddress, PointerError};
+use crate::client::data_types::scratchpad::{Scratchpad, ScratchpadAddress, ScratchpadError};
+use crate::client::high_level::register::{RegisterAddress, RegisterError, RegisterValue};
+use crate::client::Client;
+
+/// Address of mutable data that can be watched for changes with [`Client::watch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatchAddress {
+    Pointer(PointerAddress),
+    Scratchpad(ScratchpadAddress),
+    Register(RegisterAddress),
+    GraphEntry(GraphEntryAddress),
+}
+
+impl From<PointerAddress> for WatchAddress {
+    fn from(address: PointerAddress) -> Self {
+        Self::Pointer(address)
+    }
+}
+
+impl From<ScratchpadAddress> for WatchAddress {
+    fn from(address: ScratchpadAddress) -> Self {
+        Self::Scratchpad(address)
+    }
+}
+
+impl From<RegisterAddress> for WatchAddress {
+    fn from(address: RegisterAddress) -> Self {
+        Self::Register(address)
+    }
+}
+
+impl From<GraphEntryAddress> for WatchAddress {
+    fn from(address: GraphEntryAddress) -> Self {
+        Self::GraphEntry(address)
+    }
+}
+
+/// A new version of watched data, yielded by [`Client::watch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatchUpdate {
+    Pointer(Pointer),
+    Scratchpad(Scratchpad),
+    /// The value at the head of the register, or the competing values if it is forked.
+    Register {
+        /// Counter of the register's head pointer, which increases with each update.
+        counter: u32,
+        values: Vec<RegisterValue>,
+    },
+    /// Graph entries are immutable, a new version means a competing entry appeared at the same
+    /// address. All the entries found are listed, sorted.
+    GraphEntry(Vec<GraphEntry>),
+}
+
+impl WatchUpdate {
+    /// Whether this version is newer than the `previous` one.
+    ///
+    /// Pointers and Scratchpads are ordered in the same way as the nodes order them, so that a
+    /// stale copy served by a lagging node is never reported as a change, while a competing
+    /// version with the same counter is reported if it is the one the nodes keep.
+    fn supersedes(&self, previous: &WatchUpdate) -> bool {
+        match (self, previous) {
+            (Self::Pointer(new), Self::Pointer(old)) => new.supersedes(old),
+            (Self::Scratchpad(new), Self::Scratchpad(old)) => new.supersedes(old),
+            (
+                Self::Register { counter, values },
+                Self::Register {
+                    counter: old_counter,
+                    values: old_values,
+                },
+            ) => counter > old_counter || (counter == old_counter && values != old_values),
+            (Self::GraphEntry(new), Self::GraphEntry(old)) => new.len() > old.len(),
+            _ => true,
+        }
+    }
+}
+
+/// Errors that can occur while watching data.
+#[derive(Debug, thiserror::Error)]
+pub enum WatchError {
+    #[error("Failed to get pointer: {0}")]
+    Pointer(#[from] PointerError),
+    #[error("Failed to get scratchpad: {0}")]
+    Scratchpad(#[from] ScratchpadError),
+    #[error("Failed to get register: {0}")]
+    Register(#[from] Box<RegisterError>),
+    #[error("Failed to get graph entry: {0}")]
+    GraphEntry(#[from] GraphError),
+}
+
+/// Configuration of how often [`Client::watch`] checks the network for changes.
+///
+/// The watcher checks again after `min_interval` when it has just seen a change, and backs off
+/// up to `max_interval` while the data stays the same.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchConfig {
+    pub min_interval: Duration,
+    pub max_interval: Duration,
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        Self {
+            min_interval: Duration::from_secs(5),
+            max_interval: Duration::from_secs(120),
+        }
+    }
+}
+
+/// What tells a watcher to check its address again.
+///
+/// Only polling is supported for now. Change notifications pushed by the nodes of the close
+/// group can be added as another trigger, the watcher comparing versions the same way.
+#[derive(Debug)]
+enum WatchTrigger {
+    Poll {
+        config: WatchConfig,
+        interval: Duration,
+    },
+}
+
+impl WatchTrigger {
+    fn poll(config: WatchConfig) -> Self {
+        Self::Poll {
+            config,
+            interval: config.min_interval,
+        }
+    }
+
+    /// Wait until the address should be checked again.
+    async fn wait(&self) {
+        match self {
+            Self::Poll { interval, .. } => tokio::time::sleep(*interval).await,
+        }
+    }
+
+    /// Adapt to the outcome of the last check: poll faster while the data is changing, and
+    /// slower while it is not.
+    fn checked(&mut self, changed: bool) {
+        match self {
+            Self::Poll { config, interval } => {
+                *interval = if changed {
+                    config.min_interval
+                } else {
+                    interval.saturating_mul(2).min(config.max_interval)
+                };
+            }
+        }
+    }
+}
+
+/// State of a watch stream between two versions.
+struct Watcher {
+    client: Client,
+    address: WatchAddress,
+    trigger: WatchTrigger,
+    last: Option<WatchUpdate>,
+    started: bool,
+}
+
+impl Watcher {
+    /// Wait for the next version of the data, or for an error fetching it.
+    async fn next_update(&mut self) -> Result<WatchUpdate, WatchError> {
+        loop {
+            if self.started {
+                self.trigger.wait().await;
+            }
+            self.started = true;
+
+            let update = match self.client.watch_fetch(&self.address).await {
+                Ok(update) => update,
+                Err(err) => {
+                    self.trigger.checked(false);
+                    return Err(err);
+                }
+            };
+            match update {
+                Some(update)
+                    if self
+                        .last
+                        .as_ref()
+                        .is_none_or(|last| update.supersedes(last)) =>
+                {
+                    self.trigger.checked(true);
+                    self.last = Some(update.clone());
+                    return Ok(update);
+                }
+                _ => self.trigger.checked(false),
+            }
+        }
+    }
+}
+
+impl Client {
+    /// Watch mutable data for changes, see [`Client::watch_with_config`].
+    pub fn watch(
+        &self,
+        address: impl Into<WatchAddress>,
+    ) -> impl Stream<Item = Result<WatchUpdate, WatchError>> + Send + 'static {
+        self.watch_with_config(address, WatchConfig::default())
+    }
+
+    /// Watch a pointer, scratchpad, register or graph entry for changes.
+    ///
+    /// The stream yields the current version first, once the data exists, then each newer
+    /// version found on the network. Versions in between two checks are not seen. Errors
+    /// fetching the data are yielded as they happen, after which the stream keeps watching.
+    /// The stream never ends, drop it to stop watching.
+    pub fn watch_with_config(
+        &self,
+        address: impl Into<WatchAddress>,
+        config: WatchConfig,
+    ) -> impl Stream<Item = Result<WatchUpdate, WatchError>> + Send + 'static {
+        let watcher = Watcher {
+            client: self.clone(),
+            address: address.into(),
+            trigger: WatchTrigger::poll(config),
+            last: None,
+            started: false,
+        };
+        futures::stream::unfold(watcher, |mut watcher| async move {
+            let update = watcher.next_update().await;
+            Some((update, watcher))
+        })
+    }
+
+    /// Fetch the current version of watched data, `None` if it does not exist yet.
+    async fn watch_fetch(&self, address: &WatchAddress) -> Result<Option<WatchUpdate>, WatchError> {
+        let update = match address {
+            WatchAddress::Pointer(addr) => match self.pointer_get(addr).await {
+                Ok(pointer) => WatchUpdate::Pointer(pointer),
+                Err(PointerError::Network(err)) if is_not_found(&err) => return Ok(None),
+                Err(err) => return Err(err.into()),
+            },
+            WatchAddress::Scratchpad(addr) => match self.scratchpad_get(addr).await {
+                Ok(pad) => WatchUpdate::Scratchpad(pad),
+                Err(ScratchpadError::Network(err)) if is_not_found(&err) => return Ok(None),
+                Err(err) => return Err(err.into()),
+            },
+            WatchAddress::Register(addr) => match self.register_head_version(addr).await {
+                Ok((counter, values)) => WatchUpdate::Register { counter, values },
+                Err(RegisterError::PointerError(PointerError::Network(err)))
+                    if is_not_found(&err) =>
+                {
+                    return Ok(None)
+                }
+                Err(err) => return Err(Box::new(err).into()),
+            },
+            WatchAddress::GraphEntry(addr) => match self.graph_entry_get(addr).await {
+                Ok(entry) => WatchUpdate::GraphEntry(vec![entry]),
+                Err(GraphError::Fork(mut entries)) => {
+                    entries.sort();
+                    WatchUpdate::GraphEntry(entries)
+                }
+                Err(GraphError::Network(err)) if is_not_found(&err) => return Ok(None),
+                Err(err) => return Err(err.into()),
+            },
+        };
+        Ok(Some(update))
+    }
+}
+
+fn is_not_found(err: &NetworkError) -> bool {
+    matches!(
+        err,
+        NetworkError::GetRecordError(GetRecordError::RecordNotFound)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::data_types::chunk::ChunkAddress;
+    use crate::client::data_types::pointer::PointerTarget;
+    use crate::{Bytes, SecretKey};
+
+    #[test]
+    fn poll_interval_backs_off_and_resets_on_change() {
+        let config = WatchConfig {
+            min_interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(5),
+        };
+        let mut trigger = WatchTrigger::poll(config);
+        let interval = |trigger: &WatchTrigger| match trigger {
+            WatchTrigger::Poll { interval, .. } => *interval,
+        };
+
+        for expected in [2, 4, 5, 5] {
+            trigger.checked(false);
+            assert_eq!(interval(&trigger), Duration::from_secs(expected));
+        }
+        trigger.checked(true);
+        assert_eq!(interval(&trigger), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn versions_are_compared_as_the_nodes_order_them() {
+        let key = SecretKey::random();
+        let target = PointerTarget::ChunkAddress(ChunkAddress::new(xor_name::XorName::random(
+            &mut rand::thread_rng(),
+        )));
+        let pointer = |counter| WatchUpdate::Pointer(Pointer::new(&key, counter, target.clone()));
+        assert!(pointer(1).supersedes(&pointer(0)));
+        assert!(!pointer(1).supersedes(&pointer(1)));
+        assert!(!pointer(0).supersedes(&pointer(1)));
+
+        // of two pointers with the same counter, only the one the nodes keep is a change
+        let other_target = PointerTarget::ChunkAddress(ChunkAddress::new(
+            xor_name::XorName::random(&mut rand::thread_rng()),
+        ));
+        let competing = WatchUpdate::Pointer(Pointer::new(&key, 1, other_target));
+        assert_ne!(
+            competing.supersedes(&pointer(1)),
+            pointer(1).supersedes(&competing)
+        );
+
+        let scratchpad = |counter, data: &'static str| {
+            WatchUpdate::Scratchpad(Scratchpad::new(&key, 0, &Bytes::from(data), counter))
+        };
+        assert!(scratchpad(1, "one").supersedes(&scratchpad(0, "one")));
+        assert!(!scratchpad(0, "one").supersedes(&scratchpad(1, "one")));
+        let (a, b) = (scratchpad(1, "a"), scratchpad(1, "b"));
+        assert!(!a.supersedes(&a));
+        assert_ne!(a.supersedes(&b), b.supersedes(&a));
+
+        let register = |counter, value| WatchUpdate::Register {
+            counter,
+            values: vec![[value; 32]],
+        };
+        assert!(register(1, 0).supersedes(&register(0, 0)));
+        assert!(register(1, 1).supersedes(&register(1, 0)));
+        assert!(!register(1, 1).supersedes(&register(1, 1)));
+        assert!(!register(0, 1).supersedes(&register(1, 0)));
+    }
+}
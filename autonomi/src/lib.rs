@@ -65,8 +65,10 @@ pub use client::data_types::pointer;
 pub use client::data_types::scratchpad;
 
 // The high-level data types
+pub use client::blob;
 pub use client::data;
 pub use client::files;
+pub use client::map;
 pub use client::register;
 pub use client::vault;
 
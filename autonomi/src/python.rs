@@ -1,35 +1,40 @@
-use std::{path::PathBuf, str::FromStr, sync::Arc};
+use std::{collections::HashMap, path::PathBuf, pin::Pin, str::FromStr, sync::Arc, time::Duration};
 
 use crate::{
     client::{
         chunk::DataMapChunk,
-        payment::PaymentOption,
+        config::Strategy,
+        external_signer,
+        key_derivation::{
+            DerivationIndex, DerivedPubkey, DerivedSecretKey, MainPubkey, MainSecretKey,
+        },
+        payment::{PaymentOption, Receipt},
+        quote::{DataTypes, StoreQuote},
         vault::{UserData, VaultSecretKey},
+        watch::{WatchAddress, WatchConfig, WatchError, WatchUpdate},
+        ClientEvent, UploadSummary,
     },
     files::{Metadata, PrivateArchive, PublicArchive},
     register::{RegisterAddress, RegisterHistory},
-    Client, ClientConfig,
+    Client, ClientConfig, ClientOperatingStrategy,
 };
-use crate::{Bytes, Network, Wallet};
+use crate::{Amount, Bytes, Network, QuoteHash, RewardsAddress, Signature, Wallet};
+use ant_evm::QuotePayment;
+use ant_networking::{ResponseQuorum, RetryStrategy};
 use ant_protocol::storage::{
     Chunk, ChunkAddress, GraphEntry, GraphEntryAddress, Pointer, PointerAddress, PointerTarget,
     Scratchpad, ScratchpadAddress,
 };
 use bls::{PublicKey, SecretKey};
+use futures::{Stream, StreamExt};
 use libp2p::Multiaddr;
-use pyo3::exceptions::{PyConnectionError, PyRuntimeError, PyValueError};
+use pyo3::exceptions::{PyConnectionError, PyRuntimeError, PyStopAsyncIteration, PyValueError};
 use pyo3::prelude::*;
 use pyo3_async_runtimes::tokio::future_into_py;
+use tokio::sync::mpsc;
 use xor_name::XorName;
 
 /// Represents a client for the Autonomi network.
-// Missing methods:
-// - upload_chunks_with_retries
-// - enable_client_events
-// - evm_network
-// - get_store_quotes
-// - pointer_verify
-// - scratchpad_verify
 #[pyclass(name = "Client")]
 pub(crate) struct PyClient {
     inner: Client,
@@ -89,6 +94,23 @@ impl PyClient {
         })
     }
 
+    /// Receive events from the client, such as a summary of each completed upload.
+    ///
+    /// Only the operations started after this call send events.
+    fn enable_client_events(&mut self) -> PyClientEventReceiver {
+        let receiver = self.inner.enable_client_events();
+        PyClientEventReceiver {
+            inner: Arc::new(futures::lock::Mutex::new(receiver)),
+        }
+    }
+
+    /// Get the EVM network the client uses for quotations and payments.
+    fn evm_network(&self) -> PyNetwork {
+        PyNetwork {
+            inner: self.inner.evm_network().clone(),
+        }
+    }
+
     /// Get the cost of storing a chunk on the network
     fn chunk_cost<'a>(&self, py: Python<'a>, addr: PyChunkAddress) -> PyResult<Bound<'a, PyAny>> {
         let client = self.inner.clone();
@@ -136,6 +158,88 @@ impl PyClient {
         })
     }
 
+    /// Upload chunks that were paid for beforehand, retrying failed uploads.
+    ///
+    /// Returns the chunks that could not be uploaded, along with their errors.
+    fn upload_chunks_with_retries<'a>(
+        &self,
+        py: Python<'a>,
+        chunks: Vec<PyChunk>,
+        receipt: &PyReceipt,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+        let receipt = receipt.inner.clone();
+
+        future_into_py(py, async move {
+            let chunks: Vec<Chunk> = chunks.into_iter().map(|chunk| chunk.inner).collect();
+            let failed = client
+                .upload_chunks_with_retries(chunks.iter().collect(), &receipt)
+                .await;
+
+            Ok(failed
+                .into_iter()
+                .map(|(chunk, e)| (PyChunk::from(chunk.clone()), e.to_string()))
+                .collect::<Vec<_>>())
+        })
+    }
+
+    /// Get quotes for storing data of the given type at the given (hex address, size) pairs.
+    ///
+    /// Addresses that were already paid for are not quoted.
+    fn get_store_quotes<'a>(
+        &self,
+        py: Python<'a>,
+        data_type: PyDataTypes,
+        content_addrs: Vec<(String, usize)>,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+        let content_addrs = parse_content_addrs(content_addrs)?;
+
+        future_into_py(py, async move {
+            let quote = client
+                .get_store_quotes(data_type.into(), content_addrs.into_iter())
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to get quotes: {e}")))?;
+
+            Ok(PyStoreQuote { inner: quote })
+        })
+    }
+
+    /// Get quotes for storing data of the given type at the given (hex address, size) pairs, to
+    /// pay for them with an external signer.
+    ///
+    /// Returns the quotes, the payments to execute and the addresses that were already paid for.
+    /// Once paid, the quotes are turned into a receipt with `receipt_from_store_quotes`.
+    fn get_quotes_for_content_addresses<'a>(
+        &self,
+        py: Python<'a>,
+        data_type: PyDataTypes,
+        content_addrs: Vec<(String, usize)>,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+        let content_addrs = parse_content_addrs(content_addrs)?;
+
+        future_into_py(py, async move {
+            let (quotes, payments, free) = client
+                .get_quotes_for_content_addresses(data_type.into(), content_addrs.into_iter())
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to get quotes: {e}")))?;
+
+            Ok((
+                PyStoreQuote {
+                    inner: StoreQuote(quotes),
+                },
+                payments
+                    .into_iter()
+                    .map(|payment| PyQuotePayment { inner: payment })
+                    .collect::<Vec<_>>(),
+                free.into_iter()
+                    .map(crate::client::address::addr_to_str)
+                    .collect::<Vec<_>>(),
+            ))
+        })
+    }
+
     /// Fetches a GraphEntry from the network.
     fn graph_entry_get<'a>(
         &self,
@@ -343,6 +447,143 @@ impl PyClient {
         })
     }
 
+    /// Update an existing scratchpad, only if its counter on the network is still `expected_counter`.
+    ///
+    /// This prevents overwriting a concurrent update, in which case the update fails and the scratchpad needs to be fetched again.
+    fn scratchpad_update_if<'a>(
+        &self,
+        py: Python<'a>,
+        owner: PySecretKey,
+        content_type: u64,
+        data: Vec<u8>,
+        expected_counter: u64,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+
+        future_into_py(py, async move {
+            client
+                .scratchpad_update_if(
+                    &owner.inner,
+                    content_type,
+                    &Bytes::from(data),
+                    expected_counter,
+                )
+                .await
+                .map_err(|e| {
+                    PyRuntimeError::new_err(format!("Failed to update scratchpad: {e}"))
+                })?;
+
+            Ok(())
+        })
+    }
+
+    /// Create a new scratchpad that can be read by each of the `readers` as well as the owner.
+    ///
+    /// Returns the cost and the address of the scratchpad.
+    fn scratchpad_create_shared<'a>(
+        &self,
+        py: Python<'a>,
+        owner: PySecretKey,
+        content_type: u64,
+        initial_data: Vec<u8>,
+        readers: Vec<PyPublicKey>,
+        payment_option: &PyPaymentOption,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+        let payment = payment_option.inner.clone();
+        let readers: Vec<PublicKey> = readers.into_iter().map(|key| key.inner).collect();
+
+        future_into_py(py, async move {
+            let (cost, addr) = client
+                .scratchpad_create_shared(
+                    &owner.inner,
+                    content_type,
+                    &Bytes::from(initial_data),
+                    &readers,
+                    payment,
+                )
+                .await
+                .map_err(|e| {
+                    PyRuntimeError::new_err(format!("Failed to create scratchpad: {e}"))
+                })?;
+
+            Ok((cost.to_string(), addr.to_hex()))
+        })
+    }
+
+    /// Update a shared scratchpad, which can then be read by each of the `readers` as well as the owner.
+    fn scratchpad_update_shared<'a>(
+        &self,
+        py: Python<'a>,
+        owner: PySecretKey,
+        content_type: u64,
+        data: Vec<u8>,
+        readers: Vec<PyPublicKey>,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+        let readers: Vec<PublicKey> = readers.into_iter().map(|key| key.inner).collect();
+
+        future_into_py(py, async move {
+            client
+                .scratchpad_update_shared(&owner.inner, content_type, &Bytes::from(data), &readers)
+                .await
+                .map_err(|e| {
+                    PyRuntimeError::new_err(format!("Failed to update scratchpad: {e}"))
+                })?;
+
+            Ok(())
+        })
+    }
+
+    /// Give more readers access to a scratchpad, keeping its content.
+    fn scratchpad_add_readers<'a>(
+        &self,
+        py: Python<'a>,
+        owner: PySecretKey,
+        readers: Vec<PyPublicKey>,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+        let readers: Vec<PublicKey> = readers.into_iter().map(|key| key.inner).collect();
+
+        future_into_py(py, async move {
+            client
+                .scratchpad_add_readers(&owner.inner, &readers)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to add readers: {e}")))?;
+
+            Ok(())
+        })
+    }
+
+    /// Revoke the access of readers to a scratchpad, keeping its content.
+    ///
+    /// Revoked readers can still read the versions they had access to.
+    fn scratchpad_revoke_readers<'a>(
+        &self,
+        py: Python<'a>,
+        owner: PySecretKey,
+        readers: Vec<PyPublicKey>,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+        let readers: Vec<PublicKey> = readers.into_iter().map(|key| key.inner).collect();
+
+        future_into_py(py, async move {
+            client
+                .scratchpad_revoke_readers(&owner.inner, &readers)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to revoke readers: {e}")))?;
+
+            Ok(())
+        })
+    }
+
+    /// Verify a scratchpad, raising a `ValueError` if it is invalid.
+    #[staticmethod]
+    fn scratchpad_verify(scratchpad: &PyScratchpad) -> PyResult<()> {
+        Client::scratchpad_verify(&scratchpad.inner)
+            .map_err(|e| PyValueError::new_err(format!("Invalid scratchpad: {e}")))
+    }
+
     /// Get the cost of creating a new Scratchpad
     fn scratchpad_cost<'a>(
         &self,
@@ -453,6 +694,48 @@ impl PyClient {
         })
     }
 
+    /// Upload a private file to the network.
+    ///
+    /// Returns the cost and the data map of the file, which is not uploaded.
+    fn file_upload<'a>(
+        &self,
+        py: Python<'a>,
+        path: PathBuf,
+        wallet: PyWallet,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+
+        future_into_py(py, async move {
+            let (cost, data_map) = client
+                .file_upload(path, &wallet.inner)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to upload file: {e}")))?;
+
+            Ok((cost.to_string(), PyDataMapChunk { inner: data_map }))
+        })
+    }
+
+    /// Upload a public file to the network.
+    ///
+    /// Returns the cost and the address of the file.
+    fn file_upload_public<'a>(
+        &self,
+        py: Python<'a>,
+        path: PathBuf,
+        wallet: PyWallet,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+
+        future_into_py(py, async move {
+            let (cost, addr) = client
+                .file_upload_public(path, &wallet.inner)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to upload file: {e}")))?;
+
+            Ok((cost.to_string(), crate::client::address::addr_to_str(addr)))
+        })
+    }
+
     /// Download a private file from network to local file system.
     fn file_download<'a>(
         &self,
@@ -875,88 +1158,416 @@ impl PyClient {
         })
     }
 
-    /// Retrieves and returns a decrypted vault if one exists.
+    /// Create a new register with an initial value of any size.
     ///
-    /// Returns the content type of the bytes in the vault.
-    fn fetch_and_decrypt_vault<'a>(
+    /// Small values are stored in the register itself, larger ones are uploaded as public data first.
+    fn register_create_bytes<'a>(
         &self,
         py: Python<'a>,
-        key: &PyVaultSecretKey,
+        owner: PySecretKey,
+        value: Vec<u8>,
+        payment: PyPaymentOption,
     ) -> PyResult<Bound<'a, PyAny>> {
         let client = self.inner.clone();
-        let key = key.inner.clone();
 
         future_into_py(py, async move {
-            match client.fetch_and_decrypt_vault(&key).await {
-                Ok((data, content_type)) => Ok((data.to_vec(), content_type)),
-                Err(e) => Err(PyRuntimeError::new_err(format!(
-                    "Failed to fetch vault: {e}"
-                ))),
-            }
+            let (cost, addr) = client
+                .register_create_bytes(&owner.inner, Bytes::from(value), payment.inner)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to create register: {e}")))?;
+
+            Ok((cost.to_string(), addr.to_hex()))
         })
     }
 
-    /// Get the user data from the vault
-    fn get_user_data_from_vault<'a>(
+    /// Update the value of a register created with `register_create_bytes`.
+    fn register_update_bytes<'a>(
         &self,
         py: Python<'a>,
-        key: &PyVaultSecretKey,
+        owner: PySecretKey,
+        value: Vec<u8>,
+        payment: PyPaymentOption,
     ) -> PyResult<Bound<'a, PyAny>> {
         let client = self.inner.clone();
-        let key = key.inner.clone();
 
         future_into_py(py, async move {
-            match client.get_user_data_from_vault(&key).await {
-                Ok(user_data) => Ok(PyUserData { inner: user_data }),
-                Err(e) => Err(PyRuntimeError::new_err(format!(
-                    "Failed to get user data from vault: {e}"
-                ))),
-            }
+            let cost = client
+                .register_update_bytes(&owner.inner, Bytes::from(value), payment.inner)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to update register: {e}")))?;
+
+            Ok(cost.to_string())
         })
     }
 
-    /// Put the user data to the vault.
+    /// Get the current value of a register created with `register_create_bytes`.
+    fn register_get_bytes<'a>(&self, py: Python<'a>, addr: String) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+        let addr = RegisterAddress::from_hex(&addr)
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse address: {e}")))?;
+
+        future_into_py(py, async move {
+            let value = client
+                .register_get_bytes(&addr)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to get register: {e}")))?;
+
+            Ok(value.to_vec())
+        })
+    }
+
+    /// Store a value of any size for use in a register, uploading it as public data if it does not fit in a register value.
     ///
-    /// Returns the total cost of the put operation.
-    fn put_user_data_to_vault<'a>(
+    /// Returns the register value referencing the data, and the cost of the upload.
+    fn register_value_from_data<'a>(
         &self,
         py: Python<'a>,
-        key: &PyVaultSecretKey,
-        payment: &PyPaymentOption,
-        user_data: &PyUserData,
+        data: Vec<u8>,
+        payment: PyPaymentOption,
     ) -> PyResult<Bound<'a, PyAny>> {
         let client = self.inner.clone();
-        let key = key.inner.clone();
-        let payment = payment.inner.clone();
-        let user_data = user_data.inner.clone();
 
         future_into_py(py, async move {
-            match client
-                .put_user_data_to_vault(&key, payment, user_data)
+            let (value, cost) = client
+                .register_value_from_data(Bytes::from(data), payment.inner)
                 .await
-            {
-                Ok(cost) => Ok(cost.to_string()),
-                Err(e) => Err(PyRuntimeError::new_err(format!(
-                    "Failed to put user data: {e}"
-                ))),
-            }
+                .map_err(|e| {
+                    PyRuntimeError::new_err(format!("Failed to store register value: {e}"))
+                })?;
+
+            Ok((value, cost.to_string()))
         })
     }
 
-    /// Get a pointer from the network
-    fn pointer_get<'a>(
+    /// Get the data referenced by a register value stored with `register_value_from_data`.
+    fn register_value_to_data<'a>(
         &self,
         py: Python<'a>,
-        addr: PyPointerAddress,
+        value: [u8; 32],
     ) -> PyResult<Bound<'a, PyAny>> {
         let client = self.inner.clone();
 
         future_into_py(py, async move {
-            match client.pointer_get(&addr.inner).await {
-                Ok(pointer) => Ok(PyPointer { inner: pointer }),
-                Err(e) => Err(PyRuntimeError::new_err(format!(
-                    "Failed to get pointer: {e}"
-                ))),
+            let data = client.register_value_to_data(&value).await.map_err(|e| {
+                PyRuntimeError::new_err(format!("Failed to get register value: {e}"))
+            })?;
+
+            Ok(data.to_vec())
+        })
+    }
+
+    /// Get the competing values at the head of a register, sorted.
+    ///
+    /// A register that is not forked has a single value.
+    fn register_fork_heads<'a>(&self, py: Python<'a>, addr: String) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+        let addr = RegisterAddress::from_hex(&addr)
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse address: {e}")))?;
+
+        future_into_py(py, async move {
+            let heads = client.register_fork_heads(&addr).await.map_err(|e| {
+                PyRuntimeError::new_err(format!("Failed to get register heads: {e}"))
+            })?;
+
+            Ok(heads)
+        })
+    }
+
+    /// Resolve a fork of a register by writing `value` after all the competing heads.
+    ///
+    /// The value can be one of the heads listed by `register_fork_heads`, or a merge of them.
+    /// Fails if the register is not forked.
+    fn register_resolve_fork<'a>(
+        &self,
+        py: Python<'a>,
+        owner: PySecretKey,
+        value: [u8; 32],
+        payment: PyPaymentOption,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+
+        future_into_py(py, async move {
+            let cost = client
+                .register_resolve_fork(&owner.inner, |_| value, payment.inner)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to resolve fork: {e}")))?;
+
+            Ok(cost.to_string())
+        })
+    }
+
+    /// Create a new mutable blob holding `data`, which can be of any size.
+    ///
+    /// The blob is stored in a scratchpad at the owner's public key, so the owner key should not be used for another scratchpad.
+    ///
+    /// Returns the cost and the address of the blob.
+    fn blob_create<'a>(
+        &self,
+        py: Python<'a>,
+        owner: PySecretKey,
+        content_type: u64,
+        data: Vec<u8>,
+        payment: PyPaymentOption,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+
+        future_into_py(py, async move {
+            let (cost, addr) = client
+                .blob_create(&owner.inner, content_type, Bytes::from(data), payment.inner)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to create blob: {e}")))?;
+
+            Ok((cost.to_string(), addr.to_hex()))
+        })
+    }
+
+    /// Fetch and decrypt a mutable blob.
+    ///
+    /// Returns the content and its content type.
+    fn blob_get<'a>(&self, py: Python<'a>, owner: PySecretKey) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+
+        future_into_py(py, async move {
+            let (data, content_type) = client
+                .blob_get(&owner.inner)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to get blob: {e}")))?;
+
+            Ok((data.to_vec(), content_type))
+        })
+    }
+
+    /// Replace the content of a mutable blob.
+    ///
+    /// Rewriting existing space is free, while growing the blob beyond the space it ever had requires paying for it.
+    fn blob_update<'a>(
+        &self,
+        py: Python<'a>,
+        owner: PySecretKey,
+        data: Vec<u8>,
+        payment: PyPaymentOption,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+
+        future_into_py(py, async move {
+            let cost = client
+                .blob_update(&owner.inner, Bytes::from(data), payment.inner)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to update blob: {e}")))?;
+
+            Ok(cost.to_string())
+        })
+    }
+
+    /// Get the cost of writing `data` to the blob of the owner, creating the blob if needed.
+    fn blob_cost<'a>(
+        &self,
+        py: Python<'a>,
+        owner: PySecretKey,
+        data: Vec<u8>,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+
+        future_into_py(py, async move {
+            let cost = client
+                .blob_cost(&owner.inner, &Bytes::from(data))
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to get blob cost: {e}")))?;
+
+            Ok(cost.to_string())
+        })
+    }
+
+    /// Create a new map key from a SecretKey and a name.
+    ///
+    /// This derives a new `SecretKey` from the owner's `SecretKey` using the name.
+    /// Note that you will need to keep track of the names you used to create the map key.
+    #[staticmethod]
+    fn map_key_from_name(owner: PySecretKey, name: &str) -> PyResult<PySecretKey> {
+        let key = Client::map_key_from_name(&owner.inner, name);
+        Ok(PySecretKey { inner: key })
+    }
+
+    /// Create a new, empty map.
+    ///
+    /// Returns the cost and the address of the map's root.
+    fn map_create<'a>(
+        &self,
+        py: Python<'a>,
+        owner: PySecretKey,
+        payment: PyPaymentOption,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+
+        future_into_py(py, async move {
+            let (cost, addr) = client
+                .map_create(&owner.inner, payment.inner)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to create map: {e}")))?;
+
+            Ok((cost.to_string(), addr.to_hex()))
+        })
+    }
+
+    /// Set the value of a key in a map, replacing any previous value.
+    ///
+    /// Returns the cost of the insertion.
+    fn map_insert<'a>(
+        &self,
+        py: Python<'a>,
+        owner: PySecretKey,
+        key: String,
+        value: Vec<u8>,
+        payment: PyPaymentOption,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+
+        future_into_py(py, async move {
+            let cost = client
+                .map_insert(&owner.inner, &key, Bytes::from(value), payment.inner)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to insert in map: {e}")))?;
+
+            Ok(cost.to_string())
+        })
+    }
+
+    /// Remove a key from a map.
+    fn map_remove<'a>(
+        &self,
+        py: Python<'a>,
+        owner: PySecretKey,
+        key: String,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+
+        future_into_py(py, async move {
+            client
+                .map_remove(&owner.inner, &key)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to remove from map: {e}")))?;
+
+            Ok(())
+        })
+    }
+
+    /// Get the value of a key in a map, or `None` if it is not set.
+    fn map_get<'a>(
+        &self,
+        py: Python<'a>,
+        owner: PySecretKey,
+        key: String,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+
+        future_into_py(py, async move {
+            let value = client
+                .map_get(&owner.inner, &key)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to get from map: {e}")))?;
+
+            Ok(value.map(|value| value.to_vec()))
+        })
+    }
+
+    /// Get all the keys set in a map along with their values, in the order of the keys.
+    fn map_iter<'a>(&self, py: Python<'a>, owner: PySecretKey) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+
+        future_into_py(py, async move {
+            let entries = client
+                .map_iter(&owner.inner)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to iterate map: {e}")))?;
+
+            Ok(entries
+                .map(|(key, value)| (key, value.to_vec()))
+                .collect::<Vec<_>>())
+        })
+    }
+
+    /// Retrieves and returns a decrypted vault if one exists.
+    ///
+    /// Returns the content type of the bytes in the vault.
+    fn fetch_and_decrypt_vault<'a>(
+        &self,
+        py: Python<'a>,
+        key: &PyVaultSecretKey,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+        let key = key.inner.clone();
+
+        future_into_py(py, async move {
+            match client.fetch_and_decrypt_vault(&key).await {
+                Ok((data, content_type)) => Ok((data.to_vec(), content_type)),
+                Err(e) => Err(PyRuntimeError::new_err(format!(
+                    "Failed to fetch vault: {e}"
+                ))),
+            }
+        })
+    }
+
+    /// Get the user data from the vault
+    fn get_user_data_from_vault<'a>(
+        &self,
+        py: Python<'a>,
+        key: &PyVaultSecretKey,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+        let key = key.inner.clone();
+
+        future_into_py(py, async move {
+            match client.get_user_data_from_vault(&key).await {
+                Ok(user_data) => Ok(PyUserData { inner: user_data }),
+                Err(e) => Err(PyRuntimeError::new_err(format!(
+                    "Failed to get user data from vault: {e}"
+                ))),
+            }
+        })
+    }
+
+    /// Put the user data to the vault.
+    ///
+    /// Returns the total cost of the put operation.
+    fn put_user_data_to_vault<'a>(
+        &self,
+        py: Python<'a>,
+        key: &PyVaultSecretKey,
+        payment: &PyPaymentOption,
+        user_data: &PyUserData,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+        let key = key.inner.clone();
+        let payment = payment.inner.clone();
+        let user_data = user_data.inner.clone();
+
+        future_into_py(py, async move {
+            match client
+                .put_user_data_to_vault(&key, payment, user_data)
+                .await
+            {
+                Ok(cost) => Ok(cost.to_string()),
+                Err(e) => Err(PyRuntimeError::new_err(format!(
+                    "Failed to put user data: {e}"
+                ))),
+            }
+        })
+    }
+
+    /// Get a pointer from the network
+    fn pointer_get<'a>(
+        &self,
+        py: Python<'a>,
+        addr: PyPointerAddress,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+
+        future_into_py(py, async move {
+            match client.pointer_get(&addr.inner).await {
+                Ok(pointer) => Ok(PyPointer { inner: pointer }),
+                Err(e) => Err(PyRuntimeError::new_err(format!(
+                    "Failed to get pointer: {e}"
+                ))),
             }
         })
     }
@@ -1045,8 +1656,37 @@ impl PyClient {
         })
     }
 
-    /// Calculate the cost of storing a pointer
-    fn pointer_cost<'a>(&self, py: Python<'a>, key: &PyPublicKey) -> PyResult<Bound<'a, PyAny>> {
+    /// Update an existing pointer to point to a new target, only if its counter on the network is still `expected_counter`.
+    ///
+    /// This prevents overwriting a concurrent update, in which case the update fails and the pointer needs to be fetched again.
+    fn pointer_update_if<'a>(
+        &self,
+        py: Python<'a>,
+        owner: PySecretKey,
+        target: PyPointerTarget,
+        expected_counter: u32,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.inner.clone();
+
+        future_into_py(py, async move {
+            client
+                .pointer_update_if(&owner.inner, target.inner, expected_counter)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to update pointer: {e}")))?;
+
+            Ok(())
+        })
+    }
+
+    /// Verify a pointer, raising a `ValueError` if it is invalid.
+    #[staticmethod]
+    fn pointer_verify(pointer: &PyPointer) -> PyResult<()> {
+        Client::pointer_verify(&pointer.inner)
+            .map_err(|e| PyValueError::new_err(format!("Invalid pointer: {e}")))
+    }
+
+    /// Calculate the cost of storing a pointer
+    fn pointer_cost<'a>(&self, py: Python<'a>, key: &PyPublicKey) -> PyResult<Bound<'a, PyAny>> {
         let client = self.inner.clone();
         let key = key.inner;
 
@@ -1059,6 +1699,23 @@ impl PyClient {
             }
         })
     }
+
+    /// Watch a pointer, scratchpad, register or graph entry for changes.
+    ///
+    /// The returned watcher is an async iterator yielding the current version first, once the data exists,
+    /// then each newer version found on the network. Errors fetching the data are raised as they happen,
+    /// after which the watcher can be iterated again to keep watching. It never ends, drop it to stop watching.
+    fn watch(&self, address: &PyWatchAddress) -> PyWatcher {
+        PyWatcher::new(self.inner.watch(address.inner.clone()))
+    }
+
+    /// Same as `watch`, with a custom polling interval.
+    fn watch_with_config(&self, address: &PyWatchAddress, config: &PyWatchConfig) -> PyWatcher {
+        PyWatcher::new(
+            self.inner
+                .watch_with_config(address.inner.clone(), config.inner),
+        )
+    }
 }
 
 /// A network address where a pointer is stored.
@@ -1135,6 +1792,20 @@ impl PyPointer {
         }
     }
 
+    /// Returns the counter of the pointer, which increases with each update.
+    #[getter]
+    fn counter(&self) -> u32 {
+        self.inner.counter()
+    }
+
+    /// Returns the public key of the owner of the pointer.
+    #[getter]
+    fn owner(&self) -> PyPublicKey {
+        PyPublicKey {
+            inner: *self.inner.owner(),
+        }
+    }
+
     fn __str__(&self) -> PyResult<String> {
         Ok(self.hex())
     }
@@ -1314,530 +1985,1910 @@ impl PyWallet {
             }
         })
     }
+
+    /// Returns the network the wallet pays on.
+    fn network(&self) -> PyNetwork {
+        PyNetwork {
+            inner: self.inner.network().clone(),
+        }
+    }
+
+    /// Pays for the given quote payments, in batched transactions.
+    ///
+    /// Returns the hash of the transaction paying for each quote, by quote hash.
+    fn pay_for_quotes<'a>(
+        &self,
+        py: Python<'a>,
+        payments: Vec<PyQuotePayment>,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let wallet = self.inner.clone();
+        future_into_py(py, async move {
+            let tx_hashes = wallet
+                .pay_for_quotes(payments.into_iter().map(|payment| payment.inner))
+                .await
+                .map_err(|e| {
+                    PyRuntimeError::new_err(format!("Failed to pay for quotes: {}", e.0))
+                })?;
+
+            Ok(tx_hashes
+                .into_iter()
+                .map(|(quote_hash, tx_hash)| (quote_hash.to_string(), tx_hash.to_string()))
+                .collect::<HashMap<_, _>>())
+        })
+    }
+}
+
+/// Options for making payments on the network.
+#[pyclass(name = "PaymentOption")]
+#[derive(Clone)]
+pub struct PyPaymentOption {
+    pub(crate) inner: PaymentOption,
+}
+
+#[pymethods]
+impl PyPaymentOption {
+    /// Creates a payment option using the provided wallet.
+    #[staticmethod]
+    fn wallet(wallet: &PyWallet) -> Self {
+        Self {
+            inner: PaymentOption::Wallet(wallet.inner.clone()),
+        }
+    }
+
+    /// Creates a payment option using the receipt of data that was already paid for.
+    #[staticmethod]
+    fn receipt(receipt: &PyReceipt) -> Self {
+        Self {
+            inner: PaymentOption::Receipt(receipt.inner.clone()),
+        }
+    }
+}
+
+/// A cryptographic secret key used for signing operations.
+/// Can be used to derive a public key and perform cryptographic operations.
+#[pyclass(name = "SecretKey")]
+#[derive(Debug, Clone)]
+pub struct PySecretKey {
+    inner: SecretKey,
+}
+
+#[pymethods]
+impl PySecretKey {
+    /// Creates a new random secret key.
+    #[new]
+    fn new() -> PyResult<Self> {
+        Ok(Self {
+            inner: SecretKey::random(),
+        })
+    }
+
+    /// Creates a secret key from a hex string representation.
+    #[staticmethod]
+    fn from_hex(hex_str: &str) -> PyResult<Self> {
+        SecretKey::from_hex(hex_str)
+            .map(|key| Self { inner: key })
+            .map_err(|e| PyValueError::new_err(format!("Invalid hex key: {e}")))
+    }
+
+    /// Derives and returns the corresponding public key.
+    fn public_key(&self) -> PyPublicKey {
+        PyPublicKey {
+            inner: self.inner.public_key(),
+        }
+    }
+
+    /// Returns the hex string representation of the key.
+    fn to_hex(&self) -> String {
+        self.inner.to_hex()
+    }
+}
+
+/// A cryptographic public key derived from a secret key.
+#[pyclass(name = "PublicKey")]
+#[derive(Debug, Clone)]
+pub struct PyPublicKey {
+    inner: PublicKey,
+}
+
+#[pymethods]
+impl PyPublicKey {
+    /// Creates a new random public key by generating a random secret key.
+    #[new]
+    fn new() -> PyResult<Self> {
+        let secret = SecretKey::random();
+        Ok(Self {
+            inner: secret.public_key(),
+        })
+    }
+
+    /// Creates a public key from a hex string representation.
+    #[staticmethod]
+    fn from_hex(hex_str: &str) -> PyResult<Self> {
+        PublicKey::from_hex(hex_str)
+            .map(|key| Self { inner: key })
+            .map_err(|e| PyValueError::new_err(format!("Invalid hex key: {e}")))
+    }
+
+    fn to_hex(&self) -> String {
+        self.inner.to_hex()
+    }
+}
+
+/// A secret key used to encrypt and decrypt vault data.
+#[pyclass(name = "VaultSecretKey")]
+#[derive(Debug, Clone)]
+pub struct PyVaultSecretKey {
+    inner: VaultSecretKey,
+}
+
+#[pymethods]
+impl PyVaultSecretKey {
+    /// Creates a new random vault secret key.
+    #[new]
+    fn new() -> PyResult<Self> {
+        Ok(Self {
+            inner: VaultSecretKey::random(),
+        })
+    }
+
+    #[staticmethod]
+    fn from_hex(hex_str: &str) -> PyResult<Self> {
+        VaultSecretKey::from_hex(hex_str)
+            .map(|key| Self { inner: key })
+            .map_err(|e| PyValueError::new_err(format!("Invalid hex key: {e}")))
+    }
+
+    fn to_hex(&self) -> String {
+        self.inner.to_hex()
+    }
+}
+
+/// UserData is stored in Vaults and contains most of a user's private data:
+/// It allows users to keep track of only the key to their User Data Vault
+/// while having the rest kept on the Network encrypted in a Vault for them
+/// Using User Data Vault is optional, one can decide to keep all their data locally instead.
+#[pyclass(name = "UserData")]
+#[derive(Debug, Clone)]
+pub struct PyUserData {
+    inner: UserData,
+}
+
+#[pymethods]
+impl PyUserData {
+    /// Creates a new empty UserData instance.
+    #[new]
+    fn new() -> Self {
+        Self {
+            inner: UserData::new(),
+        }
+    }
+
+    /// Returns a list of public file archives as (address, name) pairs.
+    fn file_archives(&self) -> Vec<(String, String)> {
+        self.inner
+            .file_archives
+            .iter()
+            .map(|(addr, name)| (hex::encode(addr), name.clone()))
+            .collect()
+    }
+
+    /// Returns a list of private file archives as (data_map, name) pairs.
+    fn private_file_archives(&self) -> Vec<(String, String)> {
+        self.inner
+            .private_file_archives
+            .iter()
+            .map(|(addr, name)| (addr.to_hex(), name.clone()))
+            .collect()
+    }
+
+    /// Returns a list of registers as (address, name) pairs.
+    fn register_addresses(&self) -> Vec<(String, String)> {
+        self.inner
+            .register_addresses
+            .iter()
+            .map(|(addr, name)| (addr.to_hex(), name.clone()))
+            .collect()
+    }
+
+    /// Add a public file archive, with an optional name.
+    ///
+    /// Returns the previous name of the archive, if it was already there.
+    #[pyo3(signature = (archive, name=None))]
+    fn add_file_archive(
+        &mut self,
+        #[pyo3(from_py_with = "str_to_addr")] archive: XorName,
+        name: Option<String>,
+    ) -> Option<String> {
+        match name {
+            Some(name) => self.inner.add_file_archive_with_name(archive, name),
+            None => self.inner.add_file_archive(archive),
+        }
+    }
+
+    /// Add a private file archive, with an optional name.
+    ///
+    /// Returns the previous name of the archive, if it was already there.
+    #[pyo3(signature = (archive, name=None))]
+    fn add_private_file_archive(
+        &mut self,
+        archive: &PyDataMapChunk,
+        name: Option<String>,
+    ) -> Option<String> {
+        let archive = archive.inner.clone();
+        match name {
+            Some(name) => self.inner.add_private_file_archive_with_name(archive, name),
+            None => self.inner.add_private_file_archive(archive),
+        }
+    }
+
+    /// Add a register with a name.
+    ///
+    /// Returns the previous name of the register, if it was already there.
+    fn add_register(&mut self, register: &str, name: String) -> PyResult<Option<String>> {
+        let register = RegisterAddress::from_hex(register)
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse address: {e}")))?;
+        Ok(self.inner.add_register(register, name))
+    }
+
+    /// Remove a public file archive.
+    ///
+    /// Returns the name of the archive, if it was there.
+    fn remove_file_archive(
+        &mut self,
+        #[pyo3(from_py_with = "str_to_addr")] archive: XorName,
+    ) -> Option<String> {
+        self.inner.remove_file_archive(archive)
+    }
+
+    /// Remove a private file archive.
+    ///
+    /// Returns the name of the archive, if it was there.
+    fn remove_private_file_archive(&mut self, archive: &PyDataMapChunk) -> Option<String> {
+        self.inner
+            .remove_private_file_archive(archive.inner.clone())
+    }
+}
+
+/// A map with encrypted data pieces on the network. Used to locate and reconstruct private data.
+#[pyclass(name = "DataMapChunk")]
+#[derive(Debug, Clone)]
+pub struct PyDataMapChunk {
+    inner: DataMapChunk,
+}
+
+#[pymethods]
+impl PyDataMapChunk {
+    /// Creates a DataMapChunk from a hex string representation.
+    #[staticmethod]
+    fn from_hex(hex: &str) -> PyResult<Self> {
+        DataMapChunk::from_hex(hex)
+            .map(|access| Self { inner: access })
+            .map_err(|e| PyValueError::new_err(format!("Invalid hex: {e}")))
+    }
+
+    /// Returns the hex string representation of this DataMapChunk.
+    fn to_hex(&self) -> String {
+        self.inner.to_hex()
+    }
+
+    /// Returns the private address of this DataMapChunk.
+    ///
+    /// Note that this is not a network address, it is only used for refering to private data client side.
+    fn address(&self) -> String {
+        self.inner.address().to_string()
+    }
+}
+
+#[pyfunction]
+fn encrypt(data: Vec<u8>) -> PyResult<(Vec<u8>, Vec<Vec<u8>>)> {
+    let (data_map, chunks) = self_encryption::encrypt(Bytes::from(data))
+        .map_err(|e| PyRuntimeError::new_err(format!("Encryption failed: {e}")))?;
+
+    let data_map_bytes = rmp_serde::to_vec(&data_map)
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to serialize data map: {e}")))?;
+
+    let chunks_bytes: Vec<Vec<u8>> = chunks
+        .into_iter()
+        .map(|chunk| chunk.content.to_vec())
+        .collect();
+
+    Ok((data_map_bytes, chunks_bytes))
+}
+
+#[pyclass(name = "Network")]
+#[derive(Debug, Clone)]
+pub struct PyNetwork {
+    inner: Network,
+}
+
+#[pymethods]
+impl PyNetwork {
+    /// Creates a new network configuration.
+    ///
+    /// If `local` is true, configures for local network connections.
+    #[new]
+    fn new(local: bool) -> PyResult<Self> {
+        let inner = Network::new(local).map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
+        Ok(Self { inner })
+    }
+}
+
+/// Metadata for files in an archive, containing creation time, modification time, and size.
+#[pyclass(name = "Metadata")]
+#[derive(Debug, Clone)]
+pub struct PyMetadata {
+    inner: Metadata,
+}
+
+#[pymethods]
+impl PyMetadata {
+    /// Create new metadata with the given file size
+    #[new]
+    fn new(size: u64) -> Self {
+        Self {
+            inner: Metadata::new_with_size(size),
+        }
+    }
+
+    /// Get the creation time as Unix timestamp in seconds
+    #[getter]
+    fn get_created(&self) -> u64 {
+        self.inner.created
+    }
+
+    /// Set the creation time as Unix timestamp in seconds
+    #[setter]
+    fn set_created(&mut self, value: u64) {
+        self.inner.created = value;
+    }
+
+    /// Get the modification time as Unix timestamp in seconds
+    #[getter]
+    fn get_modified(&self) -> u64 {
+        self.inner.modified
+    }
+
+    /// Set the modification time as Unix timestamp in seconds
+    #[setter]
+    fn set_modified(&mut self, value: u64) {
+        self.inner.modified = value;
+    }
+
+    /// Get the file size in bytes
+    #[getter]
+    fn get_size(&self) -> u64 {
+        self.inner.size
+    }
+
+    /// Set the file size in bytes
+    #[setter]
+    fn set_size(&mut self, value: u64) {
+        self.inner.size = value;
+    }
+}
+
+/// A public archive containing files that can be accessed by anyone on the network.
+#[pyclass(name = "PublicArchive")]
+#[derive(Debug, Clone)]
+pub struct PyPublicArchive {
+    inner: PublicArchive,
+}
+
+#[pymethods]
+impl PyPublicArchive {
+    /// Create a new empty archive
+    #[new]
+    fn new() -> Self {
+        Self {
+            inner: PublicArchive::new(),
+        }
+    }
+
+    /// Rename a file in the archive.
+    ///
+    /// Returns None on success, or error message on failure
+    fn rename_file(&mut self, old_path: PathBuf, new_path: PathBuf) -> PyResult<()> {
+        self.inner
+            .rename_file(&old_path, &new_path)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to rename file: {e}")))
+    }
+
+    /// Add a file to the archive
+    fn add_file(
+        &mut self,
+        path: PathBuf,
+        #[pyo3(from_py_with = "str_to_addr")] addr: XorName,
+        metadata: &PyMetadata,
+    ) {
+        self.inner.add_file(path, addr, metadata.inner.clone());
+    }
+
+    /// List all files in the archive.
+    ///
+    /// Returns a list of (path, metadata) tuples
+    fn files(&self) -> Vec<(PathBuf, PyMetadata)> {
+        self.inner
+            .files()
+            .into_iter()
+            .map(|(path, meta)| (path, PyMetadata { inner: meta }))
+            .collect()
+    }
+
+    /// List all data addresses of files in the archive
+    fn addresses(&self) -> Vec<String> {
+        self.inner
+            .addresses()
+            .into_iter()
+            .map(crate::client::address::addr_to_str)
+            .collect()
+    }
+}
+
+/// A public archive containing files that can be accessed by anyone on the network.
+#[pyclass(name = "PrivateArchive")]
+#[derive(Debug, Clone)]
+pub struct PyPrivateArchive {
+    inner: PrivateArchive,
+}
+
+#[pymethods]
+impl PyPrivateArchive {
+    /// Create a new empty archive
+    #[new]
+    fn new() -> Self {
+        Self {
+            inner: PrivateArchive::new(),
+        }
+    }
+
+    /// Rename a file in the archive.
+    ///
+    /// Returns None on success, or error message on failure
+    fn rename_file(&mut self, old_path: PathBuf, new_path: PathBuf) -> PyResult<()> {
+        self.inner
+            .rename_file(&old_path, &new_path)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to rename file: {e}")))
+    }
+
+    /// Add a file to a local archive. Note that this does not upload the archive to the network.
+    fn add_file(&mut self, path: PathBuf, data_map: &PyDataMapChunk, metadata: &PyMetadata) {
+        self.inner
+            .add_file(path, data_map.inner.clone(), metadata.inner.clone());
+    }
+
+    /// List all files in the archive.
+    fn files(&self) -> Vec<(PathBuf, PyMetadata)> {
+        self.inner
+            .files()
+            .into_iter()
+            .map(|(path, meta)| (path, PyMetadata { inner: meta }))
+            .collect()
+    }
+
+    /// List all data maps of files in the archive
+    fn data_maps(&self) -> Vec<PyDataMapChunk> {
+        self.inner
+            .data_maps()
+            .into_iter()
+            .map(|data_map| PyDataMapChunk { inner: data_map })
+            .collect()
+    }
+}
+
+/// A generic GraphEntry on the Network.
+///
+/// Graph entries are stored at the owner's public key. Note that there can only be one graph entry per owner.
+/// Graph entries can be linked to other graph entries as parents or descendants.
+/// Applications are free to define the meaning of these links, those are not enforced by the protocol.
+/// The protocol only ensures that the graph entry is immutable once uploaded and that the signature is valid and matches the owner.
+///
+/// For convenience it is advised to make use of BLS key derivation to create multiple graph entries from a single key.
+#[pyclass(name = "GraphEntry")]
+#[derive(Debug, Clone)]
+pub struct PyGraphEntry {
+    inner: GraphEntry,
+}
+
+#[pymethods]
+impl PyGraphEntry {
+    /// Create a new graph entry, signing it with the provided secret key.
+    #[new]
+    fn new(
+        owner: &PySecretKey,
+        parents: Vec<PyPublicKey>,
+        content: [u8; 32],
+        descendants: Vec<(PyPublicKey, [u8; 32])>,
+    ) -> Self {
+        Self {
+            inner: GraphEntry::new(
+                &owner.inner,
+                parents.into_iter().map(|key| key.inner).collect(),
+                content,
+                descendants
+                    .into_iter()
+                    .map(|(key, content)| (key.inner, content))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Returns the hex address of the graph entry, derived from its owner.
+    fn address(&self) -> String {
+        self.inner.address().to_hex()
+    }
+
+    /// Returns the public key of the owner of the graph entry.
+    #[getter]
+    fn owner(&self) -> PyPublicKey {
+        PyPublicKey {
+            inner: self.inner.owner,
+        }
+    }
+
+    /// Returns the public keys of the parents of the graph entry.
+    #[getter]
+    fn parents(&self) -> Vec<PyPublicKey> {
+        self.inner
+            .parents
+            .iter()
+            .map(|key| PyPublicKey { inner: *key })
+            .collect()
+    }
+
+    /// Returns the content of the graph entry.
+    #[getter]
+    fn content(&self) -> [u8; 32] {
+        self.inner.content
+    }
+
+    /// Returns the descendants of the graph entry as (public key, content) pairs.
+    #[getter]
+    fn descendants(&self) -> Vec<(PyPublicKey, [u8; 32])> {
+        self.inner
+            .descendants
+            .iter()
+            .map(|(key, content)| (PyPublicKey { inner: *key }, *content))
+            .collect()
+    }
+
+    /// Verify the signature of the graph entry.
+    fn verify_signature(&self) -> bool {
+        self.inner.verify_signature()
+    }
+}
+
+/// Scratchpad, a mutable space for encrypted data on the Network
+#[pyclass(name = "Scratchpad")]
+#[derive(Debug, Clone)]
+pub struct PyScratchpad {
+    inner: Scratchpad,
+}
+
+#[pymethods]
+impl PyScratchpad {
+    /// Create a new scratchpad, encrypting the data with the owner key and signing it.
+    #[new]
+    fn new(owner: &PySecretKey, data_encoding: u64, data: Vec<u8>, counter: u64) -> Self {
+        Self {
+            inner: Scratchpad::new(&owner.inner, data_encoding, &Bytes::from(data), counter),
+        }
+    }
+
+    /// Create a new scratchpad that can be decrypted by each of the `readers` as well as the owner.
+    #[staticmethod]
+    fn new_shared(
+        owner: &PySecretKey,
+        data_encoding: u64,
+        data: Vec<u8>,
+        counter: u64,
+        readers: Vec<PyPublicKey>,
+    ) -> Self {
+        let readers: Vec<PublicKey> = readers.into_iter().map(|key| key.inner).collect();
+        Self {
+            inner: Scratchpad::new_shared(
+                &owner.inner,
+                data_encoding,
+                &Bytes::from(data),
+                counter,
+                &readers,
+            ),
+        }
+    }
+
+    /// Returns the hex address of the scratchpad, which is the hex of its owner's public key.
+    fn address(&self) -> String {
+        self.inner.address().to_hex()
+    }
+
+    /// Returns the public key of the owner of the scratchpad.
+    #[getter]
+    fn owner(&self) -> PyPublicKey {
+        PyPublicKey {
+            inner: *self.inner.owner(),
+        }
+    }
+
+    /// Returns the counter of the scratchpad, which increases with each update.
+    #[getter]
+    fn counter(&self) -> u64 {
+        self.inner.counter()
+    }
+
+    /// Returns the content type of the data.
+    #[getter]
+    fn data_encoding(&self) -> u64 {
+        self.inner.data_encoding()
+    }
+
+    /// Returns the encrypted data.
+    #[getter]
+    fn encrypted_data(&self) -> Vec<u8> {
+        self.inner.encrypted_data().to_vec()
+    }
+
+    /// Returns the readers of a shared scratchpad, or `None` if it is not shared.
+    fn shared_readers(&self) -> Option<Vec<PyPublicKey>> {
+        self.inner.shared_readers().map(|readers| {
+            readers
+                .into_iter()
+                .map(|key| PyPublicKey { inner: key })
+                .collect()
+        })
+    }
+
+    /// Decrypt the data with the key of the owner, or of one of the readers of a shared scratchpad.
+    fn decrypt_data(&self, key: &PySecretKey) -> PyResult<Vec<u8>> {
+        self.inner
+            .decrypt_data(&key.inner)
+            .map(|data| data.to_vec())
+            .map_err(|e| PyValueError::new_err(format!("Failed to decrypt scratchpad: {e}")))
+    }
+
+    /// Verify the signature of the scratchpad.
+    fn verify_signature(&self) -> bool {
+        self.inner.verify_signature()
+    }
+}
+
+/// A handle to the register history
+///
+/// It is an async iterator over the register values, from the first to the latest entry.
+#[pyclass(name = "RegisterHistory")]
+#[derive(Clone)]
+pub struct PyRegisterHistory {
+    inner: Arc<futures::lock::Mutex<RegisterHistory>>,
+}
+
+impl PyRegisterHistory {
+    fn new(history: RegisterHistory) -> Self {
+        Self {
+            inner: Arc::new(futures::lock::Mutex::new(history)),
+        }
+    }
+}
+
+#[pymethods]
+impl PyRegisterHistory {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
+        let arc = Arc::clone(&self.inner);
+
+        future_into_py(py, async move {
+            let mut register_history = arc.lock().await;
+            let value = register_history
+                .next()
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("history `next` failed: {e}")))?;
+
+            value.ok_or_else(|| PyStopAsyncIteration::new_err(()))
+        })
+    }
+
+    /// Get the next value, or `None` once the latest entry was reached.
+    fn next<'a>(&'a mut self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
+        let arc = Arc::clone(&self.inner);
+
+        future_into_py(py, async move {
+            let mut register_history = arc.lock().await;
+            let value = register_history
+                .next()
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("history `next` failed: {e}")))?;
+
+            Ok(value)
+        })
+    }
+
+    /// Get all the remaining values.
+    fn collect<'a>(&'a mut self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
+        let arc = Arc::clone(&self.inner);
+
+        future_into_py(py, async move {
+            let mut register_history = arc.lock().await;
+            let values = register_history
+                .collect()
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("history `collect` failed: {e}")))?;
+
+            Ok(values)
+        })
+    }
+
+    /// Same as `next`, for registers created with `register_create_bytes`.
+    fn next_bytes<'a>(&'a mut self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
+        let arc = Arc::clone(&self.inner);
+
+        future_into_py(py, async move {
+            let mut register_history = arc.lock().await;
+            let value = register_history.next_bytes().await.map_err(|e| {
+                PyRuntimeError::new_err(format!("history `next_bytes` failed: {e}"))
+            })?;
+
+            Ok(value.map(|value| value.to_vec()))
+        })
+    }
+
+    /// Same as `collect`, for registers created with `register_create_bytes`.
+    fn collect_bytes<'a>(&'a mut self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
+        let arc = Arc::clone(&self.inner);
+
+        future_into_py(py, async move {
+            let mut register_history = arc.lock().await;
+            let values = register_history.collect_bytes().await.map_err(|e| {
+                PyRuntimeError::new_err(format!("history `collect_bytes` failed: {e}"))
+            })?;
+
+            Ok(values
+                .into_iter()
+                .map(|value| value.to_vec())
+                .collect::<Vec<_>>())
+        })
+    }
+}
+
+/// Configuration for the `Client` which can be provided through: `init_with_config`.
+#[pyclass(name = "ClientConfig")]
+#[derive(Debug, Clone)]
+pub struct PyClientConfig {
+    inner: ClientConfig,
+}
+
+#[pymethods]
+impl PyClientConfig {
+    #[staticmethod]
+    fn new() -> Self {
+        Self {
+            inner: ClientConfig::default(),
+        }
+    }
+
+    /// Whether we're expected to connect to a local network.
+    #[getter]
+    fn get_local(&self) -> bool {
+        self.inner.local
+    }
+
+    /// Whether we're expected to connect to a local network.
+    #[setter]
+    fn set_local(&mut self, value: bool) {
+        self.inner.local = value;
+    }
+
+    /// List of peers to connect to.
+    ///
+    /// If not provided, the client will use the default bootstrap peers.
+    #[getter]
+    fn get_peers(&self) -> Option<Vec<String>> {
+        self.inner
+            .peers
+            .as_ref()
+            .map(|peers| peers.iter().map(|p| p.to_string()).collect())
+    }
+
+    /// List of peers to connect to. If given empty list, the client will use the default bootstrap peers.
+    #[setter]
+    fn set_peers(&mut self, peers: Vec<String>) -> PyResult<()> {
+        if peers.is_empty() {
+            self.inner.peers = None;
+            return Ok(());
+        }
+
+        let peers: Vec<Multiaddr> = peers
+            .iter()
+            .map(|p| Multiaddr::from_str(p))
+            .collect::<Result<_, _>>()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse peers: {e}")))?;
+
+        self.inner.peers = Some(peers);
+        Ok(())
+    }
+
+    /// EVM network to use for quotations and payments.
+    #[getter]
+    fn get_network(&self) -> PyNetwork {
+        PyNetwork {
+            inner: self.inner.evm_network.clone(),
+        }
+    }
+
+    /// EVM network to use for quotations and payments.
+    #[setter]
+    fn set_network(&mut self, network: PyNetwork) {
+        self.inner.evm_network = network.inner;
+    }
+
+    /// Strategy for data operations by the client.
+    #[getter]
+    fn get_strategy(&self) -> PyClientOperatingStrategy {
+        PyClientOperatingStrategy {
+            inner: self.inner.strategy.clone(),
+        }
+    }
+
+    /// Strategy for data operations by the client.
+    #[setter]
+    fn set_strategy(&mut self, strategy: PyClientOperatingStrategy) {
+        self.inner.strategy = strategy.inner;
+    }
+}
+
+/// Strategy configuration for data operations by the client, per type of data.
+#[pyclass(name = "ClientOperatingStrategy")]
+#[derive(Debug, Clone)]
+pub struct PyClientOperatingStrategy {
+    inner: ClientOperatingStrategy,
+}
+
+#[pymethods]
+impl PyClientOperatingStrategy {
+    /// Create the default strategy.
+    #[new]
+    fn new() -> Self {
+        Self {
+            inner: ClientOperatingStrategy::new(),
+        }
+    }
+
+    /// Strategy for chunks.
+    #[getter]
+    fn get_chunks(&self) -> PyStrategy {
+        PyStrategy {
+            inner: self.inner.chunks.clone(),
+        }
+    }
+
+    /// Strategy for chunks.
+    #[setter]
+    fn set_chunks(&mut self, strategy: PyStrategy) {
+        self.inner.chunks = strategy.inner;
+    }
+
+    /// Strategy for graph entries.
+    #[getter]
+    fn get_graph_entry(&self) -> PyStrategy {
+        PyStrategy {
+            inner: self.inner.graph_entry.clone(),
+        }
+    }
+
+    /// Strategy for graph entries.
+    #[setter]
+    fn set_graph_entry(&mut self, strategy: PyStrategy) {
+        self.inner.graph_entry = strategy.inner;
+    }
+
+    /// Strategy for pointers.
+    #[getter]
+    fn get_pointer(&self) -> PyStrategy {
+        PyStrategy {
+            inner: self.inner.pointer.clone(),
+        }
+    }
+
+    /// Strategy for pointers.
+    #[setter]
+    fn set_pointer(&mut self, strategy: PyStrategy) {
+        self.inner.pointer = strategy.inner;
+    }
+
+    /// Strategy for scratchpads.
+    #[getter]
+    fn get_scratchpad(&self) -> PyStrategy {
+        PyStrategy {
+            inner: self.inner.scratchpad.clone(),
+        }
+    }
+
+    /// Strategy for scratchpads.
+    #[setter]
+    fn set_scratchpad(&mut self, strategy: PyStrategy) {
+        self.inner.scratchpad = strategy.inner;
+    }
+}
+
+/// The strategy to adopt when putting and getting a type of data from the network.
+///
+/// Quorums are the number of responses to wait for: `"one"`, `"majority"`, `"all"` or a number.
+/// Retry strategies are `"none"`, `"quick"`, `"balanced"`, `"persistent"` or a number of attempts.
+#[pyclass(name = "Strategy")]
+#[derive(Debug, Clone)]
+pub struct PyStrategy {
+    inner: Strategy,
+}
+
+#[pymethods]
+impl PyStrategy {
+    /// The number of responses to wait for before considering the put operation successful.
+    #[getter]
+    fn get_put_quorum(&self) -> String {
+        quorum_to_str(&self.inner.put_quorum)
+    }
+
+    /// The number of responses to wait for before considering the put operation successful.
+    #[setter]
+    fn set_put_quorum(&mut self, quorum: &str) -> PyResult<()> {
+        self.inner.put_quorum = str_to_quorum(quorum)?;
+        Ok(())
+    }
+
+    /// The retry strategy to use if we fail to store a piece of data.
+    #[getter]
+    fn get_put_retry(&self) -> String {
+        retry_to_str(&self.inner.put_retry)
+    }
+
+    /// The retry strategy to use if we fail to store a piece of data.
+    #[setter]
+    fn set_put_retry(&mut self, retry: &str) -> PyResult<()> {
+        self.inner.put_retry = str_to_retry(retry)?;
+        Ok(())
+    }
+
+    /// The number of responses to wait for before considering the verification to be successful.
+    #[getter]
+    fn get_verification_quorum(&self) -> String {
+        quorum_to_str(&self.inner.verification_quorum)
+    }
+
+    /// The number of responses to wait for before considering the verification to be successful.
+    #[setter]
+    fn set_verification_quorum(&mut self, quorum: &str) -> PyResult<()> {
+        self.inner.verification_quorum = str_to_quorum(quorum)?;
+        Ok(())
+    }
+
+    /// The retry strategy for verification.
+    #[getter]
+    fn get_verification_retry(&self) -> String {
+        retry_to_str(&self.inner.verification_retry)
+    }
+
+    /// The retry strategy for verification.
+    #[setter]
+    fn set_verification_retry(&mut self, retry: &str) -> PyResult<()> {
+        self.inner.verification_retry = str_to_retry(retry)?;
+        Ok(())
+    }
+
+    /// The number of responses to wait for before considering the get operation successful.
+    #[getter]
+    fn get_get_quorum(&self) -> String {
+        quorum_to_str(&self.inner.get_quorum)
+    }
+
+    /// The number of responses to wait for before considering the get operation successful.
+    #[setter]
+    fn set_get_quorum(&mut self, quorum: &str) -> PyResult<()> {
+        self.inner.get_quorum = str_to_quorum(quorum)?;
+        Ok(())
+    }
+
+    /// The retry strategy to use if the get operation fails.
+    #[getter]
+    fn get_get_retry(&self) -> String {
+        retry_to_str(&self.inner.get_retry)
+    }
+
+    /// The retry strategy to use if the get operation fails.
+    #[setter]
+    fn set_get_retry(&mut self, retry: &str) -> PyResult<()> {
+        self.inner.get_retry = str_to_retry(retry)?;
+        Ok(())
+    }
+}
+
+/// Receives the events of a client, see `Client.enable_client_events`.
+///
+/// It is an async iterator over the events, which ends once the client is dropped.
+#[pyclass(name = "ClientEventReceiver")]
+pub struct PyClientEventReceiver {
+    inner: Arc<futures::lock::Mutex<mpsc::Receiver<ClientEvent>>>,
+}
+
+#[pymethods]
+impl PyClientEventReceiver {
+    /// Wait for the next event, `None` once the client is dropped.
+    fn recv<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
+        let arc = Arc::clone(&self.inner);
+
+        future_into_py(py, async move {
+            let event = arc.lock().await.recv().await;
+            Ok(event.map(|event| PyClientEvent { inner: event }))
+        })
+    }
+
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
+        let arc = Arc::clone(&self.inner);
+
+        future_into_py(py, async move {
+            let event = arc.lock().await.recv().await;
+            event
+                .map(|event| PyClientEvent { inner: event })
+                .ok_or_else(|| PyStopAsyncIteration::new_err(()))
+        })
+    }
+}
+
+/// An event sent by the client.
+#[pyclass(name = "ClientEvent")]
+#[derive(Debug, Clone)]
+pub struct PyClientEvent {
+    inner: ClientEvent,
+}
+
+#[pymethods]
+impl PyClientEvent {
+    /// The summary of the upload, if the event is the completion of an upload.
+    #[getter]
+    fn upload_summary(&self) -> Option<PyUploadSummary> {
+        match &self.inner {
+            ClientEvent::UploadComplete(summary) => Some(PyUploadSummary {
+                inner: summary.clone(),
+            }),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.inner)
+    }
+}
+
+/// Summary of an upload operation.
+#[pyclass(name = "UploadSummary")]
+#[derive(Debug, Clone)]
+pub struct PyUploadSummary {
+    inner: UploadSummary,
+}
+
+#[pymethods]
+impl PyUploadSummary {
+    /// Records that were uploaded to the network.
+    #[getter]
+    fn records_paid(&self) -> usize {
+        self.inner.records_paid
+    }
+
+    /// Records that were already paid for so were not re-uploaded.
+    #[getter]
+    fn records_already_paid(&self) -> usize {
+        self.inner.records_already_paid
+    }
+
+    /// Total cost of the upload.
+    #[getter]
+    fn tokens_spent(&self) -> String {
+        self.inner.tokens_spent.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.inner)
+    }
+}
+
+/// The main secret key, from which derived secret keys are obtained with derivation indexes.
+#[pyclass(name = "MainSecretKey")]
+#[derive(Debug, Clone)]
+pub struct PyMainSecretKey {
+    inner: MainSecretKey,
+}
+
+#[pymethods]
+impl PyMainSecretKey {
+    /// Create a main secret key from a secret key.
+    #[new]
+    fn new(key: &PySecretKey) -> Self {
+        Self {
+            inner: MainSecretKey::new(key.inner.clone()),
+        }
+    }
+
+    /// Create a random main secret key.
+    #[staticmethod]
+    fn random() -> Self {
+        Self {
+            inner: MainSecretKey::random(),
+        }
+    }
+
+    /// Returns the main public key matching this secret key.
+    fn public_key(&self) -> PyMainPubkey {
+        PyMainPubkey {
+            inner: self.inner.public_key(),
+        }
+    }
+
+    /// Sign a message.
+    fn sign(&self, msg: &[u8]) -> PySignature {
+        PySignature {
+            inner: self.inner.sign(msg),
+        }
+    }
+
+    /// Derive the secret key at the given derivation index.
+    fn derive_key(&self, index: &PyDerivationIndex) -> PyDerivedSecretKey {
+        PyDerivedSecretKey {
+            inner: self.inner.derive_key(&index.inner),
+        }
+    }
+
+    /// Derive a secret key at a random derivation index.
+    fn random_derived_key(&self) -> PyDerivedSecretKey {
+        PyDerivedSecretKey {
+            inner: self.inner.random_derived_key(&mut rand::thread_rng()),
+        }
+    }
+
+    /// Returns the bytes of the key.
+    fn to_bytes(&self) -> Vec<u8> {
+        self.inner.to_bytes()
+    }
+
+    /// Returns the secret key this main secret key was created from.
+    fn to_secret_key(&self) -> PySecretKey {
+        PySecretKey {
+            inner: self.inner.clone().into(),
+        }
+    }
+}
+
+/// The main public key, from which derived public keys are obtained with derivation indexes.
+#[pyclass(name = "MainPubkey")]
+#[derive(Debug, Clone)]
+pub struct PyMainPubkey {
+    inner: MainPubkey,
+}
+
+#[pymethods]
+impl PyMainPubkey {
+    /// Create a main public key from a public key.
+    #[new]
+    fn new(key: &PyPublicKey) -> Self {
+        Self {
+            inner: MainPubkey::new(key.inner),
+        }
+    }
+
+    /// Creates a main public key from a hex string representation.
+    #[staticmethod]
+    fn from_hex(hex: &str) -> PyResult<Self> {
+        MainPubkey::from_hex(hex)
+            .map(|key| Self { inner: key })
+            .map_err(|e| PyValueError::new_err(format!("Invalid hex key: {e}")))
+    }
+
+    /// Verify that the signature of a message was made with the matching main secret key.
+    fn verify(&self, signature: &PySignature, msg: &[u8]) -> bool {
+        self.inner.verify(&signature.inner, msg)
+    }
+
+    /// Derive the public key at the given derivation index.
+    fn derive_key(&self, index: &PyDerivationIndex) -> PyDerivedPubkey {
+        PyDerivedPubkey {
+            inner: self.inner.derive_key(&index.inner),
+        }
+    }
+
+    /// Returns the bytes of the key.
+    fn to_bytes(&self) -> Vec<u8> {
+        self.inner.to_bytes().to_vec()
+    }
+
+    /// Returns the hex string representation of the key.
+    fn to_hex(&self) -> String {
+        self.inner.to_hex()
+    }
+
+    /// Returns the public key this main public key was created from.
+    fn to_public_key(&self) -> PyPublicKey {
+        PyPublicKey {
+            inner: self.inner.into(),
+        }
+    }
 }
 
-/// Options for making payments on the network.
-#[pyclass(name = "PaymentOption")]
-#[derive(Clone)]
-pub struct PyPaymentOption {
-    pub(crate) inner: PaymentOption,
+/// An index used to derive keys from main keys.
+#[pyclass(name = "DerivationIndex")]
+#[derive(Debug, Clone)]
+pub struct PyDerivationIndex {
+    inner: DerivationIndex,
 }
 
 #[pymethods]
-impl PyPaymentOption {
-    /// Creates a payment option using the provided wallet.
+impl PyDerivationIndex {
+    /// Create a random derivation index.
     #[staticmethod]
-    fn wallet(wallet: &PyWallet) -> Self {
+    fn random() -> Self {
         Self {
-            inner: PaymentOption::Wallet(wallet.inner.clone()),
+            inner: DerivationIndex::random(&mut rand::thread_rng()),
+        }
+    }
+
+    /// Create a derivation index from 32 bytes.
+    #[staticmethod]
+    fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self {
+            inner: DerivationIndex::from_bytes(bytes),
         }
     }
+
+    /// Returns the bytes of the derivation index.
+    fn to_bytes(&self) -> [u8; 32] {
+        *self.inner.as_bytes()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.inner)
+    }
 }
 
-/// A cryptographic secret key used for signing operations.
-/// Can be used to derive a public key and perform cryptographic operations.
-#[pyclass(name = "SecretKey")]
+/// A secret key derived from a main secret key.
+#[pyclass(name = "DerivedSecretKey")]
 #[derive(Debug, Clone)]
-pub struct PySecretKey {
-    inner: SecretKey,
+pub struct PyDerivedSecretKey {
+    inner: DerivedSecretKey,
 }
 
 #[pymethods]
-impl PySecretKey {
-    /// Creates a new random secret key.
+impl PyDerivedSecretKey {
+    /// Create a derived secret key from a secret key.
     #[new]
-    fn new() -> PyResult<Self> {
-        Ok(Self {
-            inner: SecretKey::random(),
-        })
+    fn new(key: &PySecretKey) -> Self {
+        Self {
+            inner: DerivedSecretKey::new(key.inner.clone()),
+        }
     }
 
-    /// Creates a secret key from a hex string representation.
-    #[staticmethod]
-    fn from_hex(hex_str: &str) -> PyResult<Self> {
-        SecretKey::from_hex(hex_str)
-            .map(|key| Self { inner: key })
-            .map_err(|e| PyValueError::new_err(format!("Invalid hex key: {e}")))
+    /// Returns the derived public key matching this secret key.
+    fn public_key(&self) -> PyDerivedPubkey {
+        PyDerivedPubkey {
+            inner: self.inner.public_key(),
+        }
     }
 
-    /// Derives and returns the corresponding public key.
-    fn public_key(&self) -> PyPublicKey {
-        PyPublicKey {
-            inner: self.inner.public_key(),
+    /// Sign a message.
+    fn sign(&self, msg: &[u8]) -> PySignature {
+        PySignature {
+            inner: self.inner.sign(msg),
         }
     }
 
-    /// Returns the hex string representation of the key.
-    fn to_hex(&self) -> String {
-        self.inner.to_hex()
+    /// Returns the underlying secret key, to own data on the network with it.
+    fn to_secret_key(&self) -> PySecretKey {
+        PySecretKey {
+            inner: self.inner.clone().into(),
+        }
     }
 }
 
-/// A cryptographic public key derived from a secret key.
-#[pyclass(name = "PublicKey")]
+/// A public key derived from a main public key.
+#[pyclass(name = "DerivedPubkey")]
 #[derive(Debug, Clone)]
-pub struct PyPublicKey {
-    inner: PublicKey,
+pub struct PyDerivedPubkey {
+    inner: DerivedPubkey,
 }
 
 #[pymethods]
-impl PyPublicKey {
-    /// Creates a new random public key by generating a random secret key.
+impl PyDerivedPubkey {
+    /// Create a derived public key from a public key.
     #[new]
-    fn new() -> PyResult<Self> {
-        let secret = SecretKey::random();
-        Ok(Self {
-            inner: secret.public_key(),
-        })
+    fn new(key: &PyPublicKey) -> Self {
+        Self {
+            inner: DerivedPubkey::new(key.inner),
+        }
     }
 
-    /// Creates a public key from a hex string representation.
+    /// Creates a derived public key from a hex string representation.
     #[staticmethod]
-    fn from_hex(hex_str: &str) -> PyResult<Self> {
-        PublicKey::from_hex(hex_str)
+    fn from_hex(hex: &str) -> PyResult<Self> {
+        DerivedPubkey::from_hex(hex)
             .map(|key| Self { inner: key })
             .map_err(|e| PyValueError::new_err(format!("Invalid hex key: {e}")))
     }
 
+    /// Verify that the signature of a message was made with the matching derived secret key.
+    fn verify(&self, signature: &PySignature, msg: &[u8]) -> bool {
+        self.inner.verify(&signature.inner, msg)
+    }
+
+    /// Returns the bytes of the key.
+    fn to_bytes(&self) -> Vec<u8> {
+        self.inner.to_bytes().to_vec()
+    }
+
+    /// Returns the hex string representation of the key.
     fn to_hex(&self) -> String {
         self.inner.to_hex()
     }
+
+    /// Returns the underlying public key.
+    fn to_public_key(&self) -> PyPublicKey {
+        PyPublicKey {
+            inner: self.inner.into(),
+        }
+    }
 }
 
-/// A secret key used to encrypt and decrypt vault data.
-#[pyclass(name = "VaultSecretKey")]
+/// A BLS signature.
+#[pyclass(name = "Signature")]
 #[derive(Debug, Clone)]
-pub struct PyVaultSecretKey {
-    inner: VaultSecretKey,
+pub struct PySignature {
+    inner: Signature,
 }
 
 #[pymethods]
-impl PyVaultSecretKey {
-    /// Creates a new random vault secret key.
-    #[new]
-    fn new() -> PyResult<Self> {
-        Ok(Self {
-            inner: VaultSecretKey::random(),
-        })
+impl PySignature {
+    /// Creates a signature from its bytes.
+    #[staticmethod]
+    fn from_bytes(bytes: Vec<u8>) -> PyResult<Self> {
+        let bytes: [u8; bls::SIG_SIZE] = bytes.try_into().map_err(|_| {
+            PyValueError::new_err(format!("`bytes` invalid: must be {} bytes", bls::SIG_SIZE))
+        })?;
+        Signature::from_bytes(bytes)
+            .map(|signature| Self { inner: signature })
+            .map_err(|e| PyValueError::new_err(format!("Invalid signature: {e}")))
     }
 
-    #[staticmethod]
-    fn from_hex(hex_str: &str) -> PyResult<Self> {
-        VaultSecretKey::from_hex(hex_str)
-            .map(|key| Self { inner: key })
-            .map_err(|e| PyValueError::new_err(format!("Invalid hex key: {e}")))
+    /// Returns the bytes of the signature.
+    fn to_bytes(&self) -> Vec<u8> {
+        self.inner.to_bytes().to_vec()
     }
+}
 
-    fn to_hex(&self) -> String {
-        self.inner.to_hex()
+/// The types of data natively supported by the network.
+#[pyclass(name = "DataTypes", eq, eq_int)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PyDataTypes {
+    Chunk,
+    GraphEntry,
+    Pointer,
+    Scratchpad,
+}
+
+impl From<PyDataTypes> for DataTypes {
+    fn from(data_type: PyDataTypes) -> Self {
+        match data_type {
+            PyDataTypes::Chunk => DataTypes::Chunk,
+            PyDataTypes::GraphEntry => DataTypes::GraphEntry,
+            PyDataTypes::Pointer => DataTypes::Pointer,
+            PyDataTypes::Scratchpad => DataTypes::Scratchpad,
+        }
     }
 }
 
-/// UserData is stored in Vaults and contains most of a user's private data:
-/// It allows users to keep track of only the key to their User Data Vault
-/// while having the rest kept on the Network encrypted in a Vault for them
-/// Using User Data Vault is optional, one can decide to keep all their data locally instead.
-#[pyclass(name = "UserData")]
+/// A chunk of data, as stored on the network.
+#[pyclass(name = "Chunk")]
 #[derive(Debug, Clone)]
-pub struct PyUserData {
-    inner: UserData,
+pub struct PyChunk {
+    inner: Chunk,
+}
+
+impl From<Chunk> for PyChunk {
+    fn from(chunk: Chunk) -> Self {
+        Self { inner: chunk }
+    }
 }
 
 #[pymethods]
-impl PyUserData {
-    /// Creates a new empty UserData instance.
+impl PyChunk {
+    /// Creates a chunk holding the given data.
     #[new]
-    fn new() -> Self {
+    fn new(value: Vec<u8>) -> Self {
         Self {
-            inner: UserData::new(),
+            inner: Chunk::new(Bytes::from(value)),
         }
     }
 
-    /// Returns a list of public file archives as (address, name) pairs.
-    fn file_archives(&self) -> Vec<(String, String)> {
+    /// Returns the data of the chunk.
+    #[getter]
+    fn value(&self) -> Vec<u8> {
+        self.inner.value().to_vec()
+    }
+
+    /// Returns the address of the chunk, derived from its content.
+    fn address(&self) -> PyChunkAddress {
+        PyChunkAddress::from(*self.inner.address())
+    }
+
+    /// Returns the size of the chunk in bytes.
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Chunk({})", self.inner.address().to_hex())
+    }
+}
+
+/// Quotes for storing data at many addresses, see `Client.get_store_quotes`.
+#[pyclass(name = "StoreQuote")]
+#[derive(Clone)]
+pub struct PyStoreQuote {
+    inner: StoreQuote,
+}
+
+#[pymethods]
+impl PyStoreQuote {
+    /// Returns the total price of the quotes, in atto tokens.
+    fn price(&self) -> String {
+        self.inner.price().to_string()
+    }
+
+    /// Returns the hex addresses that were quoted.
+    fn addresses(&self) -> Vec<String> {
         self.inner
-            .file_archives
-            .iter()
-            .map(|(addr, name)| (hex::encode(addr), name.clone()))
+            .0
+            .keys()
+            .copied()
+            .map(crate::client::address::addr_to_str)
             .collect()
     }
 
-    /// Returns a list of private file archives as (data_map, name) pairs.
-    fn private_file_archives(&self) -> Vec<(String, String)> {
+    /// Returns the payments to execute for the quotes.
+    fn payments(&self) -> Vec<PyQuotePayment> {
         self.inner
-            .private_file_archives
-            .iter()
-            .map(|(addr, name)| (addr.to_hex(), name.clone()))
+            .payments()
+            .into_iter()
+            .map(|payment| PyQuotePayment { inner: payment })
             .collect()
     }
+
+    fn __len__(&self) -> usize {
+        self.inner.len()
+    }
 }
 
-/// A map with encrypted data pieces on the network. Used to locate and reconstruct private data.
-#[pyclass(name = "DataMapChunk")]
+/// A payment to execute for a quote: the hash of the quote, the address to pay and the amount in atto tokens.
+#[pyclass(name = "QuotePayment")]
 #[derive(Debug, Clone)]
-pub struct PyDataMapChunk {
-    inner: DataMapChunk,
+pub struct PyQuotePayment {
+    inner: QuotePayment,
 }
 
 #[pymethods]
-impl PyDataMapChunk {
-    /// Creates a DataMapChunk from a hex string representation.
-    #[staticmethod]
-    fn from_hex(hex: &str) -> PyResult<Self> {
-        DataMapChunk::from_hex(hex)
-            .map(|access| Self { inner: access })
-            .map_err(|e| PyValueError::new_err(format!("Invalid hex: {e}")))
-    }
+impl PyQuotePayment {
+    /// Creates a quote payment from the hex hash of the quote, the hex rewards address and the amount in atto tokens.
+    #[new]
+    fn new(quote_hash: &str, rewards_address: &str, amount: &str) -> PyResult<Self> {
+        let quote_hash = QuoteHash::from_str(quote_hash)
+            .map_err(|e| PyValueError::new_err(format!("`quote_hash` invalid: {e}")))?;
+        let rewards_address = RewardsAddress::from_str(rewards_address)
+            .map_err(|e| PyValueError::new_err(format!("`rewards_address` invalid: {e}")))?;
+        let amount = Amount::from_str(amount)
+            .map_err(|e| PyValueError::new_err(format!("`amount` invalid: {e}")))?;
 
-    /// Returns the hex string representation of this DataMapChunk.
-    fn to_hex(&self) -> String {
-        self.inner.to_hex()
+        Ok(Self {
+            inner: (quote_hash, rewards_address, amount),
+        })
     }
 
-    /// Returns the private address of this DataMapChunk.
-    ///
-    /// Note that this is not a network address, it is only used for refering to private data client side.
-    fn address(&self) -> String {
-        self.inner.address().to_string()
+    /// The hex hash of the quote.
+    #[getter]
+    fn quote_hash(&self) -> String {
+        self.inner.0.to_string()
     }
-}
 
-#[pyfunction]
-fn encrypt(data: Vec<u8>) -> PyResult<(Vec<u8>, Vec<Vec<u8>>)> {
-    let (data_map, chunks) = self_encryption::encrypt(Bytes::from(data))
-        .map_err(|e| PyRuntimeError::new_err(format!("Encryption failed: {e}")))?;
-
-    let data_map_bytes = rmp_serde::to_vec(&data_map)
-        .map_err(|e| PyRuntimeError::new_err(format!("Failed to serialize data map: {e}")))?;
+    /// The hex address to pay.
+    #[getter]
+    fn rewards_address(&self) -> String {
+        self.inner.1.to_string()
+    }
 
-    let chunks_bytes: Vec<Vec<u8>> = chunks
-        .into_iter()
-        .map(|chunk| chunk.content.to_vec())
-        .collect();
+    /// The amount to pay, in atto tokens.
+    #[getter]
+    fn amount(&self) -> String {
+        self.inner.2.to_string()
+    }
 
-    Ok((data_map_bytes, chunks_bytes))
+    fn __repr__(&self) -> String {
+        format!(
+            "QuotePayment({}, {}, {})",
+            self.inner.0, self.inner.1, self.inner.2
+        )
+    }
 }
 
-#[pyclass(name = "Network")]
-#[derive(Debug, Clone)]
-pub struct PyNetwork {
-    inner: Network,
+/// The proofs of payment for data that was paid for, used with `PaymentOption.receipt`.
+#[pyclass(name = "Receipt")]
+#[derive(Clone)]
+pub struct PyReceipt {
+    inner: Receipt,
 }
 
 #[pymethods]
-impl PyNetwork {
-    /// Creates a new network configuration.
-    ///
-    /// If `local` is true, configures for local network connections.
-    #[new]
-    fn new(local: bool) -> PyResult<Self> {
-        let inner = Network::new(local).map_err(|e| PyRuntimeError::new_err(format!("{e:?}")))?;
-        Ok(Self { inner })
+impl PyReceipt {
+    /// Returns the hex addresses that were paid for.
+    fn addresses(&self) -> Vec<String> {
+        self.inner
+            .keys()
+            .copied()
+            .map(crate::client::address::addr_to_str)
+            .collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.len()
     }
 }
 
-/// Metadata for files in an archive, containing creation time, modification time, and size.
-#[pyclass(name = "Metadata")]
-#[derive(Debug, Clone)]
-pub struct PyMetadata {
-    inner: Metadata,
+/// The transactions paying for quotes, to be signed and sent by an external signer.
+///
+/// The payment contract first needs to be approved to spend `approve_amount`, see `approve_to_spend_tokens_calldata`.
+#[pyclass(name = "PayForQuotesCalldata")]
+pub struct PyPayForQuotesCalldata {
+    inner: external_signer::PayForQuotesCalldataReturnType,
 }
 
 #[pymethods]
-impl PyMetadata {
-    /// Create new metadata with the given file size
-    #[new]
-    fn new(size: u64) -> Self {
-        Self {
-            inner: Metadata::new_with_size(size),
-        }
+impl PyPayForQuotesCalldata {
+    /// The calldata of each transaction, along with the hex hashes of the quotes it pays for.
+    #[getter]
+    fn batched_calldata(&self) -> Vec<(Vec<u8>, Vec<String>)> {
+        self.inner
+            .batched_calldata_map
+            .iter()
+            .map(|(calldata, quote_hashes)| {
+                (
+                    calldata.to_vec(),
+                    quote_hashes.iter().map(|hash| hash.to_string()).collect(),
+                )
+            })
+            .collect()
     }
 
-    /// Get the creation time as Unix timestamp in seconds
+    /// The hex address of the payment contract to send the transactions to.
     #[getter]
-    fn get_created(&self) -> u64 {
-        self.inner.created
+    fn to(&self) -> String {
+        self.inner.to.to_string()
     }
 
-    /// Set the creation time as Unix timestamp in seconds
-    #[setter]
-    fn set_created(&mut self, value: u64) {
-        self.inner.created = value;
+    /// The hex address to approve to spend tokens.
+    #[getter]
+    fn approve_spender(&self) -> String {
+        self.inner.approve_spender.to_string()
     }
 
-    /// Get the modification time as Unix timestamp in seconds
+    /// The amount to approve, in atto tokens.
     #[getter]
-    fn get_modified(&self) -> u64 {
-        self.inner.modified
+    fn approve_amount(&self) -> String {
+        self.inner.approve_amount.to_string()
     }
+}
 
-    /// Set the modification time as Unix timestamp in seconds
-    #[setter]
-    fn set_modified(&mut self, value: u64) {
-        self.inner.modified = value;
+/// Address of mutable data that can be watched for changes with `Client.watch`.
+#[pyclass(name = "WatchAddress")]
+#[derive(Debug, Clone)]
+pub struct PyWatchAddress {
+    inner: WatchAddress,
+}
+
+#[pymethods]
+impl PyWatchAddress {
+    /// Watch a pointer.
+    #[staticmethod]
+    fn pointer(addr: &PyPointerAddress) -> Self {
+        Self {
+            inner: WatchAddress::Pointer(addr.inner),
+        }
+    }
+
+    /// Watch a scratchpad, from its hex address.
+    #[staticmethod]
+    fn scratchpad(addr: &str) -> PyResult<Self> {
+        let addr = ScratchpadAddress::from_hex(addr)
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse address: {e}")))?;
+        Ok(Self {
+            inner: WatchAddress::Scratchpad(addr),
+        })
+    }
+
+    /// Watch a register, from its hex address.
+    #[staticmethod]
+    fn register(addr: &str) -> PyResult<Self> {
+        let addr = RegisterAddress::from_hex(addr)
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse address: {e}")))?;
+        Ok(Self {
+            inner: WatchAddress::Register(addr),
+        })
     }
 
-    /// Get the file size in bytes
-    #[getter]
-    fn get_size(&self) -> u64 {
-        self.inner.size
+    /// Watch a graph entry, from its hex address.
+    #[staticmethod]
+    fn graph_entry(#[pyo3(from_py_with = "str_to_addr")] addr: XorName) -> Self {
+        Self {
+            inner: WatchAddress::GraphEntry(GraphEntryAddress::new(addr)),
+        }
     }
 
-    /// Set the file size in bytes
-    #[setter]
-    fn set_size(&mut self, value: u64) {
-        self.inner.size = value;
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.inner)
     }
 }
 
-/// A public archive containing files that can be accessed by anyone on the network.
-#[pyclass(name = "PublicArchive")]
+/// Configuration of how often `Client.watch` checks the network for changes, in seconds.
+///
+/// The watcher checks again after `min_interval` when it has just seen a change, and backs off
+/// up to `max_interval` while the data stays the same.
+#[pyclass(name = "WatchConfig")]
 #[derive(Debug, Clone)]
-pub struct PyPublicArchive {
-    inner: PublicArchive,
+pub struct PyWatchConfig {
+    inner: WatchConfig,
 }
 
 #[pymethods]
-impl PyPublicArchive {
-    /// Create a new empty archive
+impl PyWatchConfig {
     #[new]
-    fn new() -> Self {
-        Self {
-            inner: PublicArchive::new(),
+    #[pyo3(signature = (min_interval=None, max_interval=None))]
+    fn new(min_interval: Option<f64>, max_interval: Option<f64>) -> PyResult<Self> {
+        let mut inner = WatchConfig::default();
+        if let Some(secs) = min_interval {
+            inner.min_interval = secs_to_duration(secs)?;
+        }
+        if let Some(secs) = max_interval {
+            inner.max_interval = secs_to_duration(secs)?;
         }
+        Ok(Self { inner })
     }
 
-    /// Rename a file in the archive.
-    ///
-    /// Returns None on success, or error message on failure
-    fn rename_file(&mut self, old_path: PathBuf, new_path: PathBuf) -> PyResult<()> {
-        self.inner
-            .rename_file(&old_path, &new_path)
-            .map_err(|e| PyRuntimeError::new_err(format!("Failed to rename file: {e}")))
+    /// Interval after a change, in seconds.
+    #[getter]
+    fn get_min_interval(&self) -> f64 {
+        self.inner.min_interval.as_secs_f64()
     }
 
-    /// Add a file to the archive
-    fn add_file(
-        &mut self,
-        path: PathBuf,
-        #[pyo3(from_py_with = "str_to_addr")] addr: XorName,
-        metadata: &PyMetadata,
-    ) {
-        self.inner.add_file(path, addr, metadata.inner.clone());
+    /// Interval after a change, in seconds.
+    #[setter]
+    fn set_min_interval(&mut self, secs: f64) -> PyResult<()> {
+        self.inner.min_interval = secs_to_duration(secs)?;
+        Ok(())
     }
 
-    /// List all files in the archive.
-    ///
-    /// Returns a list of (path, metadata) tuples
-    fn files(&self) -> Vec<(PathBuf, PyMetadata)> {
-        self.inner
-            .files()
-            .into_iter()
-            .map(|(path, meta)| (path, PyMetadata { inner: meta }))
-            .collect()
+    /// Longest interval while the data stays the same, in seconds.
+    #[getter]
+    fn get_max_interval(&self) -> f64 {
+        self.inner.max_interval.as_secs_f64()
     }
 
-    /// List all data addresses of files in the archive
-    fn addresses(&self) -> Vec<String> {
-        self.inner
-            .addresses()
-            .into_iter()
-            .map(crate::client::address::addr_to_str)
-            .collect()
+    /// Longest interval while the data stays the same, in seconds.
+    #[setter]
+    fn set_max_interval(&mut self, secs: f64) -> PyResult<()> {
+        self.inner.max_interval = secs_to_duration(secs)?;
+        Ok(())
     }
 }
 
-/// A public archive containing files that can be accessed by anyone on the network.
-#[pyclass(name = "PrivateArchive")]
+/// A new version of watched data, yielded by `Client.watch`.
+///
+/// Only the property matching the type of the watched data is set.
+#[pyclass(name = "WatchUpdate")]
 #[derive(Debug, Clone)]
-pub struct PyPrivateArchive {
-    inner: PrivateArchive,
+pub struct PyWatchUpdate {
+    inner: WatchUpdate,
 }
 
 #[pymethods]
-impl PyPrivateArchive {
-    /// Create a new empty archive
-    #[new]
-    fn new() -> Self {
-        Self {
-            inner: PrivateArchive::new(),
+impl PyWatchUpdate {
+    /// The new version of a watched pointer.
+    #[getter]
+    fn pointer(&self) -> Option<PyPointer> {
+        match &self.inner {
+            WatchUpdate::Pointer(pointer) => Some(PyPointer {
+                inner: pointer.clone(),
+            }),
+            _ => None,
         }
     }
 
-    /// Rename a file in the archive.
-    ///
-    /// Returns None on success, or error message on failure
-    fn rename_file(&mut self, old_path: PathBuf, new_path: PathBuf) -> PyResult<()> {
-        self.inner
-            .rename_file(&old_path, &new_path)
-            .map_err(|e| PyRuntimeError::new_err(format!("Failed to rename file: {e}")))
+    /// The new version of a watched scratchpad.
+    #[getter]
+    fn scratchpad(&self) -> Option<PyScratchpad> {
+        match &self.inner {
+            WatchUpdate::Scratchpad(scratchpad) => Some(PyScratchpad {
+                inner: scratchpad.clone(),
+            }),
+            _ => None,
+        }
     }
 
-    /// Add a file to a local archive. Note that this does not upload the archive to the network.
-    fn add_file(&mut self, path: PathBuf, data_map: &PyDataMapChunk, metadata: &PyMetadata) {
-        self.inner
-            .add_file(path, data_map.inner.clone(), metadata.inner.clone());
+    /// The counter of a watched register's head, which increases with each update.
+    #[getter]
+    fn register_counter(&self) -> Option<u32> {
+        match &self.inner {
+            WatchUpdate::Register { counter, .. } => Some(*counter),
+            _ => None,
+        }
     }
 
-    /// List all files in the archive.
-    fn files(&self) -> Vec<(PathBuf, PyMetadata)> {
-        self.inner
-            .files()
-            .into_iter()
-            .map(|(path, meta)| (path, PyMetadata { inner: meta }))
-            .collect()
+    /// The value at the head of a watched register, or the competing values if it is forked.
+    #[getter]
+    fn register_values(&self) -> Option<Vec<[u8; 32]>> {
+        match &self.inner {
+            WatchUpdate::Register { values, .. } => Some(values.clone()),
+            _ => None,
+        }
     }
 
-    /// List all data maps of files in the archive
-    fn data_maps(&self) -> Vec<PyDataMapChunk> {
-        self.inner
-            .data_maps()
-            .into_iter()
-            .map(|data_map| PyDataMapChunk { inner: data_map })
-            .collect()
+    /// All the entries found at the address of a watched graph entry, sorted.
+    #[getter]
+    fn graph_entries(&self) -> Option<Vec<PyGraphEntry>> {
+        match &self.inner {
+            WatchUpdate::GraphEntry(entries) => Some(
+                entries
+                    .iter()
+                    .map(|entry| PyGraphEntry {
+                        inner: entry.clone(),
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        }
     }
-}
 
-/// A generic GraphEntry on the Network.
-///
-/// Graph entries are stored at the owner's public key. Note that there can only be one graph entry per owner.
-/// Graph entries can be linked to other graph entries as parents or descendants.
-/// Applications are free to define the meaning of these links, those are not enforced by the protocol.
-/// The protocol only ensures that the graph entry is immutable once uploaded and that the signature is valid and matches the owner.
-///
-/// For convenience it is advised to make use of BLS key derivation to create multiple graph entries from a single key.
-#[pyclass(name = "GraphEntry")]
-#[derive(Debug, Clone)]
-pub struct PyGraphEntry {
-    inner: GraphEntry,
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.inner)
+    }
 }
 
-/// Scratchpad, a mutable space for encrypted data on the Network
-#[pyclass(name = "Scratchpad")]
-#[derive(Debug, Clone)]
-pub struct PyScratchpad {
-    inner: Scratchpad,
-}
+type WatchStream = Pin<Box<dyn Stream<Item = Result<WatchUpdate, WatchError>> + Send>>;
 
-/// A handle to the register history
-#[pyclass(name = "RegisterHistory")]
-#[derive(Clone)]
-pub struct PyRegisterHistory {
-    inner: Arc<futures::lock::Mutex<RegisterHistory>>,
+/// Watches mutable data for changes, see `Client.watch`.
+///
+/// It is an async iterator over the versions of the data, which never ends.
+#[pyclass(name = "Watcher")]
+pub struct PyWatcher {
+    inner: Arc<futures::lock::Mutex<WatchStream>>,
 }
 
-impl PyRegisterHistory {
-    fn new(history: RegisterHistory) -> Self {
+impl PyWatcher {
+    fn new(stream: impl Stream<Item = Result<WatchUpdate, WatchError>> + Send + 'static) -> Self {
         Self {
-            inner: Arc::new(futures::lock::Mutex::new(history)),
+            inner: Arc::new(futures::lock::Mutex::new(Box::pin(stream))),
         }
     }
 }
 
 #[pymethods]
-impl PyRegisterHistory {
-    fn next<'a>(&'a mut self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
-        let arc = Arc::clone(&self.inner);
-
-        future_into_py(py, async move {
-            let mut register_history = arc.lock().await;
-            let value = register_history
-                .next()
-                .await
-                .map_err(|e| PyRuntimeError::new_err(format!("history `next` failed: {e}")))?;
-
-            Ok(value)
-        })
+impl PyWatcher {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
     }
 
-    fn collect<'a>(&'a mut self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
+    fn __anext__<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
         let arc = Arc::clone(&self.inner);
 
         future_into_py(py, async move {
-            let mut register_history = arc.lock().await;
-            let values = register_history
-                .collect()
-                .await
-                .map_err(|e| PyRuntimeError::new_err(format!("history `collect` failed: {e}")))?;
-
-            Ok(values)
+            match arc.lock().await.next().await {
+                Some(Ok(update)) => Ok(PyWatchUpdate { inner: update }),
+                Some(Err(e)) => Err(PyRuntimeError::new_err(format!("Failed to watch: {e}"))),
+                None => Err(PyStopAsyncIteration::new_err(())),
+            }
         })
     }
 }
 
-/// Configuration for the `Client` which can be provided through: `init_with_config`.
-#[pyclass(name = "ClientConfig")]
-#[derive(Debug, Clone)]
-pub struct PyClientConfig {
-    inner: ClientConfig,
-}
-
-#[pymethods]
-impl PyClientConfig {
-    #[staticmethod]
-    fn new() -> Self {
-        Self {
-            inner: ClientConfig::default(),
-        }
-    }
-
-    /// Whether we're expected to connect to a local network.
-    #[getter]
-    fn get_local(&self) -> bool {
-        self.inner.local
-    }
+/// Encrypts data as chunks, to pay for them with an external signer.
+///
+/// Returns the data map chunk and the chunks of the data, all of which need to be paid for and uploaded.
+#[pyfunction]
+fn encrypt_data(data: Vec<u8>) -> PyResult<(PyChunk, Vec<PyChunk>)> {
+    let (data_map_chunk, chunks) = external_signer::encrypt_data(Bytes::from(data))
+        .map_err(|e| PyRuntimeError::new_err(format!("Encryption failed: {e}")))?;
 
-    /// Whether we're expected to connect to a local network.
-    #[setter]
-    fn set_local(&mut self, value: bool) {
-        self.inner.local = value;
-    }
+    Ok((
+        PyChunk::from(data_map_chunk),
+        chunks.into_iter().map(PyChunk::from).collect(),
+    ))
+}
 
-    /// List of peers to connect to.
-    ///
-    /// If not provided, the client will use the default bootstrap peers.
-    #[getter]
-    fn get_peers(&self) -> Option<Vec<String>> {
-        self.inner
-            .peers
-            .as_ref()
-            .map(|peers| peers.iter().map(|p| p.to_string()).collect())
+/// Turns paid quotes into a receipt, to upload the data they were paid for.
+#[pyfunction]
+fn receipt_from_store_quotes(quotes: &PyStoreQuote) -> PyReceipt {
+    PyReceipt {
+        inner: crate::client::payment::receipt_from_store_quotes(quotes.inner.clone()),
     }
+}
 
-    /// List of peers to connect to. If given empty list, the client will use the default bootstrap peers.
-    #[setter]
-    fn set_peers(&mut self, peers: Vec<String>) -> PyResult<()> {
-        if peers.is_empty() {
-            self.inner.peers = None;
-            return Ok(());
-        }
+/// Get the transactions paying for quotes, to be signed and sent by an external signer.
+#[pyfunction]
+fn pay_for_quotes_calldata(
+    network: &PyNetwork,
+    payments: Vec<PyQuotePayment>,
+) -> PyResult<PyPayForQuotesCalldata> {
+    let inner = external_signer::pay_for_quotes_calldata(
+        &network.inner,
+        payments.into_iter().map(|payment| payment.inner),
+    )
+    .map_err(|e| PyRuntimeError::new_err(format!("Failed to get calldata: {e}")))?;
+
+    Ok(PyPayForQuotesCalldata { inner })
+}
 
-        let peers: Vec<Multiaddr> = peers
-            .iter()
-            .map(|p| Multiaddr::from_str(p))
-            .collect::<Result<_, _>>()
-            .map_err(|e| PyValueError::new_err(format!("Failed to parse peers: {e}")))?;
+/// Get the transaction approving the hex address `spender` to spend `amount` atto tokens, to be
+/// signed and sent by an external signer.
+///
+/// Returns the calldata and the hex address to send the transaction to.
+#[pyfunction]
+fn approve_to_spend_tokens_calldata(
+    network: &PyNetwork,
+    spender: &str,
+    amount: &str,
+) -> PyResult<(Vec<u8>, String)> {
+    let spender = RewardsAddress::from_str(spender)
+        .map_err(|e| PyValueError::new_err(format!("`spender` invalid: {e}")))?;
+    let amount = Amount::from_str(amount)
+        .map_err(|e| PyValueError::new_err(format!("`amount` invalid: {e}")))?;
+
+    let (calldata, to) =
+        external_signer::approve_to_spend_tokens_calldata(&network.inner, spender, amount);
+    Ok((calldata.to_vec(), to.to_string()))
+}
 
-        self.inner.peers = Some(peers);
-        Ok(())
-    }
+/// Get the transaction transferring `amount` atto tokens to the hex address `receiver`, to be
+/// signed and sent by an external signer.
+///
+/// Returns the calldata and the hex address to send the transaction to.
+#[pyfunction]
+fn transfer_tokens_calldata(
+    network: &PyNetwork,
+    receiver: &str,
+    amount: &str,
+) -> PyResult<(Vec<u8>, String)> {
+    let receiver = RewardsAddress::from_str(receiver)
+        .map_err(|e| PyValueError::new_err(format!("`receiver` invalid: {e}")))?;
+    let amount = Amount::from_str(amount)
+        .map_err(|e| PyValueError::new_err(format!("`amount` invalid: {e}")))?;
+
+    let (calldata, to) =
+        external_signer::transfer_tokens_calldata(&network.inner, receiver, amount);
+    Ok((calldata.to_vec(), to.to_string()))
+}
 
-    /// EVM network to use for quotations and payments.
-    #[getter]
-    fn get_network(&self) -> PyNetwork {
-        PyNetwork {
-            inner: self.inner.evm_network.clone(),
-        }
-    }
+/// Derives a vault key from the hex private key of an EVM wallet.
+#[pyfunction]
+fn derive_vault_key(evm_sk_hex: &str) -> PyResult<PyVaultSecretKey> {
+    crate::client::vault::derive_vault_key(evm_sk_hex)
+        .map(|key| PyVaultSecretKey { inner: key })
+        .map_err(|e| PyValueError::new_err(format!("Failed to derive vault key: {e}")))
+}
 
-    /// EVM network to use for quotations and payments.
-    #[setter]
-    fn set_network(&mut self, network: PyNetwork) {
-        self.inner.evm_network = network.inner;
-    }
+/// Derives a vault key from the hex signature of the vault key derivation message by an EVM wallet.
+#[pyfunction]
+fn vault_key_from_signature_hex(signature_hex: &str) -> PyResult<PyVaultSecretKey> {
+    crate::client::vault::key::vault_key_from_signature_hex(signature_hex)
+        .map(|key| PyVaultSecretKey { inner: key })
+        .map_err(|e| PyValueError::new_err(format!("Failed to derive vault key: {e}")))
+}
 
-    // TODO
-    // fn strategy() { }
+/// Get the content type of the data of an app in a vault, from the name of the app.
+#[pyfunction]
+fn app_name_to_vault_content_type(name: &str) -> u64 {
+    crate::client::vault::app_name_to_vault_content_type(name)
 }
 
 #[pymodule]
@@ -1863,7 +3914,36 @@ fn autonomi_client_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyScratchpad>()?;
     m.add_class::<PyRegisterHistory>()?;
     m.add_class::<PyClientConfig>()?;
+    m.add_class::<PyClientOperatingStrategy>()?;
+    m.add_class::<PyStrategy>()?;
+    m.add_class::<PyClientEventReceiver>()?;
+    m.add_class::<PyClientEvent>()?;
+    m.add_class::<PyUploadSummary>()?;
+    m.add_class::<PyMainSecretKey>()?;
+    m.add_class::<PyMainPubkey>()?;
+    m.add_class::<PyDerivationIndex>()?;
+    m.add_class::<PyDerivedSecretKey>()?;
+    m.add_class::<PyDerivedPubkey>()?;
+    m.add_class::<PySignature>()?;
+    m.add_class::<PyDataTypes>()?;
+    m.add_class::<PyChunk>()?;
+    m.add_class::<PyStoreQuote>()?;
+    m.add_class::<PyQuotePayment>()?;
+    m.add_class::<PyReceipt>()?;
+    m.add_class::<PyPayForQuotesCalldata>()?;
+    m.add_class::<PyWatchAddress>()?;
+    m.add_class::<PyWatchConfig>()?;
+    m.add_class::<PyWatchUpdate>()?;
+    m.add_class::<PyWatcher>()?;
     m.add_function(wrap_pyfunction!(encrypt, m)?)?;
+    m.add_function(wrap_pyfunction!(encrypt_data, m)?)?;
+    m.add_function(wrap_pyfunction!(receipt_from_store_quotes, m)?)?;
+    m.add_function(wrap_pyfunction!(pay_for_quotes_calldata, m)?)?;
+    m.add_function(wrap_pyfunction!(approve_to_spend_tokens_calldata, m)?)?;
+    m.add_function(wrap_pyfunction!(transfer_tokens_calldata, m)?)?;
+    m.add_function(wrap_pyfunction!(derive_vault_key, m)?)?;
+    m.add_function(wrap_pyfunction!(vault_key_from_signature_hex, m)?)?;
+    m.add_function(wrap_pyfunction!(app_name_to_vault_content_type, m)?)?;
     Ok(())
 }
 
@@ -1873,3 +3953,59 @@ fn str_to_addr(addr: &Bound<'_, PyAny>) -> PyResult<XorName> {
     crate::client::address::str_to_addr(&addr)
         .map_err(|e| PyValueError::new_err(format!("`addr` has invalid format: {e:?}")))
 }
+
+// Helper function to convert (hex address, size) pairs to the content addresses to quote.
+fn parse_content_addrs(content_addrs: Vec<(String, usize)>) -> PyResult<Vec<(XorName, usize)>> {
+    content_addrs
+        .into_iter()
+        .map(|(addr, size)| {
+            crate::client::address::str_to_addr(&addr)
+                .map(|addr| (addr, size))
+                .map_err(|e| PyValueError::new_err(format!("`addr` has invalid format: {e:?}")))
+        })
+        .collect()
+}
+
+fn quorum_to_str(quorum: &ResponseQuorum) -> String {
+    match quorum {
+        ResponseQuorum::One => "one".to_string(),
+        ResponseQuorum::Majority => "majority".to_string(),
+        ResponseQuorum::All => "all".to_string(),
+        ResponseQuorum::N(n) => n.to_string(),
+    }
+}
+
+fn str_to_quorum(quorum: &str) -> PyResult<ResponseQuorum> {
+    ResponseQuorum::from_str(quorum)
+        .map_err(|e| PyValueError::new_err(format!("`quorum` invalid: {e}")))
+}
+
+fn retry_to_str(retry: &RetryStrategy) -> String {
+    match retry {
+        RetryStrategy::None => "none".to_string(),
+        RetryStrategy::Quick => "quick".to_string(),
+        RetryStrategy::Balanced => "balanced".to_string(),
+        RetryStrategy::Persistent => "persistent".to_string(),
+        RetryStrategy::N(n) => n.to_string(),
+    }
+}
+
+fn str_to_retry(retry: &str) -> PyResult<RetryStrategy> {
+    match retry {
+        "none" => Ok(RetryStrategy::None),
+        "quick" => Ok(RetryStrategy::Quick),
+        "balanced" => Ok(RetryStrategy::Balanced),
+        "persistent" => Ok(RetryStrategy::Persistent),
+        _ => retry
+            .parse()
+            .ok()
+            .and_then(std::num::NonZeroUsize::new)
+            .map(RetryStrategy::N)
+            .ok_or_else(|| PyValueError::new_err(format!("`retry` invalid: {retry}"))),
+    }
+}
+
+fn secs_to_duration(secs: f64) -> PyResult<Duration> {
+    Duration::try_from_secs_f64(secs)
+        .map_err(|e| PyValueError::new_err(format!("Invalid interval: {e}")))
+}
@@ -0,0 +1,97 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use ant_logging::LogBuilder;
+use autonomi::blob::BlobError;
+use autonomi::client::payment::PaymentOption;
+use autonomi::scratchpad::Scratchpad;
+use autonomi::{AttoTokens, Bytes, Client};
+use eyre::Result;
+use serial_test::serial;
+use test_utils::evm::get_funded_wallet;
+
+#[tokio::test]
+#[serial]
+async fn blob_create_update_and_get() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("blob", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+    let key = bls::SecretKey::random();
+
+    // a blob spanning three scratchpads
+    let mut content = vec![0u8; 2 * Scratchpad::MAX_SIZE];
+    content.extend_from_slice(b"Massive Array of Internet Disks");
+    let content = Bytes::from(content);
+
+    let cost = client.blob_cost(&key, &content).await?;
+    println!("blob cost: {cost}");
+
+    let (create_cost, _addr) = client
+        .blob_create(&key, 42, content.clone(), PaymentOption::from(&wallet))
+        .await?;
+    println!("blob create cost: {create_cost}");
+
+    // wait for the scratchpads to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    let (got, content_type) = client.blob_get(&key).await?;
+    assert_eq!(got, content);
+    assert_eq!(content_type, 42);
+
+    // creating it again must fail
+    let res = client
+        .blob_create(&key, 42, content.clone(), PaymentOption::from(&wallet))
+        .await;
+    assert!(matches!(res, Err(BlobError::BlobAlreadyExists(_))));
+
+    // changing the tail only stores a new last segment
+    let mut content2 = content.to_vec();
+    let len = content2.len();
+    content2[len - 1] = b'!';
+    let content2 = Bytes::from(content2);
+    let estimated_cost = client.blob_cost(&key, &content2).await?;
+    assert_ne!(estimated_cost, AttoTokens::zero());
+    let update_cost = client
+        .blob_update(&key, content2.clone(), PaymentOption::from(&wallet))
+        .await?;
+    assert_eq!(update_cost, estimated_cost);
+
+    // wait for the scratchpads to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    let (got, _) = client.blob_get(&key).await?;
+    assert_eq!(got, content2);
+
+    // going back to content that is already stored is free
+    assert_eq!(client.blob_cost(&key, &content).await?, AttoTokens::zero());
+    let update_cost = client
+        .blob_update(&key, content.clone(), PaymentOption::from(&wallet))
+        .await?;
+    assert_eq!(update_cost, AttoTokens::zero());
+
+    // wait for the scratchpads to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    let (got, _) = client.blob_get(&key).await?;
+    assert_eq!(got, content);
+
+    // shrink the blob
+    let content3 = Bytes::from("Secure Access For Everyone");
+    client
+        .blob_update(&key, content3.clone(), PaymentOption::from(&wallet))
+        .await?;
+
+    // wait for the scratchpads to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    let (got, _) = client.blob_get(&key).await?;
+    assert_eq!(got, content3);
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use ant_logging::LogBuilder;
+use autonomi::client::payment::PaymentOption;
+use autonomi::map::MapError;
+use autonomi::{Bytes, Client};
+use eyre::Result;
+use serial_test::serial;
+use test_utils::evm::get_funded_wallet;
+
+#[tokio::test]
+#[serial]
+async fn map_insert_remove_and_iter() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("map", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+    let main_key = bls::SecretKey::random();
+    let key = Client::map_key_from_name(&main_key, "index");
+
+    let (cost, _addr) = client
+        .map_create(&key, PaymentOption::from(&wallet))
+        .await?;
+    println!("map create cost: {cost}");
+
+    // wait for the map root to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    // a map can only be created once
+    let res = client.map_create(&key, PaymentOption::from(&wallet)).await;
+    assert!(matches!(res, Err(MapError::AlreadyExists(_))));
+
+    for (k, v) in [("apple", "red"), ("pear", "green"), ("plum", "purple")] {
+        let cost = client
+            .map_insert(&key, k, Bytes::from(v), PaymentOption::from(&wallet))
+            .await?;
+        println!("map insert {k} cost: {cost}");
+    }
+
+    // wait for the pages to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    assert_eq!(
+        client.map_get(&key, "pear").await?,
+        Some(Bytes::from("green"))
+    );
+    assert_eq!(client.map_get(&key, "cherry").await?, None);
+
+    client
+        .map_insert(
+            &key,
+            "apple",
+            Bytes::from("yellow"),
+            PaymentOption::from(&wallet),
+        )
+        .await?;
+    client.map_remove(&key, "pear").await?;
+
+    // wait for the pages to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    let entries: Vec<_> = client.map_iter(&key).await?.collect();
+    assert_eq!(
+        entries,
+        vec![
+            ("apple".to_string(), Bytes::from("yellow")),
+            ("plum".to_string(), Bytes::from("purple")),
+        ]
+    );
+
+    Ok(())
+}
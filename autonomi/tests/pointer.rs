@@ -11,7 +11,7 @@ use autonomi::client::payment::PaymentOption;
 use autonomi::AttoTokens;
 use autonomi::{
     chunk::ChunkAddress,
-    client::pointer::{Pointer, PointerTarget},
+    client::pointer::{Pointer, PointerError, PointerTarget},
     Client,
 };
 use eyre::Result;
@@ -116,3 +116,49 @@ async fn pointer_put() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+#[serial]
+async fn pointer_update_if() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("pointer", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+
+    let key = bls::SecretKey::random();
+    let target =
+        PointerTarget::ChunkAddress(ChunkAddress::new(XorName::random(&mut rand::thread_rng())));
+
+    let payment_option = PaymentOption::from(&wallet);
+    let (_cost, addr) = client.pointer_create(&key, target, payment_option).await?;
+
+    // wait for the pointer to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    // update from the counter we know about
+    let target1 = PointerTarget::PointerAddress(addr);
+    client.pointer_update_if(&key, target1.clone(), 0).await?;
+
+    // wait for the pointer to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    // a second writer still expecting counter 0 must be told about the newer pointer
+    let stale_target =
+        PointerTarget::ChunkAddress(ChunkAddress::new(XorName::random(&mut rand::thread_rng())));
+    let res = client.pointer_update_if(&key, stale_target, 0).await;
+    match res {
+        Err(PointerError::Conflict {
+            expected_counter,
+            current,
+        }) => {
+            assert_eq!(expected_counter, 0);
+            assert_eq!(*current, Pointer::new(&key, 1, target1.clone()));
+        }
+        other => panic!("Expected a conflict, got {other:?}"),
+    }
+
+    // the stale write did not overwrite the pointer
+    let got = client.pointer_get(&addr).await?;
+    assert_eq!(got, Pointer::new(&key, 1, target1));
+    Ok(())
+}
@@ -0,0 +1,181 @@
+"""Tests of the client against a local network.
+
+Start a local network with an EVM testnet first, see the root README. The wallet paying for
+uploads can be set with the `SECRET_KEY` environment variable, and defaults to the deployer
+wallet of the local EVM testnet.
+"""
+
+import asyncio
+import os
+
+import pytest
+from autonomi_client import (
+    Chunk,
+    Client,
+    DataTypes,
+    Network,
+    PaymentOption,
+    PointerTarget,
+    SecretKey,
+    Wallet,
+    WatchAddress,
+    WatchConfig,
+    encrypt_data,
+    receipt_from_store_quotes,
+)
+
+DEFAULT_SECRET_KEY = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"
+
+
+@pytest.fixture(scope="session")
+def client():
+    return asyncio.run(Client.init_local())
+
+
+@pytest.fixture(scope="session")
+def wallet():
+    key = os.environ.get("SECRET_KEY", DEFAULT_SECRET_KEY)
+    return Wallet.new_from_private_key(Network(True), key)
+
+
+@pytest.fixture(scope="session")
+def payment(wallet):
+    return PaymentOption.wallet(wallet)
+
+
+def test_data_with_client_events(wallet, payment):
+    async def run():
+        client = await Client.init_local()
+        events = client.enable_client_events()
+
+        data = os.urandom(4096)
+        _cost, data_map = await client.data_put(data, payment)
+        assert await client.data_get(data_map) == data
+
+        event = await events.recv()
+        summary = event.upload_summary
+        assert summary is not None
+        assert summary.records_paid + summary.records_already_paid > 0
+        assert int(summary.tokens_spent) >= 0
+
+    asyncio.run(run())
+
+
+def test_external_signer(client, wallet):
+    async def run():
+        data_map, chunks = encrypt_data(os.urandom(10_000))
+        all_chunks = [data_map, *chunks]
+        content_addrs = [(chunk.address().hex, chunk.size()) for chunk in all_chunks]
+
+        quotes, payments, free = await client.get_quotes_for_content_addresses(
+            DataTypes.Chunk, content_addrs
+        )
+        assert len(quotes) + len(free) == len(all_chunks)
+
+        tx_hashes = await wallet.pay_for_quotes(payments)
+        assert set(tx_hashes) == {payment.quote_hash for payment in payments}
+
+        receipt = receipt_from_store_quotes(quotes)
+        failed = await client.upload_chunks_with_retries(all_chunks, receipt)
+        assert failed == []
+
+        data = await client.data_get_public(data_map.address().hex)
+        assert len(data) == 10_000
+
+        # The same chunks can be uploaded with `data_put` by paying with the receipt.
+        _cost, _data_map = await client.data_put(data, PaymentOption.receipt(receipt))
+
+    asyncio.run(run())
+
+
+def test_register_history(client, payment):
+    async def run():
+        owner = Client.register_key_from_name(SecretKey(), "history")
+        _cost, addr = await client.register_create_bytes(owner, b"first", payment)
+        await client.register_update_bytes(owner, b"second value, longer than 32 bytes", payment)
+        assert await client.register_get_bytes(addr) == b"second value, longer than 32 bytes"
+
+        values = [value async for value in client.register_history(addr)]
+        assert len(values) == 2
+
+        history = client.register_history(addr)
+        assert await history.collect_bytes() == [
+            b"first",
+            b"second value, longer than 32 bytes",
+        ]
+
+    asyncio.run(run())
+
+
+def test_pointer_update_if(client, payment):
+    async def run():
+        owner = SecretKey()
+        first = PointerTarget.from_chunk_address(Chunk(b"first").address())
+        second = PointerTarget.from_chunk_address(Chunk(b"second").address())
+
+        _cost, addr = await client.pointer_create(owner, first, payment)
+        await client.pointer_update_if(owner, second, 0)
+
+        pointer = await client.pointer_get(addr)
+        assert pointer.counter == 1
+        assert pointer.target.hex == second.hex
+
+        with pytest.raises(RuntimeError):
+            await client.pointer_update_if(owner, first, 0)
+
+    asyncio.run(run())
+
+
+def test_shared_scratchpad(client, payment):
+    async def run():
+        owner = SecretKey()
+        reader = SecretKey()
+        _cost, addr = await client.scratchpad_create_shared(
+            owner, 42, b"shared", [reader.public_key()], payment
+        )
+
+        scratchpad = await client.scratchpad_get(addr)
+        assert scratchpad.decrypt_data(reader) == b"shared"
+
+        other = SecretKey()
+        await client.scratchpad_add_readers(owner, [other.public_key()])
+        scratchpad = await client.scratchpad_get(addr)
+        assert scratchpad.decrypt_data(other) == b"shared"
+
+    asyncio.run(run())
+
+
+def test_blob(client, payment):
+    async def run():
+        owner = SecretKey()
+        data = os.urandom(8192)
+        _cost, _addr = await client.blob_create(owner, 7, data, payment)
+        assert await client.blob_get(owner) == (data, 7)
+
+        await client.blob_update(owner, b"updated", payment)
+        assert await client.blob_get(owner) == (b"updated", 7)
+
+    asyncio.run(run())
+
+
+def test_watch(client, payment):
+    async def run():
+        owner = SecretKey()
+        target = PointerTarget.from_chunk_address(Chunk(b"watched").address())
+        _cost, addr = await client.pointer_create(owner, target, payment)
+
+        config = WatchConfig(min_interval=0.5, max_interval=2.0)
+        watcher = client.watch_with_config(WatchAddress.pointer(addr), config)
+        update = await asyncio.wait_for(watcher.__anext__(), timeout=60)
+        assert update.pointer.target.hex == target.hex
+
+        await client.pointer_update(owner, PointerTarget.from_chunk_address(Chunk(b"new").address()))
+        update = await asyncio.wait_for(watcher.__anext__(), timeout=60)
+        assert update.pointer.counter == 1
+
+    asyncio.run(run())
+
+
+def test_evm_network(client):
+    wallet = Wallet.new_from_private_key(client.evm_network(), DEFAULT_SECRET_KEY)
+    assert wallet.network() is not None
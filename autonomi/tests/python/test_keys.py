@@ -0,0 +1,151 @@
+"""Tests of the types that do not need a network."""
+
+import pytest
+from autonomi_client import (
+    Client,
+    ClientConfig,
+    ClientOperatingStrategy,
+    DataTypes,
+    DerivationIndex,
+    DerivedPubkey,
+    GraphEntry,
+    MainPubkey,
+    MainSecretKey,
+    Pointer,
+    PointerTarget,
+    PublicKey,
+    QuotePayment,
+    Scratchpad,
+    SecretKey,
+    Signature,
+    UserData,
+    WatchConfig,
+    app_name_to_vault_content_type,
+    encrypt_data,
+)
+
+
+def test_key_derivation():
+    main_key = MainSecretKey.random()
+    index = DerivationIndex.random()
+
+    derived = main_key.derive_key(index)
+    assert derived.public_key().to_hex() == main_key.public_key().derive_key(index).to_hex()
+    assert DerivationIndex.from_bytes(index.to_bytes()).to_bytes() == index.to_bytes()
+
+    other = main_key.derive_key(DerivationIndex.random())
+    assert other.public_key().to_hex() != derived.public_key().to_hex()
+
+
+def test_main_key_from_secret_key():
+    key = SecretKey()
+    main_key = MainSecretKey(key)
+    assert main_key.to_secret_key().to_hex() == key.to_hex()
+    assert main_key.public_key().to_public_key().to_hex() == key.public_key().to_hex()
+    assert MainPubkey(key.public_key()).to_hex() == main_key.public_key().to_hex()
+
+
+def test_signatures():
+    main_key = MainSecretKey.random()
+    signature = main_key.sign(b"message")
+    assert main_key.public_key().verify(signature, b"message")
+    assert not main_key.public_key().verify(signature, b"other message")
+
+    derived = main_key.random_derived_key()
+    signature = Signature.from_bytes(derived.sign(b"message").to_bytes())
+    pubkey = DerivedPubkey.from_hex(derived.public_key().to_hex())
+    assert pubkey.verify(signature, b"message")
+
+    with pytest.raises(ValueError):
+        Signature.from_bytes(b"too short")
+
+
+def test_strategy():
+    config = ClientConfig.new()
+    strategy = config.strategy
+    assert strategy.chunks.get_quorum == "one"
+    assert strategy.pointer.put_quorum == "majority"
+
+    chunks = strategy.chunks
+    chunks.put_quorum = "3"
+    chunks.put_retry = "persistent"
+    strategy.chunks = chunks
+    config.strategy = strategy
+    assert config.strategy.chunks.put_quorum == "3"
+    assert config.strategy.chunks.put_retry == "persistent"
+
+    with pytest.raises(ValueError):
+        chunks.get_quorum = "0"
+    with pytest.raises(ValueError):
+        chunks.get_retry = "sometimes"
+
+    assert ClientOperatingStrategy().scratchpad.verification_retry == "quick"
+
+
+def test_graph_entry():
+    owner = SecretKey()
+    descendant = SecretKey().public_key()
+    entry = GraphEntry(owner, [], bytes(32), [(descendant, b"\x01" * 32)])
+
+    assert entry.owner.to_hex() == owner.public_key().to_hex()
+    assert entry.content == bytes(32)
+    assert entry.descendants[0][0].to_hex() == descendant.to_hex()
+    assert entry.descendants[0][1] == b"\x01" * 32
+    assert entry.verify_signature()
+
+
+def test_scratchpads():
+    owner = SecretKey()
+    scratchpad = Scratchpad(owner, 42, b"secret", 0)
+    assert scratchpad.address() == owner.public_key().to_hex()
+    assert scratchpad.data_encoding == 42
+    assert scratchpad.decrypt_data(owner) == b"secret"
+    assert scratchpad.shared_readers() is None
+    Client.scratchpad_verify(scratchpad)
+
+    reader = SecretKey()
+    shared = Scratchpad.new_shared(owner, 42, b"shared", 1, [reader.public_key()])
+    assert shared.decrypt_data(reader) == b"shared"
+    with pytest.raises(ValueError):
+        shared.decrypt_data(SecretKey())
+
+
+def test_pointer():
+    owner = SecretKey()
+    pointer = Pointer(owner, 7, PointerTarget.from_hex("ab" * 32))
+    assert pointer.counter == 7
+    assert pointer.owner.to_hex() == owner.public_key().to_hex()
+    Client.pointer_verify(pointer)
+
+
+def test_user_data():
+    user_data = UserData()
+    assert user_data.add_file_archive("ab" * 32, "photos") is None
+    assert user_data.add_file_archive("ab" * 32) == "photos"
+    assert user_data.file_archives() == [("ab" * 32, "")]
+    assert user_data.remove_file_archive("ab" * 32) == ""
+    assert user_data.file_archives() == []
+
+
+def test_external_signer_helpers():
+    data_map, chunks = encrypt_data(b"x" * 10_000)
+    assert len(chunks) >= 3
+    assert data_map.size() > 0
+    assert all(chunk.address().hex != data_map.address().hex for chunk in chunks)
+
+    payment = QuotePayment("0x" + "aa" * 32, "0x" + "bb" * 20, "42")
+    assert payment.amount == "42"
+    assert payment.quote_hash == "0x" + "aa" * 32
+    with pytest.raises(ValueError):
+        QuotePayment("0x", "0x" + "bb" * 20, "42")
+
+    assert DataTypes.Chunk != DataTypes.Pointer
+
+
+def test_misc():
+    assert app_name_to_vault_content_type("app") == app_name_to_vault_content_type("app")
+    assert app_name_to_vault_content_type("app") != app_name_to_vault_content_type("other")
+    assert WatchConfig(min_interval=0.5).min_interval == 0.5
+    with pytest.raises(ValueError):
+        WatchConfig(max_interval=-1)
+    assert PublicKey.from_hex(SecretKey().public_key().to_hex())
@@ -9,9 +9,10 @@
 use ant_logging::LogBuilder;
 use autonomi::{
     client::{payment::PaymentOption, register::RegisterAddress},
+    files::archive_public::ArchiveAddr,
     graph::GraphError,
     register::RegisterError,
-    Client,
+    Bytes, Client,
 };
 use eyre::Result;
 use serial_test::serial;
@@ -162,3 +163,91 @@ async fn test_register_history() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+#[serial]
+async fn registers_with_large_and_typed_values() -> Result<()> {
+    let _log_appender_guard =
+        LogBuilder::init_single_threaded_tokio_test("registers_large_values", false);
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+    let main_key = bls::SecretKey::random();
+
+    // a value too large to be stored inline
+    let register_key = Client::register_key_from_name(&main_key, "large");
+    let json = Bytes::from(format!("{{\"data\": \"{}\"}}", "a".repeat(2000)));
+    let (_cost, addr) = client
+        .register_create_bytes(&register_key, json.clone(), PaymentOption::from(&wallet))
+        .await?;
+
+    // wait for the register to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    assert_eq!(client.register_get_bytes(&addr).await?, json);
+
+    // a value small enough to be stored inline
+    let small = Bytes::from("https://autonomi.com");
+    client
+        .register_update_bytes(&register_key, small.clone(), PaymentOption::from(&wallet))
+        .await?;
+
+    // wait for the register to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    assert_eq!(client.register_get_bytes(&addr).await?, small);
+    let history = client.register_history(&addr).collect_bytes().await?;
+    assert_eq!(history, vec![json, small]);
+
+    // typed values
+    let register_key = Client::register_key_from_name(&main_key, "archive");
+    let archive = ArchiveAddr::random(&mut rand::thread_rng());
+    let (_cost, addr) = client
+        .register_create_typed(&register_key, &archive, PaymentOption::from(&wallet))
+        .await?;
+
+    // wait for the register to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    let got: ArchiveAddr = client.register_get_typed(&addr).await?;
+    assert_eq!(got, archive);
+    let res = client.register_get_typed::<String>(&addr).await;
+    assert!(matches!(res, Err(RegisterError::InvalidTypedValue(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn registers_fork_resolution_without_fork() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("registers_fork", false);
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+    let main_key = bls::SecretKey::random();
+
+    let register_key = Client::register_key_from_name(&main_key, "register1");
+    let content = Client::register_value_from_bytes(b"Hello, World!")?;
+    let (cost, addr) = client
+        .register_create(&register_key, content, PaymentOption::from(&wallet))
+        .await?;
+    println!("register created: {cost} {addr:?}");
+
+    // wait for the register to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    // a register that is not forked has a single head
+    let heads = client.register_fork_heads(&addr).await?;
+    assert_eq!(heads, vec![content]);
+
+    // so there is nothing to resolve
+    let res = client
+        .register_resolve_fork(
+            &register_key,
+            |heads| heads[0],
+            PaymentOption::from(&wallet),
+        )
+        .await;
+    println!("resolving a register that is not forked should fail: {res:?}");
+    assert!(matches!(res.unwrap_err(), RegisterError::NotForked));
+
+    Ok(())
+}
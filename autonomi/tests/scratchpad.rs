@@ -211,3 +211,125 @@ async fn scratchpad_errors() -> Result<()> {
     assert_eq!(got_content, content);
     Ok(())
 }
+
+#[tokio::test]
+#[serial]
+async fn scratchpad_update_if() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("scratchpad", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+
+    let key = bls::SecretKey::random();
+    let content = Bytes::from("v0");
+    let content_type = 42;
+
+    let payment_option = PaymentOption::from(&wallet);
+    let (_cost, addr) = client
+        .scratchpad_create(&key, content_type, &content, payment_option)
+        .await?;
+
+    // wait for the scratchpad to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    // update from the counter we know about
+    let content1 = Bytes::from("v1");
+    client
+        .scratchpad_update_if(&key, content_type, &content1, 0)
+        .await?;
+
+    // wait for the scratchpad to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    // a second writer still expecting counter 0 must be told about the newer scratchpad
+    let stale_content = Bytes::from("stale");
+    let res = client
+        .scratchpad_update_if(&key, content_type, &stale_content, 0)
+        .await;
+    match res {
+        Err(ScratchpadError::Conflict {
+            expected_counter,
+            current,
+        }) => {
+            assert_eq!(expected_counter, 0);
+            assert_eq!(current.counter(), 1);
+            assert_eq!(current.decrypt_data(&key), Ok(content1.clone()));
+        }
+        other => panic!("Expected a conflict, got {other:?}"),
+    }
+
+    // the stale write did not overwrite the scratchpad
+    let got = client.scratchpad_get(&addr).await?;
+    assert_eq!(got.counter(), 1);
+    assert_eq!(got.decrypt_data(&key), Ok(content1));
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn scratchpad_shared_readers() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("scratchpad", false);
+
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+
+    let owner = bls::SecretKey::random();
+    let reader = bls::SecretKey::random();
+    let late_reader = bls::SecretKey::random();
+    let content = Bytes::from("shared document");
+    let content_type = 42;
+
+    let payment_option = PaymentOption::from(&wallet);
+    let (_cost, addr) = client
+        .scratchpad_create_shared(
+            &owner,
+            content_type,
+            &content,
+            &[reader.public_key()],
+            payment_option,
+        )
+        .await?;
+
+    // wait for the scratchpad to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    let got = client.scratchpad_get(&addr).await?;
+    assert_eq!(got.decrypt_data(&owner), Ok(content.clone()));
+    assert_eq!(got.decrypt_data(&reader), Ok(content.clone()));
+    assert_ne!(got.decrypt_data(&late_reader), Ok(content.clone()));
+
+    // share with another reader and revoke the first one
+    client
+        .scratchpad_add_readers(&owner, &[late_reader.public_key()])
+        .await?;
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+    client
+        .scratchpad_revoke_readers(&owner, &[reader.public_key()])
+        .await?;
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    let got = client.scratchpad_get(&addr).await?;
+    assert_eq!(got.counter(), 2);
+    assert_eq!(
+        got.shared_readers(),
+        Some(vec![owner.public_key(), late_reader.public_key()])
+    );
+    assert_eq!(got.decrypt_data(&late_reader), Ok(content.clone()));
+    assert_ne!(got.decrypt_data(&reader), Ok(content));
+
+    // a plain update keeps the current readers
+    let new_content = Bytes::from("updated shared document");
+    client
+        .scratchpad_update(&owner, content_type, &new_content)
+        .await?;
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    let got = client.scratchpad_get(&addr).await?;
+    assert_eq!(got.counter(), 3);
+    assert_eq!(
+        got.shared_readers(),
+        Some(vec![owner.public_key(), late_reader.public_key()])
+    );
+    assert_eq!(got.decrypt_data(&late_reader), Ok(new_content));
+    Ok(())
+}
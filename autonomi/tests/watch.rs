@@ -0,0 +1,107 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use std::time::Duration;
+
+use ant_logging::LogBuilder;
+use autonomi::{
+    chunk::ChunkAddress,
+    client::payment::PaymentOption,
+    client::pointer::PointerTarget,
+    client::register::RegisterAddress,
+    client::watch::{WatchConfig, WatchUpdate},
+    Client,
+};
+use eyre::Result;
+use futures::StreamExt;
+use serial_test::serial;
+use test_utils::evm::get_funded_wallet;
+use xor_name::XorName;
+
+fn test_config() -> WatchConfig {
+    WatchConfig {
+        min_interval: Duration::from_secs(1),
+        max_interval: Duration::from_secs(2),
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn watch_pointer() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("watch", false);
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+
+    let key = bls::SecretKey::random();
+    let target =
+        PointerTarget::ChunkAddress(ChunkAddress::new(XorName::random(&mut rand::thread_rng())));
+    let (cost, addr) = client
+        .pointer_create(&key, target.clone(), PaymentOption::from(&wallet))
+        .await?;
+    println!("pointer created: {cost} {addr:?}");
+
+    // wait for the pointer to be replicated
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    // the current version comes first
+    let mut updates = std::pin::pin!(client.watch_with_config(addr, test_config()));
+    let first = updates.next().await.expect("watch streams never end")?;
+    match first {
+        WatchUpdate::Pointer(pointer) => {
+            assert_eq!(pointer.counter(), 0);
+            assert_eq!(pointer.target(), &target);
+        }
+        other => panic!("expected a pointer, got {other:?}"),
+    }
+
+    // then the update
+    let new_target = PointerTarget::PointerAddress(addr);
+    client.pointer_update(&key, new_target.clone()).await?;
+    let next = tokio::time::timeout(Duration::from_secs(60), updates.next())
+        .await?
+        .expect("watch streams never end")?;
+    match next {
+        WatchUpdate::Pointer(pointer) => {
+            assert_eq!(pointer.counter(), 1);
+            assert_eq!(pointer.target(), &new_target);
+        }
+        other => panic!("expected a pointer, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn watch_register_created_later() -> Result<()> {
+    let _log_appender_guard = LogBuilder::init_single_threaded_tokio_test("watch2", false);
+    let client = Client::init_local().await?;
+    let wallet = get_funded_wallet();
+
+    let register_key = Client::register_key_from_name(&bls::SecretKey::random(), "register1");
+    let addr = RegisterAddress::new(register_key.public_key());
+
+    // nothing is yielded until the register exists
+    let mut updates = std::pin::pin!(client.watch_with_config(addr, test_config()));
+    let res = tokio::time::timeout(Duration::from_secs(3), updates.next()).await;
+    assert!(res.is_err(), "nothing should be yielded yet: {res:?}");
+
+    let content = Client::register_value_from_bytes(b"Hello, World!")?;
+    client
+        .register_create(&register_key, content, PaymentOption::from(&wallet))
+        .await?;
+    let next = tokio::time::timeout(Duration::from_secs(60), updates.next())
+        .await?
+        .expect("watch streams never end")?;
+    match next {
+        WatchUpdate::Register { values, .. } => assert_eq!(values, vec![content]),
+        other => panic!("expected a register, got {other:?}"),
+    }
+
+    Ok(())
+}
@@ -3,6 +3,9 @@ use crate::contract::payment_vault::handler::PaymentVaultHandler;
 use crate::quoting_metrics::QuotingMetrics;
 use crate::utils::http_provider;
 use crate::Network;
+use alloy::providers::Provider;
+use alloy::rpc::types::Filter;
+use alloy::sol_types::SolEvent;
 
 pub mod error;
 pub mod handler;
@@ -28,10 +31,21 @@ pub async fn verify_data_payment(
     owned_quote_hashes: Vec<QuoteHash>,
     payment: Vec<(QuoteHash, QuotingMetrics, Address)>,
 ) -> Result<Amount, error::Error> {
+    let amounts = verify_data_payment_by_quote(network, owned_quote_hashes, payment).await?;
+    Ok(amounts.into_iter().map(|(_, amount)| amount).sum())
+}
+
+/// Helper function to verify whether a data payment is valid.
+/// Returns the amount paid to each of the owned quote hashes that is part of the payment.
+pub async fn verify_data_payment_by_quote(
+    network: &Network,
+    owned_quote_hashes: Vec<QuoteHash>,
+    payment: Vec<(QuoteHash, QuotingMetrics, Address)>,
+) -> Result<Vec<(QuoteHash, Amount)>, error::Error> {
     let provider = http_provider(network.rpc_url().clone());
     let payment_vault = PaymentVaultHandler::new(*network.data_payments_address(), provider);
 
-    let mut amount = Amount::ZERO;
+    let mut amounts = vec![];
 
     let payment_verifications: Vec<_> = payment
         .into_iter()
@@ -47,9 +61,41 @@ pub async fn verify_data_payment(
         }
 
         if owned_quote_hashes.contains(&payment_verification_result.quoteHash) {
-            amount += payment_verification_result.amountPaid;
+            amounts.push((
+                payment_verification_result.quoteHash,
+                payment_verification_result.amountPaid,
+            ));
         }
     }
 
-    Ok(amount)
+    Ok(amounts)
+}
+
+/// Number of blocks searched back from the latest one for the payment of a quote.
+///
+/// Quotes expire after an hour, which this covers for block times down to a quarter second.
+const PAYER_LOOKUP_BLOCKS: u64 = 16_000;
+
+/// Helper function to find the address that paid for a quote, from the transaction that made
+/// the payment. Returns `None` if no recent payment was found for the quote hash.
+pub async fn get_payer_address(
+    network: &Network,
+    quote_hash: QuoteHash,
+) -> Result<Option<Address>, error::Error> {
+    let provider = http_provider(network.rpc_url().clone());
+
+    let latest_block = provider.get_block_number().await?;
+    let filter = Filter::new()
+        .address(*network.data_payments_address())
+        .event_signature(interface::IPaymentVault::DataPaymentMade::SIGNATURE_HASH)
+        .topic3(quote_hash)
+        .from_block(latest_block.saturating_sub(PAYER_LOOKUP_BLOCKS));
+
+    let logs = provider.get_logs(&filter).await?;
+    let Some(tx_hash) = logs.iter().find_map(|log| log.transaction_hash) else {
+        return Ok(None);
+    };
+
+    let tx = provider.get_transaction_by_hash(tx_hash).await?;
+    Ok(tx.map(|tx| tx.from))
 }
@@ -47,21 +47,40 @@ pub enum StatusActions {
     StopNodes,
     StartNodesCompleted,
     StopNodesCompleted,
-    ResetNodesCompleted { trigger_start_node: bool },
+    ResetNodesCompleted {
+        trigger_start_node: bool,
+    },
     UpdateNodesCompleted,
     SuccessfullyDetectedNatStatus,
     ErrorWhileRunningNatDetection,
-    ErrorLoadingNodeRegistry { raw_error: String },
-    ErrorGettingNodeRegistryPath { raw_error: String },
-    ErrorScalingUpNodes { raw_error: String },
-    ErrorStoppingNodes { raw_error: String },
-    ErrorResettingNodes { raw_error: String },
-    ErrorUpdatingNodes { raw_error: String },
+    ErrorLoadingNodeRegistry {
+        raw_error: String,
+    },
+    ErrorGettingNodeRegistryPath {
+        raw_error: String,
+    },
+    ErrorScalingUpNodes {
+        raw_error: String,
+    },
+    ErrorStoppingNodes {
+        raw_error: String,
+    },
+    ErrorResettingNodes {
+        raw_error: String,
+    },
+    ErrorUpdatingNodes {
+        raw_error: String,
+    },
     NodesStatsObtained(NodeStats),
 
     TriggerManageNodes,
     TriggerRewardsAddress,
     TriggerNodeLogs,
+    TriggerNodeDetails,
+    OpenNodeDetails {
+        service_name: String,
+        log_dir_path: PathBuf,
+    },
 
     PreviousTableItem,
     NextTableItem,
@@ -12,6 +12,7 @@ use crate::{
     action::Action,
     components::{
         help::Help,
+        node_details::NodeDetails,
         options::Options,
         popup::{
             change_drive::ChangeDrivePopup, connection_mode::ChangeConnectionModePopUp,
@@ -100,6 +101,8 @@ impl App {
             connection_mode,
             port_from: Some(port_from),
             port_to: Some(port_to),
+            max_client_bandwidth: app_data.max_client_bandwidth,
+            max_replication_bandwidth: app_data.max_replication_bandwidth,
         };
 
         let status = Status::new(status_config).await?;
@@ -113,6 +116,7 @@ impl App {
         )
         .await?;
         let help = Help::new().await?;
+        let node_details = NodeDetails::new()?;
 
         // Popups
         let reset_nodes = ResetNodesPopup::default();
@@ -134,6 +138,8 @@ impl App {
                 connection_mode: Some(connection_mode),
                 port_from: Some(port_from),
                 port_to: Some(port_to),
+                max_client_bandwidth: app_data.max_client_bandwidth,
+                max_replication_bandwidth: app_data.max_replication_bandwidth,
             },
             tick_rate,
             frame_rate,
@@ -142,6 +148,7 @@ impl App {
                 Box::new(status),
                 Box::new(options),
                 Box::new(help),
+                Box::new(node_details),
                 // Popups
                 Box::new(change_drive),
                 Box::new(change_connection_mode),
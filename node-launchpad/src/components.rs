@@ -20,6 +20,7 @@ use crate::{
 pub mod footer;
 pub mod header;
 pub mod help;
+pub mod node_details;
 pub mod options;
 pub mod popup;
 pub mod status;
@@ -44,6 +44,9 @@ impl StatefulWidget for Footer {
             Span::styled("[L] ", command_style),
             Span::styled("Open Logs", Style::default().fg(EUCALYPTUS)),
             Span::styled(" ", Style::default()),
+            Span::styled("[D] ", command_style),
+            Span::styled("Details", Style::default().fg(EUCALYPTUS)),
+            Span::styled(" ", Style::default()),
             Span::styled("[Ctrl+X] ", command_style),
             Span::styled(
                 "Stop All",
@@ -0,0 +1,369 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use super::{
+    header::{Header, SelectedMenuItem},
+    utils::open_logs,
+    Component,
+};
+use crate::{
+    action::{Action, StatusActions},
+    mode::{InputMode, Scene},
+    node_history::{
+        get_node_stats_history_path, read_last_lines, unix_timestamp, NodeStatsHistory,
+        NodeStatsSample,
+    },
+    style::{EUCALYPTUS, GHOST_WHITE, LIGHT_PERIWINKLE, VERY_LIGHT_AZURE},
+    tui::Frame,
+};
+use color_eyre::eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{prelude::*, widgets::*};
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+/// How often the history is written to disk.
+const HISTORY_SAVE_INTERVAL: Duration = Duration::from_secs(60);
+/// The number of log lines shown for the node.
+const LOG_LINES: usize = 100;
+/// How far back from the end of the log file to look for the lines to show.
+const LOG_TAIL_BYTES: u64 = 64 * 1024;
+const LOG_FILE_NAME: &str = "antnode.log";
+
+/// A screen showing the recent history of a single node, along with the tail of its log.
+pub struct NodeDetails {
+    /// Whether the component is active right now, capturing keystrokes + drawing things.
+    active: bool,
+    history: NodeStatsHistory,
+    history_path: PathBuf,
+    history_last_save: Instant,
+    service_name: Option<String>,
+    log_dir_path: Option<PathBuf>,
+    log_lines: Vec<String>,
+}
+
+impl NodeDetails {
+    pub fn new() -> Result<Self> {
+        let history_path = get_node_stats_history_path()?;
+        // A broken history file should not stop the launchpad from starting.
+        let history = NodeStatsHistory::load(&history_path).unwrap_or_else(|err| {
+            error!("Failed to load the node stats history from {history_path:?}: {err:?}");
+            NodeStatsHistory::default()
+        });
+        Ok(Self {
+            active: false,
+            history,
+            history_path,
+            history_last_save: Instant::now(),
+            service_name: None,
+            log_dir_path: None,
+            log_lines: Vec::new(),
+        })
+    }
+
+    fn save_history(&mut self) {
+        self.history_last_save = Instant::now();
+        if let Err(err) = self.history.save(&self.history_path) {
+            error!(
+                "Failed to save the node stats history to {:?}: {err:?}",
+                self.history_path
+            );
+        }
+    }
+
+    fn refresh_log_lines(&mut self) {
+        let Some(log_dir_path) = &self.log_dir_path else {
+            return;
+        };
+        let log_path = log_dir_path.join(LOG_FILE_NAME);
+        match read_last_lines(&log_path, LOG_LINES, LOG_TAIL_BYTES) {
+            Ok(lines) => self.log_lines = lines,
+            Err(err) => {
+                debug!("Could not read the log file at {log_path:?}: {err:?}");
+                self.log_lines = vec![format!("No logs found at {}", log_path.display())];
+            }
+        }
+    }
+
+    fn draw_chart(
+        f: &mut Frame<'_>,
+        area: Rect,
+        title: &str,
+        samples: &[&NodeStatsSample],
+        value: impl Fn(&NodeStatsSample) -> u64,
+        format_value: impl Fn(u64) -> String,
+    ) {
+        let current = samples
+            .last()
+            .map(|sample| format_value(value(sample)))
+            .unwrap_or_else(|| "-".to_string());
+        let block = Block::default()
+            .title(Line::from(vec![
+                Span::styled(
+                    format!(" {title} "),
+                    Style::default().fg(GHOST_WHITE).bold(),
+                ),
+                Span::styled(format!("{current} "), Style::default().fg(LIGHT_PERIWINKLE)),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(VERY_LIGHT_AZURE));
+
+        // Only the most recent samples that fit in the chart are shown.
+        let width = block.inner(area).width as usize;
+        let skip = samples.len().saturating_sub(width);
+        let data: Vec<u64> = samples.iter().skip(skip).map(|s| value(s)).collect();
+
+        let sparkline = Sparkline::default()
+            .block(block)
+            .data(data)
+            .style(Style::default().fg(EUCALYPTUS));
+        f.render_widget(sparkline, area);
+    }
+}
+
+fn format_mbps(bytes_per_second: u64) -> String {
+    format!("{:.2} Mbps", (bytes_per_second * 8) as f64 / 1_000_000.0)
+}
+
+impl Component for NodeDetails {
+    fn handle_key_events(&mut self, key: KeyEvent) -> Result<Vec<Action>> {
+        if !self.active {
+            return Ok(vec![]);
+        }
+        // while in entry mode, keybinds are not captured, so gotta exit entry mode from here
+        let send_back = match key.code {
+            KeyCode::Esc => {
+                debug!("Got Esc, closing the node details.");
+                vec![Action::SwitchScene(Scene::Status)]
+            }
+            KeyCode::Char('l') | KeyCode::Char('L') => {
+                open_logs(self.service_name.clone())?;
+                vec![]
+            }
+            _ => vec![],
+        };
+        Ok(send_back)
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        let send_back = match action {
+            Action::SwitchScene(scene) => match scene {
+                Scene::NodeDetails => {
+                    self.active = true;
+                    Some(Action::SwitchInputMode(InputMode::Entry))
+                }
+                _ => {
+                    self.active = false;
+                    None
+                }
+            },
+            Action::StatusActions(StatusActions::NodesStatsObtained(ref stats)) => {
+                self.history.record(stats, unix_timestamp());
+                if self.history_last_save.elapsed() > HISTORY_SAVE_INTERVAL {
+                    self.save_history();
+                }
+                if self.active {
+                    self.refresh_log_lines();
+                }
+                None
+            }
+            Action::StatusActions(StatusActions::ResetNodesCompleted { .. }) => {
+                debug!("Nodes were reset. Clearing the node stats history.");
+                self.history.clear();
+                self.save_history();
+                None
+            }
+            Action::StatusActions(StatusActions::OpenNodeDetails {
+                service_name,
+                log_dir_path,
+            }) => {
+                debug!("Opening the details of {service_name:?}");
+                self.service_name = Some(service_name);
+                self.log_dir_path = Some(log_dir_path);
+                self.refresh_log_lines();
+                Some(Action::SwitchScene(Scene::NodeDetails))
+            }
+            Action::Quit => {
+                self.save_history();
+                None
+            }
+            _ => None,
+        };
+        Ok(send_back)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        if !self.active {
+            return Ok(());
+        }
+        let Some(service_name) = self.service_name.clone() else {
+            return Ok(());
+        };
+
+        let layout = Layout::new(
+            Direction::Vertical,
+            [
+                // Header
+                Constraint::Length(1),
+                // Charts
+                Constraint::Length(14),
+                // Logs
+                Constraint::Min(3),
+                // Footer
+                Constraint::Length(3),
+            ],
+        )
+        .split(area);
+
+        // ==== Header =====
+
+        let header = Header::new();
+        f.render_stateful_widget(header, layout[0], &mut SelectedMenuItem::Status);
+
+        // ==== Charts =====
+
+        let samples: Vec<&NodeStatsSample> = self
+            .history
+            .samples(&service_name)
+            .map(|samples| samples.iter().collect())
+            .unwrap_or_default();
+
+        let charts_block = Block::default()
+            .title(Line::from(vec![
+                Span::styled(
+                    format!(" {service_name} "),
+                    Style::default().fg(GHOST_WHITE).bold(),
+                ),
+                Span::styled(
+                    format!("({} samples) ", samples.len()),
+                    Style::default().fg(LIGHT_PERIWINKLE),
+                ),
+            ]))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(EUCALYPTUS));
+        let charts_area = charts_block.inner(layout[1]);
+        f.render_widget(charts_block, layout[1]);
+
+        if samples.is_empty() {
+            f.render_widget(
+                Paragraph::new("No stats have been recorded for this node yet.")
+                    .fg(LIGHT_PERIWINKLE)
+                    .block(Block::default().padding(Padding::uniform(1))),
+                charts_area,
+            );
+        } else {
+            let rows =
+                Layout::new(Direction::Vertical, [Constraint::Ratio(1, 3); 3]).split(charts_area);
+            let cells: Vec<_> = rows
+                .iter()
+                .flat_map(|row| {
+                    Layout::new(Direction::Horizontal, [Constraint::Ratio(1, 2); 2])
+                        .split(*row)
+                        .to_vec()
+                })
+                .collect();
+
+            Self::draw_chart(
+                f,
+                cells[0],
+                "Attos",
+                &samples,
+                |s| s.rewards_wallet_balance as u64,
+                |v| v.to_string(),
+            );
+            Self::draw_chart(
+                f,
+                cells[1],
+                "Memory",
+                &samples,
+                |s| s.memory_usage_mb as u64,
+                |v| format!("{v} MB"),
+            );
+            Self::draw_chart(
+                f,
+                cells[2],
+                "Download",
+                &samples,
+                |s| s.bandwidth_inbound_rate as u64,
+                format_mbps,
+            );
+            Self::draw_chart(
+                f,
+                cells[3],
+                "Upload",
+                &samples,
+                |s| s.bandwidth_outbound_rate as u64,
+                format_mbps,
+            );
+            Self::draw_chart(
+                f,
+                cells[4],
+                "Records",
+                &samples,
+                |s| s.records as u64,
+                |v| v.to_string(),
+            );
+            Self::draw_chart(
+                f,
+                cells[5],
+                "Peers",
+                &samples,
+                |s| s.peers as u64,
+                |v| v.to_string(),
+            );
+        }
+
+        // ==== Logs =====
+
+        let logs_block = Block::default()
+            .title(" Recent Logs ")
+            .bold()
+            .title_style(Style::default().fg(GHOST_WHITE))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(EUCALYPTUS))
+            .padding(Padding::horizontal(1));
+        // Show as many of the most recent lines as fit.
+        let height = logs_block.inner(layout[2]).height as usize;
+        let skip = self.log_lines.len().saturating_sub(height);
+        let log_lines: Vec<Line> = self
+            .log_lines
+            .iter()
+            .skip(skip)
+            .map(|line| Line::raw(line.as_str()))
+            .collect();
+        f.render_widget(
+            Paragraph::new(log_lines)
+                .fg(LIGHT_PERIWINKLE)
+                .block(logs_block),
+            layout[2],
+        );
+
+        // ==== Footer =====
+
+        let commands = Line::from(vec![
+            Span::styled("[Esc] ", Style::default().fg(GHOST_WHITE)),
+            Span::styled("Back", Style::default().fg(EUCALYPTUS)),
+            Span::styled(" ", Style::default()),
+            Span::styled("[L] ", Style::default().fg(GHOST_WHITE)),
+            Span::styled("Open Logs", Style::default().fg(EUCALYPTUS)),
+        ]);
+        f.render_widget(
+            Paragraph::new(commands).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(EUCALYPTUS))
+                    .padding(Padding::horizontal(1)),
+            ),
+            layout[3],
+        );
+
+        Ok(())
+    }
+}
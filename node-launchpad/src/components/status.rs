@@ -106,6 +106,9 @@ pub struct Status<'a> {
     port_from: Option<u32>,
     // Port to
     port_to: Option<u32>,
+    // Bandwidth limits, in bytes per second
+    max_client_bandwidth: Option<u64>,
+    max_replication_bandwidth: Option<u64>,
     error_popup: Option<ErrorPopup>,
 }
 
@@ -122,6 +125,8 @@ pub struct StatusConfig {
     pub antnode_path: Option<PathBuf>,
     pub connection_mode: ConnectionMode,
     pub data_dir_path: PathBuf,
+    pub max_client_bandwidth: Option<u64>,
+    pub max_replication_bandwidth: Option<u64>,
     pub network_id: Option<u8>,
     pub peers_args: PeersArgs,
     pub port_from: Option<u32>,
@@ -152,6 +157,8 @@ impl Status<'_> {
             connection_mode: config.connection_mode,
             port_from: config.port_from,
             port_to: config.port_to,
+            max_client_bandwidth: config.max_client_bandwidth,
+            max_replication_bandwidth: config.max_replication_bandwidth,
             error_popup: None,
         };
 
@@ -623,6 +630,8 @@ impl Component for Status<'_> {
                         connection_mode: self.connection_mode,
                         count: self.nodes_to_start as u16,
                         data_dir_path: Some(self.data_dir_path.clone()),
+                        max_client_bandwidth: self.max_client_bandwidth,
+                        max_replication_bandwidth: self.max_replication_bandwidth,
                         network_id: self.network_id,
                         owner: self.rewards_address.clone(),
                         peers_args: self.peers_args.clone(),
@@ -676,6 +685,25 @@ impl Component for Status<'_> {
                         debug!("Got action to open node logs but no node was selected.");
                     }
                 }
+                StatusActions::TriggerNodeDetails => {
+                    let selected = self.items.as_ref().and_then(|items| items.selected_item());
+                    if let Some(node) = selected.and_then(|item| {
+                        self.node_services
+                            .iter()
+                            .find(|node| node.service_name == item.name)
+                    }) {
+                        return Ok(Some(Action::StatusActions(
+                            StatusActions::OpenNodeDetails {
+                                service_name: node.service_name.clone(),
+                                log_dir_path: node.log_dir_path.clone(),
+                            },
+                        )));
+                    } else {
+                        debug!("Got action to open node details but no node was selected.");
+                    }
+                }
+                // Handled by the node details screen.
+                StatusActions::OpenNodeDetails { .. } => {}
             },
             Action::OptionsActions(OptionsActions::UpdateNodes) => {
                 debug!("Got action to Update Nodes");
@@ -107,6 +107,13 @@ pub struct AppData {
     pub connection_mode: Option<ConnectionMode>,
     pub port_from: Option<u32>,
     pub port_to: Option<u32>,
+    /// Max bytes per second of records each node sends to clients fetching them directly.
+    /// Unlimited if not set.
+    #[serde(default)]
+    pub max_client_bandwidth: Option<u64>,
+    /// Max bytes per second of replication traffic for each node. Unlimited if not set.
+    #[serde(default)]
+    pub max_replication_bandwidth: Option<u64>,
 }
 
 impl Default for AppData {
@@ -119,6 +126,8 @@ impl Default for AppData {
             connection_mode: None,
             port_from: None,
             port_to: None,
+            max_client_bandwidth: None,
+            max_replication_bandwidth: None,
         }
     }
 }
@@ -13,6 +13,7 @@ pub mod config;
 pub mod connection_mode;
 pub mod error;
 pub mod mode;
+pub mod node_history;
 pub mod node_mgmt;
 pub mod node_stats;
 pub mod style;
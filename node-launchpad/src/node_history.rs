@@ -0,0 +1,241 @@
+// Copyright 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! A rolling history of the stats scraped from each node, kept on disk so it survives restarts of
+//! the launchpad.
+
+use crate::{config::get_launchpad_data_dir_path, node_stats::NodeStats};
+use color_eyre::eyre::Result;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    io::{Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// The number of samples kept for each node.
+///
+/// With stats fetched every 5 seconds, this is the last hour.
+pub const MAX_HISTORY_SAMPLES: usize = 720;
+
+const HISTORY_FILE_NAME: &str = "node_stats_history.json";
+
+/// The stats of a node at a point in time.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeStatsSample {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub rewards_wallet_balance: usize,
+    /// The total bytes received by the node since it started.
+    pub bandwidth_inbound: usize,
+    /// The total bytes sent by the node since it started.
+    pub bandwidth_outbound: usize,
+    /// Bytes per second received since the previous sample.
+    pub bandwidth_inbound_rate: usize,
+    /// Bytes per second sent since the previous sample.
+    pub bandwidth_outbound_rate: usize,
+    pub records: usize,
+    pub peers: usize,
+    pub connections: usize,
+    pub memory_usage_mb: usize,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeStatsHistory {
+    nodes: BTreeMap<String, VecDeque<NodeStatsSample>>,
+}
+
+impl NodeStatsHistory {
+    /// Load the history from the given path.
+    ///
+    /// An empty history is returned if the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Append a sample for each of the nodes in the stats, dropping the oldest samples once a node
+    /// has more than `MAX_HISTORY_SAMPLES`.
+    ///
+    /// The bandwidth rates are calculated from the totals of the previous sample, so they reflect
+    /// the traffic since the last fetch rather than since the node started.
+    pub fn record(&mut self, stats: &NodeStats, timestamp: u64) {
+        for node_stats in &stats.individual_stats {
+            let samples = self
+                .nodes
+                .entry(node_stats.service_name.clone())
+                .or_default();
+
+            let (bandwidth_inbound_rate, bandwidth_outbound_rate) = match samples.back() {
+                Some(previous) if timestamp > previous.timestamp => {
+                    let elapsed = (timestamp - previous.timestamp) as usize;
+                    // The totals go back to zero when the node is restarted.
+                    (
+                        node_stats
+                            .bandwidth_inbound
+                            .checked_sub(previous.bandwidth_inbound)
+                            .map_or(0, |bytes| bytes / elapsed),
+                        node_stats
+                            .bandwidth_outbound
+                            .checked_sub(previous.bandwidth_outbound)
+                            .map_or(0, |bytes| bytes / elapsed),
+                    )
+                }
+                _ => (0, 0),
+            };
+
+            samples.push_back(NodeStatsSample {
+                timestamp,
+                rewards_wallet_balance: node_stats.rewards_wallet_balance,
+                bandwidth_inbound: node_stats.bandwidth_inbound,
+                bandwidth_outbound: node_stats.bandwidth_outbound,
+                bandwidth_inbound_rate,
+                bandwidth_outbound_rate,
+                records: node_stats.max_records,
+                peers: node_stats.peers,
+                connections: node_stats.connections,
+                memory_usage_mb: node_stats.memory_usage_mb,
+            });
+            while samples.len() > MAX_HISTORY_SAMPLES {
+                let _ = samples.pop_front();
+            }
+        }
+    }
+
+    /// The samples for a node, oldest first.
+    pub fn samples(&self, service_name: &str) -> Option<&VecDeque<NodeStatsSample>> {
+        self.nodes.get(service_name)
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+}
+
+pub fn get_node_stats_history_path() -> Result<PathBuf> {
+    Ok(get_launchpad_data_dir_path()?.join(HISTORY_FILE_NAME))
+}
+
+pub fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+/// Read the last `count` lines of a file, without reading the whole file.
+///
+/// Only the final `max_bytes` of the file are considered, so fewer lines can be returned if they
+/// are very long.
+pub fn read_last_lines(path: &Path, count: usize, max_bytes: u64) -> Result<Vec<String>> {
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    let start = len.saturating_sub(max_bytes);
+    let _ = file.seek(SeekFrom::Start(start))?;
+
+    let mut buf = Vec::new();
+    let _ = file.read_to_end(&mut buf)?;
+    let contents = String::from_utf8_lossy(&buf);
+
+    let mut lines: Vec<&str> = contents.lines().collect();
+    // The first line is most likely partial if we did not start from the beginning of the file.
+    if start > 0 && !lines.is_empty() {
+        let _ = lines.remove(0);
+    }
+    let skip = lines.len().saturating_sub(count);
+    Ok(lines
+        .into_iter()
+        .skip(skip)
+        .map(|line| line.to_string())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node_stats::IndividualNodeStats;
+
+    fn stats(bandwidth_inbound: usize, bandwidth_outbound: usize) -> NodeStats {
+        NodeStats {
+            individual_stats: vec![IndividualNodeStats {
+                service_name: "antnode1".to_string(),
+                bandwidth_inbound,
+                bandwidth_outbound,
+                max_records: 10,
+                peers: 20,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn record_should_calculate_rates_from_the_previous_sample() {
+        let mut history = NodeStatsHistory::default();
+        history.record(&stats(1_000, 500), 100);
+        history.record(&stats(6_000, 1_500), 105);
+        // The node was restarted, so the totals went back down.
+        history.record(&stats(200, 100), 110);
+
+        let samples = history.samples("antnode1").unwrap();
+        assert_eq!(samples.len(), 3);
+        assert_eq!(samples[0].bandwidth_inbound_rate, 0);
+        assert_eq!(samples[1].bandwidth_inbound_rate, 1_000);
+        assert_eq!(samples[1].bandwidth_outbound_rate, 200);
+        assert_eq!(samples[2].bandwidth_inbound_rate, 0);
+        assert_eq!(samples[2].records, 10);
+        assert_eq!(samples[2].peers, 20);
+    }
+
+    #[test]
+    fn record_should_keep_a_rolling_window_of_samples() -> Result<()> {
+        let mut history = NodeStatsHistory::default();
+        for i in 0..MAX_HISTORY_SAMPLES as u64 + 10 {
+            history.record(&stats(0, 0), i);
+        }
+        let samples = history.samples("antnode1").unwrap();
+        assert_eq!(samples.len(), MAX_HISTORY_SAMPLES);
+        assert_eq!(samples.front().unwrap().timestamp, 10);
+
+        let tmp_dir = tempfile::tempdir()?;
+        let path = tmp_dir.path().join(HISTORY_FILE_NAME);
+        assert_eq!(NodeStatsHistory::load(&path)?, NodeStatsHistory::default());
+        history.save(&path)?;
+        assert_eq!(NodeStatsHistory::load(&path)?, history);
+        Ok(())
+    }
+
+    #[test]
+    fn read_last_lines_should_skip_partial_lines() -> Result<()> {
+        let tmp_dir = tempfile::tempdir()?;
+        let path = tmp_dir.path().join("antnode.log");
+        std::fs::write(&path, "first line\nsecond line\nthird line\n")?;
+
+        assert_eq!(
+            read_last_lines(&path, 2, 1024)?,
+            vec!["second line".to_string(), "third line".to_string()]
+        );
+        assert_eq!(
+            read_last_lines(&path, 10, 15)?,
+            vec!["third line".to_string()]
+        );
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@ use ant_node_manager::{
     add_services::config::PortRange, config::get_node_registry_path, VerbosityLevel,
 };
 use ant_releases::{self, AntReleaseRepoActions, ReleaseType};
-use ant_service_management::NodeRegistry;
+use ant_service_management::{resources::ResourceLimits, NodeRegistry};
 use color_eyre::eyre::{eyre, Error};
 use color_eyre::Result;
 use std::{path::PathBuf, str::FromStr};
@@ -127,6 +127,8 @@ pub struct MaintainNodesArgs {
     pub connection_mode: ConnectionMode,
     pub count: u16,
     pub data_dir_path: Option<PathBuf>,
+    pub max_client_bandwidth: Option<u64>,
+    pub max_replication_bandwidth: Option<u64>,
     pub network_id: Option<u8>,
     pub owner: String,
     pub peers_args: PeersArgs,
@@ -225,6 +227,7 @@ async fn upgrade_nodes(args: UpgradeNodesArgs) {
         args.fixed_interval,
         args.peer_ids,
         args.provided_env_variables,
+        None,
         args.service_names,
         args.url,
         args.version,
@@ -296,6 +299,8 @@ struct NodeConfig {
     custom_ports: Option<PortRange>,
     data_dir_path: Option<PathBuf>,
     home_network: bool,
+    max_client_bandwidth: Option<u64>,
+    max_replication_bandwidth: Option<u64>,
     network_id: Option<u8>,
     owner: Option<String>,
     peers_args: PeersArgs,
@@ -361,6 +366,8 @@ fn prepare_node_config(args: &MaintainNodesArgs) -> NodeConfig {
             Some(args.owner.clone())
         },
         home_network: args.connection_mode == ConnectionMode::HomeNetwork,
+        max_client_bandwidth: args.max_client_bandwidth,
+        max_replication_bandwidth: args.max_replication_bandwidth,
         network_id: args.network_id,
         peers_args: args.peers_args.clone(),
         rewards_address: args.rewards_address.clone(),
@@ -387,6 +394,19 @@ fn debug_log_config(config: &NodeConfig, args: &MaintainNodesArgs) {
         " auto_set_nat_flags: {:?}, custom_ports: {:?}, upnp: {}, home_network: {}",
         config.auto_set_nat_flags, config.custom_ports, config.upnp, config.home_network
     );
+    debug!(
+        " max_client_bandwidth: {:?}, max_replication_bandwidth: {:?}",
+        config.max_client_bandwidth, config.max_replication_bandwidth
+    );
+}
+
+/// Get the resource limits holding the bandwidth limits of the node config
+fn bandwidth_limits(config: &NodeConfig) -> ResourceLimits {
+    ResourceLimits {
+        max_client_bandwidth: config.max_client_bandwidth,
+        max_replication_bandwidth: config.max_replication_bandwidth,
+        ..Default::default()
+    }
 }
 
 /// Get the currently used ports from the node registry
@@ -430,6 +450,7 @@ async fn scale_down_nodes(config: &NodeConfig, count: u16) {
         None,
         None, // We don't care about the port, as we are scaling down
         config.peers_args.clone(),
+        bandwidth_limits(config),
         RewardsAddress::from_str(config.rewards_address.as_str()).unwrap(),
         None,
         None,
@@ -503,6 +524,7 @@ async fn add_nodes(
             None,
             port_range,
             config.peers_args.clone(),
+            bandwidth_limits(config),
             RewardsAddress::from_str(config.rewards_address.as_str()).unwrap(),
             None,
             None,
@@ -2,10 +2,8 @@
 module.exports = {
     preset: 'ts-jest',
     testEnvironment: 'node',
-    roots: ['<rootDir>/src', '<rootDir>/tests'],
+    roots: ['<rootDir>/tests'],
     testMatch: ['**/*.test.ts'],
-    collectCoverage: true,
-    coverageDirectory: 'coverage',
-    coverageReporters: ['text', 'lcov'],
-    coveragePathIgnorePatterns: ['/node_modules/', '/dist/']
-}; 
\ No newline at end of file
+    // Uploads pay on the local EVM testnet and wait for the nodes to store the data.
+    testTimeout: 120000,
+};
@@ -1,91 +0,0 @@
-import { NetworkConfig, PaymentOption, GraphEntryOptions, PointerOptions, VaultOptions, UserData } from './types';
-
-export class Client {
-    private nativeClient: any; // Will be replaced with actual native binding type
-
-    private constructor(nativeClient: any) {
-        this.nativeClient = nativeClient;
-    }
-
-    static async connect(config: NetworkConfig): Promise<Client> {
-        // TODO: Initialize native client
-        throw new Error('Not implemented');
-    }
-
-    // Data Operations
-    async dataPutPublic(data: Buffer, payment: PaymentOption): Promise<string> {
-        // TODO: Implement native binding call
-        throw new Error('Not implemented');
-    }
-
-    async dataGetPublic(address: string): Promise<Buffer> {
-        // TODO: Implement native binding call
-        throw new Error('Not implemented');
-    }
-
-    // Graph Operations
-    async graphEntryGet(address: string): Promise<any[]> {
-        // TODO: Implement native binding call
-        throw new Error('Not implemented');
-    }
-
-    async graphEntryPut(options: GraphEntryOptions, payment: PaymentOption): Promise<void> {
-        // TODO: Implement native binding call
-        throw new Error('Not implemented');
-    }
-
-    async graphEntryCost(key: string): Promise<string> {
-        // TODO: Implement native binding call
-        throw new Error('Not implemented');
-    }
-
-    // Pointer Operations
-    async pointerGet(address: string): Promise<any> {
-        // TODO: Implement native binding call
-        throw new Error('Not implemented');
-    }
-
-    async pointerPut(options: PointerOptions, payment: PaymentOption): Promise<void> {
-        // TODO: Implement native binding call
-        throw new Error('Not implemented');
-    }
-
-    async pointerCost(key: string): Promise<string> {
-        // TODO: Implement native binding call
-        throw new Error('Not implemented');
-    }
-
-    // Vault Operations
-    async vaultCost(key: string): Promise<string> {
-        // TODO: Implement native binding call
-        throw new Error('Not implemented');
-    }
-
-    async writeBytesToVault(
-        data: Buffer,
-        payment: PaymentOption,
-        options: VaultOptions
-    ): Promise<string> {
-        // TODO: Implement native binding call
-        throw new Error('Not implemented');
-    }
-
-    async fetchAndDecryptVault(key: string): Promise<[Buffer, number]> {
-        // TODO: Implement native binding call
-        throw new Error('Not implemented');
-    }
-
-    async getUserDataFromVault(key: string): Promise<UserData> {
-        // TODO: Implement native binding call
-        throw new Error('Not implemented');
-    }
-
-    async putUserDataToVault(
-        key: string,
-        payment: PaymentOption,
-        userData: UserData
-    ): Promise<void> {
-        // TODO: Implement native binding call
-        throw new Error('Not implemented');
-    }
-} 
\ No newline at end of file
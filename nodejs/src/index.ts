@@ -1,6 +0,0 @@
-export * from './client';
-export * from './types';
-export * from './wallet';
-export * from './GraphEntry';
-export * from './pointer';
-export * from './vault'; 
\ No newline at end of file
@@ -0,0 +1,1277 @@
+// Copyright 2025 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+//! Node.js bindings for the Autonomi client.
+//!
+//! Addresses are passed around as hex strings and amounts of tokens as decimal strings, as they do
+//! not fit in a JavaScript number. Network operations return promises.
+
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use autonomi::client::address::str_to_addr;
+use autonomi::client::chunk::{ChunkAddress, DataMapChunk};
+use autonomi::client::data::DataAddr;
+use autonomi::client::payment::PaymentOption;
+use autonomi::client::vault::UserData;
+use autonomi::files::{Metadata, PublicArchive};
+use autonomi::graph::{GraphEntry, GraphEntryAddress};
+use autonomi::pointer::{Pointer, PointerAddress, PointerTarget};
+use autonomi::register::RegisterAddress;
+use autonomi::scratchpad::{Scratchpad, ScratchpadAddress};
+use autonomi::{Bytes, Multiaddr, Network, PublicKey, SecretKey, Wallet};
+use napi::bindgen_prelude::*;
+use napi::{Env, JsObject};
+use napi_derive::napi;
+
+/// Represents a client for the Autonomi network.
+#[napi(js_name = "Client")]
+pub struct JsClient {
+    inner: autonomi::Client,
+}
+
+#[napi]
+impl JsClient {
+    /// Initialize the client with default configuration.
+    #[napi(ts_return_type = "Promise<Client>")]
+    pub fn init(env: Env) -> Result<JsObject> {
+        env.spawn_future(async {
+            let inner = autonomi::Client::init()
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to connect: {e}")))?;
+            Ok(JsClient { inner })
+        })
+    }
+
+    /// Initialize a client that is configured to be local.
+    #[napi(ts_return_type = "Promise<Client>")]
+    pub fn init_local(env: Env) -> Result<JsObject> {
+        env.spawn_future(async {
+            let inner = autonomi::Client::init_local()
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to connect: {e}")))?;
+            Ok(JsClient { inner })
+        })
+    }
+
+    /// Initialize a client that bootstraps from a list of peers.
+    ///
+    /// If any of the provided peers is a global address, the client will not be local.
+    #[napi(ts_return_type = "Promise<Client>")]
+    pub fn init_with_peers(env: Env, peers: Vec<String>) -> Result<JsObject> {
+        let peers: Vec<Multiaddr> = peers
+            .iter()
+            .map(|p| Multiaddr::from_str(p))
+            .collect::<std::result::Result<_, _>>()
+            .map_err(|e| Error::from_reason(format!("Failed to parse peers: {e}")))?;
+
+        env.spawn_future(async {
+            let inner = autonomi::Client::init_with_peers(peers)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to connect: {e}")))?;
+            Ok(JsClient { inner })
+        })
+    }
+
+    /// Get a chunk from the network.
+    #[napi(ts_return_type = "Promise<Buffer>")]
+    pub fn chunk_get(&self, env: Env, addr: String) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let addr = ChunkAddress::new(parse_address(&addr)?);
+
+        env.spawn_future(async move {
+            let chunk = client
+                .chunk_get(&addr)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get chunk: {e}")))?;
+            Ok(Buffer::from(chunk.value.to_vec()))
+        })
+    }
+
+    /// Upload a piece of private data to the network. This data will be self-encrypted.
+    /// The data map is not uploaded to the network, keeping the data private.
+    ///
+    /// Returns the cost and the data map, in hex, needed to get the data back.
+    #[napi(ts_return_type = "Promise<PutResult>")]
+    pub fn data_put(&self, env: Env, data: Buffer, payment: &JsPaymentOption) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let payment = payment.inner.clone();
+        let data = Bytes::from(data.to_vec());
+
+        env.spawn_future(async move {
+            let (cost, data_map) = client
+                .data_put(data, payment)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to put data: {e}")))?;
+            Ok(PutResult {
+                cost: cost.to_string(),
+                address: data_map.to_hex(),
+            })
+        })
+    }
+
+    /// Fetch a piece of private data from the network, from its data map in hex.
+    #[napi(ts_return_type = "Promise<Buffer>")]
+    pub fn data_get(&self, env: Env, data_map: String) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let data_map = DataMapChunk::from_hex(&data_map)
+            .map_err(|e| Error::from_reason(format!("Failed to parse data map: {e}")))?;
+
+        env.spawn_future(async move {
+            let data = client
+                .data_get(&data_map)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get data: {e}")))?;
+            Ok(Buffer::from(data.to_vec()))
+        })
+    }
+
+    /// Upload a piece of data to the network. This data is publicly accessible.
+    ///
+    /// Returns the cost and the address at which the data was stored.
+    #[napi(ts_return_type = "Promise<PutResult>")]
+    pub fn data_put_public(
+        &self,
+        env: Env,
+        data: Buffer,
+        payment: &JsPaymentOption,
+    ) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let payment = payment.inner.clone();
+        let data = Bytes::from(data.to_vec());
+
+        env.spawn_future(async move {
+            let (cost, addr) = client
+                .data_put_public(data, payment)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to put data: {e}")))?;
+            Ok(PutResult {
+                cost: cost.to_string(),
+                address: autonomi::client::address::addr_to_str(addr),
+            })
+        })
+    }
+
+    /// Fetch a piece of public data from the network.
+    #[napi(ts_return_type = "Promise<Buffer>")]
+    pub fn data_get_public(&self, env: Env, addr: String) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let addr = parse_address(&addr)?;
+
+        env.spawn_future(async move {
+            let data = client
+                .data_get_public(&addr)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get data: {e}")))?;
+            Ok(Buffer::from(data.to_vec()))
+        })
+    }
+
+    /// Get the estimated cost of storing a piece of data.
+    #[napi(ts_return_type = "Promise<string>")]
+    pub fn data_cost(&self, env: Env, data: Buffer) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let data = Bytes::from(data.to_vec());
+
+        env.spawn_future(async move {
+            let cost = client
+                .data_cost(data)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get data cost: {e}")))?;
+            Ok(cost.to_string())
+        })
+    }
+
+    /// Upload a public archive to the network.
+    ///
+    /// Returns the cost and the address of the archive.
+    #[napi(ts_return_type = "Promise<PutResult>")]
+    pub fn archive_put_public(
+        &self,
+        env: Env,
+        archive: &JsPublicArchive,
+        wallet: &JsWallet,
+    ) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let archive = archive.inner.clone();
+        let wallet = wallet.inner.clone();
+
+        env.spawn_future(async move {
+            let (cost, addr) = client
+                .archive_put_public(&archive, &wallet)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to put archive: {e}")))?;
+            Ok(PutResult {
+                cost: cost.to_string(),
+                address: autonomi::client::address::addr_to_str(addr),
+            })
+        })
+    }
+
+    /// Get a public archive from the network.
+    #[napi(ts_return_type = "Promise<PublicArchive>")]
+    pub fn archive_get_public(&self, env: Env, addr: String) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let addr = parse_address(&addr)?;
+
+        env.spawn_future(async move {
+            let archive = client
+                .archive_get_public(&addr)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get archive: {e}")))?;
+            Ok(JsPublicArchive { inner: archive })
+        })
+    }
+
+    /// Upload a directory to the network. The directory is recursively walked and each file is
+    /// uploaded to the network.
+    ///
+    /// This returns, but does not upload (!), the public archive of the uploaded files.
+    #[napi(ts_return_type = "Promise<PublicArchive>")]
+    pub fn dir_upload_public(
+        &self,
+        env: Env,
+        dir_path: String,
+        wallet: &JsWallet,
+    ) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let wallet = wallet.inner.clone();
+
+        env.spawn_future(async move {
+            let (_cost, archive) = client
+                .dir_upload_public(PathBuf::from(dir_path), &wallet)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to upload directory: {e}")))?;
+            Ok(JsPublicArchive { inner: archive })
+        })
+    }
+
+    /// Download a public archive from the network to a local directory.
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn dir_download_public(
+        &self,
+        env: Env,
+        addr: String,
+        dir_path: String,
+    ) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let addr = parse_address(&addr)?;
+
+        env.spawn_future(async move {
+            client
+                .dir_download_public(&addr, PathBuf::from(dir_path))
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to download directory: {e}")))?;
+            Ok(())
+        })
+    }
+
+    /// Fetches a graph entry from the network.
+    #[napi(ts_return_type = "Promise<GraphEntry>")]
+    pub fn graph_entry_get(&self, env: Env, addr: String) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let addr = GraphEntryAddress::new(parse_address(&addr)?);
+
+        env.spawn_future(async move {
+            let entry = client
+                .graph_entry_get(&addr)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get graph entry: {e}")))?;
+            Ok(JsGraphEntry { inner: entry })
+        })
+    }
+
+    /// Check if a graph entry exists on the network.
+    #[napi(ts_return_type = "Promise<boolean>")]
+    pub fn graph_entry_check_existance(&self, env: Env, addr: String) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let addr = GraphEntryAddress::new(parse_address(&addr)?);
+
+        env.spawn_future(async move {
+            let exists = client
+                .graph_entry_check_existance(&addr)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get graph entry: {e}")))?;
+            Ok(exists)
+        })
+    }
+
+    /// Manually puts a graph entry to the network.
+    ///
+    /// Returns the cost and the address of the entry.
+    #[napi(ts_return_type = "Promise<PutResult>")]
+    pub fn graph_entry_put(
+        &self,
+        env: Env,
+        entry: &JsGraphEntry,
+        payment: &JsPaymentOption,
+    ) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let entry = entry.inner.clone();
+        let payment = payment.inner.clone();
+
+        env.spawn_future(async move {
+            let (cost, addr) = client
+                .graph_entry_put(entry, payment)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to put graph entry: {e}")))?;
+            Ok(PutResult {
+                cost: cost.to_string(),
+                address: addr.to_hex(),
+            })
+        })
+    }
+
+    /// Get the cost to create a graph entry.
+    #[napi(ts_return_type = "Promise<string>")]
+    pub fn graph_entry_cost(&self, env: Env, key: &JsPublicKey) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let key = key.inner;
+
+        env.spawn_future(async move {
+            let cost = client
+                .graph_entry_cost(&key)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get graph entry cost: {e}")))?;
+            Ok(cost.to_string())
+        })
+    }
+
+    /// Get a pointer from the network.
+    #[napi(ts_return_type = "Promise<Pointer>")]
+    pub fn pointer_get(&self, env: Env, addr: String) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let addr = PointerAddress::new(parse_address(&addr)?);
+
+        env.spawn_future(async move {
+            let pointer = client
+                .pointer_get(&addr)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get pointer: {e}")))?;
+            Ok(JsPointer::from(pointer))
+        })
+    }
+
+    /// Check if a pointer exists on the network.
+    #[napi(ts_return_type = "Promise<boolean>")]
+    pub fn pointer_check_existance(&self, env: Env, addr: String) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let addr = PointerAddress::new(parse_address(&addr)?);
+
+        env.spawn_future(async move {
+            let exists = client
+                .pointer_check_existance(&addr)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get pointer: {e}")))?;
+            Ok(exists)
+        })
+    }
+
+    /// Create a new pointer on the network.
+    ///
+    /// Make sure that the owner key is not already used for another pointer as each key is
+    /// associated with one pointer. Returns the cost and the address of the pointer.
+    #[napi(ts_return_type = "Promise<PutResult>")]
+    pub fn pointer_create(
+        &self,
+        env: Env,
+        owner: &JsSecretKey,
+        target: JsPointerTarget,
+        payment: &JsPaymentOption,
+    ) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let owner = owner.inner.clone();
+        let target = PointerTarget::try_from(target)?;
+        let payment = payment.inner.clone();
+
+        env.spawn_future(async move {
+            let (cost, addr) = client
+                .pointer_create(&owner, target, payment)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to create pointer: {e}")))?;
+            Ok(PutResult {
+                cost: cost.to_string(),
+                address: addr.to_hex(),
+            })
+        })
+    }
+
+    /// Update an existing pointer to point to a new target on the network.
+    ///
+    /// The pointer needs to be created first with `pointerCreate`.
+    /// This operation is free as the pointer was already paid for at creation.
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn pointer_update(
+        &self,
+        env: Env,
+        owner: &JsSecretKey,
+        target: JsPointerTarget,
+    ) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let owner = owner.inner.clone();
+        let target = PointerTarget::try_from(target)?;
+
+        env.spawn_future(async move {
+            client
+                .pointer_update(&owner, target)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to update pointer: {e}")))?;
+            Ok(())
+        })
+    }
+
+    /// Calculate the cost of storing a pointer.
+    #[napi(ts_return_type = "Promise<string>")]
+    pub fn pointer_cost(&self, env: Env, key: &JsPublicKey) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let key = key.inner;
+
+        env.spawn_future(async move {
+            let cost = client
+                .pointer_cost(&key)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get pointer cost: {e}")))?;
+            Ok(cost.to_string())
+        })
+    }
+
+    /// Get a scratchpad from the network, from the hex of its owner's public key.
+    #[napi(ts_return_type = "Promise<Scratchpad>")]
+    pub fn scratchpad_get(&self, env: Env, addr: String) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let addr = ScratchpadAddress::from_hex(&addr)
+            .map_err(|e| Error::from_reason(format!("Failed to parse address: {e}")))?;
+
+        env.spawn_future(async move {
+            let scratchpad = client
+                .scratchpad_get(&addr)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get scratchpad: {e}")))?;
+            Ok(JsScratchpad { inner: scratchpad })
+        })
+    }
+
+    /// Check if a scratchpad exists on the network.
+    #[napi(ts_return_type = "Promise<boolean>")]
+    pub fn scratchpad_check_existance(&self, env: Env, addr: String) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let addr = ScratchpadAddress::from_hex(&addr)
+            .map_err(|e| Error::from_reason(format!("Failed to parse address: {e}")))?;
+
+        env.spawn_future(async move {
+            let exists = client
+                .scratchpad_check_existance(&addr)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get scratchpad: {e}")))?;
+            Ok(exists)
+        })
+    }
+
+    /// Create a new scratchpad on the network.
+    ///
+    /// Make sure that the owner key is not already used for another scratchpad as each key is
+    /// associated with one scratchpad. The data will be encrypted with the owner key before being
+    /// stored on the network. The content type is used to identify the type of data stored in the
+    /// scratchpad, the choice is up to the caller.
+    ///
+    /// Returns the cost and the address of the scratchpad.
+    #[napi(ts_return_type = "Promise<PutResult>")]
+    pub fn scratchpad_create(
+        &self,
+        env: Env,
+        owner: &JsSecretKey,
+        content_type: BigInt,
+        initial_data: Buffer,
+        payment: &JsPaymentOption,
+    ) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let owner = owner.inner.clone();
+        let content_type = big_int_to_u64(content_type, "contentType")?;
+        let initial_data = Bytes::from(initial_data.to_vec());
+        let payment = payment.inner.clone();
+
+        env.spawn_future(async move {
+            let (cost, addr) = client
+                .scratchpad_create(&owner, content_type, &initial_data, payment)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to create scratchpad: {e}")))?;
+            Ok(PutResult {
+                cost: cost.to_string(),
+                address: addr.to_hex(),
+            })
+        })
+    }
+
+    /// Update an existing scratchpad on the network.
+    ///
+    /// The scratchpad needs to be created first with `scratchpadCreate`.
+    /// This operation is free as the scratchpad was already paid for at creation.
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn scratchpad_update(
+        &self,
+        env: Env,
+        owner: &JsSecretKey,
+        content_type: BigInt,
+        data: Buffer,
+    ) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let owner = owner.inner.clone();
+        let content_type = big_int_to_u64(content_type, "contentType")?;
+        let data = Bytes::from(data.to_vec());
+
+        env.spawn_future(async move {
+            client
+                .scratchpad_update(&owner, content_type, &data)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to update scratchpad: {e}")))?;
+            Ok(())
+        })
+    }
+
+    /// Get the cost of creating a new scratchpad.
+    #[napi(ts_return_type = "Promise<string>")]
+    pub fn scratchpad_cost(&self, env: Env, owner: &JsPublicKey) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let owner = owner.inner;
+
+        env.spawn_future(async move {
+            let cost = client
+                .scratchpad_cost(&owner)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get scratchpad cost: {e}")))?;
+            Ok(cost.to_string())
+        })
+    }
+
+    /// Create a new register key from a secret key and a name.
+    ///
+    /// Note that you will need to keep track of the names you used to create the register key.
+    #[napi]
+    pub fn register_key_from_name(owner: &JsSecretKey, name: String) -> JsSecretKey {
+        JsSecretKey {
+            inner: autonomi::Client::register_key_from_name(&owner.inner, &name),
+        }
+    }
+
+    /// Create a new register with an initial value of any size.
+    ///
+    /// Returns the cost and the address of the register.
+    #[napi(ts_return_type = "Promise<PutResult>")]
+    pub fn register_create(
+        &self,
+        env: Env,
+        owner: &JsSecretKey,
+        value: Buffer,
+        payment: &JsPaymentOption,
+    ) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let owner = owner.inner.clone();
+        let value = Bytes::from(value.to_vec());
+        let payment = payment.inner.clone();
+
+        env.spawn_future(async move {
+            let (cost, addr) = client
+                .register_create_bytes(&owner, value, payment)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to create register: {e}")))?;
+            Ok(PutResult {
+                cost: cost.to_string(),
+                address: addr.to_hex(),
+            })
+        })
+    }
+
+    /// Update the value of a register.
+    ///
+    /// The register needs to be created first with `registerCreate`.
+    #[napi(ts_return_type = "Promise<string>")]
+    pub fn register_update(
+        &self,
+        env: Env,
+        owner: &JsSecretKey,
+        value: Buffer,
+        payment: &JsPaymentOption,
+    ) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let owner = owner.inner.clone();
+        let value = Bytes::from(value.to_vec());
+        let payment = payment.inner.clone();
+
+        env.spawn_future(async move {
+            let cost = client
+                .register_update_bytes(&owner, value, payment)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to update register: {e}")))?;
+            Ok(cost.to_string())
+        })
+    }
+
+    /// Get the current value of a register.
+    #[napi(ts_return_type = "Promise<Buffer>")]
+    pub fn register_get(&self, env: Env, addr: String) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let addr = RegisterAddress::from_hex(&addr)
+            .map_err(|e| Error::from_reason(format!("Failed to parse address: {e}")))?;
+
+        env.spawn_future(async move {
+            let value = client
+                .register_get_bytes(&addr)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get register: {e}")))?;
+            Ok(Buffer::from(value.to_vec()))
+        })
+    }
+
+    /// Get the cost of a register operation.
+    /// Returns the cost of creation if it doesn't exist, else returns the cost of an update.
+    #[napi(ts_return_type = "Promise<string>")]
+    pub fn register_cost(&self, env: Env, owner: &JsPublicKey) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let owner = owner.inner;
+
+        env.spawn_future(async move {
+            let cost = client
+                .register_cost(&owner)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get register cost: {e}")))?;
+            Ok(cost.to_string())
+        })
+    }
+
+    /// Get the cost of creating a new vault.
+    #[napi(ts_return_type = "Promise<string>")]
+    pub fn vault_cost(
+        &self,
+        env: Env,
+        key: &JsSecretKey,
+        max_expected_size: BigInt,
+    ) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let key = key.inner.clone();
+        let max_expected_size = big_int_to_u64(max_expected_size, "maxExpectedSize")?;
+
+        env.spawn_future(async move {
+            let cost = client
+                .vault_cost(&key, max_expected_size)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get vault cost: {e}")))?;
+            Ok(cost.to_string())
+        })
+    }
+
+    /// Put data into the client's vault.
+    ///
+    /// Dynamically expand the vault capacity by paying for more space when needed.
+    /// It is recommended to use the hash of the app name or unique identifier as the content type.
+    #[napi(ts_return_type = "Promise<string>")]
+    pub fn write_bytes_to_vault(
+        &self,
+        env: Env,
+        data: Buffer,
+        payment: &JsPaymentOption,
+        key: &JsSecretKey,
+        content_type: BigInt,
+    ) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let data = Bytes::from(data.to_vec());
+        let payment = payment.inner.clone();
+        let key = key.inner.clone();
+        let content_type = big_int_to_u64(content_type, "contentType")?;
+
+        env.spawn_future(async move {
+            let cost = client
+                .write_bytes_to_vault(data, payment, &key, content_type)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to write to vault: {e}")))?;
+            Ok(cost.to_string())
+        })
+    }
+
+    /// Retrieves and returns a decrypted vault if one exists, along with its content type.
+    #[napi(ts_return_type = "Promise<VaultContent>")]
+    pub fn fetch_and_decrypt_vault(&self, env: Env, key: &JsSecretKey) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let key = key.inner.clone();
+
+        env.spawn_future(async move {
+            let (data, content_type) = client
+                .fetch_and_decrypt_vault(&key)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to fetch vault: {e}")))?;
+            Ok(VaultContent {
+                data: Buffer::from(data.to_vec()),
+                content_type: BigInt::from(content_type),
+            })
+        })
+    }
+
+    /// Get the user data from the vault.
+    #[napi(ts_return_type = "Promise<UserData>")]
+    pub fn get_user_data_from_vault(&self, env: Env, key: &JsSecretKey) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let key = key.inner.clone();
+
+        env.spawn_future(async move {
+            let user_data = client.get_user_data_from_vault(&key).await.map_err(|e| {
+                Error::from_reason(format!("Failed to get user data from vault: {e}"))
+            })?;
+            Ok(JsUserData { inner: user_data })
+        })
+    }
+
+    /// Put the user data to the vault.
+    ///
+    /// Returns the total cost of the put operation.
+    #[napi(ts_return_type = "Promise<string>")]
+    pub fn put_user_data_to_vault(
+        &self,
+        env: Env,
+        key: &JsSecretKey,
+        payment: &JsPaymentOption,
+        user_data: &JsUserData,
+    ) -> Result<JsObject> {
+        let client = self.inner.clone();
+        let key = key.inner.clone();
+        let payment = payment.inner.clone();
+        let user_data = user_data.inner.clone();
+
+        env.spawn_future(async move {
+            let cost = client
+                .put_user_data_to_vault(&key, payment, user_data)
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to put user data: {e}")))?;
+            Ok(cost.to_string())
+        })
+    }
+}
+
+/// The cost of an upload, in AttoTokens, and the address of what was uploaded.
+#[napi(object)]
+pub struct PutResult {
+    pub cost: String,
+    pub address: String,
+}
+
+/// The decrypted content of a vault.
+#[napi(object)]
+pub struct VaultContent {
+    pub data: Buffer,
+    pub content_type: BigInt,
+}
+
+/// The kind of data a pointer points to.
+#[napi(string_enum)]
+pub enum PointerTargetKind {
+    Chunk,
+    GraphEntry,
+    Pointer,
+    Scratchpad,
+}
+
+/// The target that a pointer points to on the network.
+#[napi(object, js_name = "PointerTarget")]
+pub struct JsPointerTarget {
+    pub kind: PointerTargetKind,
+    /// Hex of the address of the target, the owner's public key for a scratchpad.
+    pub address: String,
+}
+
+impl TryFrom<JsPointerTarget> for PointerTarget {
+    type Error = Error;
+
+    fn try_from(target: JsPointerTarget) -> Result<Self> {
+        let target = match target.kind {
+            PointerTargetKind::Chunk => {
+                PointerTarget::ChunkAddress(ChunkAddress::new(parse_address(&target.address)?))
+            }
+            PointerTargetKind::GraphEntry => PointerTarget::GraphEntryAddress(
+                GraphEntryAddress::new(parse_address(&target.address)?),
+            ),
+            PointerTargetKind::Pointer => {
+                PointerTarget::PointerAddress(PointerAddress::new(parse_address(&target.address)?))
+            }
+            PointerTargetKind::Scratchpad => PointerTarget::ScratchpadAddress(
+                ScratchpadAddress::from_hex(&target.address)
+                    .map_err(|e| Error::from_reason(format!("Failed to parse address: {e}")))?,
+            ),
+        };
+        Ok(target)
+    }
+}
+
+impl From<&PointerTarget> for JsPointerTarget {
+    fn from(target: &PointerTarget) -> Self {
+        let (kind, address) = match target {
+            PointerTarget::ChunkAddress(addr) => (PointerTargetKind::Chunk, addr.to_hex()),
+            PointerTarget::GraphEntryAddress(addr) => {
+                (PointerTargetKind::GraphEntry, addr.to_hex())
+            }
+            PointerTarget::PointerAddress(addr) => (PointerTargetKind::Pointer, addr.to_hex()),
+            PointerTarget::ScratchpadAddress(addr) => {
+                (PointerTargetKind::Scratchpad, addr.to_hex())
+            }
+        };
+        Self { kind, address }
+    }
+}
+
+/// Pointer, a mutable address pointing to other data on the network.
+/// It is stored at the owner's public key and can only be updated by the owner.
+#[napi(object, js_name = "Pointer")]
+pub struct JsPointer {
+    pub address: String,
+    /// Hex of the owner's public key.
+    pub owner: String,
+    pub counter: u32,
+    pub target: JsPointerTarget,
+}
+
+impl From<Pointer> for JsPointer {
+    fn from(pointer: Pointer) -> Self {
+        Self {
+            address: pointer.address().to_hex(),
+            owner: pointer.owner().to_hex(),
+            counter: pointer.counter(),
+            target: JsPointerTarget::from(pointer.target()),
+        }
+    }
+}
+
+/// A descendant of a graph entry: the public key of the descendant and some content.
+#[napi(object)]
+pub struct GraphDescendant {
+    pub public_key: String,
+    /// 32 bytes of content.
+    pub content: Buffer,
+}
+
+/// An immutable entry of a graph, linking to parents and descendants.
+#[napi(js_name = "GraphEntry")]
+pub struct JsGraphEntry {
+    inner: GraphEntry,
+}
+
+#[napi]
+impl JsGraphEntry {
+    /// Create a new graph entry, signing it with the owner's secret key.
+    ///
+    /// Parents are given as the hex of their public keys. The content is 32 bytes.
+    #[napi(constructor)]
+    pub fn new(
+        owner: &JsSecretKey,
+        parents: Vec<String>,
+        content: Buffer,
+        descendants: Vec<GraphDescendant>,
+    ) -> Result<Self> {
+        let parents = parents
+            .iter()
+            .map(|key| parse_public_key(key))
+            .collect::<Result<Vec<_>>>()?;
+        let descendants = descendants
+            .into_iter()
+            .map(|d| Ok((parse_public_key(&d.public_key)?, graph_content(d.content)?)))
+            .collect::<Result<Vec<_>>>()?;
+        let inner = GraphEntry::new(&owner.inner, parents, graph_content(content)?, descendants);
+        Ok(Self { inner })
+    }
+
+    /// The network address where this entry is stored.
+    #[napi(getter)]
+    pub fn address(&self) -> String {
+        self.inner.address().to_hex()
+    }
+
+    /// Hex of the owner's public key.
+    #[napi(getter)]
+    pub fn owner(&self) -> String {
+        self.inner.owner.to_hex()
+    }
+
+    /// Hex of the public keys of the parents.
+    #[napi(getter)]
+    pub fn parents(&self) -> Vec<String> {
+        self.inner.parents.iter().map(|key| key.to_hex()).collect()
+    }
+
+    #[napi(getter)]
+    pub fn content(&self) -> Buffer {
+        Buffer::from(self.inner.content.to_vec())
+    }
+
+    #[napi(getter)]
+    pub fn descendants(&self) -> Vec<GraphDescendant> {
+        self.inner
+            .descendants
+            .iter()
+            .map(|(key, content)| GraphDescendant {
+                public_key: key.to_hex(),
+                content: Buffer::from(content.to_vec()),
+            })
+            .collect()
+    }
+}
+
+/// A scratchpad, a mutable and encrypted piece of data stored at its owner's public key.
+#[napi(js_name = "Scratchpad")]
+pub struct JsScratchpad {
+    inner: Scratchpad,
+}
+
+#[napi]
+impl JsScratchpad {
+    /// Hex of the owner's public key, which is also the address of the scratchpad.
+    #[napi(getter)]
+    pub fn address(&self) -> String {
+        self.inner.address().to_hex()
+    }
+
+    #[napi(getter)]
+    pub fn content_type(&self) -> BigInt {
+        BigInt::from(self.inner.data_encoding())
+    }
+
+    #[napi(getter)]
+    pub fn counter(&self) -> BigInt {
+        BigInt::from(self.inner.counter())
+    }
+
+    /// Decrypt the data of the scratchpad with the owner's secret key.
+    #[napi]
+    pub fn decrypt_data(&self, key: &JsSecretKey) -> Result<Buffer> {
+        let data = self
+            .inner
+            .decrypt_data(&key.inner)
+            .map_err(|e| Error::from_reason(format!("Failed to decrypt scratchpad: {e}")))?;
+        Ok(Buffer::from(data.to_vec()))
+    }
+}
+
+/// Metadata of a file in an archive: creation time, modification time and size.
+#[napi(js_name = "Metadata")]
+pub struct JsMetadata {
+    inner: Metadata,
+}
+
+#[napi]
+impl JsMetadata {
+    /// Create new metadata with the given file size, and the current time as creation and
+    /// modification times.
+    #[napi(constructor)]
+    pub fn new(size: BigInt) -> Result<Self> {
+        Ok(Self {
+            inner: Metadata::new_with_size(big_int_to_u64(size, "size")?),
+        })
+    }
+
+    /// Creation time, in seconds since the Unix epoch.
+    #[napi(getter)]
+    pub fn created(&self) -> BigInt {
+        BigInt::from(self.inner.created)
+    }
+
+    /// Modification time, in seconds since the Unix epoch.
+    #[napi(getter)]
+    pub fn modified(&self) -> BigInt {
+        BigInt::from(self.inner.modified)
+    }
+
+    /// File size in bytes.
+    #[napi(getter)]
+    pub fn size(&self) -> BigInt {
+        BigInt::from(self.inner.size)
+    }
+}
+
+/// A file of an archive, along with the address of its data.
+#[napi(object)]
+pub struct ArchiveFile {
+    pub path: String,
+    pub address: String,
+    pub size: BigInt,
+}
+
+/// A public archive, mapping file paths to the addresses of their data.
+#[napi(js_name = "PublicArchive")]
+pub struct JsPublicArchive {
+    inner: PublicArchive,
+}
+
+#[napi]
+impl JsPublicArchive {
+    /// Create a new, empty archive.
+    #[napi(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            inner: PublicArchive::new(),
+        }
+    }
+
+    /// Add a file to the archive, from the address of its data.
+    #[napi]
+    pub fn add_file(&mut self, path: String, address: String, metadata: &JsMetadata) -> Result<()> {
+        let address = parse_address(&address)?;
+        self.inner
+            .add_file(PathBuf::from(path), address, metadata.inner.clone());
+        Ok(())
+    }
+
+    /// Rename a file in the archive.
+    #[napi]
+    pub fn rename_file(&mut self, old_path: String, new_path: String) -> Result<()> {
+        self.inner
+            .rename_file(&PathBuf::from(old_path), &PathBuf::from(new_path))
+            .map_err(|e| Error::from_reason(format!("Failed to rename file: {e}")))
+    }
+
+    /// List the files in the archive, along with the addresses of their data.
+    #[napi]
+    pub fn files(&self) -> Vec<ArchiveFile> {
+        self.inner
+            .iter()
+            .map(|(path, addr, meta)| ArchiveFile {
+                path: path.to_string_lossy().into_owned(),
+                address: autonomi::client::address::addr_to_str(*addr),
+                size: BigInt::from(meta.size),
+            })
+            .collect()
+    }
+}
+
+/// An archive owned by a user, along with its name.
+#[napi(object)]
+pub struct NamedArchive {
+    pub address: String,
+    pub name: String,
+}
+
+/// The data of a user stored in their vault.
+#[napi(js_name = "UserData")]
+pub struct JsUserData {
+    inner: UserData,
+}
+
+#[napi]
+impl JsUserData {
+    /// Create new, empty user data.
+    #[napi(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            inner: UserData::new(),
+        }
+    }
+
+    /// Add a public archive, returning the previous name of the archive if it was already there.
+    #[napi]
+    pub fn add_file_archive(&mut self, address: String, name: String) -> Result<Option<String>> {
+        let address = parse_address(&address)?;
+        Ok(self.inner.add_file_archive_with_name(address, name))
+    }
+
+    /// The public archives of the user, along with their names.
+    #[napi]
+    pub fn file_archives(&self) -> Vec<NamedArchive> {
+        self.inner
+            .file_archives
+            .iter()
+            .map(|(addr, name)| NamedArchive {
+                address: autonomi::client::address::addr_to_str(*addr),
+                name: name.clone(),
+            })
+            .collect()
+    }
+
+    /// The private archives of the user, given by the hex of their data maps, along with their
+    /// names.
+    #[napi]
+    pub fn private_file_archives(&self) -> Vec<NamedArchive> {
+        self.inner
+            .private_file_archives
+            .iter()
+            .map(|(data_map, name)| NamedArchive {
+                address: data_map.to_hex(),
+                name: name.clone(),
+            })
+            .collect()
+    }
+}
+
+/// The EVM network to pay on.
+#[napi(js_name = "Network")]
+pub struct JsNetwork {
+    inner: Network,
+}
+
+#[napi]
+impl JsNetwork {
+    /// Creates a new network configuration.
+    ///
+    /// If `local` is true, configures for local network connections.
+    #[napi(constructor)]
+    pub fn new(local: bool) -> Result<Self> {
+        let inner = Network::new(local).map_err(|e| Error::from_reason(format!("{e:?}")))?;
+        Ok(Self { inner })
+    }
+}
+
+/// A wallet paying for uploads.
+#[napi(js_name = "Wallet")]
+pub struct JsWallet {
+    inner: Wallet,
+}
+
+#[napi]
+impl JsWallet {
+    /// Convenience function that creates a new wallet from a private key on the given network.
+    #[napi(factory)]
+    pub fn new_from_private_key(network: &JsNetwork, private_key: String) -> Result<Self> {
+        let inner = Wallet::new_from_private_key(network.inner.clone(), &private_key)
+            .map_err(|e| Error::from_reason(format!("Invalid private key: {e}")))?;
+        Ok(Self { inner })
+    }
+
+    /// Returns a string representation of the wallet's address.
+    #[napi]
+    pub fn address(&self) -> String {
+        format!("{:?}", self.inner.address())
+    }
+
+    /// Returns the raw balance of payment tokens in the wallet.
+    #[napi(ts_return_type = "Promise<string>")]
+    pub fn balance(&self, env: Env) -> Result<JsObject> {
+        let wallet = self.inner.clone();
+
+        env.spawn_future(async move {
+            let balance = wallet
+                .balance_of_tokens()
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get balance: {e}")))?;
+            Ok(balance.to_string())
+        })
+    }
+
+    /// Returns the current balance of gas tokens in the wallet.
+    #[napi(ts_return_type = "Promise<string>")]
+    pub fn balance_of_gas(&self, env: Env) -> Result<JsObject> {
+        let wallet = self.inner.clone();
+
+        env.spawn_future(async move {
+            let balance = wallet
+                .balance_of_gas_tokens()
+                .await
+                .map_err(|e| Error::from_reason(format!("Failed to get balance: {e}")))?;
+            Ok(balance.to_string())
+        })
+    }
+}
+
+/// Options for making payments on the network.
+#[napi(js_name = "PaymentOption")]
+pub struct JsPaymentOption {
+    inner: PaymentOption,
+}
+
+#[napi]
+impl JsPaymentOption {
+    /// Creates a payment option using the provided wallet.
+    #[napi(factory)]
+    pub fn from_wallet(wallet: &JsWallet) -> Self {
+        Self {
+            inner: PaymentOption::Wallet(wallet.inner.clone()),
+        }
+    }
+}
+
+/// A secret key used to own and sign data on the network.
+#[napi(js_name = "SecretKey")]
+pub struct JsSecretKey {
+    inner: SecretKey,
+}
+
+#[napi]
+impl JsSecretKey {
+    /// Creates a new random secret key.
+    #[napi(factory)]
+    pub fn random() -> Self {
+        Self {
+            inner: SecretKey::random(),
+        }
+    }
+
+    /// Creates a secret key from its hex representation.
+    #[napi(factory)]
+    pub fn from_hex(hex: String) -> Result<Self> {
+        let inner = SecretKey::from_hex(&hex)
+            .map_err(|e| Error::from_reason(format!("Invalid hex key: {e}")))?;
+        Ok(Self { inner })
+    }
+
+    /// Derives the corresponding public key.
+    #[napi]
+    pub fn public_key(&self) -> JsPublicKey {
+        JsPublicKey {
+            inner: self.inner.public_key(),
+        }
+    }
+
+    /// Returns the hex representation of the key.
+    #[napi]
+    pub fn to_hex(&self) -> String {
+        self.inner.to_hex()
+    }
+}
+
+/// A public key, identifying the owner of data on the network.
+#[napi(js_name = "PublicKey")]
+pub struct JsPublicKey {
+    inner: PublicKey,
+}
+
+#[napi]
+impl JsPublicKey {
+    /// Creates a public key from its hex representation.
+    #[napi(factory)]
+    pub fn from_hex(hex: String) -> Result<Self> {
+        Ok(Self {
+            inner: parse_public_key(&hex)?,
+        })
+    }
+
+    /// Returns the hex representation of the key.
+    #[napi]
+    pub fn to_hex(&self) -> String {
+        self.inner.to_hex()
+    }
+}
+
+fn parse_address(hex: &str) -> Result<DataAddr> {
+    str_to_addr(hex).map_err(|e| Error::from_reason(format!("Failed to parse address: {e}")))
+}
+
+fn parse_public_key(hex: &str) -> Result<PublicKey> {
+    PublicKey::from_hex(hex)
+        .map_err(|e| Error::from_reason(format!("Failed to parse public key: {e}")))
+}
+
+fn graph_content(content: Buffer) -> Result<[u8; 32]> {
+    content
+        .to_vec()
+        .try_into()
+        .map_err(|_| Error::from_reason("Graph entry content must be 32 bytes"))
+}
+
+fn big_int_to_u64(value: BigInt, name: &str) -> Result<u64> {
+    let (signed, value, lossless) = value.get_u64();
+    if signed || !lossless {
+        return Err(Error::from_reason(format!(
+            "`{name}` must fit in an unsigned 64-bit integer"
+        )));
+    }
+    Ok(value)
+}
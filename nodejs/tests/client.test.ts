@@ -1,33 +1,146 @@
-import { Client } from '../src/client';
+import {
+    Client,
+    Metadata,
+    Network,
+    PaymentOption,
+    PointerTargetKind,
+    PublicArchive,
+    SecretKey,
+    UserData,
+    Wallet,
+} from '../index';
+
+// These tests run against a local testnet, started with `cargo run --bin evm-testnet` and
+// `cargo run --bin antctl -- local run --build --clean --rewards-address <address>`.
+
+// Default deployer wallet of the testnet.
+const DEFAULT_WALLET_PRIVATE_KEY = '0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80';
+
+const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+// Wait for the data to be replicated.
+const replication = () => sleep(5000);
 
 describe('Client', () => {
-    describe('connect', () => {
-        it('should throw not implemented error', async () => {
-            await expect(Client.connect({ peers: [] })).rejects.toThrow('Not implemented');
-        });
-    });
-
-    describe('GraphEntryOperations', () => {
-        it('should throw not implemented error for GraphEntryGet', async () => {
-            const client = await Client.connect({ peers: [] }).catch(() => null);
-            if (!client) return;
-            await expect(client.GraphEntryGet('address')).rejects.toThrow('Not implemented');
-        });
-
-        it('should throw not implemented error for GraphEntryPut', async () => {
-            const client = await Client.connect({ peers: [] }).catch(() => null);
-            if (!client) return;
-            await expect(
-                client.GraphEntryPut(
-                    {
-                        owner: 'owner',
-                        counter: 0,
-                        target: 'target',
-                        key: 'key'
-                    },
-                    { type: 'wallet', wallet: 'wallet' }
-                )
-            ).rejects.toThrow('Not implemented');
-        });
-    });
-}); 
\ No newline at end of file
+    let client: Client;
+    let wallet: Wallet;
+    let payment: PaymentOption;
+
+    beforeAll(async () => {
+        client = await Client.initLocal();
+        const privateKey = process.env.SECRET_KEY ?? DEFAULT_WALLET_PRIVATE_KEY;
+        wallet = Wallet.newFromPrivateKey(new Network(true), privateKey);
+        payment = PaymentOption.fromWallet(wallet);
+    });
+
+    it('puts and gets public data', async () => {
+        const data = Buffer.from(`Hello, Autonomi! ${Date.now()}`);
+        const { address } = await client.dataPutPublic(data, payment);
+        await replication();
+
+        const got = await client.dataGetPublic(address);
+        expect(got.equals(data)).toBe(true);
+    });
+
+    it('puts and gets private data', async () => {
+        const data = Buffer.from(`Hello, private Autonomi! ${Date.now()}`);
+        const { address: dataMap } = await client.dataPut(data, payment);
+        await replication();
+
+        const got = await client.dataGet(dataMap);
+        expect(got.equals(data)).toBe(true);
+    });
+
+    it('puts and gets public archives', async () => {
+        const data = Buffer.from(`file content ${Date.now()}`);
+        const { address: dataAddress } = await client.dataPutPublic(data, payment);
+
+        const archive = new PublicArchive();
+        archive.addFile('folder/file.txt', dataAddress, new Metadata(BigInt(data.length)));
+        const { address } = await client.archivePutPublic(archive, wallet);
+        await replication();
+
+        const got = await client.archiveGetPublic(address);
+        const files = got.files();
+        expect(files).toHaveLength(1);
+        expect(files[0].path).toBe('folder/file.txt');
+        expect(files[0].address).toBe(dataAddress);
+        expect(files[0].size).toBe(BigInt(data.length));
+    });
+
+    it('creates and updates pointers', async () => {
+        const key = SecretKey.random();
+        const target = { kind: PointerTargetKind.Chunk, address: 'ab'.repeat(32) };
+        const { address } = await client.pointerCreate(key, target, payment);
+        await replication();
+
+        let pointer = await client.pointerGet(address);
+        expect(pointer.counter).toBe(0);
+        expect(pointer.target).toEqual(target);
+        expect(pointer.owner).toBe(key.publicKey().toHex());
+
+        const newTarget = { kind: PointerTargetKind.Pointer, address };
+        await client.pointerUpdate(key, newTarget);
+        await replication();
+
+        pointer = await client.pointerGet(address);
+        expect(pointer.counter).toBe(1);
+        expect(pointer.target).toEqual(newTarget);
+    });
+
+    it('creates and updates scratchpads', async () => {
+        const key = SecretKey.random();
+        const contentType = BigInt(42);
+        const { address } = await client.scratchpadCreate(key, contentType, Buffer.from('first'), payment);
+        await replication();
+
+        let scratchpad = await client.scratchpadGet(address);
+        expect(scratchpad.contentType).toBe(contentType);
+        expect(scratchpad.decryptData(key).toString()).toBe('first');
+
+        await client.scratchpadUpdate(key, contentType, Buffer.from('second'));
+        await replication();
+
+        scratchpad = await client.scratchpadGet(address);
+        expect(scratchpad.counter).toBe(BigInt(1));
+        expect(scratchpad.decryptData(key).toString()).toBe('second');
+    });
+
+    it('creates and updates registers', async () => {
+        const key = Client.registerKeyFromName(SecretKey.random(), 'register1');
+        const { address } = await client.registerCreate(key, Buffer.from('Hello, World!'), payment);
+        await replication();
+
+        expect((await client.registerGet(address)).toString()).toBe('Hello, World!');
+
+        // values do not have to fit in a single register entry
+        const large = Buffer.alloc(1000, 'a');
+        await client.registerUpdate(key, large, payment);
+        await replication();
+
+        expect((await client.registerGet(address)).equals(large)).toBe(true);
+    });
+
+    it('writes and reads vaults', async () => {
+        const key = SecretKey.random();
+        const contentType = BigInt(7);
+        const data = Buffer.from('vault content');
+        await client.writeBytesToVault(data, payment, key, contentType);
+        await replication();
+
+        const vault = await client.fetchAndDecryptVault(key);
+        expect(vault.data.equals(data)).toBe(true);
+        expect(vault.contentType).toBe(contentType);
+    });
+
+    it('puts and gets user data in vaults', async () => {
+        const key = SecretKey.random();
+        const userData = new UserData();
+        userData.addFileArchive('cd'.repeat(32), 'my archive');
+        await client.putUserDataToVault(key, payment, userData);
+        await replication();
+
+        const got = await client.getUserDataFromVault(key);
+        expect(got.fileArchives()).toEqual([{ address: 'cd'.repeat(32), name: 'my archive' }]);
+    });
+});